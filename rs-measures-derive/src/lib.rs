@@ -0,0 +1,227 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, DeriveInput, Expr, ExprLit, Lit, LitStr, Type};
+
+// The SI prefixes, with their names, their symbols, and their factors.
+const SI_PREFIXES: [(&str, &str, f64); 24] = [
+    ("Quecto", "q", 1e-30),
+    ("Ronto", "r", 1e-27),
+    ("Yocto", "y", 1e-24),
+    ("Zepto", "z", 1e-21),
+    ("Atto", "a", 1e-18),
+    ("Femto", "f", 1e-15),
+    ("Pico", "p", 1e-12),
+    ("Nano", "n", 1e-9),
+    ("Micro", "\u{b5}", 1e-6),
+    ("Milli", "m", 1e-3),
+    ("Centi", "c", 1e-2),
+    ("Deci", "d", 1e-1),
+    ("Deca", "da", 1e1),
+    ("Hecto", "h", 1e2),
+    ("Kilo", "k", 1e3),
+    ("Mega", "M", 1e6),
+    ("Giga", "G", 1e9),
+    ("Tera", "T", 1e12),
+    ("Peta", "P", 1e15),
+    ("Exa", "E", 1e18),
+    ("Zetta", "Z", 1e21),
+    ("Yotta", "Y", 1e24),
+    ("Ronna", "R", 1e27),
+    ("Quetta", "Q", 1e30),
+];
+
+// The binary prefixes, with their names, their symbols, and their factors.
+const BINARY_PREFIXES: [(&str, &str, f64); 8] = [
+    ("Kibi", "Ki", 1024.),
+    ("Mebi", "Mi", 1048576.),
+    ("Gibi", "Gi", 1073741824.),
+    ("Tebi", "Ti", 1099511627776.),
+    ("Pebi", "Pi", 1125899906842624.),
+    ("Exbi", "Ei", 1152921504606846976.),
+    ("Zebi", "Zi", 1180591620717411303424.),
+    ("Yobi", "Yi", 1208925819614629174706176.),
+];
+
+/// #[derive(MeasurementUnit)]
+/// #[unit(property = Property, ratio = 1., offset = 0., suffix = " u", cycle_fraction = 360.)]
+/// It implements the trait `MeasurementUnit` for the annotated type.
+/// The keys `property` and `suffix` are required.
+/// The key `ratio` defaults to 1, and the key `offset` defaults to 0.
+/// If the key `cycle_fraction` is specified, also the trait `AngleMeasurementUnit`
+/// is implemented.
+/// The flags `si_prefixes` and `binary_prefixes`, and the key `prefixes(Kilo, Milli, ...)`
+/// define also the units obtained by prefixing the annotated unit,
+/// like `KiloMetre` for `Metre`, with the SI prefixes, the binary prefixes,
+/// or the specified prefixes.
+/// They require that `suffix` is a string literal.
+#[proc_macro_derive(MeasurementUnit, attributes(unit))]
+pub fn derive_measurement_unit(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    measurement_unit_impl(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// #[derive(VectorProperty)]
+/// It implements the trait `VectorProperty` for the annotated type.
+#[proc_macro_derive(VectorProperty)]
+pub fn derive_vector_property(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::rs_measures::traits::VectorProperty for #name #type_generics #where_clause {}
+    }
+    .into()
+}
+
+fn measurement_unit_impl(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let mut property: Option<Type> = None;
+    let mut ratio: Option<Expr> = None;
+    let mut offset: Option<Expr> = None;
+    let mut suffix: Option<Expr> = None;
+    let mut cycle_fraction: Option<Expr> = None;
+    let mut prefixes: Vec<(&str, &str, f64)> = Vec::new();
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("unit"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let (slot, key) = if meta.path.is_ident("ratio") {
+                (&mut ratio, "ratio")
+            } else if meta.path.is_ident("offset") {
+                (&mut offset, "offset")
+            } else if meta.path.is_ident("suffix") {
+                (&mut suffix, "suffix")
+            } else if meta.path.is_ident("cycle_fraction") {
+                (&mut cycle_fraction, "cycle_fraction")
+            } else if meta.path.is_ident("si_prefixes") {
+                add_prefixes(&mut prefixes, &SI_PREFIXES);
+                return Ok(());
+            } else if meta.path.is_ident("binary_prefixes") {
+                add_prefixes(&mut prefixes, &BINARY_PREFIXES);
+                return Ok(());
+            } else if meta.path.is_ident("prefixes") {
+                return meta.parse_nested_meta(|prefix| {
+                    let found = SI_PREFIXES
+                        .iter()
+                        .chain(BINARY_PREFIXES.iter())
+                        .find(|(name, _, _)| prefix.path.is_ident(name));
+                    match found {
+                        Some(found) => {
+                            add_prefixes(&mut prefixes, &[*found]);
+                            Ok(())
+                        }
+                        None => Err(prefix.error("unknown SI or binary prefix")),
+                    }
+                });
+            } else if meta.path.is_ident("property") {
+                if property.is_some() {
+                    return Err(meta.error("duplicate key `property`"));
+                }
+                property = Some(meta.value()?.parse()?);
+                return Ok(());
+            } else {
+                return Err(meta.error(
+                    "unsupported key; expected `property`, `ratio`, `offset`, `suffix`, `cycle_fraction`, `si_prefixes`, `binary_prefixes`, or `prefixes`",
+                ));
+            };
+            if slot.is_some() {
+                return Err(meta.error(format_args!("duplicate key `{}`", key)));
+            }
+            *slot = Some(meta.value()?.parse()?);
+            Ok(())
+        })?;
+    }
+    let property = property.ok_or_else(|| {
+        syn::Error::new_spanned(&input.ident, "missing `property` in `#[unit(...)]`")
+    })?;
+    let suffix = suffix.ok_or_else(|| {
+        syn::Error::new_spanned(&input.ident, "missing `suffix` in `#[unit(...)]`")
+    })?;
+    let prefixed_suffix = if prefixes.is_empty() {
+        None
+    } else if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "prefixed units cannot be defined for generic types",
+        ));
+    } else if let Expr::Lit(ExprLit {
+        lit: Lit::Str(suffix),
+        ..
+    }) = &suffix
+    {
+        Some(suffix.value())
+    } else {
+        return Err(syn::Error::new_spanned(
+            &suffix,
+            "prefixed units require a string literal as `suffix`",
+        ));
+    };
+    let ratio = ratio.map_or_else(|| quote! { 1. }, |ratio| quote! { #ratio });
+    let offset = offset.map_or_else(|| quote! { 0. }, |offset| quote! { #offset });
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let mut output = quote! {
+        impl #impl_generics ::rs_measures::traits::MeasurementUnit for #name #type_generics #where_clause {
+            type Property = #property;
+            const RATIO: f64 = #ratio;
+            const OFFSET: f64 = #offset;
+            const SUFFIX: &'static str = #suffix;
+        }
+    };
+    let has_cycle_fraction = cycle_fraction.is_some();
+    if let Some(cycle_fraction) = cycle_fraction {
+        output.extend(quote! {
+            impl #impl_generics ::rs_measures::traits::AngleMeasurementUnit for #name #type_generics #where_clause {
+                const CYCLE_FRACTION: f64 = #cycle_fraction;
+            }
+        });
+    }
+    if let Some(suffix) = prefixed_suffix {
+        let visibility = &input.vis;
+        let spaces = suffix.len() - suffix.trim_start().len();
+        for (prefix, symbol, factor) in prefixes {
+            let prefixed_name = format_ident!("{}{}", prefix, name);
+            let prefixed_suffix = LitStr::new(
+                &format!("{}{}{}", &suffix[..spaces], symbol, &suffix[spaces..]),
+                name.span(),
+            );
+            let factor = proc_macro2::Literal::f64_unsuffixed(factor);
+            output.extend(quote! {
+                #visibility struct #prefixed_name;
+                impl ::rs_measures::traits::MeasurementUnit for #prefixed_name {
+                    type Property = #property;
+                    const RATIO: f64 = #factor * <#name as ::rs_measures::traits::MeasurementUnit>::RATIO;
+                    const OFFSET: f64 = <#name as ::rs_measures::traits::MeasurementUnit>::OFFSET;
+                    const SUFFIX: &'static str = #prefixed_suffix;
+                }
+            });
+            if has_cycle_fraction {
+                output.extend(quote! {
+                    impl ::rs_measures::traits::AngleMeasurementUnit for #prefixed_name {
+                        const CYCLE_FRACTION: f64 =
+                            <#name as ::rs_measures::traits::AngleMeasurementUnit>::CYCLE_FRACTION / #factor;
+                    }
+                });
+            }
+        }
+    }
+    Ok(output)
+}
+
+// It appends the specified prefixes to the list of the prefixes to apply,
+// skipping the ones already present.
+fn add_prefixes(
+    prefixes: &mut Vec<(&'static str, &'static str, f64)>,
+    to_add: &[(&'static str, &'static str, f64)],
+) {
+    for prefix in to_add {
+        if !prefixes.iter().any(|(name, _, _)| *name == prefix.0) {
+            prefixes.push(*prefix);
+        }
+    }
+}
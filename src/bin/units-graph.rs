@@ -0,0 +1,874 @@
+// It reads a Rust source file, or all the Rust source files of a directory,
+// containing definitions of properties, of units of measurement,
+// and of relationships among units, declared by `define_units_relationship!`.
+// Then, it exports the graph of such items, or it searches how a unit
+// can be obtained from other units.
+//
+// Usage:
+//     units-graph dot <pathname>
+//     units-graph json <pathname>
+//     units-graph derive <pathname> <target unit> <given unit>...
+//
+// The option `dot` prints the graph in the Graphviz DOT language.
+// The option `json` prints the graph in JSON format.
+// The option `derive` prints the sequence of operations which allows to compute
+// a measure in the target unit from measures in the given units.
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    if args.len() < 3 {
+        panic!(
+            "Usage: {} (dot|json) <pathname> | derive <pathname> <target unit> <given unit>...",
+            args[0]
+        );
+    }
+    let option = &args[1];
+    let graph = Graph::from_sources(&read_sources(&args[2]));
+    match option.as_str() {
+        "dot" if args.len() == 3 => print!("{}", graph.to_dot()),
+        "json" if args.len() == 3 => print!("{}", graph.to_json()),
+        "derive" if args.len() >= 5 => {
+            let target = &args[3];
+            let given = &args[4..];
+            match graph.derive(target, given) {
+                Some(steps) => {
+                    println!("{} can be obtained from {}:", target, given.join(", "));
+                    for step in steps {
+                        println!("    {}", step);
+                    }
+                }
+                None => {
+                    println!("{} cannot be obtained from {}.", target, given.join(", "));
+                    std::process::exit(1);
+                }
+            }
+        }
+        "dot" | "json" | "derive" => panic!("Wrong number of arguments for option \"{}\".", option),
+        _ => panic!("Invalid option \"{}\".", option),
+    }
+}
+
+// It returns the contents of the specified file,
+// or of all the Rust files contained in the specified directory and in its subdirectories.
+fn read_sources(pathname: &str) -> Vec<String> {
+    let path = std::path::Path::new(pathname);
+    if path.is_dir() {
+        let mut pathnames = Vec::new();
+        collect_rust_files(path, &mut pathnames);
+        pathnames.sort();
+        pathnames
+            .iter()
+            .map(|pathname| {
+                std::fs::read_to_string(pathname)
+                    .unwrap_or_else(|_| panic!("Failed to read file \"{}\".", pathname.display()))
+            })
+            .collect()
+    } else {
+        vec![std::fs::read_to_string(pathname)
+            .unwrap_or_else(|_| panic!("Failed to read file \"{}\".", pathname))]
+    }
+}
+
+fn collect_rust_files(directory: &std::path::Path, pathnames: &mut Vec<std::path::PathBuf>) {
+    let entries = std::fs::read_dir(directory)
+        .unwrap_or_else(|_| panic!("Failed to read directory \"{}\".", directory.display()));
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_rust_files(&path, pathnames);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            pathnames.push(path);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Number(String),
+    Str(String),
+    Punct(&'static str),
+    // The text following "// Property:" in a comment line.
+    PropertyComment(String),
+}
+
+// It splits Rust source code into the tokens needed to find
+// the definitions of properties, units, and relationships.
+// Other comments, lifetimes, and character literals are skipped.
+fn tokenize(source: &str) -> Vec<Token> {
+    const PUNCTUATIONS: [&str; 18] = [
+        "==", "::", "=", ":", ";", ",", "*", "/", "^", "+", "-", "!", "{", "}", "(", ")", "<", ">",
+    ];
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let rest: String = chars[i..chars.len().min(i + 2)].iter().collect();
+        if c.is_whitespace() {
+            i += 1;
+        } else if rest == "//" {
+            let end = chars[i..]
+                .iter()
+                .position(|&c| c == '\n')
+                .map_or(chars.len(), |n| i + n);
+            let comment: String = chars[i + 2..end].iter().collect();
+            if let Some(description) = comment.trim().strip_prefix("Property:") {
+                tokens.push(Token::PropertyComment(description.trim().to_string()));
+            }
+            i = end;
+        } else if rest == "/*" {
+            i = (i + 2..chars.len().saturating_sub(1))
+                .find(|&n| chars[n] == '*' && chars[n + 1] == '/')
+                .map_or(chars.len(), |n| n + 2);
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        } else if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_alphanumeric()
+                    || chars[i] == '_'
+                    || chars[i] == '.' && chars.get(i + 1).is_none_or(|c| !c.is_alphabetic())
+                    || (chars[i] == '+' || chars[i] == '-')
+                        && matches!(chars[i - 1], 'e' | 'E')
+                        && !chars[start..i].contains(&'x'))
+            {
+                i += 1;
+            }
+            tokens.push(Token::Number(chars[start..i].iter().collect()));
+        } else if c == '"' {
+            let mut text = String::new();
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                if chars[i] == '\\' && i + 1 < chars.len() {
+                    i += 1;
+                    match chars[i] {
+                        'n' => text.push('\n'),
+                        't' => text.push('\t'),
+                        'u' => {
+                            let end = chars[i..]
+                                .iter()
+                                .position(|&c| c == '}')
+                                .map_or(chars.len(), |n| i + n);
+                            let code: String = chars[i + 2..end].iter().collect();
+                            if let Some(c) =
+                                u32::from_str_radix(&code, 16).ok().and_then(char::from_u32)
+                            {
+                                text.push(c);
+                            }
+                            i = end;
+                        }
+                        c => text.push(c),
+                    }
+                } else {
+                    text.push(chars[i]);
+                }
+                i += 1;
+            }
+            tokens.push(Token::Str(text));
+            i += 1;
+        } else if c == '\'' {
+            // A character literal, like 'a' or '\n', or a lifetime, like 'static.
+            if chars.get(i + 1) == Some(&'\\') {
+                i = chars[i + 2..]
+                    .iter()
+                    .position(|&c| c == '\'')
+                    .map_or(chars.len(), |n| i + n + 3);
+            } else if chars.get(i + 2) == Some(&'\'') {
+                i += 3;
+            } else {
+                i += 1;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+            }
+        } else if let Some(punctuation) = PUNCTUATIONS
+            .iter()
+            .find(|punctuation| rest.starts_with(**punctuation))
+        {
+            tokens.push(Token::Punct(punctuation));
+            i += punctuation.len();
+        } else {
+            i += 1;
+        }
+    }
+    tokens
+}
+
+// It returns the position of the token closing the group opened at the specified position.
+fn closing_position(tokens: &[Token], open_position: usize) -> usize {
+    let mut depth = 0;
+    for (position, token) in tokens.iter().enumerate().skip(open_position) {
+        match token {
+            Token::Punct("{" | "(") => depth += 1,
+            Token::Punct("}" | ")") => {
+                depth -= 1;
+                if depth == 0 {
+                    return position;
+                }
+            }
+            _ => {}
+        }
+    }
+    tokens.len()
+}
+
+// It renders a sequence of tokens as source code.
+fn tokens_to_text(tokens: &[Token]) -> String {
+    let mut text = String::new();
+    for token in tokens {
+        match token {
+            Token::Ident(s) | Token::Number(s) => {
+                if text.ends_with(|c: char| c.is_alphanumeric() || c == '_' || c == '.') {
+                    text.push(' ');
+                }
+                text += s;
+            }
+            Token::Str(s) => write!(text, "{:?}", s).unwrap(),
+            Token::Punct(p @ (":" | "::" | "(" | ")" | "!")) => {
+                text.truncate(text.trim_end().len());
+                text += p;
+            }
+            Token::Punct(p) => {
+                if !text.is_empty() && !text.ends_with(['(', ' ']) {
+                    text.push(' ');
+                }
+                text += p;
+                text.push(' ');
+            }
+            Token::PropertyComment(_) => {}
+        }
+    }
+    text.trim().to_string()
+}
+
+struct Property {
+    name: String,
+    description: Option<String>,
+    is_vector: bool,
+}
+
+struct Unit {
+    name: String,
+    property: String,
+    ratio: String,
+    offset: String,
+    suffix: String,
+}
+
+// A unit of measurement used in a relationship, with the number of dimensions
+// of the measure, that is 1, 2, or 3.
+#[derive(Clone)]
+struct Operand {
+    unit: String,
+    dimensions: u32,
+}
+
+impl Operand {
+    fn to_json(&self) -> String {
+        format!(
+            "{{\"unit\": {}, \"dimensions\": {}}}",
+            json_string(&self.unit),
+            self.dimensions
+        )
+    }
+}
+
+enum Operation {
+    // The result is the product of the operands, multiplied or divided by an optional factor,
+    // like `* 1000` or `/ 1000`.
+    Product(Operand, Operand, Option<String>),
+    Quotient(Operand, Operand),
+    CrossProduct(Operand, Operand),
+    Inverse(Operand),
+    Power(Operand, u32),
+}
+
+struct Relationship {
+    text: String,
+    result: Operand,
+    operation: Operation,
+}
+
+impl Relationship {
+    // It parses the contents of an invocation of `define_units_relationship!`.
+    fn parse(tokens: &[Token]) -> Option<Relationship> {
+        let text = tokens_to_text(tokens);
+        let mut position = 0;
+        let result = parse_operand(tokens, &mut position, None)?;
+        if tokens.get(position) != Some(&Token::Punct("==")) {
+            return None;
+        }
+        position += 1;
+        let operation = if tokens.get(position) == Some(&Token::Number("1".to_string())) {
+            if tokens.get(position + 1) != Some(&Token::Punct("/")) {
+                return None;
+            }
+            position += 2;
+            Operation::Inverse(parse_operand(tokens, &mut position, None)?)
+        } else {
+            let left = parse_operand(tokens, &mut position, None)?;
+            let operator = tokens.get(position)?.clone();
+            position += 1;
+            match operator {
+                Token::Punct("^") => match tokens.get(position) {
+                    Some(Token::Number(exponent)) => {
+                        position += 1;
+                        Operation::Power(left, exponent.parse().ok()?)
+                    }
+                    _ => return None,
+                },
+                Token::Punct("*") => {
+                    let right = parse_operand(tokens, &mut position, Some(&left))?;
+                    let factor = match (tokens.get(position), tokens.get(position + 1)) {
+                        (
+                            Some(Token::Punct(operator @ ("*" | "/"))),
+                            Some(Token::Number(factor)),
+                        ) => {
+                            position += 2;
+                            Some(format!("{} {}", operator, factor))
+                        }
+                        _ => None,
+                    };
+                    Operation::Product(left, right, factor)
+                }
+                Token::Punct("/") => {
+                    Operation::Quotient(left, parse_operand(tokens, &mut position, None)?)
+                }
+                Token::Ident(ref x) if x == "X" => {
+                    let right = parse_operand(tokens, &mut position, Some(&left))?;
+                    Operation::CrossProduct(left, right)
+                }
+                _ => return None,
+            }
+        };
+        if position == tokens.len() {
+            Some(Relationship {
+                text,
+                result,
+                operation,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn operands(&self) -> Vec<&Operand> {
+        match &self.operation {
+            Operation::Product(left, right, _)
+            | Operation::Quotient(left, right)
+            | Operation::CrossProduct(left, right) => vec![left, right],
+            Operation::Inverse(operand) | Operation::Power(operand, _) => vec![operand],
+        }
+    }
+
+    fn symbol(&self) -> String {
+        match &self.operation {
+            Operation::Product(_, _, None) => "*".to_string(),
+            Operation::Product(_, _, Some(factor)) => format!("* {}", factor),
+            Operation::Quotient(..) => "/".to_string(),
+            Operation::CrossProduct(..) => "X".to_string(),
+            Operation::Inverse(_) => "1 /".to_string(),
+            Operation::Power(_, exponent) => format!("^ {}", exponent),
+        }
+    }
+
+    // It returns the ways to compute a scalar measure allowed by this relationship,
+    // as tuples containing the computed unit, the needed units, and the expression.
+    // Relationships involving vector measures are ignored.
+    fn scalar_derivations(&self) -> Vec<(String, Vec<String>, String)> {
+        if self.result.dimensions != 1 || self.operands().iter().any(|o| o.dimensions != 1) {
+            return vec![];
+        }
+        let u1 = &self.result.unit;
+        let mut derivations = Vec::new();
+        let mut add = |target: &String, needed: &[&String], expression: String| {
+            derivations.push((
+                target.clone(),
+                needed.iter().map(|unit| unit.to_string()).collect(),
+                expression,
+            ));
+        };
+        match &self.operation {
+            Operation::Product(left, right, _) => {
+                let (u2, u3) = (&left.unit, &right.unit);
+                add(u1, &[u2, u3], format!("{} * {}", u2, u3));
+                if u2 == u3 {
+                    add(u2, &[u1], format!("{}.sqrt()", u1));
+                } else {
+                    add(u2, &[u1, u3], format!("{} / {}", u1, u3));
+                    add(u3, &[u1, u2], format!("{} / {}", u1, u2));
+                }
+            }
+            Operation::Quotient(left, right) => {
+                let (u2, u3) = (&left.unit, &right.unit);
+                add(u1, &[u2, u3], format!("{} / {}", u2, u3));
+                add(u2, &[u1, u3], format!("{} * {}", u1, u3));
+                add(u3, &[u2, u1], format!("{} / {}", u2, u1));
+            }
+            Operation::Inverse(operand) => {
+                let u2 = &operand.unit;
+                add(u1, &[u2], format!("1. / {}", u2));
+                add(u2, &[u1], format!("1. / {}", u1));
+            }
+            Operation::Power(operand, 2) => {
+                let u2 = &operand.unit;
+                add(u1, &[u2], format!("{}.squared()", u2));
+                add(u2, &[u1], format!("{}.sqrt()", u1));
+            }
+            Operation::Power(operand, _) => {
+                let u2 = &operand.unit;
+                add(u1, &[u2], format!("{}.cubed()", u2));
+                add(u2, &[u1], format!("{}.cubic_root()", u1));
+            }
+            Operation::CrossProduct(..) => {}
+        }
+        derivations
+    }
+
+    fn to_json(&self) -> String {
+        let (name, extra) = match &self.operation {
+            Operation::Product(_, _, None) => ("product", String::new()),
+            Operation::Product(_, _, Some(factor)) => {
+                ("product", format!(", \"factor\": {}", json_string(factor)))
+            }
+            Operation::Quotient(..) => ("quotient", String::new()),
+            Operation::CrossProduct(..) => ("cross_product", String::new()),
+            Operation::Inverse(_) => ("inverse", String::new()),
+            Operation::Power(_, exponent) => ("power", format!(", \"exponent\": {}", exponent)),
+        };
+        format!(
+            "{{\"text\": {}, \"operation\": \"{}\", \"result\": {}, \"operands\": [{}]{}}}",
+            json_string(&self.text),
+            name,
+            self.result.to_json(),
+            self.operands()
+                .iter()
+                .map(|operand| operand.to_json())
+                .collect::<Vec<_>>()
+                .join(", "),
+            extra
+        )
+    }
+}
+
+// It parses a unit name, or the sign `=`, meaning the same unit as the previous operand,
+// optionally followed by a number of dimensions, like in `Metre:2`.
+fn parse_operand(
+    tokens: &[Token],
+    position: &mut usize,
+    previous: Option<&Operand>,
+) -> Option<Operand> {
+    let unit = match tokens.get(*position)? {
+        Token::Ident(name) => {
+            // A path, like `units::Metre`, is reduced to its last segment.
+            let mut name = name.clone();
+            while tokens.get(*position + 1) == Some(&Token::Punct("::")) {
+                *position += 2;
+                match tokens.get(*position)? {
+                    Token::Ident(segment) => name = segment.clone(),
+                    _ => return None,
+                }
+            }
+            name
+        }
+        Token::Punct("=") => previous?.unit.clone(),
+        _ => return None,
+    };
+    *position += 1;
+    let mut dimensions = 1;
+    if tokens.get(*position) == Some(&Token::Punct(":")) {
+        match tokens.get(*position + 1)? {
+            Token::Number(n) if n == "2" || n == "3" => dimensions = n.parse().unwrap(),
+            _ => return None,
+        }
+        *position += 2;
+    }
+    Some(Operand { unit, dimensions })
+}
+
+struct Graph {
+    properties: Vec<Property>,
+    units: Vec<Unit>,
+    relationships: Vec<Relationship>,
+}
+
+impl Graph {
+    fn from_sources(sources: &[String]) -> Graph {
+        let mut graph = Graph {
+            // The property and the unit defined by `define_measure_types!`.
+            properties: vec![Property {
+                name: "Angle".to_string(),
+                description: Some("angle".to_string()),
+                is_vector: false,
+            }],
+            units: vec![Unit {
+                name: "Radian".to_string(),
+                property: "Angle".to_string(),
+                ratio: "1.".to_string(),
+                offset: "0.".to_string(),
+                suffix: " rad".to_string(),
+            }],
+            relationships: Vec::new(),
+        };
+        let mut structs = Vec::new();
+        let mut vector_properties = Vec::new();
+        for source in sources {
+            graph.scan(&tokenize(source), &mut structs, &mut vector_properties);
+        }
+        // If the sources contain the library itself, `Radian` has been found again.
+        if graph.units[1..].iter().any(|unit| unit.name == "Radian") {
+            graph.units.remove(0);
+        }
+        // The declared structs used as properties of some units are properties.
+        for (name, description) in structs {
+            if name != "Angle" && graph.units.iter().any(|unit| unit.property == name) {
+                graph.properties.push(Property {
+                    is_vector: vector_properties.contains(&name),
+                    name,
+                    description,
+                });
+            }
+        }
+        graph
+    }
+
+    fn scan(
+        &mut self,
+        tokens: &[Token],
+        structs: &mut Vec<(String, Option<String>)>,
+        vector_properties: &mut Vec<String>,
+    ) {
+        let mut description = None;
+        let mut position = 0;
+        while position < tokens.len() {
+            match &tokens[position] {
+                Token::PropertyComment(text) => description = Some(text.clone()),
+                Token::Ident(keyword) if keyword == "struct" => {
+                    if let (Some(Token::Ident(name)), Some(Token::Punct(";"))) =
+                        (tokens.get(position + 1), tokens.get(position + 2))
+                    {
+                        structs.push((name.clone(), description.take()));
+                    }
+                }
+                Token::Ident(keyword) if keyword == "impl" => {
+                    let header_end = (position..tokens.len())
+                        .find(|&n| matches!(tokens[n], Token::Punct("{" | ";")))
+                        .unwrap_or(tokens.len());
+                    let header = &tokens[position + 1..header_end];
+                    let trait_name = header
+                        .iter()
+                        .take_while(|t| **t != Token::Ident("for".to_string()))
+                        .last();
+                    let type_name = match header {
+                        [.., Token::Ident(for_keyword), Token::Ident(name)]
+                            if for_keyword == "for" =>
+                        {
+                            Some(name.clone())
+                        }
+                        _ => None,
+                    };
+                    match (trait_name, type_name) {
+                        (Some(Token::Ident(trait_name)), Some(name))
+                            if trait_name == "MeasurementUnit" =>
+                        {
+                            let body_end = closing_position(tokens, header_end);
+                            self.units
+                                .push(parse_unit(name, &tokens[header_end + 1..body_end]));
+                            position = body_end;
+                        }
+                        (Some(Token::Ident(trait_name)), Some(name))
+                            if trait_name == "VectorProperty" =>
+                        {
+                            vector_properties.push(name);
+                        }
+                        _ => {}
+                    }
+                }
+                Token::Ident(name)
+                    if name == "define_units_relationship"
+                        && tokens.get(position + 1) == Some(&Token::Punct("!"))
+                        && matches!(tokens.get(position + 2), Some(Token::Punct("{" | "("))) =>
+                {
+                    let end = closing_position(tokens, position + 2);
+                    let contents = &tokens[position + 3..end.min(tokens.len())];
+                    match Relationship::parse(contents) {
+                        Some(relationship) => self.relationships.push(relationship),
+                        None => {
+                            eprintln!("Unrecognized relationship: {}", tokens_to_text(contents))
+                        }
+                    }
+                    position = end;
+                }
+                _ => {}
+            }
+            position += 1;
+        }
+    }
+
+    fn units_of(&self, property: &str) -> impl Iterator<Item = &Unit> {
+        let property = property.to_string();
+        self.units
+            .iter()
+            .filter(move |unit| unit.property == property)
+    }
+
+    fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph units {\n    rankdir=LR;\n    node [shape=box];\n");
+        for property in &self.properties {
+            writeln!(
+                dot,
+                "    subgraph {} {{",
+                dot_string(&format!("cluster_{}", property.name))
+            )
+            .unwrap();
+            writeln!(dot, "        label={};", dot_string(&property.name)).unwrap();
+            for unit in self.units_of(&property.name) {
+                writeln!(
+                    dot,
+                    "        {} [label={}];",
+                    dot_string(&unit.name),
+                    dot_string(&format!("{}\n{}", unit.name, unit.suffix.trim()))
+                )
+                .unwrap();
+            }
+            dot += "    }\n";
+        }
+        for (i, relationship) in self.relationships.iter().enumerate() {
+            let node = format!("relationship_{}", i);
+            writeln!(
+                dot,
+                "    {} [shape=circle, label={}, tooltip={}];",
+                dot_string(&node),
+                dot_string(&relationship.symbol()),
+                dot_string(&relationship.text)
+            )
+            .unwrap();
+            let is_ordered = matches!(
+                relationship.operation,
+                Operation::Quotient(..) | Operation::CrossProduct(..)
+            );
+            for (position, operand) in relationship.operands().iter().enumerate() {
+                let mut label = String::new();
+                if is_ordered {
+                    label += &(position + 1).to_string();
+                }
+                if operand.dimensions > 1 {
+                    write!(label, ":{}", operand.dimensions).unwrap();
+                }
+                write!(
+                    dot,
+                    "    {} -> {}",
+                    dot_string(&operand.unit),
+                    dot_string(&node)
+                )
+                .unwrap();
+                if !label.is_empty() {
+                    write!(dot, " [label={}]", dot_string(&label)).unwrap();
+                }
+                dot += ";\n";
+            }
+            write!(
+                dot,
+                "    {} -> {}",
+                dot_string(&node),
+                dot_string(&relationship.result.unit)
+            )
+            .unwrap();
+            if relationship.result.dimensions > 1 {
+                write!(dot, " [label=\":{}\"]", relationship.result.dimensions).unwrap();
+            }
+            dot += ";\n";
+        }
+        dot += "}\n";
+        dot
+    }
+
+    fn to_json(&self) -> String {
+        let properties = self
+            .properties
+            .iter()
+            .map(|property| {
+                format!(
+                    "{{\"name\": {}, \"description\": {}, \"vector\": {}, \"units\": [{}]}}",
+                    json_string(&property.name),
+                    property
+                        .description
+                        .as_deref()
+                        .map_or("null".to_string(), json_string),
+                    property.is_vector,
+                    self.units_of(&property.name)
+                        .map(|unit| json_string(&unit.name))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+            .collect::<Vec<_>>();
+        let units = self
+            .units
+            .iter()
+            .map(|unit| {
+                format!(
+                    "{{\"name\": {}, \"property\": {}, \"ratio\": {}, \"offset\": {}, \"suffix\": {}}}",
+                    json_string(&unit.name),
+                    json_string(&unit.property),
+                    json_string(&unit.ratio),
+                    json_string(&unit.offset),
+                    json_string(&unit.suffix)
+                )
+            })
+            .collect::<Vec<_>>();
+        let relationships = self
+            .relationships
+            .iter()
+            .map(|relationship| relationship.to_json())
+            .collect::<Vec<_>>();
+        format!(
+            "{{\n  \"properties\": [\n    {}\n  ],\n  \"units\": [\n    {}\n  ],\n  \"relationships\": [\n    {}\n  ]\n}}\n",
+            properties.join(",\n    "),
+            units.join(",\n    "),
+            relationships.join(",\n    ")
+        )
+    }
+
+    // It searches the shortest sequence of operations which computes a measure
+    // in the target unit from measures in the given units.
+    // Any unit may be converted to another unit of the same property.
+    // It returns the lines describing such operations,
+    // or `None` if the target unit cannot be obtained.
+    fn derive(&self, target: &str, given: &[String]) -> Option<Vec<String>> {
+        // For every obtained unit, the units needed to compute it, and the description of the step.
+        let mut obtained: BTreeMap<String, (Vec<String>, String)> = BTreeMap::new();
+        for unit in given {
+            obtained.insert(unit.clone(), (vec![], format!("{}: given", unit)));
+        }
+        let derivations: Vec<(String, Vec<String>, String, &str)> = self
+            .relationships
+            .iter()
+            .flat_map(|relationship| {
+                relationship.scalar_derivations().into_iter().map(
+                    move |(unit, needed, expression)| {
+                        (unit, needed, expression, relationship.text.as_str())
+                    },
+                )
+            })
+            .collect();
+        while !obtained.contains_key(target) {
+            // Every round adds only units computed from units obtained in the previous rounds.
+            let mut new_units = BTreeMap::new();
+            for (unit, needed, expression, text) in &derivations {
+                if !obtained.contains_key(unit)
+                    && !new_units.contains_key(unit)
+                    && needed.iter().all(|unit| obtained.contains_key(unit))
+                {
+                    new_units.insert(
+                        unit.clone(),
+                        (
+                            needed.clone(),
+                            format!("{} = {}    [{}]", unit, expression, text),
+                        ),
+                    );
+                }
+            }
+            for known in obtained.keys() {
+                if let Some(known_unit) = self.units.iter().find(|unit| &unit.name == known) {
+                    for unit in self.units_of(&known_unit.property) {
+                        if !obtained.contains_key(&unit.name) && !new_units.contains_key(&unit.name)
+                        {
+                            new_units.insert(
+                                unit.name.clone(),
+                                (
+                                    vec![known.clone()],
+                                    format!("{} = {}.convert::<{}>()", unit.name, known, unit.name),
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+            if new_units.is_empty() {
+                return None;
+            }
+            obtained.append(&mut new_units);
+        }
+        let mut steps = Vec::new();
+        collect_steps(target, &obtained, &mut steps);
+        Some(steps)
+    }
+}
+
+// It appends to `steps` the steps needed to compute `unit`, in execution order, without repetitions.
+fn collect_steps(
+    unit: &str,
+    obtained: &BTreeMap<String, (Vec<String>, String)>,
+    steps: &mut Vec<String>,
+) {
+    let (needed, step) = &obtained[unit];
+    for unit in needed {
+        collect_steps(unit, obtained, steps);
+    }
+    if !steps.contains(step) {
+        steps.push(step.clone());
+    }
+}
+
+// It parses the body of an implementation of `MeasurementUnit`.
+fn parse_unit(name: String, tokens: &[Token]) -> Unit {
+    let mut unit = Unit {
+        name,
+        property: String::new(),
+        ratio: String::new(),
+        offset: String::new(),
+        suffix: String::new(),
+    };
+    let mut position = 0;
+    while position < tokens.len() {
+        let item_end = (position..tokens.len())
+            .find(|&n| tokens[n] == Token::Punct(";"))
+            .unwrap_or(tokens.len());
+        let item = &tokens[position..item_end];
+        let value_start = item
+            .iter()
+            .position(|token| *token == Token::Punct("="))
+            .map_or(item.len(), |n| n + 1);
+        let value = &item[value_start..];
+        match item.get(1) {
+            Some(Token::Ident(key)) if key == "Property" => unit.property = tokens_to_text(value),
+            Some(Token::Ident(key)) if key == "RATIO" => unit.ratio = tokens_to_text(value),
+            Some(Token::Ident(key)) if key == "OFFSET" => unit.offset = tokens_to_text(value),
+            Some(Token::Ident(key)) if key == "SUFFIX" => {
+                if let [Token::Str(suffix)] = value {
+                    unit.suffix = suffix.clone();
+                }
+            }
+            _ => {}
+        }
+        position = item_end + 1;
+    }
+    unit
+}
+
+fn json_string(text: &str) -> String {
+    let mut result = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => result += "\\\"",
+            '\\' => result += "\\\\",
+            '\n' => result += "\\n",
+            c if (c as u32) < 0x20 => write!(result, "\\u{:04x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+fn dot_string(text: &str) -> String {
+    json_string(text)
+}
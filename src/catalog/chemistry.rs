@@ -0,0 +1,116 @@
+// Units of measurement of chemistry: amount of substance and its concentrations.
+use crate::traits::MeasurementUnit;
+
+// Property: amount of substance, count
+pub struct Amount;
+
+pub struct Unit;
+impl MeasurementUnit for Unit {
+    type Property = Amount;
+    const RATIO: f64 = 1. / 6.0221413e23;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " u.";
+}
+
+pub struct Dozen;
+impl MeasurementUnit for Dozen {
+    type Property = Amount;
+    const RATIO: f64 = 12. / 6.0221413e23;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " dz.";
+}
+
+pub struct Mole;
+impl MeasurementUnit for Mole {
+    type Property = Amount;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mol";
+}
+
+// Property: catalytic activity
+pub struct CatalyticActivity;
+
+pub struct Katal;
+impl MeasurementUnit for Katal {
+    type Property = CatalyticActivity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kat";
+}
+
+// Property: chemical potential, molar energy
+pub struct ChemicalPotential;
+
+pub struct JoulePerMole;
+impl MeasurementUnit for JoulePerMole {
+    type Property = ChemicalPotential;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " J/mol";
+}
+
+// Property: molar concentration
+pub struct MolarConcentration;
+
+pub struct MolePerCubicMetre;
+impl MeasurementUnit for MolePerCubicMetre {
+    type Property = MolarConcentration;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mol/m\u{b3}";
+}
+
+// Property: molar heat capacity, molar entropy
+pub struct MolarHeatCapacity;
+
+pub struct JoulePerKelvinPerMole;
+impl MeasurementUnit for JoulePerKelvinPerMole {
+    type Property = MolarHeatCapacity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " J/\u{b0}K/mol";
+}
+
+// Property: reaction rate, catalytic activity concentration
+pub struct ReactionRate;
+
+pub struct MolePerCubicMetrePerSecond;
+impl MeasurementUnit for MolePerCubicMetrePerSecond {
+    type Property = ReactionRate;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mol/m\u{b3}/s";
+}
+
+// It defines the relationships among the units of this module,
+// using also the units of the modules `dynamics`, `geometry`, `kinematics` and `thermodynamics`.
+// It is invoked by the macro `define_units_catalog_relationships`.
+#[macro_export]
+macro_rules! inner_define_catalog_chemistry {
+    { $with_2d:tt $with_3d:tt } => {
+        use rs_measures::catalog::chemistry::*;
+        use rs_measures::catalog::dynamics::*;
+        use rs_measures::catalog::geometry::*;
+        use rs_measures::catalog::kinematics::*;
+        use rs_measures::catalog::thermodynamics::*;
+
+        // CatalyticActivity == Amount / Time
+        rs_measures::define_units_relationship! {Mole == Katal * Second}
+
+        // ChemicalPotential == Energy / Amount
+        rs_measures::define_units_relationship! {Joule == JoulePerMole * Mole}
+
+        // MolarConcentration == Amount / Volume
+        rs_measures::define_units_relationship! {Mole == MolePerCubicMetre * CubicMetre}
+
+        // MolarHeatCapacity == ChemicalPotential / Temperature
+        rs_measures::define_units_relationship! {JoulePerMole == JoulePerKelvinPerMole * Kelvin}
+
+        // ReactionRate == MolarConcentration / Time
+        rs_measures::define_units_relationship! {MolePerCubicMetre == MolePerCubicMetrePerSecond * Second}
+
+        // ReactionRate == CatalyticActivity / Volume
+        rs_measures::define_units_relationship! {Katal == MolePerCubicMetrePerSecond * CubicMetre}
+    };
+}
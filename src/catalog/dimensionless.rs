@@ -0,0 +1,44 @@
+// Units of measurement of dimensionless quantities.
+use crate::traits::{MeasurementUnit, VectorProperty};
+
+// Property: dimensionless
+pub struct Dimensionless;
+impl VectorProperty for Dimensionless {}
+
+pub struct Unspecified;
+impl MeasurementUnit for Unspecified {
+    type Property = Dimensionless;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = "";
+}
+
+pub struct Mach;
+impl MeasurementUnit for Mach {
+    type Property = Dimensionless;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mach";
+}
+
+// It defines the relationships among the units of this module.
+// It is invoked by the macro `define_units_catalog_relationships`.
+#[macro_export]
+macro_rules! inner_define_catalog_dimensionless {
+    { $with_2d:tt $with_3d:tt } => {
+        use rs_measures::catalog::dimensionless::*;
+
+        // Dimensionless == Dimensionless * Dimensionless
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {Unspecified == Unspecified:2 * =:2} }
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {Unspecified == Unspecified:2 X =:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {Unspecified == Unspecified:3 * =:3} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {Unspecified:3 == Unspecified:3 X =:3} }
+        // N.B.: The following definition is not allowed:
+        // ```
+        // define_units_relationship! {Unspecified == Unspecified * Unspecified}
+        // ```
+        // This is because it would imply that `Measure<Unspecified> / Measure<Unspecified>`
+        // is a `Measure<Unspecified>`. Though, in general, that division is already defined
+        // to be a number.
+    };
+}
@@ -0,0 +1,759 @@
+// Units of measurement of dynamics: mass, force, energy, power, and pressure.
+use crate::traits::{MeasurementUnit, VectorProperty};
+
+// Property: action
+pub struct Action;
+
+pub struct JouleSecond;
+impl MeasurementUnit for JouleSecond {
+    type Property = Action;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " J\u{b7}s";
+}
+
+// Property: angular momentum, spin
+pub struct AngularMomentum;
+impl VectorProperty for AngularMomentum {}
+
+pub struct KiloGramSquareMetrePerSecond;
+impl MeasurementUnit for KiloGramSquareMetrePerSecond {
+    type Property = AngularMomentum;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kg\u{b7}m\u{b2}/s";
+}
+
+pub struct GramSquareCentiMetrePerSecond;
+impl MeasurementUnit for GramSquareCentiMetrePerSecond {
+    type Property = AngularMomentum;
+    const RATIO: f64 = 1e-7;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " g\u{b7}cm\u{b2}/s";
+}
+
+// Property: dynamic viscosity, absolute viscosity
+pub struct DynamicViscosity;
+
+pub struct PascalSecond;
+impl MeasurementUnit for PascalSecond {
+    type Property = DynamicViscosity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Pa\u{b7}s";
+}
+
+// Property: energy, work, heat
+pub struct Energy;
+
+pub struct Joule;
+impl MeasurementUnit for Joule {
+    type Property = Energy;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " J";
+}
+
+pub struct Erg;
+impl MeasurementUnit for Erg {
+    type Property = Energy;
+    const RATIO: f64 = 1e-7;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " erg";
+}
+
+pub struct WattHour;
+impl MeasurementUnit for WattHour {
+    type Property = Energy;
+    const RATIO: f64 = 3600.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " W\u{b7}h";
+}
+
+pub struct KiloWattHour;
+impl MeasurementUnit for KiloWattHour {
+    type Property = Energy;
+    const RATIO: f64 = 3.6e6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kW\u{b7}h";
+}
+
+pub struct MegaWattHour;
+impl MeasurementUnit for MegaWattHour {
+    type Property = Energy;
+    const RATIO: f64 = 3.6e9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " MW\u{b7}h";
+}
+
+pub struct Calorie;
+impl MeasurementUnit for Calorie {
+    type Property = Energy;
+    const RATIO: f64 = 4.187;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " cal";
+}
+
+pub struct KiloCalorie;
+impl MeasurementUnit for KiloCalorie {
+    type Property = Energy;
+    const RATIO: f64 = 4187.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kcal";
+}
+
+pub struct ElectronVolt;
+impl MeasurementUnit for ElectronVolt {
+    type Property = Energy;
+    const RATIO: f64 = 1.602176634e-19;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " eV";
+}
+
+pub struct KiloElectronVolt;
+impl MeasurementUnit for KiloElectronVolt {
+    type Property = Energy;
+    const RATIO: f64 = 1.602176634e-16;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " keV";
+}
+
+pub struct MegaElectronVolt;
+impl MeasurementUnit for MegaElectronVolt {
+    type Property = Energy;
+    const RATIO: f64 = 1.602176634e-13;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " MeV";
+}
+
+pub struct GigaElectronVolt;
+impl MeasurementUnit for GigaElectronVolt {
+    type Property = Energy;
+    const RATIO: f64 = 1.602176634e-10;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " GeV";
+}
+
+pub struct TeraElectronVolt;
+impl MeasurementUnit for TeraElectronVolt {
+    type Property = Energy;
+    const RATIO: f64 = 1.602176634e-7;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " TeV";
+}
+
+// Property: energy density
+pub struct EnergyDensity;
+
+pub struct JoulePerCubicMetre;
+impl MeasurementUnit for JoulePerCubicMetre {
+    type Property = EnergyDensity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " J/m\u{b3}";
+}
+
+// Property: force, weight
+pub struct Force;
+impl VectorProperty for Force {}
+
+pub struct Newton;
+impl MeasurementUnit for Newton {
+    type Property = Force;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " N";
+}
+
+pub struct Dyne;
+impl MeasurementUnit for Dyne {
+    type Property = Force;
+    const RATIO: f64 = 1e-5;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " dyn";
+}
+
+pub struct KiloGramForce;
+impl MeasurementUnit for KiloGramForce {
+    type Property = Force;
+    const RATIO: f64 = 9.80665;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kgf";
+}
+
+pub struct PoundForce;
+impl MeasurementUnit for PoundForce {
+    type Property = Force;
+    const RATIO: f64 = 4.448222;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " lbf";
+}
+
+pub struct Poundal;
+impl MeasurementUnit for Poundal {
+    type Property = Force;
+    const RATIO: f64 = 0.138255;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " pdl";
+}
+
+// Property: linear density
+pub struct LinearDensity;
+
+pub struct KiloGramPerMetre;
+impl MeasurementUnit for KiloGramPerMetre {
+    type Property = LinearDensity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kg/m";
+}
+
+pub struct GramPerCentiMetre;
+impl MeasurementUnit for GramPerCentiMetre {
+    type Property = LinearDensity;
+    const RATIO: f64 = 1e-1;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " g/cm";
+}
+
+// Property: mass
+pub struct Mass;
+
+pub struct KiloGram;
+impl MeasurementUnit for KiloGram {
+    type Property = Mass;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kg";
+}
+
+pub struct Tonne;
+impl MeasurementUnit for Tonne {
+    type Property = Mass;
+    const RATIO: f64 = 1e3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " t";
+}
+
+#[allow(dead_code)]
+pub type MetricTon = Tonne;
+
+pub struct MegaGram;
+impl MeasurementUnit for MegaGram {
+    type Property = Mass;
+    const RATIO: f64 = 1e3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Mg";
+}
+
+pub struct HectoGram;
+impl MeasurementUnit for HectoGram {
+    type Property = Mass;
+    const RATIO: f64 = 0.1;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " hg";
+}
+
+pub struct DecaGram;
+impl MeasurementUnit for DecaGram {
+    type Property = Mass;
+    const RATIO: f64 = 0.01;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " dag";
+}
+
+pub struct Gram;
+impl MeasurementUnit for Gram {
+    type Property = Mass;
+    const RATIO: f64 = 1e-3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " g";
+}
+
+pub struct MilliGram;
+impl MeasurementUnit for MilliGram {
+    type Property = Mass;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mg";
+}
+
+pub struct MicroGram;
+impl MeasurementUnit for MicroGram {
+    type Property = Mass;
+    const RATIO: f64 = 1e-9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{b5}g";
+}
+
+pub struct NanoGram;
+impl MeasurementUnit for NanoGram {
+    type Property = Mass;
+    const RATIO: f64 = 1e-12;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ng";
+}
+
+pub struct ImperialTon;
+impl MeasurementUnit for ImperialTon {
+    type Property = Mass;
+    const RATIO: f64 = 1016.0469;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " t";
+}
+
+#[allow(dead_code)]
+pub type LongTon = ImperialTon;
+
+pub struct USTon;
+impl MeasurementUnit for USTon {
+    type Property = Mass;
+    const RATIO: f64 = 907.18474;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " t";
+}
+
+#[allow(dead_code)]
+pub type ShortTon = USTon;
+
+pub struct Stone;
+impl MeasurementUnit for Stone {
+    type Property = Mass;
+    const RATIO: f64 = 6.35029;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " st.";
+}
+
+pub struct Pound;
+impl MeasurementUnit for Pound {
+    type Property = Mass;
+    const RATIO: f64 = 0.45359237;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " lb";
+}
+
+pub struct Ounce;
+impl MeasurementUnit for Ounce {
+    type Property = Mass;
+    const RATIO: f64 = 0.028349523;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " oz";
+}
+
+pub struct Carat;
+impl MeasurementUnit for Carat {
+    type Property = Mass;
+    const RATIO: f64 = 0.0002;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ct";
+}
+
+// Property: mass density
+pub struct MassDensity;
+
+pub struct KiloGramPerCubicMetre;
+impl MeasurementUnit for KiloGramPerCubicMetre {
+    type Property = MassDensity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kg/m\u{b3}";
+}
+
+pub struct GramPerMilliLitre;
+impl MeasurementUnit for GramPerMilliLitre {
+    type Property = MassDensity;
+    const RATIO: f64 = 1e3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " g/ml";
+}
+
+// Property: mass flow rate
+pub struct MassFlowRate;
+
+pub struct KiloGramPerSecond;
+impl MeasurementUnit for KiloGramPerSecond {
+    type Property = MassFlowRate;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kg/s";
+}
+
+pub struct GramPerSecond;
+impl MeasurementUnit for GramPerSecond {
+    type Property = MassFlowRate;
+    const RATIO: f64 = 1e-3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " g/s";
+}
+
+// Property: moment of inertia, rotational inertia
+pub struct MomentOfInertia;
+
+pub struct KiloGramSquareMetre;
+impl MeasurementUnit for KiloGramSquareMetre {
+    type Property = MomentOfInertia;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kg\u{b7}m\u{b2}";
+}
+
+pub struct GramSquareCentiMetre;
+impl MeasurementUnit for GramSquareCentiMetre {
+    type Property = MomentOfInertia;
+    const RATIO: f64 = 1e-7;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " g\u{b7}cm\u{b2}";
+}
+
+// Property: momentum, impulse
+pub struct Momentum;
+impl VectorProperty for Momentum {}
+
+pub struct NewtonSecond;
+impl MeasurementUnit for NewtonSecond {
+    type Property = Momentum;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " N\u{b7}s";
+}
+
+pub struct KiloGramMetrePerSecond;
+impl MeasurementUnit for KiloGramMetrePerSecond {
+    type Property = Momentum;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kg\u{b7}m/s";
+}
+
+pub struct DyneSecond;
+impl MeasurementUnit for DyneSecond {
+    type Property = Momentum;
+    const RATIO: f64 = 1e-5;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " dyn\u{b7}s";
+}
+
+pub struct GramCentiMetrePerSecond;
+impl MeasurementUnit for GramCentiMetrePerSecond {
+    type Property = Momentum;
+    const RATIO: f64 = 1e-5;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " g\u{b7}cm/s";
+}
+
+// Property: power
+pub struct Power;
+
+pub struct Watt;
+impl MeasurementUnit for Watt {
+    type Property = Power;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " W";
+}
+
+pub struct MilliWatt;
+impl MeasurementUnit for MilliWatt {
+    type Property = Power;
+    const RATIO: f64 = 1e-3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mW";
+}
+
+pub struct KiloWatt;
+impl MeasurementUnit for KiloWatt {
+    type Property = Power;
+    const RATIO: f64 = 1e3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kW";
+}
+
+pub struct MegaWatt;
+impl MeasurementUnit for MegaWatt {
+    type Property = Power;
+    const RATIO: f64 = 1e6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " MW";
+}
+
+pub struct GigaWatt;
+impl MeasurementUnit for GigaWatt {
+    type Property = Power;
+    const RATIO: f64 = 1e9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " GW";
+}
+
+pub struct ErgPerSecond;
+impl MeasurementUnit for ErgPerSecond {
+    type Property = Power;
+    const RATIO: f64 = 1e-7;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " erg/s";
+}
+
+pub struct HorsePower;
+impl MeasurementUnit for HorsePower {
+    type Property = Power;
+    const RATIO: f64 = 745.699872;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " HP";
+}
+
+// Property: pressure, stress
+pub struct Pressure;
+
+pub struct Pascal;
+impl MeasurementUnit for Pascal {
+    type Property = Pressure;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Pa";
+}
+
+pub struct HectoPascal;
+impl MeasurementUnit for HectoPascal {
+    type Property = Pressure;
+    const RATIO: f64 = 100.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " hPa";
+}
+
+pub struct Atmosphere;
+impl MeasurementUnit for Atmosphere {
+    type Property = Pressure;
+    const RATIO: f64 = 1.013e5;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " atm";
+}
+
+pub struct Bar;
+impl MeasurementUnit for Bar {
+    type Property = Pressure;
+    const RATIO: f64 = 1e5;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " bar";
+}
+
+pub struct MilliBar;
+impl MeasurementUnit for MilliBar {
+    type Property = Pressure;
+    const RATIO: f64 = 100.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mbar";
+}
+
+pub struct MmHg;
+impl MeasurementUnit for MmHg {
+    type Property = Pressure;
+    const RATIO: f64 = 133.322;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " torr";
+}
+
+pub struct PoundForcePerSquareInch;
+impl MeasurementUnit for PoundForcePerSquareInch {
+    type Property = Pressure;
+    const RATIO: f64 = 6894.757;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " lb/in\u{b2}";
+}
+
+// Property: specific energy
+pub struct SpecificEnergy;
+
+pub struct JoulePerKiloGram;
+impl MeasurementUnit for JoulePerKiloGram {
+    type Property = SpecificEnergy;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " J/kg";
+}
+
+// Property: specific volume
+pub struct SpecificVolume;
+
+pub struct CubicMetrePerKiloGram;
+impl MeasurementUnit for CubicMetrePerKiloGram {
+    type Property = SpecificVolume;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m\u{b3}/kg";
+}
+
+// Property: surface density
+pub struct SurfaceDensity;
+
+pub struct KiloGramPerSquareMetre;
+impl MeasurementUnit for KiloGramPerSquareMetre {
+    type Property = SurfaceDensity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kg/m\u{b2}";
+}
+
+// Property: surface tension
+pub struct SurfaceTension;
+
+pub struct JoulePerSquareMetre;
+impl MeasurementUnit for JoulePerSquareMetre {
+    type Property = SurfaceTension;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " J/m\u{b2}";
+}
+
+// Property: torque
+pub struct Torque;
+impl VectorProperty for Torque {}
+
+pub struct NewtonMetre;
+impl MeasurementUnit for NewtonMetre {
+    type Property = Torque;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " N\u{b7}m";
+}
+
+pub struct PoundFoot;
+impl MeasurementUnit for PoundFoot {
+    type Property = Torque;
+    const RATIO: f64 = 4.448222 * 0.3048;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " lbf-ft";
+}
+
+pub struct PoundInch;
+impl MeasurementUnit for PoundInch {
+    type Property = Torque;
+    const RATIO: f64 = 4.448222 * 0.0254;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " lbf-in";
+}
+
+// It defines the relationships among the units of this module,
+// using also the units of the modules `geometry` and `kinematics`.
+// It is invoked by the macro `define_units_catalog_relationships`.
+#[macro_export]
+macro_rules! inner_define_catalog_dynamics {
+    { $with_2d:tt $with_3d:tt } => {
+        use rs_measures::catalog::dynamics::*;
+        use rs_measures::catalog::geometry::*;
+        use rs_measures::catalog::kinematics::*;
+
+        // Action == Energy * Time
+        rs_measures::define_units_relationship! {JouleSecond == Joule * Second}
+
+        // Action == Power * SquareTime
+        rs_measures::define_units_relationship! {JouleSecond == Watt * SquareSecond}
+
+        // AngularMomentum == Momentum * Length
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {KiloGramSquareMetrePerSecond == KiloGramMetrePerSecond:2 X Metre:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {KiloGramSquareMetrePerSecond:3 == KiloGramMetrePerSecond:3 X Metre:3} }
+
+        // AngularMomentum == MomentOfInertia / Time
+        rs_measures::define_units_relationship! {KiloGramSquareMetre == KiloGramSquareMetrePerSecond * Second}
+
+        // DynamicViscosity == Pressure * Time
+        rs_measures::define_units_relationship! {PascalSecond == Pascal * Second}
+
+        // Energy == Force * Length
+        rs_measures::define_units_relationship! {Joule == Newton * Metre}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {Joule == Newton:2 * Metre:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {Joule == Newton:3 * Metre:3} }
+        rs_measures::define_units_relationship! {Erg == Dyne * CentiMetre}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {Erg == Dyne:2 * CentiMetre:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {Erg == Dyne:3 * CentiMetre:3} }
+
+        // Energy == Momentum * Velocity
+        rs_measures::define_units_relationship! {Joule == NewtonSecond * MetrePerSecond}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {Joule == NewtonSecond:2 * MetrePerSecond:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {Joule == NewtonSecond:3 * MetrePerSecond:3} }
+        rs_measures::define_units_relationship! {Erg == DyneSecond * CentiMetrePerSecond}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {Erg == DyneSecond:2 * CentiMetrePerSecond:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {Erg == DyneSecond:3 * CentiMetrePerSecond:3} }
+
+        // Energy == MomentOfInertia / SquareTime
+        rs_measures::define_units_relationship! {KiloGramSquareMetre == Joule * SquareSecond}
+
+        // EnergyDensity == Energy / Volume
+        rs_measures::define_units_relationship! {Joule == JoulePerCubicMetre * CubicMetre}
+
+        // Force == Mass * Acceleration
+        rs_measures::define_units_relationship! {Newton == KiloGram * MetrePerSquareSecond}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {Newton:2 == KiloGram * MetrePerSquareSecond:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {Newton:3 == KiloGram * MetrePerSquareSecond:3} }
+        rs_measures::define_units_relationship! {Dyne == Gram * CentiMetrePerSquareSecond}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {Dyne:2 == Gram * CentiMetrePerSquareSecond:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {Dyne:3 == Gram * CentiMetrePerSquareSecond:3} }
+        rs_measures::define_units_relationship! {KiloGramForce == KiloGram * GForce}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {KiloGramForce:2 == KiloGram * GForce:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {KiloGramForce:3 == KiloGram * GForce:3} }
+
+        // LinearDensity == Mass / Length
+        rs_measures::define_units_relationship! {KiloGram == KiloGramPerMetre * Metre}
+        rs_measures::define_units_relationship! {Gram == GramPerCentiMetre * CentiMetre}
+
+        // MassDensity == Mass / Volume
+        rs_measures::define_units_relationship! {KiloGram == KiloGramPerCubicMetre * CubicMetre}
+        rs_measures::define_units_relationship! {Gram == GramPerMilliLitre * MilliLitre}
+
+        // MassFlowRate == Mass / Time
+        rs_measures::define_units_relationship! {KiloGram == KiloGramPerSecond * Second}
+        rs_measures::define_units_relationship! {Gram == GramPerSecond * Second}
+
+        // MomentOfInertia == Mass * Area
+        rs_measures::define_units_relationship! {KiloGramSquareMetre == KiloGram * SquareMetre}
+        rs_measures::define_units_relationship! {GramSquareCentiMetre == Gram * SquareCentiMetre}
+
+        // Momentum == Force * Time
+        rs_measures::define_units_relationship! {NewtonSecond == Newton * Second}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {NewtonSecond:2 == Newton:2 * Second} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {NewtonSecond:3 == Newton:3 * Second} }
+        rs_measures::define_units_relationship! {DyneSecond == Dyne * Second}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {DyneSecond:2 == Dyne:2 * Second} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {DyneSecond:3 == Dyne:3 * Second} }
+
+        // Momentum == Mass * Velocity
+        rs_measures::define_units_relationship! {NewtonSecond == KiloGram * MetrePerSecond}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {NewtonSecond:2 == KiloGram * MetrePerSecond:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {NewtonSecond:3 == KiloGram * MetrePerSecond:3} }
+        rs_measures::define_units_relationship! {DyneSecond == Gram * CentiMetrePerSecond}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {DyneSecond:2 == Gram * CentiMetrePerSecond:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {DyneSecond:3 == Gram * CentiMetrePerSecond:3} }
+
+        // Power == Energy / Time
+        rs_measures::define_units_relationship! {Joule == Watt * Second}
+        rs_measures::define_units_relationship! {WattHour == Watt * Hour}
+        rs_measures::define_units_relationship! {KiloWattHour == KiloWatt * Hour}
+        rs_measures::define_units_relationship! {Erg == ErgPerSecond * Second}
+
+        // Pressure == Force / Area
+        rs_measures::define_units_relationship! {Newton == Pascal * SquareMetre}
+        rs_measures::define_units_relationship! {PoundForce == PoundForcePerSquareInch * SquareInch}
+        rs_measures::define_units_relationship! {Newton == HectoPascal * SquareDeciMetre}
+
+        // SpecificEnergy == Energy / Mass
+        rs_measures::define_units_relationship! {Joule == JoulePerKiloGram * KiloGram}
+
+        // SpecificVolume == Volume / Mass
+        rs_measures::define_units_relationship! {CubicMetre == CubicMetrePerKiloGram * KiloGram}
+
+        // SpecificVolume == 1 / MassDensity
+        rs_measures::define_units_relationship! {CubicMetrePerKiloGram == 1 / KiloGramPerCubicMetre}
+
+        // SurfaceDensity == Mass / Area
+        rs_measures::define_units_relationship! {KiloGram == KiloGramPerSquareMetre * SquareMetre}
+
+        // SurfaceTension == Energy / Area
+        rs_measures::define_units_relationship! {Joule == JoulePerSquareMetre * SquareMetre}
+
+        // Torque == Force * Length
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {NewtonMetre == Newton:2 X Metre:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {NewtonMetre:3 == Newton:3 X Metre:3} }
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {PoundFoot == PoundForce:2 X Foot:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {PoundFoot:3 == PoundForce:3 X Foot:3} }
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {PoundInch == PoundForce:2 X Inch:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {PoundInch:3 == PoundForce:3 X Inch:3} }
+    };
+}
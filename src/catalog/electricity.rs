@@ -0,0 +1,367 @@
+// Units of measurement of electricity.
+use crate::traits::{MeasurementUnit, VectorProperty};
+
+// Property: capacitance
+pub struct Capacitance;
+
+pub struct Farad;
+impl MeasurementUnit for Farad {
+    type Property = Capacitance;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " F";
+}
+
+pub struct MilliFarad;
+impl MeasurementUnit for MilliFarad {
+    type Property = Capacitance;
+    const RATIO: f64 = 1e-3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mF";
+}
+
+pub struct MicroFarad;
+impl MeasurementUnit for MicroFarad {
+    type Property = Capacitance;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{b5}F";
+}
+
+pub struct NanoFarad;
+impl MeasurementUnit for NanoFarad {
+    type Property = Capacitance;
+    const RATIO: f64 = 1e-9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " nF";
+}
+
+pub struct PicoFarad;
+impl MeasurementUnit for PicoFarad {
+    type Property = Capacitance;
+    const RATIO: f64 = 1e-12;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " pF";
+}
+
+// Property: current density
+pub struct CurrentDensity;
+impl VectorProperty for CurrentDensity {}
+
+pub struct AmperePerSquareMetre;
+impl MeasurementUnit for AmperePerSquareMetre {
+    type Property = CurrentDensity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " A/m\u{b2}";
+}
+
+// Property: electrical conductance, electric susceptance, electric admittance
+pub struct ElectricalConductance;
+
+pub struct Siemens;
+impl MeasurementUnit for Siemens {
+    type Property = ElectricalConductance;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " S";
+}
+
+// Property: electrical conductivity
+pub struct ElectricalConductivity;
+
+pub struct SiemensPerMetre;
+impl MeasurementUnit for SiemensPerMetre {
+    type Property = ElectricalConductivity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " S/m";
+}
+
+// Property: electrical resistance, electrical impedance
+pub struct ElectricalResistance;
+
+pub struct Ohm;
+impl MeasurementUnit for Ohm {
+    type Property = ElectricalResistance;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{3a9}";
+}
+
+pub struct MilliOhm;
+impl MeasurementUnit for MilliOhm {
+    type Property = ElectricalResistance;
+    const RATIO: f64 = 1e-3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m\u{3a9}";
+}
+
+pub struct KiloOhm;
+impl MeasurementUnit for KiloOhm {
+    type Property = ElectricalResistance;
+    const RATIO: f64 = 1e3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " k\u{3a9}";
+}
+
+// Property: electrical resistivity
+pub struct ElectricalResistivity;
+
+pub struct OhmMetre;
+impl MeasurementUnit for OhmMetre {
+    type Property = ElectricalResistivity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{3a9}\u{b7}m";
+}
+
+// Property: electric charge
+pub struct ElectricCharge;
+
+pub struct Coulomb;
+impl MeasurementUnit for Coulomb {
+    type Property = ElectricCharge;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " C";
+}
+
+pub struct MilliCoulomb;
+impl MeasurementUnit for MilliCoulomb {
+    type Property = ElectricCharge;
+    const RATIO: f64 = 1e-3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mC";
+}
+
+pub struct MicroCoulomb;
+impl MeasurementUnit for MicroCoulomb {
+    type Property = ElectricCharge;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{b5}C";
+}
+
+pub struct NanoCoulomb;
+impl MeasurementUnit for NanoCoulomb {
+    type Property = ElectricCharge;
+    const RATIO: f64 = 1e-9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " nC";
+}
+
+pub struct PicoCoulomb;
+impl MeasurementUnit for PicoCoulomb {
+    type Property = ElectricCharge;
+    const RATIO: f64 = 1e-12;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " pC";
+}
+
+// Property: electric charge density
+pub struct ElectricChargeDensity;
+
+pub struct CoulombPerCubicMetre;
+impl MeasurementUnit for CoulombPerCubicMetre {
+    type Property = ElectricChargeDensity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " C/m\u{b3}";
+}
+
+// Property: electric current
+pub struct ElectricCurrent;
+
+pub struct Ampere;
+impl MeasurementUnit for Ampere {
+    type Property = ElectricCurrent;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " A";
+}
+
+pub struct MilliAmpere;
+impl MeasurementUnit for MilliAmpere {
+    type Property = ElectricCurrent;
+    const RATIO: f64 = 1e-3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mA";
+}
+
+pub struct MicroAmpere;
+impl MeasurementUnit for MicroAmpere {
+    type Property = ElectricCurrent;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{b5}A";
+}
+
+// Property: electric displacement, surface electric charge density
+pub struct ElectricDisplacement;
+
+pub struct CoulombPerSquareMetre;
+impl MeasurementUnit for CoulombPerSquareMetre {
+    type Property = ElectricDisplacement;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " C/m\u{b2}";
+}
+
+// Property: electric field strength
+pub struct ElectricFieldStrength;
+impl VectorProperty for ElectricFieldStrength {}
+
+pub struct VoltPerMetre;
+impl MeasurementUnit for VoltPerMetre {
+    type Property = ElectricFieldStrength;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " V/m";
+}
+
+pub struct NewtonPerCoulomb;
+impl MeasurementUnit for NewtonPerCoulomb {
+    type Property = ElectricFieldStrength;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " N/C";
+}
+
+// Property: electric potential
+pub struct ElectricPotential;
+
+pub struct Volt;
+impl MeasurementUnit for Volt {
+    type Property = ElectricPotential;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " V";
+}
+
+pub struct KiloVolt;
+impl MeasurementUnit for KiloVolt {
+    type Property = ElectricPotential;
+    const RATIO: f64 = 1e3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kV";
+}
+
+pub struct MilliVolt;
+impl MeasurementUnit for MilliVolt {
+    type Property = ElectricPotential;
+    const RATIO: f64 = 1e-3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mV";
+}
+
+pub struct MicroVolt;
+impl MeasurementUnit for MicroVolt {
+    type Property = ElectricPotential;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{b5}V";
+}
+
+// Property: linear electric charge density
+pub struct LinearElectricChargeDensity;
+
+pub struct CoulombPerMetre;
+impl MeasurementUnit for CoulombPerMetre {
+    type Property = LinearElectricChargeDensity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " C/m";
+}
+
+// Property: permittivity
+pub struct Permittivity;
+
+pub struct FaradPerMetre;
+impl MeasurementUnit for FaradPerMetre {
+    type Property = Permittivity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " F/m";
+}
+
+// It defines the relationships among the units of this module,
+// using also the units of the modules `dynamics`, `geometry` and `kinematics`.
+// It is invoked by the macro `define_units_catalog_relationships`.
+#[macro_export]
+macro_rules! inner_define_catalog_electricity {
+    { $with_2d:tt $with_3d:tt } => {
+        use rs_measures::catalog::dynamics::*;
+        use rs_measures::catalog::electricity::*;
+        use rs_measures::catalog::geometry::*;
+        use rs_measures::catalog::kinematics::*;
+
+        // Capacitance == ElectricCharge / ElectricPotential
+        rs_measures::define_units_relationship! {Coulomb == Farad * Volt}
+        rs_measures::define_units_relationship! {MilliCoulomb == MilliFarad * Volt}
+        rs_measures::define_units_relationship! {Coulomb == MilliFarad * KiloVolt}
+        rs_measures::define_units_relationship! {MicroCoulomb == MicroFarad * Volt}
+        rs_measures::define_units_relationship! {MilliCoulomb == MicroFarad * KiloVolt}
+        rs_measures::define_units_relationship! {NanoCoulomb == NanoFarad * Volt}
+        rs_measures::define_units_relationship! {MicroCoulomb == NanoFarad * KiloVolt}
+        rs_measures::define_units_relationship! {PicoCoulomb == PicoFarad * Volt}
+        rs_measures::define_units_relationship! {NanoCoulomb == PicoFarad * KiloVolt}
+
+        // CurrentDensity == ElectricCurrent * Area
+        rs_measures::define_units_relationship! {Ampere == AmperePerSquareMetre * SquareMetre}
+
+        // ElectricalConductance == ElectricCurrent / ElectricPotential
+        rs_measures::define_units_relationship! {Ampere == Siemens * Volt}
+        rs_measures::define_units_relationship! {MilliAmpere == Siemens * MilliVolt}
+
+        // ElectricalConductance == 1 / ElectricalResistance
+        rs_measures::define_units_relationship! {Siemens == 1 / Ohm}
+
+        // ElectricalConductivity == ElectricalConductance / Length
+        rs_measures::define_units_relationship! {Siemens == SiemensPerMetre * Metre}
+
+        // ElectricalResistance == ElectricPotential / ElectricCurrent
+        rs_measures::define_units_relationship! {Volt == Ohm * Ampere}
+
+        // ElectricCurrent == ElectricCharge / Time
+        rs_measures::define_units_relationship! {Coulomb == Ampere * Second}
+        rs_measures::define_units_relationship! {MilliCoulomb == Ampere * MilliSecond}
+        rs_measures::define_units_relationship! {MilliCoulomb == MilliAmpere * Second}
+        rs_measures::define_units_relationship! {MicroCoulomb == MilliAmpere * MilliSecond}
+        rs_measures::define_units_relationship! {MicroCoulomb == MicroAmpere * Second}
+
+        // ElectricChargeDensity == ElectricCharge / Volume
+        rs_measures::define_units_relationship! {Coulomb == CoulombPerCubicMetre * CubicMetre}
+        rs_measures::define_units_relationship! {MilliCoulomb == CoulombPerCubicMetre * Litre}
+        rs_measures::define_units_relationship! {MicroCoulomb == CoulombPerCubicMetre * MilliLitre}
+
+        // ElectricDisplacement == ElectricCharge / Area
+        rs_measures::define_units_relationship! {Coulomb == CoulombPerSquareMetre * SquareMetre}
+        rs_measures::define_units_relationship! {MicroCoulomb == CoulombPerSquareMetre * SquareMilliMetre}
+
+        // ElectricFieldStrength == ElectricPotential / Length
+        rs_measures::define_units_relationship! {Volt == VoltPerMetre * Metre}
+
+        // ElectricFieldStrength == Force / ElectricCharge
+        rs_measures::define_units_relationship! {Newton == NewtonPerCoulomb * Coulomb}
+
+        // ElectricPotential == Power / ElectricCurrent
+        rs_measures::define_units_relationship! {Watt == Volt * Ampere}
+        rs_measures::define_units_relationship! {MilliWatt == Volt * MilliAmpere}
+        rs_measures::define_units_relationship! {Watt == KiloVolt * MilliAmpere}
+        rs_measures::define_units_relationship! {MilliWatt == KiloVolt * MicroAmpere}
+        rs_measures::define_units_relationship! {KiloWatt == KiloVolt * Ampere}
+        rs_measures::define_units_relationship! {MilliWatt == MilliVolt * Ampere}
+
+        // LinearElectricChargeDensity == ElectricCharge / Length
+        rs_measures::define_units_relationship! {Coulomb == CoulombPerMetre * Metre}
+        rs_measures::define_units_relationship! {MilliCoulomb == CoulombPerMetre * MilliMetre}
+        rs_measures::define_units_relationship! {MicroCoulomb == CoulombPerMetre * MicroMetre}
+
+        // Permittivity == Capacitance / Length
+        rs_measures::define_units_relationship! {Farad == FaradPerMetre * Metre}
+        rs_measures::define_units_relationship! {MilliFarad == FaradPerMetre * MilliMetre}
+        rs_measures::define_units_relationship! {MicroFarad == FaradPerMetre * MicroMetre}
+        rs_measures::define_units_relationship! {NanoFarad == FaradPerMetre * NanoMetre}
+    };
+}
@@ -0,0 +1,569 @@
+// Units of measurement of geometry: lengths, areas, volumes, and angles.
+use crate::angle::Angle;
+use crate::traits::{AngleMeasurementUnit, MeasurementUnit, VectorProperty};
+
+// Property: angle
+pub struct Cycle;
+impl MeasurementUnit for Cycle {
+    type Property = Angle;
+    const RATIO: f64 = core::f64::consts::TAU;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " cycles";
+}
+impl AngleMeasurementUnit for Cycle {
+    const CYCLE_FRACTION: f64 = 1.;
+}
+
+pub struct Gradian;
+impl MeasurementUnit for Gradian {
+    type Property = Angle;
+    const RATIO: f64 = core::f64::consts::TAU / 400.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " grad";
+}
+impl AngleMeasurementUnit for Gradian {
+    const CYCLE_FRACTION: f64 = 400.;
+}
+
+pub struct Degree;
+impl MeasurementUnit for Degree {
+    type Property = Angle;
+    const RATIO: f64 = core::f64::consts::TAU / 360.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " deg";
+}
+impl AngleMeasurementUnit for Degree {
+    const CYCLE_FRACTION: f64 = 360.;
+}
+
+pub struct ArcMinute;
+impl MeasurementUnit for ArcMinute {
+    type Property = Angle;
+    const RATIO: f64 = core::f64::consts::TAU / 360. / 60.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " deg'";
+}
+impl AngleMeasurementUnit for ArcMinute {
+    const CYCLE_FRACTION: f64 = 360. * 60.;
+}
+
+pub struct ArcSecond;
+impl MeasurementUnit for ArcSecond {
+    type Property = Angle;
+    const RATIO: f64 = core::f64::consts::TAU / 360. / 60. / 60.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " deg\"";
+}
+impl AngleMeasurementUnit for ArcSecond {
+    const CYCLE_FRACTION: f64 = 360. * 60. * 60.;
+}
+
+// Property: area
+pub struct Area;
+
+pub struct SquareMetre;
+impl MeasurementUnit for SquareMetre {
+    type Property = Area;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m\u{b2}";
+}
+
+pub struct SquareKiloMetre;
+impl MeasurementUnit for SquareKiloMetre {
+    type Property = Area;
+    const RATIO: f64 = 1e6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " km\u{b2}";
+}
+
+pub struct Hectare;
+impl MeasurementUnit for Hectare {
+    type Property = Area;
+    const RATIO: f64 = 1e4;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ha";
+}
+
+pub struct Are;
+impl MeasurementUnit for Are {
+    type Property = Area;
+    const RATIO: f64 = 100.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " are";
+}
+
+pub struct SquareDeciMetre;
+impl MeasurementUnit for SquareDeciMetre {
+    type Property = Area;
+    const RATIO: f64 = 0.01;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " dm\u{b2}";
+}
+
+pub struct SquareCentiMetre;
+impl MeasurementUnit for SquareCentiMetre {
+    type Property = Area;
+    const RATIO: f64 = 1e-4;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " cm\u{b2}";
+}
+
+pub struct SquareMilliMetre;
+impl MeasurementUnit for SquareMilliMetre {
+    type Property = Area;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mm\u{b2}";
+}
+
+pub struct SquareMicroMetre;
+impl MeasurementUnit for SquareMicroMetre {
+    type Property = Area;
+    const RATIO: f64 = 1e-12;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{b5}m\u{b2}";
+}
+
+pub struct SquareNanoMetre;
+impl MeasurementUnit for SquareNanoMetre {
+    type Property = Area;
+    const RATIO: f64 = 1e-18;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " nm\u{b2}";
+}
+
+pub struct SquareInch;
+impl MeasurementUnit for SquareInch {
+    type Property = Area;
+    const RATIO: f64 = 0.0254 * 0.0254;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " in\u{b2}";
+}
+
+pub struct SquareFoot;
+impl MeasurementUnit for SquareFoot {
+    type Property = Area;
+    const RATIO: f64 = 0.3048 * 0.3048;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ft\u{b2}";
+}
+
+pub struct SquareYard;
+impl MeasurementUnit for SquareYard {
+    type Property = Area;
+    const RATIO: f64 = 0.9144 * 0.9144;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " yd\u{b2}";
+}
+
+pub struct SquareMile;
+impl MeasurementUnit for SquareMile {
+    type Property = Area;
+    const RATIO: f64 = 1609. * 1609.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mi\u{b2}";
+}
+
+// Property: length, width, height, depth, space, wavelength
+pub struct Length;
+impl VectorProperty for Length {}
+
+pub struct Metre;
+impl MeasurementUnit for Metre {
+    type Property = Length;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m";
+}
+
+pub struct AstronomicalUnit;
+impl MeasurementUnit for AstronomicalUnit {
+    type Property = Length;
+    const RATIO: f64 = 149597870691.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " a.u.";
+}
+
+pub struct Parsec;
+impl MeasurementUnit for Parsec {
+    type Property = Length;
+    const RATIO: f64 = 3.0856775813e16;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " psc";
+}
+
+pub struct LightYear;
+impl MeasurementUnit for LightYear {
+    type Property = Length;
+    const RATIO: f64 = 31557600. * 2.99792458e8;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ly";
+}
+
+pub struct KiloMetre;
+impl MeasurementUnit for KiloMetre {
+    type Property = Length;
+    const RATIO: f64 = 1e3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " km";
+}
+
+pub struct HectoMetre;
+impl MeasurementUnit for HectoMetre {
+    type Property = Length;
+    const RATIO: f64 = 100.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " hm";
+}
+
+pub struct DecaMetre;
+impl MeasurementUnit for DecaMetre {
+    type Property = Length;
+    const RATIO: f64 = 10.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " dam";
+}
+
+pub struct DeciMetre;
+impl MeasurementUnit for DeciMetre {
+    type Property = Length;
+    const RATIO: f64 = 0.1;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " dm";
+}
+
+pub struct CentiMetre;
+impl MeasurementUnit for CentiMetre {
+    type Property = Length;
+    const RATIO: f64 = 0.01;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " cm";
+}
+
+pub struct MilliMetre;
+impl MeasurementUnit for MilliMetre {
+    type Property = Length;
+    const RATIO: f64 = 1e-3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mm";
+}
+
+pub struct MicroMetre;
+impl MeasurementUnit for MicroMetre {
+    type Property = Length;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{b5}m";
+}
+
+pub struct NanoMetre;
+impl MeasurementUnit for NanoMetre {
+    type Property = Length;
+    const RATIO: f64 = 1e-9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " nm";
+}
+
+pub struct Angstrom;
+impl MeasurementUnit for Angstrom {
+    type Property = Length;
+    const RATIO: f64 = 1e-10;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{212b}";
+}
+
+pub struct Inch;
+impl MeasurementUnit for Inch {
+    type Property = Length;
+    const RATIO: f64 = 0.0254;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " in";
+}
+
+pub struct Foot;
+impl MeasurementUnit for Foot {
+    type Property = Length;
+    const RATIO: f64 = 0.3048;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ft";
+}
+
+pub struct Yard;
+impl MeasurementUnit for Yard {
+    type Property = Length;
+    const RATIO: f64 = 0.9144;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " yd";
+}
+
+pub struct Mile;
+impl MeasurementUnit for Mile {
+    type Property = Length;
+    const RATIO: f64 = 1609.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mi";
+}
+
+pub struct NauticalMile;
+impl MeasurementUnit for NauticalMile {
+    type Property = Length;
+    const RATIO: f64 = 1852.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " naut.mi";
+}
+
+// Property: solid angle
+pub struct SolidAngle;
+
+pub struct Steradian;
+impl MeasurementUnit for Steradian {
+    type Property = SolidAngle;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " sr";
+}
+
+pub struct Spat;
+impl MeasurementUnit for Spat {
+    type Property = SolidAngle;
+    const RATIO: f64 = 2. * core::f64::consts::TAU;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " sp";
+}
+
+pub struct Sphere;
+impl MeasurementUnit for Sphere {
+    type Property = SolidAngle;
+    const RATIO: f64 = 2. * core::f64::consts::TAU;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " sphere";
+}
+
+pub struct SquareDegree;
+impl MeasurementUnit for SquareDegree {
+    type Property = SolidAngle;
+    const RATIO: f64 = core::f64::consts::TAU * core::f64::consts::TAU / 360. / 360.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " deg\u{b2}";
+}
+
+// Property: volume
+pub struct Volume;
+
+pub struct CubicMetre;
+impl MeasurementUnit for CubicMetre {
+    type Property = Volume;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m\u{b3}";
+}
+
+pub struct CubicKiloMetre;
+impl MeasurementUnit for CubicKiloMetre {
+    type Property = Volume;
+    const RATIO: f64 = 1e9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " km\u{b3}";
+}
+
+pub struct CubicMicroMetre;
+impl MeasurementUnit for CubicMicroMetre {
+    type Property = Volume;
+    const RATIO: f64 = 1e-18;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{b5}m\u{b3}?";
+}
+
+pub struct CubicNanoMetre;
+impl MeasurementUnit for CubicNanoMetre {
+    type Property = Volume;
+    const RATIO: f64 = 1e-27;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " nm\u{b3}?";
+}
+
+pub struct CubicInch;
+impl MeasurementUnit for CubicInch {
+    type Property = Volume;
+    const RATIO: f64 = 0.0254 * 0.0254 * 0.0254;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " in\u{b3}";
+}
+
+pub struct CubicFoot;
+impl MeasurementUnit for CubicFoot {
+    type Property = Volume;
+    const RATIO: f64 = 0.3048 * 0.3048 * 0.3048;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ft\u{b3}";
+}
+
+pub struct CubicYard;
+impl MeasurementUnit for CubicYard {
+    type Property = Volume;
+    const RATIO: f64 = 0.9144 * 0.9144 * 0.9144;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " yd\u{b3}";
+}
+
+pub struct CubicMile;
+impl MeasurementUnit for CubicMile {
+    type Property = Volume;
+    const RATIO: f64 = 1609. * 1609. * 1609.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mi\u{b3}";
+}
+
+pub struct Litre;
+impl MeasurementUnit for Litre {
+    type Property = Volume;
+    const RATIO: f64 = 1e-3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " l";
+}
+
+pub struct CubicDecimetre;
+impl MeasurementUnit for CubicDecimetre {
+    type Property = Volume;
+    const RATIO: f64 = 1e-3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " dm\u{b3}";
+}
+
+pub struct MilliLitre;
+impl MeasurementUnit for MilliLitre {
+    type Property = Volume;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ml";
+}
+
+pub struct CubicCentimetre;
+impl MeasurementUnit for CubicCentimetre {
+    type Property = Volume;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " cm\u{b3}";
+}
+
+pub struct MicroLitre;
+impl MeasurementUnit for MicroLitre {
+    type Property = Volume;
+    const RATIO: f64 = 1e-9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{b5}l";
+}
+
+pub struct CubicMillimetre;
+impl MeasurementUnit for CubicMillimetre {
+    type Property = Volume;
+    const RATIO: f64 = 1e-9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mm\u{b3}";
+}
+
+pub struct NanoLitre;
+impl MeasurementUnit for NanoLitre {
+    type Property = Volume;
+    const RATIO: f64 = 1e-12;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " nl";
+}
+
+pub struct PicoLitre;
+impl MeasurementUnit for PicoLitre {
+    type Property = Volume;
+    const RATIO: f64 = 1e-15;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " pl";
+}
+
+pub struct Pint;
+impl MeasurementUnit for Pint {
+    type Property = Volume;
+    const RATIO: f64 = 473.2e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " pt";
+}
+
+pub struct Gallon;
+impl MeasurementUnit for Gallon {
+    type Property = Volume;
+    const RATIO: f64 = 4546e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " gal";
+}
+
+// Property: wave number
+pub struct WaveNumber;
+
+pub struct CyclePerMetre;
+impl MeasurementUnit for CyclePerMetre {
+    type Property = WaveNumber;
+    const RATIO: f64 = core::f64::consts::TAU;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " 1/m";
+}
+
+pub struct RadianPerMetre;
+impl MeasurementUnit for RadianPerMetre {
+    type Property = WaveNumber;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " rad/m";
+}
+
+// It defines the relationships among the units of this module.
+// It is invoked by the macro `define_units_catalog_relationships`.
+#[macro_export]
+macro_rules! inner_define_catalog_geometry {
+    { $with_2d:tt $with_3d:tt } => {
+        use rs_measures::catalog::geometry::*;
+
+        // Area == Length * Length
+        rs_measures::define_units_relationship! {SquareMetre == Metre * =}
+        rs_measures::define_units_relationship! {SquareKiloMetre == KiloMetre * =}
+        rs_measures::define_units_relationship! {Hectare == HectoMetre * =}
+        rs_measures::define_units_relationship! {Are == DecaMetre * =}
+        rs_measures::define_units_relationship! {SquareDeciMetre == DeciMetre * =}
+        rs_measures::define_units_relationship! {SquareCentiMetre == CentiMetre * =}
+        rs_measures::define_units_relationship! {SquareMilliMetre == MilliMetre * =}
+        rs_measures::define_units_relationship! {SquareMicroMetre == MicroMetre * =}
+        rs_measures::define_units_relationship! {SquareNanoMetre == NanoMetre * =}
+        rs_measures::define_units_relationship! {SquareInch == Inch * =}
+        rs_measures::define_units_relationship! {SquareFoot == Foot * =}
+        rs_measures::define_units_relationship! {SquareYard == Yard * =}
+        rs_measures::define_units_relationship! {SquareMile == Mile * =}
+
+        // Volume == Area * Length
+        rs_measures::define_units_relationship! {CubicMetre == SquareMetre * Metre}
+        rs_measures::define_units_relationship! {CubicKiloMetre == SquareKiloMetre * KiloMetre}
+        rs_measures::define_units_relationship! {Litre == SquareDeciMetre * DeciMetre}
+        rs_measures::define_units_relationship! {MilliLitre == SquareCentiMetre * CentiMetre}
+        rs_measures::define_units_relationship! {MicroLitre == SquareMilliMetre * MilliMetre}
+        rs_measures::define_units_relationship! {CubicMicroMetre == SquareMicroMetre * MicroMetre}
+        rs_measures::define_units_relationship! {CubicNanoMetre == SquareNanoMetre * NanoMetre}
+        rs_measures::define_units_relationship! {CubicInch == SquareInch * Inch}
+        rs_measures::define_units_relationship! {CubicFoot == SquareFoot * Foot}
+        rs_measures::define_units_relationship! {CubicYard == SquareYard * Yard}
+        rs_measures::define_units_relationship! {CubicMile == SquareMile * Mile}
+
+        // Volume == Length ^ 3
+        rs_measures::define_units_relationship! {CubicMetre == Metre ^ 3}
+        rs_measures::define_units_relationship! {CubicKiloMetre == KiloMetre ^ 3}
+        rs_measures::define_units_relationship! {Litre == DeciMetre ^ 3}
+        rs_measures::define_units_relationship! {MilliLitre == CentiMetre ^ 3}
+        rs_measures::define_units_relationship! {MicroLitre == MilliMetre ^ 3}
+        rs_measures::define_units_relationship! {CubicMicroMetre == MicroMetre ^ 3}
+        rs_measures::define_units_relationship! {CubicNanoMetre == NanoMetre ^ 3}
+        rs_measures::define_units_relationship! {CubicInch == Inch ^ 3}
+        rs_measures::define_units_relationship! {CubicFoot == Foot ^ 3}
+        rs_measures::define_units_relationship! {CubicYard == Yard ^ 3}
+        rs_measures::define_units_relationship! {CubicMile == Mile ^ 3}
+
+        // WaveNumber == Angle / Length
+        rs_measures::define_units_relationship! {Cycle == CyclePerMetre * Metre}
+        rs_measures::define_units_relationship! {Radian == RadianPerMetre * Metre}
+    };
+}
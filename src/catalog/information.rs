@@ -0,0 +1,327 @@
+// Units of measurement of information and its transfer rate.
+use crate::traits::MeasurementUnit;
+
+// Property: information
+pub struct Information;
+
+pub struct Bit;
+impl MeasurementUnit for Bit {
+    type Property = Information;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " b";
+}
+
+pub struct Byte;
+impl MeasurementUnit for Byte {
+    type Property = Information;
+    const RATIO: f64 = 8.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " B";
+}
+
+pub struct KiloBit;
+impl MeasurementUnit for KiloBit {
+    type Property = Information;
+    const RATIO: f64 = 1e3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kb";
+}
+
+pub struct KiloByte;
+impl MeasurementUnit for KiloByte {
+    type Property = Information;
+    const RATIO: f64 = 8e3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kB";
+}
+
+pub struct KibiBit;
+impl MeasurementUnit for KibiBit {
+    type Property = Information;
+    const RATIO: f64 = 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kib";
+}
+
+pub struct KibiByte;
+impl MeasurementUnit for KibiByte {
+    type Property = Information;
+    const RATIO: f64 = 8. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kiB";
+}
+
+pub struct MegaBit;
+impl MeasurementUnit for MegaBit {
+    type Property = Information;
+    const RATIO: f64 = 1e6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Mb";
+}
+
+pub struct MegaByte;
+impl MeasurementUnit for MegaByte {
+    type Property = Information;
+    const RATIO: f64 = 8e6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " MB";
+}
+
+pub struct MebiBit;
+impl MeasurementUnit for MebiBit {
+    type Property = Information;
+    const RATIO: f64 = 1024. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Mib";
+}
+
+pub struct MebiByte;
+impl MeasurementUnit for MebiByte {
+    type Property = Information;
+    const RATIO: f64 = 8. * 1024. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " MiB";
+}
+
+pub struct GigaBit;
+impl MeasurementUnit for GigaBit {
+    type Property = Information;
+    const RATIO: f64 = 1e9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Gb";
+}
+
+pub struct GigaByte;
+impl MeasurementUnit for GigaByte {
+    type Property = Information;
+    const RATIO: f64 = 8e9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " GB";
+}
+
+pub struct GibiBit;
+impl MeasurementUnit for GibiBit {
+    type Property = Information;
+    const RATIO: f64 = 1024. * 1024. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Gib";
+}
+
+pub struct GibiByte;
+impl MeasurementUnit for GibiByte {
+    type Property = Information;
+    const RATIO: f64 = 8. * 1024. * 1024. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " GiB";
+}
+
+pub struct TeraBit;
+impl MeasurementUnit for TeraBit {
+    type Property = Information;
+    const RATIO: f64 = 1e12;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Tb";
+}
+
+pub struct TeraByte;
+impl MeasurementUnit for TeraByte {
+    type Property = Information;
+    const RATIO: f64 = 8e12;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " TB";
+}
+
+pub struct TebiBit;
+impl MeasurementUnit for TebiBit {
+    type Property = Information;
+    const RATIO: f64 = 1024. * 1024. * 1024. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Tib";
+}
+
+pub struct TebiByte;
+impl MeasurementUnit for TebiByte {
+    type Property = Information;
+    const RATIO: f64 = 8. * 1024. * 1024. * 1024. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " TiB";
+}
+
+// Property: information rate
+pub struct InformationRate;
+
+pub struct BitPerSecond;
+impl MeasurementUnit for BitPerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " b/s";
+}
+
+pub struct BytePerSecond;
+impl MeasurementUnit for BytePerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 8.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " B/s";
+}
+
+pub struct KiloBitPerSecond;
+impl MeasurementUnit for KiloBitPerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 1e3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kb/s";
+}
+
+pub struct KiloBytePerSecond;
+impl MeasurementUnit for KiloBytePerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 8e3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kB/s";
+}
+
+pub struct KibiBitPerSecond;
+impl MeasurementUnit for KibiBitPerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kib/s";
+}
+
+pub struct KibiBytePerSecond;
+impl MeasurementUnit for KibiBytePerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 8. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kiB/s";
+}
+
+pub struct MegaBitPerSecond;
+impl MeasurementUnit for MegaBitPerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 1e6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Mb/s";
+}
+
+pub struct MegaBytePerSecond;
+impl MeasurementUnit for MegaBytePerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 8e6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " MB/s";
+}
+
+pub struct MebiBitPerSecond;
+impl MeasurementUnit for MebiBitPerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 1024. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Mib/s";
+}
+
+pub struct MebiBytePerSecond;
+impl MeasurementUnit for MebiBytePerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 8. * 1024. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " MiB/s";
+}
+
+pub struct GigaBitPerSecond;
+impl MeasurementUnit for GigaBitPerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 1e9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Gb/s";
+}
+
+pub struct GigaBytePerSecond;
+impl MeasurementUnit for GigaBytePerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 8e9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " GB/s";
+}
+
+pub struct GibiBitPerSecond;
+impl MeasurementUnit for GibiBitPerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 1024. * 1024. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Gib/s";
+}
+
+pub struct GibiBytePerSecond;
+impl MeasurementUnit for GibiBytePerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 8. * 1024. * 1024. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " GiB/s";
+}
+
+pub struct TeraBitPerSecond;
+impl MeasurementUnit for TeraBitPerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 1e12;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Tb/s";
+}
+
+pub struct TeraBytePerSecond;
+impl MeasurementUnit for TeraBytePerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 8e12;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " TB/s";
+}
+
+pub struct TebiBitPerSecond;
+impl MeasurementUnit for TebiBitPerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 1024. * 1024. * 1024. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Tib/s";
+}
+
+pub struct TebiBytePerSecond;
+impl MeasurementUnit for TebiBytePerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 8. * 1024. * 1024. * 1024. * 1024.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " TiB/s";
+}
+
+// It defines the relationships among the units of this module,
+// using also the units of the module `kinematics`.
+// It is invoked by the macro `define_units_catalog_relationships`.
+#[macro_export]
+macro_rules! inner_define_catalog_information {
+    { $with_2d:tt $with_3d:tt } => {
+        use rs_measures::catalog::information::*;
+        use rs_measures::catalog::kinematics::*;
+
+        // InformationRate == Information / Time
+        rs_measures::define_units_relationship! {Bit == BitPerSecond * Second}
+        rs_measures::define_units_relationship! {Byte == BytePerSecond * Second}
+        rs_measures::define_units_relationship! {KiloBit == KiloBitPerSecond * Second}
+        rs_measures::define_units_relationship! {KiloByte == KiloBytePerSecond * Second}
+        rs_measures::define_units_relationship! {KibiBit == KibiBitPerSecond * Second}
+        rs_measures::define_units_relationship! {KibiByte == KibiBytePerSecond * Second}
+        rs_measures::define_units_relationship! {MegaBit == MegaBitPerSecond * Second}
+        rs_measures::define_units_relationship! {MegaByte == MegaBytePerSecond * Second}
+        rs_measures::define_units_relationship! {MebiBit == MebiBitPerSecond * Second}
+        rs_measures::define_units_relationship! {MebiByte == MebiBytePerSecond * Second}
+        rs_measures::define_units_relationship! {GigaBit == GigaBitPerSecond * Second}
+        rs_measures::define_units_relationship! {GigaByte == GigaBytePerSecond * Second}
+        rs_measures::define_units_relationship! {GibiBit == GibiBitPerSecond * Second}
+        rs_measures::define_units_relationship! {GibiByte == GibiBytePerSecond * Second}
+        rs_measures::define_units_relationship! {TeraBit == TeraBitPerSecond * Second}
+        rs_measures::define_units_relationship! {TeraByte == TeraBytePerSecond * Second}
+        rs_measures::define_units_relationship! {TebiBit == TebiBitPerSecond * Second}
+        rs_measures::define_units_relationship! {TebiByte == TebiBytePerSecond * Second}
+    };
+}
@@ -0,0 +1,414 @@
+// Units of measurement of kinematics: time, velocity, acceleration, and frequency.
+use crate::traits::{MeasurementUnit, VectorProperty};
+
+// Property: acceleration
+pub struct Acceleration;
+impl VectorProperty for Acceleration {}
+
+pub struct MetrePerSquareSecond;
+impl MeasurementUnit for MetrePerSquareSecond {
+    type Property = Acceleration;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m/s\u{b2}";
+}
+
+pub struct CentiMetrePerSquareSecond;
+impl MeasurementUnit for CentiMetrePerSquareSecond {
+    type Property = Acceleration;
+    const RATIO: f64 = 1e-2;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " cm/s\u{b2}";
+}
+
+pub struct GForce;
+impl MeasurementUnit for GForce {
+    type Property = Acceleration;
+    const RATIO: f64 = 9.80665;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " g";
+}
+
+pub struct KiloMetrePerHourPerSecond;
+impl MeasurementUnit for KiloMetrePerHourPerSecond {
+    type Property = Acceleration;
+    const RATIO: f64 = 1000. / 3600.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " km/h/s";
+}
+
+// Property: angular acceleration
+pub struct AngularAcceleration;
+
+pub struct RadianPerSquareSecond;
+impl MeasurementUnit for RadianPerSquareSecond {
+    type Property = AngularAcceleration;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " rad/s\u{b2}";
+}
+
+// Property: frequency, angular speed, angular velocity
+pub struct Frequency;
+
+pub struct Hertz;
+impl MeasurementUnit for Hertz {
+    type Property = Frequency;
+    const RATIO: f64 = core::f64::consts::TAU;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Hz";
+}
+
+pub struct CyclePerSecond;
+impl MeasurementUnit for CyclePerSecond {
+    type Property = Frequency;
+    const RATIO: f64 = core::f64::consts::TAU;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " c/s";
+}
+
+pub struct KiloHertz;
+impl MeasurementUnit for KiloHertz {
+    type Property = Frequency;
+    const RATIO: f64 = 1e3 * core::f64::consts::TAU;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kHz";
+}
+
+pub struct MegaHertz;
+impl MeasurementUnit for MegaHertz {
+    type Property = Frequency;
+    const RATIO: f64 = 1e6 * core::f64::consts::TAU;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " MHz";
+}
+
+pub struct GigaHertz;
+impl MeasurementUnit for GigaHertz {
+    type Property = Frequency;
+    const RATIO: f64 = 1e9 * core::f64::consts::TAU;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " GHz";
+}
+
+pub struct RadianPerSecond;
+impl MeasurementUnit for RadianPerSecond {
+    type Property = Frequency;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " rad/s";
+}
+
+pub struct CyclePerMinute;
+impl MeasurementUnit for CyclePerMinute {
+    type Property = Frequency;
+    const RATIO: f64 = core::f64::consts::TAU / 60.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " rpm";
+}
+
+// Property: kinematic viscosity
+pub struct KinematicViscosity;
+
+pub struct SquareMetrePerSecond;
+impl MeasurementUnit for SquareMetrePerSecond {
+    type Property = KinematicViscosity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m\u{b2}/s";
+}
+
+pub struct Stoke;
+impl MeasurementUnit for Stoke {
+    type Property = KinematicViscosity;
+    const RATIO: f64 = 1e-4;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " St";
+}
+
+pub struct CentiStoke;
+impl MeasurementUnit for CentiStoke {
+    type Property = KinematicViscosity;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " cSt";
+}
+
+// Property: square time
+pub struct SquareTime;
+
+pub struct SquareSecond;
+impl MeasurementUnit for SquareSecond {
+    type Property = SquareTime;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " s\u{b2}";
+}
+
+pub struct HourSecond;
+impl MeasurementUnit for HourSecond {
+    type Property = SquareTime;
+    const RATIO: f64 = 3600.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " h\u{b7}s";
+}
+
+pub struct HourHour;
+impl MeasurementUnit for HourHour {
+    type Property = SquareTime;
+    const RATIO: f64 = 3600. * 3600.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " h\u{b7}h";
+}
+
+// Property: time, mean lifetime
+pub struct Time;
+
+pub struct Second;
+impl MeasurementUnit for Second {
+    type Property = Time;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " s";
+}
+
+pub struct Year;
+impl MeasurementUnit for Year {
+    type Property = Time;
+    const RATIO: f64 = 365.25 * 86400.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Y";
+}
+
+pub struct Month;
+impl MeasurementUnit for Month {
+    type Property = Time;
+    const RATIO: f64 = 30. * 86400.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " M";
+}
+
+pub struct Week;
+impl MeasurementUnit for Week {
+    type Property = Time;
+    const RATIO: f64 = 7. * 86400.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " W";
+}
+
+pub struct Day;
+impl MeasurementUnit for Day {
+    type Property = Time;
+    const RATIO: f64 = 86400.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " D";
+}
+
+pub struct Hour;
+impl MeasurementUnit for Hour {
+    type Property = Time;
+    const RATIO: f64 = 3600.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " h";
+}
+
+pub struct Minute;
+impl MeasurementUnit for Minute {
+    type Property = Time;
+    const RATIO: f64 = 60.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " min";
+}
+
+pub struct MilliSecond;
+impl MeasurementUnit for MilliSecond {
+    type Property = Time;
+    const RATIO: f64 = 1e-3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ms";
+}
+
+pub struct MicroSecond;
+impl MeasurementUnit for MicroSecond {
+    type Property = Time;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{b5}s";
+}
+
+pub struct NanoSecond;
+impl MeasurementUnit for NanoSecond {
+    type Property = Time;
+    const RATIO: f64 = 1e-9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ns";
+}
+
+pub struct PicoSecond;
+impl MeasurementUnit for PicoSecond {
+    type Property = Time;
+    const RATIO: f64 = 1e-12;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ps";
+}
+
+pub struct FemtoSecond;
+impl MeasurementUnit for FemtoSecond {
+    type Property = Time;
+    const RATIO: f64 = 1e-15;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " fs";
+}
+
+// Property: velocity, speed
+pub struct Velocity;
+impl VectorProperty for Velocity {}
+
+pub struct MetrePerSecond;
+impl MeasurementUnit for MetrePerSecond {
+    type Property = Velocity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m/s";
+}
+
+pub struct Knot;
+impl MeasurementUnit for Knot {
+    type Property = Velocity;
+    const RATIO: f64 = 1852. / 3600.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kt";
+}
+
+pub struct KiloMetrePerHour;
+impl MeasurementUnit for KiloMetrePerHour {
+    type Property = Velocity;
+    const RATIO: f64 = 1000. / 3600.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " km/h";
+}
+
+pub struct MilePerHour;
+impl MeasurementUnit for MilePerHour {
+    type Property = Velocity;
+    const RATIO: f64 = 1609. / 3600.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mi/h";
+}
+
+pub struct CentiMetrePerSecond;
+impl MeasurementUnit for CentiMetrePerSecond {
+    type Property = Velocity;
+    const RATIO: f64 = 0.01;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " cm/s";
+}
+
+pub struct KiloMetrePerSecond;
+impl MeasurementUnit for KiloMetrePerSecond {
+    type Property = Velocity;
+    const RATIO: f64 = 1000.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " km/s";
+}
+
+// Property: volumetric flow rate
+pub struct VolumetricFlowRate;
+
+pub struct CubicMetrePerSecond;
+impl MeasurementUnit for CubicMetrePerSecond {
+    type Property = VolumetricFlowRate;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m\u{b3}/s";
+}
+
+pub struct MilliLitrePerSecond;
+impl MeasurementUnit for MilliLitrePerSecond {
+    type Property = VolumetricFlowRate;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ml/s";
+}
+
+pub struct CubicCentimetrePerSecond;
+impl MeasurementUnit for CubicCentimetrePerSecond {
+    type Property = VolumetricFlowRate;
+    const RATIO: f64 = 1e-6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " cm\u{b3}/s";
+}
+
+// It defines the relationships among the units of this module,
+// using also the units of the module `geometry`.
+// It is invoked by the macro `define_units_catalog_relationships`.
+#[macro_export]
+macro_rules! inner_define_catalog_kinematics {
+    { $with_2d:tt $with_3d:tt } => {
+        use rs_measures::catalog::geometry::*;
+        use rs_measures::catalog::kinematics::*;
+
+        // Acceleration == Velocity / Time
+        rs_measures::define_units_relationship! {MetrePerSecond == MetrePerSquareSecond * Second}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {MetrePerSecond:2 == MetrePerSquareSecond:2 * Second} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {MetrePerSecond:3 == MetrePerSquareSecond:3 * Second} }
+        rs_measures::define_units_relationship! {CentiMetrePerSecond == CentiMetrePerSquareSecond * Second}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {CentiMetrePerSecond:2 == CentiMetrePerSquareSecond:2 * Second} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {CentiMetrePerSecond:3 == CentiMetrePerSquareSecond:3 * Second} }
+        rs_measures::define_units_relationship! {KiloMetrePerHour == KiloMetrePerHourPerSecond * Second}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {KiloMetrePerHour:2 == KiloMetrePerHourPerSecond:2 * Second} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {KiloMetrePerHour:3 == KiloMetrePerHourPerSecond:3 * Second} }
+
+        // Velocity == Length / Time
+        rs_measures::define_units_relationship! {Metre == MetrePerSecond * Second}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {Metre:2 == MetrePerSecond:2 * Second} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {Metre:3 == MetrePerSecond:3 * Second} }
+        rs_measures::define_units_relationship! {NauticalMile == Knot * Hour}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {NauticalMile:2 == Knot:2 * Hour} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {NauticalMile:3 == Knot:3 * Hour} }
+        rs_measures::define_units_relationship! {KiloMetre == KiloMetrePerHour * Hour}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {KiloMetre:2 == KiloMetrePerHour:2 * Hour} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {KiloMetre:3 == KiloMetrePerHour:3 * Hour} }
+        rs_measures::define_units_relationship! {Mile == MilePerHour * Hour}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {Mile:2 == MilePerHour:2 * Hour} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {Mile:3 == MilePerHour:3 * Hour} }
+        rs_measures::define_units_relationship! {CentiMetre == CentiMetrePerSecond * Second}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {CentiMetre:2 == CentiMetrePerSecond:2 * Second} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {CentiMetre:3 == CentiMetrePerSecond:3 * Second} }
+
+        // AngularAcceleration == Frequency / Time
+        rs_measures::define_units_relationship! {RadianPerSecond == RadianPerSquareSecond * Second}
+
+        // Frequency == Angle / Time
+        rs_measures::define_units_relationship! {Cycle == Hertz * Second}
+        rs_measures::define_units_relationship! {Radian == RadianPerSecond * Second}
+        rs_measures::define_units_relationship! {Cycle == CyclePerMinute * Minute}
+
+        // KinematicViscosity == Area / Time
+        rs_measures::define_units_relationship! {SquareMetre == SquareMetrePerSecond * Second}
+        rs_measures::define_units_relationship! {SquareMilliMetre == SquareMetrePerSecond * MicroSecond}
+        rs_measures::define_units_relationship! {SquareMicroMetre == SquareMetrePerSecond * PicoSecond}
+        rs_measures::define_units_relationship! {SquareCentiMetre == Stoke * Second}
+        rs_measures::define_units_relationship! {SquareMilliMetre == CentiStoke * Second}
+        rs_measures::define_units_relationship! {SquareMicroMetre == CentiStoke * MicroSecond}
+        rs_measures::define_units_relationship! {SquareNanoMetre == CentiStoke * PicoSecond}
+
+        // KinematicViscosity == Length * Velocity
+        rs_measures::define_units_relationship! {SquareMetrePerSecond == Metre * MetrePerSecond}
+        rs_measures::define_units_relationship! {SquareMetrePerSecond == HectoMetre * CentiMetrePerSecond}
+        rs_measures::define_units_relationship! {Stoke == CentiMetre * CentiMetrePerSecond}
+
+        // SquareTime == Time * Time
+        rs_measures::define_units_relationship! {SquareSecond == Second * =}
+        rs_measures::define_units_relationship! {HourSecond == Hour * Second}
+        rs_measures::define_units_relationship! {HourHour == Hour * =}
+
+        // VolumetricFlowRate == Volume / Time
+        rs_measures::define_units_relationship! {CubicMetre == CubicMetrePerSecond * Second}
+        rs_measures::define_units_relationship! {MilliLitre == MilliLitrePerSecond * Second}
+
+        // VolumetricFlowRate == Area * Velocity
+        rs_measures::define_units_relationship! {CubicMetrePerSecond == SquareMetre * MetrePerSecond}
+        rs_measures::define_units_relationship! {MilliLitrePerSecond == SquareCentiMetre * CentiMetrePerSecond}
+    };
+}
@@ -0,0 +1,115 @@
+// Units of measurement of magnetism.
+use crate::traits::{MeasurementUnit, VectorProperty};
+
+// Property: inductance
+pub struct Inductance;
+
+pub struct Henry;
+impl MeasurementUnit for Henry {
+    type Property = Inductance;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " H";
+}
+
+// Property: magnetic field strength, magnetic field intensity, magnetization
+pub struct MagneticFieldStrength;
+impl VectorProperty for MagneticFieldStrength {}
+
+pub struct AmperePerMetre;
+impl MeasurementUnit for AmperePerMetre {
+    type Property = MagneticFieldStrength;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " A/m";
+}
+
+// Property: magnetic flux
+pub struct MagneticFlux;
+
+pub struct Weber;
+impl MeasurementUnit for Weber {
+    type Property = MagneticFlux;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Wb";
+}
+
+// Property: magnetic flux density
+pub struct MagneticFluxDensity;
+impl VectorProperty for MagneticFluxDensity {}
+
+pub struct Tesla;
+impl MeasurementUnit for Tesla {
+    type Property = MagneticFluxDensity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " T";
+}
+
+pub struct Gauss;
+impl MeasurementUnit for Gauss {
+    type Property = MagneticFluxDensity;
+    const RATIO: f64 = 1e-4;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " G";
+}
+
+// Property: magnetic permeability
+pub struct MagneticPermeability;
+
+pub struct HenryPerMetre;
+impl MeasurementUnit for HenryPerMetre {
+    type Property = MagneticPermeability;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " H/m";
+}
+
+// Property: magnetic reluctance, magnetic resistance
+pub struct MagneticReluctance;
+
+pub struct InverseHenry;
+impl MeasurementUnit for InverseHenry {
+    type Property = MagneticReluctance;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " 1/H";
+}
+
+// It defines the relationships among the units of this module,
+// using also the units of the modules `electricity`, `geometry` and `kinematics`.
+// It is invoked by the macro `define_units_catalog_relationships`.
+#[macro_export]
+macro_rules! inner_define_catalog_magnetism {
+    { $with_2d:tt $with_3d:tt } => {
+        use rs_measures::catalog::electricity::*;
+        use rs_measures::catalog::geometry::*;
+        use rs_measures::catalog::kinematics::*;
+        use rs_measures::catalog::magnetism::*;
+
+        // Inductance == MagneticFlux / ElectricCurrent
+        rs_measures::define_units_relationship! {Weber == Henry * Ampere}
+
+        // MagneticFieldStrength == ElectricCurrent / Length
+        rs_measures::define_units_relationship! {Ampere == AmperePerMetre * Metre}
+        rs_measures::define_units_relationship! {MilliAmpere == AmperePerMetre * MilliMetre}
+        rs_measures::define_units_relationship! {MicroAmpere == AmperePerMetre * MicroMetre}
+
+        // TODO: MagneticFlux == Mass * Area / SquareTime / Current
+
+        // ElectricFieldStrength == Velocity X MagneticFlux
+        rs_measures::define_units_relationship! {VoltPerMetre == MetrePerSecond * Weber}
+        rs_measures::if_true! { $with_2d, rs_measures::define_units_relationship! {VoltPerMetre == MetrePerSecond:2 X Weber:2} }
+        rs_measures::if_true! { $with_3d, rs_measures::define_units_relationship! {VoltPerMetre:3 == MetrePerSecond:3 X Weber:3} }
+
+        // MagneticFluxDensity == MagneticFlux / Area
+        // TODO: MagneticFluxDensity == Mass / SquareTime / Current
+        // TODO: MagneticFluxDensity == Force / Length / Current
+
+        // MagneticPermeability == Inductance / Length
+
+        // MagneticReluctance == 1 / Inductance
+        rs_measures::define_units_relationship! {InverseHenry == 1 / Henry}
+    };
+}
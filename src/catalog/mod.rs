@@ -0,0 +1,81 @@
+// A catalog of properties and units of measurement, with their relationships,
+// split by domain.
+// The properties and the units are defined in the submodules,
+// and they can be imported as, for example, `use rs_measures::catalog::geometry::*;`.
+// The relationships among them must be defined in the crate
+// which invokes the macro `define_measure_types`,
+// by invoking the macro `define_units_catalog_relationships`.
+pub mod chemistry;
+pub mod dimensionless;
+pub mod dynamics;
+pub mod electricity;
+pub mod geometry;
+pub mod information;
+pub mod kinematics;
+pub mod magnetism;
+pub mod optics;
+pub mod radioactivity;
+pub mod thermodynamics;
+
+// It defines the relationships among the units of the specified domains of the catalog.
+// It must be invoked after the macro `define_measure_types`,
+// specifying the same values of `with_2d` and `with_3d`.
+// The relationships of a domain can involve the units of other domains,
+// and so it imports all the units they need.
+// Example:
+// ```
+// rs_measures::define_units_catalog_relationships! {
+//     with_2d: true,
+//     with_3d: true,
+//     domains: [geometry, kinematics],
+// }
+// ```
+#[macro_export]
+macro_rules! define_units_catalog_relationships {
+    {
+        with_2d: $with_2d:tt,
+        with_3d: $with_3d:tt,
+        domains: [$($domain:ident),* $(,)?] $(,)?
+    } => {
+        $(
+            rs_measures::inner_define_catalog_relationships! { $domain $with_2d $with_3d }
+        )*
+    };
+}
+
+#[macro_export]
+macro_rules! inner_define_catalog_relationships {
+    { information $with_2d:tt $with_3d:tt } => {
+        rs_measures::inner_define_catalog_information! { $with_2d $with_3d }
+    };
+    { geometry $with_2d:tt $with_3d:tt } => {
+        rs_measures::inner_define_catalog_geometry! { $with_2d $with_3d }
+    };
+    { kinematics $with_2d:tt $with_3d:tt } => {
+        rs_measures::inner_define_catalog_kinematics! { $with_2d $with_3d }
+    };
+    { dynamics $with_2d:tt $with_3d:tt } => {
+        rs_measures::inner_define_catalog_dynamics! { $with_2d $with_3d }
+    };
+    { thermodynamics $with_2d:tt $with_3d:tt } => {
+        rs_measures::inner_define_catalog_thermodynamics! { $with_2d $with_3d }
+    };
+    { chemistry $with_2d:tt $with_3d:tt } => {
+        rs_measures::inner_define_catalog_chemistry! { $with_2d $with_3d }
+    };
+    { radioactivity $with_2d:tt $with_3d:tt } => {
+        rs_measures::inner_define_catalog_radioactivity! { $with_2d $with_3d }
+    };
+    { optics $with_2d:tt $with_3d:tt } => {
+        rs_measures::inner_define_catalog_optics! { $with_2d $with_3d }
+    };
+    { electricity $with_2d:tt $with_3d:tt } => {
+        rs_measures::inner_define_catalog_electricity! { $with_2d $with_3d }
+    };
+    { magnetism $with_2d:tt $with_3d:tt } => {
+        rs_measures::inner_define_catalog_magnetism! { $with_2d $with_3d }
+    };
+    { dimensionless $with_2d:tt $with_3d:tt } => {
+        rs_measures::inner_define_catalog_dimensionless! { $with_2d $with_3d }
+    };
+}
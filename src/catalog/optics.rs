@@ -0,0 +1,160 @@
+// Units of measurement of photometry and radiometry.
+use crate::traits::{MeasurementUnit, VectorProperty};
+
+// Property: illuminance
+pub struct Illuminance;
+
+pub struct Lux;
+impl MeasurementUnit for Lux {
+    type Property = Illuminance;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " lx";
+}
+
+pub struct Phot;
+impl MeasurementUnit for Phot {
+    type Property = Illuminance;
+    const RATIO: f64 = 10000.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " phot";
+}
+
+pub struct FootCandle;
+impl MeasurementUnit for FootCandle {
+    type Property = Illuminance;
+    const RATIO: f64 = 1. / (0.3048 * 0.3048);
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " fc";
+}
+
+// Property: irradiance, heat flux density
+pub struct Irradiance;
+
+pub struct WattPerSquareMetre;
+impl MeasurementUnit for WattPerSquareMetre {
+    type Property = Irradiance;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " W/m\u{b2}";
+}
+
+// Property: luminance
+pub struct Luminance;
+
+pub struct CandelaPerSquareMetre;
+impl MeasurementUnit for CandelaPerSquareMetre {
+    type Property = Luminance;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " cd/m\u{b2}";
+}
+
+pub struct Nit;
+impl MeasurementUnit for Nit {
+    type Property = Luminance;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " nt";
+}
+
+pub struct Stilb;
+impl MeasurementUnit for Stilb {
+    type Property = Luminance;
+    const RATIO: f64 = 10000.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " stilb";
+}
+
+pub struct CandelaPerSquareFoot;
+impl MeasurementUnit for CandelaPerSquareFoot {
+    type Property = Luminance;
+    const RATIO: f64 = 1. / (0.3048 * 0.3048);
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " stilb";
+}
+
+// Property: luminous flux, luminous power
+pub struct LuminousFlux;
+impl VectorProperty for LuminousFlux {}
+
+pub struct Lumen;
+impl MeasurementUnit for Lumen {
+    type Property = LuminousFlux;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " lm";
+}
+
+// Property: luminous intensity
+pub struct LuminousIntensity;
+
+pub struct Candela;
+impl MeasurementUnit for Candela {
+    type Property = LuminousIntensity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " cd";
+}
+
+// Property: radiance
+pub struct Radiance;
+
+pub struct WattPerSquareMetrePerSteradian;
+impl MeasurementUnit for WattPerSquareMetrePerSteradian {
+    type Property = Radiance;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " W/m\u{b2}/sr";
+}
+
+// Property: radiant intensity
+pub struct RadiantIntensity;
+
+pub struct WattPerSteradian;
+impl MeasurementUnit for WattPerSteradian {
+    type Property = RadiantIntensity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " W/sr";
+}
+
+// It defines the relationships among the units of this module,
+// using also the units of the modules `dynamics` and `geometry`.
+// It is invoked by the macro `define_units_catalog_relationships`.
+#[macro_export]
+macro_rules! inner_define_catalog_optics {
+    { $with_2d:tt $with_3d:tt } => {
+        use rs_measures::catalog::dynamics::*;
+        use rs_measures::catalog::geometry::*;
+        use rs_measures::catalog::optics::*;
+
+        // Illuminance == LuminousFlux / Area
+        rs_measures::define_units_relationship! {Lumen == Lux * SquareMetre}
+        rs_measures::define_units_relationship! {Lux == CandelaPerSquareMetre * Steradian}
+        rs_measures::define_units_relationship! {Lumen == Phot * SquareCentiMetre}
+        rs_measures::define_units_relationship! {Phot == Stilb * Steradian}
+        rs_measures::define_units_relationship! {Lumen == FootCandle * SquareFoot}
+        rs_measures::define_units_relationship! {FootCandle == CandelaPerSquareFoot * Steradian}
+
+        // Irradiance == Power / Area
+        rs_measures::define_units_relationship! {Watt == WattPerSquareMetre * SquareMetre}
+
+        // Luminance == LuminousIntensity / Area
+        rs_measures::define_units_relationship! {Candela == CandelaPerSquareMetre * SquareMetre}
+        rs_measures::define_units_relationship! {Candela == Stilb * SquareCentiMetre}
+        rs_measures::define_units_relationship! {Candela == CandelaPerSquareFoot * SquareFoot}
+
+        // LuminousFlux == LuminousIntensity * SolidAngle
+        rs_measures::define_units_relationship! {Lumen == Candela * Steradian}
+
+        // Radiance == RadiantIntensity / Area
+        rs_measures::define_units_relationship! {WattPerSteradian == WattPerSquareMetrePerSteradian * SquareMetre}
+
+        // Radiance == Irradiance / SolidAngle
+        rs_measures::define_units_relationship! {WattPerSquareMetre == WattPerSquareMetrePerSteradian * Steradian}
+
+        // RadiantIntensity == Power / SolidAngle
+        rs_measures::define_units_relationship! {Watt == WattPerSteradian * Steradian}
+    };
+}
@@ -0,0 +1,100 @@
+// Units of measurement of radioactivity and radiation doses.
+use crate::traits::MeasurementUnit;
+
+// Property: dose equivalent
+pub struct DoseEquivalent;
+
+pub struct Sievert;
+impl MeasurementUnit for Sievert {
+    type Property = DoseEquivalent;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Sv";
+}
+
+pub struct Rem;
+impl MeasurementUnit for Rem {
+    type Property = DoseEquivalent;
+    const RATIO: f64 = 0.01;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " rem";
+}
+
+// Property: radioactive activity
+pub struct RadioactiveActivity;
+
+pub struct Becquerel;
+impl MeasurementUnit for Becquerel {
+    type Property = RadioactiveActivity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Bq";
+}
+
+pub struct KiloBecquerel;
+impl MeasurementUnit for KiloBecquerel {
+    type Property = RadioactiveActivity;
+    const RATIO: f64 = 1e3;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kBq";
+}
+
+pub struct MegaBecquerel;
+impl MeasurementUnit for MegaBecquerel {
+    type Property = RadioactiveActivity;
+    const RATIO: f64 = 1e6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " MBq";
+}
+
+pub struct GigaBecquerel;
+impl MeasurementUnit for GigaBecquerel {
+    type Property = RadioactiveActivity;
+    const RATIO: f64 = 1e9;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " GBq";
+}
+
+// Property: radioactive dose
+pub struct RadioactiveDose;
+
+pub struct Gray;
+impl MeasurementUnit for Gray {
+    type Property = RadioactiveDose;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Gy";
+}
+
+pub struct Rad;
+impl MeasurementUnit for Rad {
+    type Property = RadioactiveDose;
+    const RATIO: f64 = 0.01;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " rad";
+}
+
+// Property: radioactive dose rate
+pub struct RadioactiveDoseRate;
+
+pub struct GrayPerSecond;
+impl MeasurementUnit for GrayPerSecond {
+    type Property = RadioactiveDoseRate;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Gy/s";
+}
+
+// It defines the relationships among the units of this module,
+// using also the units of the module `kinematics`.
+// It is invoked by the macro `define_units_catalog_relationships`.
+#[macro_export]
+macro_rules! inner_define_catalog_radioactivity {
+    { $with_2d:tt $with_3d:tt } => {
+        use rs_measures::catalog::kinematics::*;
+        use rs_measures::catalog::radioactivity::*;
+
+        // RadioactiveDoseRate == RadioactiveDose / Time
+        rs_measures::define_units_relationship! {Gray == GrayPerSecond * Second}
+    };
+}
@@ -0,0 +1,82 @@
+// Units of measurement of thermodynamics: temperature, entropy, and heat.
+use crate::traits::MeasurementUnit;
+
+// Property: entropy, heat capacity
+pub struct Entropy;
+
+pub struct JoulePerKelvin;
+impl MeasurementUnit for JoulePerKelvin {
+    type Property = Entropy;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " J/\u{b0}K";
+}
+
+// Property: specific heat capacity
+pub struct SpecificHeatCapacity;
+
+pub struct JoulePerKiloGramPerKelvin;
+impl MeasurementUnit for JoulePerKiloGramPerKelvin {
+    type Property = SpecificHeatCapacity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " J/kg/\u{b0}K";
+}
+
+// Property: temperature
+pub struct Temperature;
+
+pub struct Kelvin;
+impl MeasurementUnit for Kelvin {
+    type Property = Temperature;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " \u{b0}K";
+}
+
+pub struct Celsius;
+impl MeasurementUnit for Celsius {
+    type Property = Temperature;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 273.15;
+    const SUFFIX: &'static str = " \u{b0}C";
+}
+
+pub struct Fahrenheit;
+impl MeasurementUnit for Fahrenheit {
+    type Property = Temperature;
+    const RATIO: f64 = 5. / 9.;
+    const OFFSET: f64 = 273.15 - 32. * 5. / 9.;
+    const SUFFIX: &'static str = " \u{b0}F";
+}
+
+// Property: thermal conductivity
+pub struct ThermalConductivity;
+
+pub struct WattPerMetrePerKelvin;
+impl MeasurementUnit for WattPerMetrePerKelvin {
+    type Property = ThermalConductivity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " W/m/\u{b0}K";
+}
+
+// It defines the relationships among the units of this module,
+// using also the units of the module `dynamics`.
+// It is invoked by the macro `define_units_catalog_relationships`.
+#[macro_export]
+macro_rules! inner_define_catalog_thermodynamics {
+    { $with_2d:tt $with_3d:tt } => {
+        use rs_measures::catalog::dynamics::*;
+        use rs_measures::catalog::thermodynamics::*;
+
+        // Entropy == Energy / Temperature
+        rs_measures::define_units_relationship! {Joule == JoulePerKelvin * Kelvin}
+
+        // SpecificHeatCapacity == Entropy / Mass
+        rs_measures::define_units_relationship! {JoulePerKelvin == JoulePerKiloGramPerKelvin * KiloGram}
+
+        // TODO: ThermalConductivity == Power / Length / Temperature
+        // TODO: define_units_relationship! {WattPerMetrePerKelvin == ?}
+    };
+}
@@ -0,0 +1,70 @@
+// Statistics of samples of directions, which take into account that the directions wrap around,
+// so that, for example, the mean of 350° and 10° is 0°, and not 180°.
+// The directions are expressed in a unit of angle in which a cycle has the value `cycle_fraction`.
+use crate::traits::ArithmeticOps;
+
+// The angle of the mean of the unit vectors having the specified directions,
+// between minus half cycle and plus half cycle, and the length of that mean vector,
+// which is between 0, for directions spread evenly, and 1, for coincident directions.
+// For an empty sample, it returns `None`.
+pub fn mean_resultant<Number: ArithmeticOps>(
+    directions: impl IntoIterator<Item = Number>,
+    cycle_fraction: f64,
+) -> Option<(Number, Number)> {
+    let to_radians = Number::from_f64(std::f64::consts::TAU / cycle_fraction);
+    let mut count = Number::ZERO;
+    let mut sum_sin = Number::ZERO;
+    let mut sum_cos = Number::ZERO;
+    for direction in directions {
+        let (sin, cos) = (direction * to_radians).sin_cos();
+        sum_sin += sin;
+        sum_cos += cos;
+        count += Number::ONE;
+    }
+    if count == Number::ZERO {
+        return None;
+    }
+    let (mean_sin, mean_cos) = (sum_sin / count, sum_cos / count);
+    // For coincident directions, rounding errors could make the length slightly greater than 1.
+    let length = (mean_sin * mean_sin + mean_cos * mean_cos).sqrt();
+    Some((
+        mean_sin.atan2(mean_cos) / to_radians,
+        if length > Number::ONE {
+            Number::ONE
+        } else {
+            length
+        },
+    ))
+}
+
+// The angular distance between two directions, between zero and half cycle.
+fn angular_distance<Number: ArithmeticOps>(a: Number, b: Number, cycle: Number) -> Number {
+    let mut difference = (a - b) % cycle;
+    if difference < Number::ZERO {
+        difference += cycle;
+    }
+    if difference + difference > cycle {
+        cycle - difference
+    } else {
+        difference
+    }
+}
+
+// The direction of the sample which minimizes the sum of the angular distances
+// from all the directions of the sample.
+// For an empty sample, it returns `None`.
+pub fn median<Number: ArithmeticOps>(directions: &[Number], cycle_fraction: f64) -> Option<Number> {
+    let cycle = Number::from_f64(cycle_fraction);
+    let mut best: Option<(Number, Number)> = None;
+    for &candidate in directions {
+        let total = directions
+            .iter()
+            .map(|&direction| angular_distance(candidate, direction, cycle))
+            .sum::<Number>();
+        match best {
+            Some((_, best_total)) if best_total <= total => {}
+            _ => best = Some((candidate, total)),
+        }
+    }
+    best.map(|(direction, _)| direction)
+}
@@ -19,6 +19,13 @@ macro_rules! if_true {
     { false, $fragment:item } => {};
 }
 
+#[macro_export]
+macro_rules! if_no_uncertainty {
+    { None, $fragment:item } => { $fragment };
+    { Symmetric, $fragment:item } => {};
+    { TwoSided, $fragment:item } => {};
+}
+
 #[macro_export]
 macro_rules! if_some_uncertainty {
     { None, $fragment:item } => {};
@@ -40,6 +47,32 @@ macro_rules! if_two_sided_uncertainty {
     { TwoSided, $fragment:item } => { $fragment };
 }
 
+#[macro_export]
+macro_rules! if_not_two_sided_uncertainty {
+    { None, $fragment:item } => { $fragment };
+    { Symmetric, $fragment:item } => { $fragment };
+    { TwoSided, $fragment:item } => {};
+}
+
+#[macro_export]
+macro_rules! if_not_symmetric_uncertainty {
+    { None, $fragment:item } => { $fragment };
+    { Symmetric, $fragment:item } => {};
+    { TwoSided, $fragment:item } => { $fragment };
+}
+
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! if_serde {
+    { $fragment:item } => { $fragment };
+}
+
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! if_serde {
+    { $fragment:item } => {};
+}
+
 #[macro_export]
 macro_rules! define_measure_types {
     {
@@ -56,15 +89,19 @@ macro_rules! define_measure_types {
         use rs_measures::{
             angle::{Angle, Radian},
             traits::{
-                AngleMeasurementUnit, ArithmeticOps, LossyFrom, MeasurementUnit, Sqrt, VectorProperty,
+                AngleMeasurementUnit, ArithmeticOps, CubicRoot, LossyFrom, MeasurementUnit, Sqrt,
+                VectorProperty,
             },
         };
         use std::fmt;
         use std::marker::PhantomData;
 
-        rs_measures::inner_define_measure! {}
+        rs_measures::inner_define_measure! { $with_uncertainty }
         rs_measures::if_true! { $with_points,
-            rs_measures::inner_define_measure_point! {}
+            rs_measures::inner_define_measure_point! { $with_uncertainty }
+        }
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            rs_measures::inner_define_monte_carlo! { $with_directions $with_uncertainty }
         }
         rs_measures::if_true! { $with_directions,
             rs_measures::inner_define_unsigned_direction! {}
@@ -73,19 +110,64 @@ macro_rules! define_measure_types {
             rs_measures::inner_define_signed_direction! {}
         }
         rs_measures::if_true! { $with_2d,
-            rs_measures::inner_define_measure_2d! { $with_points $with_directions }
+            rs_measures::inner_define_measure_2d! { $with_points $with_directions $with_uncertainty }
         }
         rs_measures::if_true! { $with_2d,
             rs_measures::if_true! { $with_points,
-                rs_measures::inner_define_measure_point_2d! {}
+                rs_measures::inner_define_measure_point_2d! { $with_uncertainty }
+            }
+        }
+        rs_measures::if_true! { $with_2d,
+            rs_measures::if_true! { $with_points,
+                rs_measures::if_true! { $with_directions,
+                    rs_measures::inner_define_polar_point_2d! {}
+                }
+            }
+        }
+        rs_measures::if_true! { $with_2d,
+            rs_measures::inner_define_measure_linear_map_2d! {}
+        }
+        rs_measures::if_true! { $with_2d,
+            rs_measures::inner_define_tensor_2d! { $with_transformations }
+        }
+        rs_measures::if_true! { $with_3d,
+            rs_measures::inner_define_measure_3d! { $with_directions $with_uncertainty }
+        }
+        rs_measures::if_true! { $with_3d,
+            rs_measures::inner_define_measure_linear_map_3d! {}
+        }
+        rs_measures::if_true! { $with_3d,
+            rs_measures::inner_define_tensor_3d! { $with_transformations }
+        }
+        rs_measures::if_true! { $with_3d,
+            rs_measures::if_true! { $with_directions,
+                rs_measures::inner_define_direction_3d! {}
+            }
+        }
+        rs_measures::if_true! { $with_3d,
+            rs_measures::if_true! { $with_points,
+                rs_measures::inner_define_measure_point_3d! { $with_uncertainty }
             }
         }
         rs_measures::if_true! { $with_3d,
-            rs_measures::inner_define_measure_3d! {}
+            rs_measures::if_true! { $with_points,
+                rs_measures::if_true! { $with_directions,
+                    rs_measures::inner_define_cylindrical_point_3d! {}
+                }
+            }
+        }
+        rs_measures::if_true! { $with_3d,
+            rs_measures::if_true! { $with_points,
+                rs_measures::if_true! { $with_directions,
+                    rs_measures::inner_define_spherical_point_3d! {}
+                }
+            }
         }
         rs_measures::if_true! { $with_3d,
             rs_measures::if_true! { $with_points,
-                rs_measures::inner_define_measure_point_3d! {}
+                rs_measures::if_true! { $with_directions,
+                    rs_measures::inner_define_geodesy! { $with_transformations }
+                }
             }
         }
         rs_measures::if_true! { $with_2d,
@@ -98,6 +180,11 @@ macro_rules! define_measure_types {
                 rs_measures::inner_define_linear_map_3d! {}
             }
         }
+        rs_measures::if_true! { $with_3d,
+            rs_measures::if_true! { $with_transformations,
+                rs_measures::inner_define_rotation_3d! { $with_points }
+            }
+        }
         rs_measures::if_true! { $with_2d,
             rs_measures::if_true! { $with_transformations,
                 rs_measures::if_true! { $with_points,
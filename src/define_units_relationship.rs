@@ -1,7 +1,20 @@
 #[macro_export]
 macro_rules! define_units_relationship {
     { $id1:ident == $id2:ident * $id3:ident} => { rs_measures::expand_1_1! {$id2 $id3 $id1} };
+    // The factor is part of an equation among units, and so the values are scaled by its inverse.
+    // For example, `Litre == SquareMetre * Metre / 1000` means that a litre is a thousandth
+    // of a square metre times a metre, and so the values of the products are multiplied by 1000.
+    { $id1:ident == $id2:ident * $id3:ident * $factor:literal } => {
+        rs_measures::check_units_ratios! { $id1 == $id2 * $id3 * $factor }
+        rs_measures::expand_1_1_scaled! {$id2 $id3 $id1 (1. / $factor as f64)}
+    };
+    { $id1:ident == $id2:ident * $id3:ident / $factor:literal } => {
+        rs_measures::check_units_ratios! { $id1 == $id2 * $id3 / $factor }
+        rs_measures::expand_1_1_scaled! {$id2 $id3 $id1 ($factor as f64)}
+    };
     { $id1:ident == $id2:ident * =} => { rs_measures::expand_1_1_same! {$id2 $id1} };
+    { $id1:ident == $id2:ident ^ 2 } => { rs_measures::expand_1_1_same! {$id2 $id1} };
+    { $id1:ident == $id2:ident ^ 3 } => { rs_measures::expand_1_1_cube! {$id2 $id1} };
     { $id1:ident:2 == $id2:ident * $id3:ident:2} => { rs_measures::expand_1_2! {$id2 $id3 $id1} };
     { $id1:ident:2 == $id2:ident:2 * $id3:ident} => { rs_measures::expand_1_2! {$id3 $id2 $id1} };
     { $id1:ident:3 == $id2:ident * $id3:ident:3} => { rs_measures::expand_1_3! {$id2 $id3 $id1} };
@@ -11,6 +24,9 @@ macro_rules! define_units_relationship {
     { $id1:ident == $id2:ident :3 * $id3:ident:3} => { rs_measures::expand_3_3! {$id2 $id3 $id1} };
     { $id1:ident == $id2:ident :3 * =:3} => { rs_measures::expand_3_3_same! {$id2 $id1} };
     { $id1:ident == 1 / $id3:ident } => { rs_measures::expand_inverse! {$id1 $id3} };
+    { $id1:ident == $id2:ident / $id3:ident } => { rs_measures::expand_1_1! {$id1 $id3 $id2} };
+    { $id1:ident:2 == $id2:ident:2 / $id3:ident } => { rs_measures::expand_1_2! {$id3 $id1 $id2} };
+    { $id1:ident:3 == $id2:ident:3 / $id3:ident } => { rs_measures::expand_1_3! {$id3 $id1 $id2} };
     { $id1:ident == $id2:ident:2 X $id3:ident:2 } => { rs_measures::expand_cross_2! {$id2 $id3 $id1} };
     { $id1:ident == $id2:ident:2 X =:2 } => { rs_measures::expand_cross_2_same! {$id2 $id1} };
     { $id1:ident :3 == $id2:ident:3 X $id3:ident:3 } => { rs_measures::expand_cross_3! {$id2 $id3 $id1} };
@@ -20,35 +36,82 @@ macro_rules! define_units_relationship {
 #[macro_export]
 macro_rules! expand_1_1 {
     {$unit1:ident $unit2:ident $unit3:ident} => {
+        rs_measures::check_units_ratios! { $unit3 == $unit1 * $unit2 }
+
         // Measure<U1> * Measure<U2> -> Measure<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure<$unit1, Number>, Measure<$unit2, Number> }
         impl<Number: ArithmeticOps> Mul<Measure<$unit2, Number>> for Measure<$unit1, Number> {
             type Output = Measure<$unit3, Number>;
             fn mul(self, other: Measure<$unit2, Number>) -> Self::Output {
-                Self::Output::new(self.value * other.value)
+                self.product_into::<$unit2, $unit3>(other)
             }
         }
 
         // Measure<U2> * Measure<U1> -> Measure<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure<$unit2, Number>, Measure<$unit1, Number> }
         impl<Number: ArithmeticOps> Mul<Measure<$unit1, Number>> for Measure<$unit2, Number> {
             type Output = Measure<$unit3, Number>;
             fn mul(self, other: Measure<$unit1, Number>) -> Self::Output {
-                Self::Output::new(self.value * other.value)
+                self.product_into::<$unit1, $unit3>(other)
             }
         }
 
         // Measure<U3> / Measure<U1> -> Measure<U2>
+        rs_measures::mark_units_operation! { Division, Measure<$unit3, Number>, Measure<$unit1, Number> }
         impl<Number: ArithmeticOps> Div<Measure<$unit1, Number>> for Measure<$unit3, Number> {
             type Output = Measure<$unit2, Number>;
             fn div(self, other: Measure<$unit1, Number>) -> Self::Output {
-                Self::Output::new(self.value / other.value)
+                self.quotient_into::<$unit1, $unit2>(other)
             }
         }
 
         // Measure<U3> / Measure<U2> -> Measure<U1>
+        rs_measures::mark_units_operation! { Division, Measure<$unit3, Number>, Measure<$unit2, Number> }
         impl<Number: ArithmeticOps> Div<Measure<$unit2, Number>> for Measure<$unit3, Number> {
             type Output = Measure<$unit1, Number>;
             fn div(self, other: Measure<$unit2, Number>) -> Self::Output {
-                Self::Output::new(self.value / other.value)
+                self.quotient_into::<$unit2, $unit1>(other)
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! expand_1_1_scaled {
+    {$unit1:ident $unit2:ident $unit3:ident $factor:expr} => {
+        // Measure<U1> * Measure<U2> -> Measure<U3>, scaled by a factor
+        rs_measures::mark_units_operation! { Multiplication, Measure<$unit1, Number>, Measure<$unit2, Number> }
+        impl<Number: ArithmeticOps> Mul<Measure<$unit2, Number>> for Measure<$unit1, Number> {
+            type Output = Measure<$unit3, Number>;
+            fn mul(self, other: Measure<$unit2, Number>) -> Self::Output {
+                self.product_into::<$unit2, $unit3>(other) * Number::from_f64($factor)
+            }
+        }
+
+        // Measure<U2> * Measure<U1> -> Measure<U3>, scaled by a factor
+        rs_measures::mark_units_operation! { Multiplication, Measure<$unit2, Number>, Measure<$unit1, Number> }
+        impl<Number: ArithmeticOps> Mul<Measure<$unit1, Number>> for Measure<$unit2, Number> {
+            type Output = Measure<$unit3, Number>;
+            fn mul(self, other: Measure<$unit1, Number>) -> Self::Output {
+                self.product_into::<$unit1, $unit3>(other) * Number::from_f64($factor)
+            }
+        }
+
+        // Measure<U3> / Measure<U1> -> Measure<U2>, scaled by the inverse of a factor
+        rs_measures::mark_units_operation! { Division, Measure<$unit3, Number>, Measure<$unit1, Number> }
+        impl<Number: ArithmeticOps> Div<Measure<$unit1, Number>> for Measure<$unit3, Number> {
+            type Output = Measure<$unit2, Number>;
+            fn div(self, other: Measure<$unit1, Number>) -> Self::Output {
+                self.quotient_into::<$unit1, $unit2>(other) / Number::from_f64($factor)
+            }
+        }
+
+        // Measure<U3> / Measure<U2> -> Measure<U1>, scaled by the inverse of a factor
+        rs_measures::mark_units_operation! { Division, Measure<$unit3, Number>, Measure<$unit2, Number> }
+        impl<Number: ArithmeticOps> Div<Measure<$unit2, Number>> for Measure<$unit3, Number> {
+            type Output = Measure<$unit1, Number>;
+            fn div(self, other: Measure<$unit2, Number>) -> Self::Output {
+                self.quotient_into::<$unit2, $unit1>(other) / Number::from_f64($factor)
             }
         }
     };
@@ -57,33 +120,59 @@ macro_rules! expand_1_1 {
 #[macro_export]
 macro_rules! expand_1_1_same {
     { $unit1:ident $unit3:ident } => {
+        rs_measures::check_units_ratios! { $unit3 == $unit1 * $unit1 }
+
         // Measure<U1> * Measure<U1> -> Measure<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure<$unit1, Number>, Measure<$unit1, Number> }
         impl<Number: ArithmeticOps> Mul<Measure<$unit1, Number>> for Measure<$unit1, Number> {
             type Output = Measure<$unit3, Number>;
             fn mul(self, other: Measure<$unit1, Number>) -> Self::Output {
-                Self::Output::new(self.value * other.value)
+                self.product_into::<$unit1, $unit3>(other)
             }
         }
 
         // Measure<U3> / Measure<U1> -> Measure<U1>
+        rs_measures::mark_units_operation! { Division, Measure<$unit3, Number>, Measure<$unit1, Number> }
         impl<Number: ArithmeticOps> Div<Measure<$unit1, Number>> for Measure<$unit3, Number> {
             type Output = Measure<$unit1, Number>;
             fn div(self, other: Measure<$unit1, Number>) -> Self::Output {
-                Self::Output::new(self.value / other.value)
+                self.quotient_into::<$unit1, $unit1>(other)
             }
         }
 
         // Measure<U1>.squared() -> Measure<U3>
         impl<Number: ArithmeticOps> Measure<$unit1, Number> {
             fn squared(self) -> Measure<$unit3, Number> {
-                Measure::<$unit3, Number>::new(self.value * self.value)
+                self.squared_into::<$unit3>()
             }
         }
         // Measure<U3>.sqrt() -> Measure<U1>
         impl<Number: ArithmeticOps> Sqrt for Measure<$unit3, Number> {
             type Output = Measure<$unit1, Number>;
             fn sqrt(self) -> Self::Output {
-                Self::Output::new(self.value.sqrt())
+                self.sqrt_into::<$unit1>()
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! expand_1_1_cube {
+    { $unit1:ident $unit3:ident } => {
+        rs_measures::check_units_ratios! { $unit3 == $unit1 * $unit1 * $unit1 }
+
+        // Measure<U1>.cubed() -> Measure<U3>
+        impl<Number: ArithmeticOps> Measure<$unit1, Number> {
+            fn cubed(self) -> Measure<$unit3, Number> {
+                self.cubed_into::<$unit3>()
+            }
+        }
+
+        // Measure<U3>.cubic_root() -> Measure<U1>
+        impl<Number: ArithmeticOps> CubicRoot for Measure<$unit3, Number> {
+            type Output = Measure<$unit1, Number>;
+            fn cubic_root(self) -> Self::Output {
+                self.cubic_root_into::<$unit1>()
             }
         }
     };
@@ -92,27 +181,41 @@ macro_rules! expand_1_1_same {
 #[macro_export]
 macro_rules! expand_1_2 {
     {$unit1:ident $unit2:ident $unit3:ident} => {
+        rs_measures::check_units_ratios! { $unit3 == $unit1 * $unit2 }
+
         // Measure<U1> * Measure2d<U2> -> Measure2d<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure<$unit1, Number>, Measure2d<$unit2, Number> }
         impl<Number: ArithmeticOps> Mul<Measure2d<$unit2, Number>> for Measure<$unit1, Number> {
             type Output = Measure2d<$unit3, Number>;
             fn mul(self, other: Measure2d<$unit2, Number>) -> Self::Output {
-                Self::Output::new(self.value * other.x, self.value * other.y)
+                other.scaled_into::<$unit1, $unit3>(self)
             }
         }
 
         // Measure2d<U2> * Measure<U1> -> Measure2d<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure2d<$unit2, Number>, Measure<$unit1, Number> }
         impl<Number: ArithmeticOps> Mul<Measure<$unit1, Number>> for Measure2d<$unit2, Number> {
             type Output = Measure2d<$unit3, Number>;
             fn mul(self, other: Measure<$unit1, Number>) -> Self::Output {
-                Self::Output::new(self.x * other.value, self.y * other.value)
+                self.scaled_into::<$unit1, $unit3>(other)
             }
         }
 
         // Measure2d<U3> / Measure<U1> -> Measure2d<U2>
+        rs_measures::mark_units_operation! { Division, Measure2d<$unit3, Number>, Measure<$unit1, Number> }
         impl<Number: ArithmeticOps> Div<Measure<$unit1, Number>> for Measure2d<$unit3, Number> {
             type Output = Measure2d<$unit2, Number>;
             fn div(self, other: Measure<$unit1, Number>) -> Self::Output {
-                Self::Output::new(self.x / other.value, self.y / other.value)
+                self.divided_into::<$unit1, $unit2>(other)
+            }
+        }
+
+        // MeasureLinearMap2d<U2, U3>::from([[Measure<U1>]]) -> MeasureLinearMap2d<U2, U3>
+        impl<Number: ArithmeticOps> From<[[Measure<$unit1, Number>; 2]; 2]>
+            for MeasureLinearMap2d<$unit2, $unit3, Number>
+        {
+            fn from(coefficients: [[Measure<$unit1, Number>; 2]; 2]) -> Self {
+                Self::new(coefficients.map(|row| row.map(|c| c.value)))
             }
         }
     };
@@ -121,39 +224,41 @@ macro_rules! expand_1_2 {
 #[macro_export]
 macro_rules! expand_1_3 {
     {$unit1:ident $unit2:ident $unit3:ident} => {
+        rs_measures::check_units_ratios! { $unit3 == $unit1 * $unit2 }
+
         // Measure<U1> * Measure3d<U2> -> Measure3d<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure<$unit1, Number>, Measure3d<$unit2, Number> }
         impl<Number: ArithmeticOps> Mul<Measure3d<$unit2, Number>> for Measure<$unit1, Number> {
             type Output = Measure3d<$unit3, Number>;
             fn mul(self, other: Measure3d<$unit2, Number>) -> Self::Output {
-                Self::Output::new(
-                    self.value * other.x,
-                    self.value * other.y,
-                    self.value * other.z,
-                )
+                other.scaled_into::<$unit1, $unit3>(self)
             }
         }
 
         // Measure3d<U2> * Measure<U1> -> Measure3d<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure3d<$unit2, Number>, Measure<$unit1, Number> }
         impl<Number: ArithmeticOps> Mul<Measure<$unit1, Number>> for Measure3d<$unit2, Number> {
             type Output = Measure3d<$unit3, Number>;
             fn mul(self, other: Measure<$unit1, Number>) -> Self::Output {
-                Self::Output::new(
-                    self.x * other.value,
-                    self.y * other.value,
-                    self.z * other.value,
-                )
+                self.scaled_into::<$unit1, $unit3>(other)
             }
         }
 
         // Measure3d<U3> / Measure<U1> -> Measure3d<U2>
+        rs_measures::mark_units_operation! { Division, Measure3d<$unit3, Number>, Measure<$unit1, Number> }
         impl<Number: ArithmeticOps> Div<Measure<$unit1, Number>> for Measure3d<$unit3, Number> {
             type Output = Measure3d<$unit2, Number>;
             fn div(self, other: Measure<$unit1, Number>) -> Self::Output {
-                Self::Output::new(
-                    self.x / other.value,
-                    self.y / other.value,
-                    self.z / other.value,
-                )
+                self.divided_into::<$unit1, $unit2>(other)
+            }
+        }
+
+        // MeasureLinearMap3d<U2, U3>::from([[Measure<U1>]]) -> MeasureLinearMap3d<U2, U3>
+        impl<Number: ArithmeticOps> From<[[Measure<$unit1, Number>; 3]; 3]>
+            for MeasureLinearMap3d<$unit2, $unit3, Number>
+        {
+            fn from(coefficients: [[Measure<$unit1, Number>; 3]; 3]) -> Self {
+                Self::new(coefficients.map(|row| row.map(|c| c.value)))
             }
         }
     };
@@ -162,19 +267,23 @@ macro_rules! expand_1_3 {
 #[macro_export]
 macro_rules! expand_2_2 {
     {$unit1:ident $unit2:ident $unit3:ident} => {
+        rs_measures::check_units_ratios! { $unit3 == $unit1 * $unit2 }
+
         // Measure2d<U1> * Measure2d<U2> -> Measure<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure2d<$unit1, Number>, Measure2d<$unit2, Number> }
         impl<Number: ArithmeticOps> Mul<Measure2d<$unit2, Number>> for Measure2d<$unit1, Number> {
             type Output = Measure<$unit3, Number>;
             fn mul(self, other: Measure2d<$unit2, Number>) -> Self::Output {
-                Self::Output::new(self.x * other.x + self.y * other.y)
+                self.dot_product_into::<$unit2, $unit3>(other)
             }
         }
 
         // Measure2d<U2> * Measure2d<U1> -> Measure<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure2d<$unit2, Number>, Measure2d<$unit1, Number> }
         impl<Number: ArithmeticOps> Mul<Measure2d<$unit1, Number>> for Measure2d<$unit2, Number> {
             type Output = Measure<$unit3, Number>;
             fn mul(self, other: Measure2d<$unit1, Number>) -> Self::Output {
-                Self::Output::new(self.x * other.x + self.y * other.y)
+                self.dot_product_into::<$unit1, $unit3>(other)
             }
         }
     };
@@ -183,18 +292,21 @@ macro_rules! expand_2_2 {
 #[macro_export]
 macro_rules! expand_2_2_same {
     {$unit1:ident $unit2:ident} => {
+        rs_measures::check_units_ratios! { $unit2 == $unit1 * $unit1 }
+
         // Measure2d<U1> * Measure2d<U1> -> Measure<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure2d<$unit1, Number>, Measure2d<$unit1, Number> }
         impl<Number: ArithmeticOps> Mul<Measure2d<$unit1, Number>> for Measure2d<$unit1, Number> {
             type Output = Measure<$unit2, Number>;
             fn mul(self, other: Measure2d<$unit1, Number>) -> Self::Output {
-                Self::Output::new(self.x * other.x + self.y * other.y)
+                self.dot_product_into::<$unit1, $unit2>(other)
             }
         }
 
         // Measure2d<U1>.squared() -> Measure<U3>
         impl<Number: ArithmeticOps> Measure2d<$unit1, Number> {
             fn squared(self) -> Measure<$unit2, Number> {
-                Measure::<$unit2, Number>::new(self.x * self.x + self.y * self.y)
+                self.squared_into::<$unit2>()
             }
         }
     };
@@ -203,18 +315,21 @@ macro_rules! expand_2_2_same {
 #[macro_export]
 macro_rules! expand_3_3_same {
     {$unit1:ident $unit2:ident} => {
+        rs_measures::check_units_ratios! { $unit2 == $unit1 * $unit1 }
+
         // Measure3d<U1> * Measure3d<U1> -> Measure<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure3d<$unit1, Number>, Measure3d<$unit1, Number> }
         impl<Number: ArithmeticOps> Mul<Measure3d<$unit1, Number>> for Measure3d<$unit1, Number> {
             type Output = Measure<$unit2, Number>;
             fn mul(self, other: Measure3d<$unit1, Number>) -> Self::Output {
-                Self::Output::new(self.x * other.x + self.y * other.y + self.z * other.z)
+                self.dot_product_into::<$unit1, $unit2>(other)
             }
         }
 
         // Measure3d<U1>.squared() -> Measure<U3>
         impl<Number: ArithmeticOps> Measure3d<$unit1, Number> {
             fn squared(self) -> Measure<$unit2, Number> {
-                Measure::<$unit2, Number>::new(self.x * self.x + self.y * self.y + self.z * self.z)
+                self.squared_into::<$unit2>()
             }
         }
     };
@@ -223,19 +338,23 @@ macro_rules! expand_3_3_same {
 #[macro_export]
 macro_rules! expand_3_3 {
     {$unit1:ident $unit2:ident $unit3:ident} => {
+        rs_measures::check_units_ratios! { $unit3 == $unit1 * $unit2 }
+
         // Measure3d<U1> * Measure3d<U2> -> Measure<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure3d<$unit1, Number>, Measure3d<$unit2, Number> }
         impl<Number: ArithmeticOps> Mul<Measure3d<$unit2, Number>> for Measure3d<$unit1, Number> {
             type Output = Measure<$unit3, Number>;
             fn mul(self, other: Measure3d<$unit2, Number>) -> Self::Output {
-                Self::Output::new(self.x * other.x + self.y * other.y + self.z * other.z)
+                self.dot_product_into::<$unit2, $unit3>(other)
             }
         }
 
         // Measure3d<U2> * Measure3d<U1> -> Measure<U3>
+        rs_measures::mark_units_operation! { Multiplication, Measure3d<$unit2, Number>, Measure3d<$unit1, Number> }
         impl<Number: ArithmeticOps> Mul<Measure3d<$unit1, Number>> for Measure3d<$unit2, Number> {
             type Output = Measure<$unit3, Number>;
             fn mul(self, other: Measure3d<$unit1, Number>) -> Self::Output {
-                Self::Output::new(self.x * other.x + self.y * other.y + self.z * other.z)
+                self.dot_product_into::<$unit1, $unit3>(other)
             }
         }
     };
@@ -244,7 +363,10 @@ macro_rules! expand_3_3 {
 #[macro_export]
 macro_rules! expand_inverse {
     {$unit1:ident $unit2:ident} => {
+        rs_measures::check_units_ratios! { 1 == $unit1 * $unit2 }
+
         // Measure<U1> * Measure<U2> -> N
+        rs_measures::mark_units_operation! { Multiplication, Measure<$unit1, Number>, Measure<$unit2, Number> }
         impl<Number: ArithmeticOps> Mul<Measure<$unit2, Number>> for Measure<$unit1, Number> {
             type Output = Number;
             fn mul(self, other: Measure<$unit2, Number>) -> Self::Output {
@@ -253,6 +375,7 @@ macro_rules! expand_inverse {
         }
 
         // Measure<U2> * Measure<U1> -> N
+        rs_measures::mark_units_operation! { Multiplication, Measure<$unit2, Number>, Measure<$unit1, Number> }
         impl<Number: ArithmeticOps> Mul<Measure<$unit1, Number>> for Measure<$unit2, Number> {
             type Output = Number;
             fn mul(self, other: Measure<$unit1, Number>) -> Self::Output {
@@ -261,34 +384,38 @@ macro_rules! expand_inverse {
         }
 
         // N64 / Measure<U1> -> Measure<U2>
+        rs_measures::mark_units_operation! { concrete Division, f64, Measure<$unit1, f64> }
         impl Div<Measure<$unit1, f64>> for f64 {
             type Output = Measure<$unit2, f64>;
             fn div(self, other: Measure<$unit1, f64>) -> Self::Output {
-                Self::Output::new(self / other.value)
+                other.reciprocal_into::<$unit2>(self)
             }
         }
 
         // N32 / Measure<U1> -> Measure<U2>
+        rs_measures::mark_units_operation! { concrete Division, f32, Measure<$unit1, f32> }
         impl Div<Measure<$unit1, f32>> for f32 {
             type Output = Measure<$unit2, f32>;
             fn div(self, other: Measure<$unit1, f32>) -> Self::Output {
-                Self::Output::new(self / other.value)
+                other.reciprocal_into::<$unit2>(self)
             }
         }
 
         // N64 / Measure<U2> -> Measure<U1>
+        rs_measures::mark_units_operation! { Division, f64, Measure<$unit2, Number> }
         impl<Number: ArithmeticOps> Div<Measure<$unit2, Number>> for f64 {
             type Output = Measure<$unit1, Number>;
             fn div(self, other: Measure<$unit2, Number>) -> Self::Output {
-                Self::Output::new(Number::from_f64(self) / other.value)
+                other.reciprocal_into::<$unit1>(Number::from_f64(self))
             }
         }
 
         // N32 / Measure<U2> -> Measure<U1>
+        rs_measures::mark_units_operation! { Division, f32, Measure<$unit2, Number> }
         impl<Number: ArithmeticOps> Div<Measure<$unit2, Number>> for f32 {
             type Output = Measure<$unit1, Number>;
             fn div(self, other: Measure<$unit2, Number>) -> Self::Output {
-                Self::Output::new(Number::from_f64(self as f64) / other.value)
+                other.reciprocal_into::<$unit1>(Number::from_f64(self as f64))
             }
         }
     };
@@ -297,11 +424,14 @@ macro_rules! expand_inverse {
 #[macro_export]
 macro_rules! expand_cross_2_same {
     {$unit1:ident $unit2:ident} => {
+        rs_measures::check_units_ratios! { $unit2 == $unit1 * $unit1 }
+
         // Measure2d<U1>.cross_product(Measure2d<U1>) -> Measure<U3>
+        rs_measures::mark_units_operation! { CrossMultiplication, Measure2d<$unit1, Number>, Measure2d<$unit1, Number> }
         impl<Number: ArithmeticOps> rs_measures::traits::CrossProduct<Measure2d<$unit1, Number>> for Measure2d<$unit1, Number> {
             type Output = Measure<$unit2, Number>;
             fn cross_product(self, other: Measure2d<$unit1, Number>) -> Self::Output {
-                Self::Output::new(self.x * other.y - self.y * other.x)
+                self.cross_product_into::<$unit1, $unit2>(other)
             }
         }
     };
@@ -310,19 +440,23 @@ macro_rules! expand_cross_2_same {
 #[macro_export]
 macro_rules! expand_cross_2 {
     {$unit1:ident $unit2:ident $unit3:ident} => {
+        rs_measures::check_units_ratios! { $unit3 == $unit1 * $unit2 }
+
         // Measure2d<U1>.cross_product(Measure2d<U2>) -> Measure<U3>
+        rs_measures::mark_units_operation! { CrossMultiplication, Measure2d<$unit1, Number>, Measure2d<$unit2, Number> }
         impl<Number: ArithmeticOps> rs_measures::traits::CrossProduct<Measure2d<$unit2, Number>> for Measure2d<$unit1, Number> {
             type Output = Measure<$unit3, Number>;
             fn cross_product(self, other: Measure2d<$unit2, Number>) -> Self::Output {
-                Self::Output::new(self.x * other.y - self.y * other.x)
+                self.cross_product_into::<$unit2, $unit3>(other)
             }
         }
 
         // Measure2d<U2>.cross_product(Measure2d<U1>) -> Measure<U3>
+        rs_measures::mark_units_operation! { CrossMultiplication, Measure2d<$unit2, Number>, Measure2d<$unit1, Number> }
         impl<Number: ArithmeticOps> rs_measures::traits::CrossProduct<Measure2d<$unit1, Number>> for Measure2d<$unit2, Number> {
             type Output = Measure<$unit3, Number>;
             fn cross_product(self, other: Measure2d<$unit1, Number>) -> Self::Output {
-                Self::Output::new(self.x * other.y - self.y * other.x)
+                self.cross_product_into::<$unit1, $unit3>(other)
             }
         }
     };
@@ -331,15 +465,14 @@ macro_rules! expand_cross_2 {
 #[macro_export]
 macro_rules! expand_cross_3_same {
     {$unit1:ident $unit2:ident} => {
+        rs_measures::check_units_ratios! { $unit2 == $unit1 * $unit1 }
+
         // Measure3d<U1>.cross_product(Measure3d<U1>) -> Measure<U3>
+        rs_measures::mark_units_operation! { CrossMultiplication, Measure3d<$unit1, Number>, Measure3d<$unit1, Number> }
         impl<Number: ArithmeticOps> rs_measures::traits::CrossProduct<Measure3d<$unit1, Number>> for Measure3d<$unit1, Number> {
             type Output = Measure3d<$unit2, Number>;
             fn cross_product(self, other: Measure3d<$unit1, Number>) -> Self::Output {
-                Self::Output::new(
-                    self.y * other.z - self.z * other.y,
-                    self.z * other.x - self.x * other.z,
-                    self.x * other.y - self.y * other.x,
-                )
+                self.cross_product_into::<$unit1, $unit2>(other)
             }
         }
     };
@@ -348,28 +481,139 @@ macro_rules! expand_cross_3_same {
 #[macro_export]
 macro_rules! expand_cross_3 {
     {$unit1:ident $unit2:ident $unit3:ident} => {
+        rs_measures::check_units_ratios! { $unit3 == $unit1 * $unit2 }
+
         // Measure3d<U1>.cross_product(Measure3d<U2>) -> Measure<U4>
+        rs_measures::mark_units_operation! { CrossMultiplication, Measure3d<$unit1, Number>, Measure3d<$unit2, Number> }
         impl<Number: ArithmeticOps> rs_measures::traits::CrossProduct<Measure3d<$unit2, Number>> for Measure3d<$unit1, Number> {
             type Output = Measure3d<$unit3, Number>;
             fn cross_product(self, other: Measure3d<$unit2, Number>) -> Self::Output {
-                Self::Output::new(
-                    self.y * other.z - self.z * other.y,
-                    self.z * other.x - self.x * other.z,
-                    self.x * other.y - self.y * other.x,
-                )
+                self.cross_product_into::<$unit2, $unit3>(other)
             }
         }
 
         // Measure3d<U2>.cross_product(Measure3d<U1>) -> Measure<U4>
+        rs_measures::mark_units_operation! { CrossMultiplication, Measure3d<$unit2, Number>, Measure3d<$unit1, Number> }
         impl<Number: ArithmeticOps> rs_measures::traits::CrossProduct<Measure3d<$unit1, Number>> for Measure3d<$unit2, Number> {
             type Output = Measure3d<$unit3, Number>;
             fn cross_product(self, other: Measure3d<$unit1, Number>) -> Self::Output {
-                Self::Output::new(
-                    self.y * other.z - self.z * other.y,
-                    self.z * other.x - self.x * other.z,
-                    self.x * other.y - self.y * other.x,
-                )
+                self.cross_product_into::<$unit1, $unit3>(other)
             }
         }
     };
 }
+
+// Operations among measures, used by the macro `mark_units_operation`.
+pub enum Multiplication {}
+pub enum Division {}
+pub enum CrossMultiplication {}
+
+// It is implemented by the macro `define_units_relationship`
+// for every operation it generates, having `Self` as left operand
+// and `Rhs` as right operand.
+// If two relationships generate the same operation,
+// besides the conflicting implementations of the operator,
+// the compiler reports conflicting implementations of this trait,
+// whose name states the problem, and whose arguments tell
+// which operation is defined more than once.
+pub trait UnitsOperationDefinedTwice<Operation, Rhs> {}
+
+#[macro_export]
+macro_rules! mark_units_operation {
+    { $operation:ident, $lhs:ty, $rhs:ty } => {
+        impl<Number: ArithmeticOps> rs_measures::define_units_relationship::UnitsOperationDefinedTwice<
+            rs_measures::define_units_relationship::$operation,
+            $rhs,
+        > for $lhs {}
+    };
+    // For operations among types having a specific number type.
+    { concrete $operation:ident, $lhs:ty, $rhs:ty } => {
+        impl rs_measures::define_units_relationship::UnitsOperationDefinedTwice<
+            rs_measures::define_units_relationship::$operation,
+            $rhs,
+        > for $lhs {}
+    };
+}
+
+// It returns whether two ratios of units are equal,
+// except for rounding errors.
+pub const fn ratios_match(ratio1: f64, ratio2: f64) -> bool {
+    let tolerance = if ratio1.abs() > ratio2.abs() {
+        ratio1.abs() * 1e-6
+    } else {
+        ratio2.abs() * 1e-6
+    };
+    (ratio1 - ratio2).abs() <= tolerance
+}
+
+// It checks, at compile time, that the ratios of the units of a relationship
+// are consistent with the relationship.
+// For example, the relationship `KiloMetre == Metre * Metre` is rejected,
+// as `KiloMetre::RATIO` is 1000, while `Metre::RATIO * Metre::RATIO` is 1.
+#[macro_export]
+macro_rules! check_units_ratios {
+    { $unit3:ident == $unit1:ident * $unit2:ident } => {
+        const _: () = assert!(
+            rs_measures::define_units_relationship::ratios_match(
+                <$unit3 as MeasurementUnit>::RATIO,
+                <$unit1 as MeasurementUnit>::RATIO * <$unit2 as MeasurementUnit>::RATIO,
+            ),
+            concat!(
+                "inconsistent ratios of units in the relationship ",
+                stringify!($unit3 == $unit1 * $unit2),
+            ),
+        );
+    };
+    { $unit3:ident == $unit1:ident * $unit2:ident * $unit4:ident } => {
+        const _: () = assert!(
+            rs_measures::define_units_relationship::ratios_match(
+                <$unit3 as MeasurementUnit>::RATIO,
+                <$unit1 as MeasurementUnit>::RATIO
+                    * <$unit2 as MeasurementUnit>::RATIO
+                    * <$unit4 as MeasurementUnit>::RATIO,
+            ),
+            concat!(
+                "inconsistent ratios of units in the relationship ",
+                stringify!($unit3 == $unit1 * $unit2 * $unit4),
+            ),
+        );
+    };
+    { $unit3:ident == $unit1:ident * $unit2:ident * $factor:literal } => {
+        const _: () = assert!(
+            rs_measures::define_units_relationship::ratios_match(
+                <$unit3 as MeasurementUnit>::RATIO,
+                <$unit1 as MeasurementUnit>::RATIO * <$unit2 as MeasurementUnit>::RATIO * ($factor as f64),
+            ),
+            concat!(
+                "inconsistent ratios of units in the relationship ",
+                stringify!($unit3 == $unit1 * $unit2 * $factor),
+                "; the factor is part of an equation among units, like in KiloWattHour == Watt * Hour * 1000",
+            ),
+        );
+    };
+    { $unit3:ident == $unit1:ident * $unit2:ident / $factor:literal } => {
+        const _: () = assert!(
+            rs_measures::define_units_relationship::ratios_match(
+                <$unit3 as MeasurementUnit>::RATIO * ($factor as f64),
+                <$unit1 as MeasurementUnit>::RATIO * <$unit2 as MeasurementUnit>::RATIO,
+            ),
+            concat!(
+                "inconsistent ratios of units in the relationship ",
+                stringify!($unit3 == $unit1 * $unit2 / $factor),
+                "; the factor is part of an equation among units, like in Litre == SquareMetre * Metre / 1000",
+            ),
+        );
+    };
+    { 1 == $unit1:ident * $unit2:ident } => {
+        const _: () = assert!(
+            rs_measures::define_units_relationship::ratios_match(
+                1.,
+                <$unit1 as MeasurementUnit>::RATIO * <$unit2 as MeasurementUnit>::RATIO,
+            ),
+            concat!(
+                "inconsistent ratios of units in the relationship ",
+                stringify!($unit1 == 1 / $unit2),
+            ),
+        );
+    };
+}
@@ -0,0 +1,488 @@
+// Properties encoded as vectors of exponents of the seven base dimensions
+// of the International System of Units (SI):
+// length, mass, time, electric current, temperature, amount of substance,
+// and luminous intensity.
+// Every such property has a coherent SI unit, `SiUnit<Property>`,
+// whose ratio is one.
+// Multiplying or dividing the measures of coherent SI units
+// produces a measure of the coherent SI unit of the resulting dimension,
+// without declaring any relationship,
+// after invoking the macro `define_dimension_relationships`.
+// This module is available only when the feature `dimensions` is enabled.
+// The properties which are not SI properties,
+// like angles or information quantities, keep using
+// the macro `define_units_relationship`.
+use crate::traits::{MeasurementUnit, VectorProperty};
+use core::marker::PhantomData;
+use core::ops::{Add, Sub};
+use typenum::{Diff, Integer, PartialDiv, PartialQuot, Sum, N1, N2, N3, P1, P2, P3, Z0};
+
+pub use typenum;
+
+// The property having the specified exponents of length, mass, time,
+// electric current, temperature, amount of substance, and luminous intensity.
+// The exponents are type-level integers of the crate `typenum`,
+// like `P2` for 2, `Z0` for 0, and `N1` for -1.
+pub struct Dimension<Length, Mass, Time, Current, Temperature, Amount, Luminosity>(
+    PhantomData<(Length, Mass, Time, Current, Temperature, Amount, Luminosity)>,
+);
+
+// Every dimension can be the property of vector measures.
+impl<L, M, T, I, Th, N, J> VectorProperty for Dimension<L, M, T, I, Th, N, J> {}
+
+// A property whose exponents are known at compile time.
+pub trait DimensionVector {
+    // The exponents of length, mass, time, electric current, temperature,
+    // amount of substance, and luminous intensity.
+    const EXPONENTS: [i32; 7];
+}
+
+impl<L, M, T, I, Th, N, J> DimensionVector for Dimension<L, M, T, I, Th, N, J>
+where
+    L: Integer,
+    M: Integer,
+    T: Integer,
+    I: Integer,
+    Th: Integer,
+    N: Integer,
+    J: Integer,
+{
+    const EXPONENTS: [i32; 7] = [L::I32, M::I32, T::I32, I::I32, Th::I32, N::I32, J::I32];
+}
+
+// The dimension of the product of a measure of `Self` by a measure of `Other`.
+// Its exponents are the sums of the exponents of the factors.
+pub trait DimensionProduct<Other> {
+    type Output: DimensionVector;
+}
+
+impl<L1, M1, T1, I1, Th1, N1_, J1, L2, M2, T2, I2, Th2, N2_, J2>
+    DimensionProduct<Dimension<L2, M2, T2, I2, Th2, N2_, J2>>
+    for Dimension<L1, M1, T1, I1, Th1, N1_, J1>
+where
+    L1: Add<L2>,
+    M1: Add<M2>,
+    T1: Add<T2>,
+    I1: Add<I2>,
+    Th1: Add<Th2>,
+    N1_: Add<N2_>,
+    J1: Add<J2>,
+    Sum<L1, L2>: Integer,
+    Sum<M1, M2>: Integer,
+    Sum<T1, T2>: Integer,
+    Sum<I1, I2>: Integer,
+    Sum<Th1, Th2>: Integer,
+    Sum<N1_, N2_>: Integer,
+    Sum<J1, J2>: Integer,
+{
+    type Output = Dimension<
+        Sum<L1, L2>,
+        Sum<M1, M2>,
+        Sum<T1, T2>,
+        Sum<I1, I2>,
+        Sum<Th1, Th2>,
+        Sum<N1_, N2_>,
+        Sum<J1, J2>,
+    >;
+}
+
+// The dimension of the quotient of a measure of `Self` by a measure of `Other`.
+// Its exponents are the differences of the exponents of the operands.
+pub trait DimensionQuotient<Other> {
+    type Output: DimensionVector;
+}
+
+impl<L1, M1, T1, I1, Th1, N1_, J1, L2, M2, T2, I2, Th2, N2_, J2>
+    DimensionQuotient<Dimension<L2, M2, T2, I2, Th2, N2_, J2>>
+    for Dimension<L1, M1, T1, I1, Th1, N1_, J1>
+where
+    L1: Sub<L2>,
+    M1: Sub<M2>,
+    T1: Sub<T2>,
+    I1: Sub<I2>,
+    Th1: Sub<Th2>,
+    N1_: Sub<N2_>,
+    J1: Sub<J2>,
+    Diff<L1, L2>: Integer,
+    Diff<M1, M2>: Integer,
+    Diff<T1, T2>: Integer,
+    Diff<I1, I2>: Integer,
+    Diff<Th1, Th2>: Integer,
+    Diff<N1_, N2_>: Integer,
+    Diff<J1, J2>: Integer,
+{
+    type Output = Dimension<
+        Diff<L1, L2>,
+        Diff<M1, M2>,
+        Diff<T1, T2>,
+        Diff<I1, I2>,
+        Diff<Th1, Th2>,
+        Diff<N1_, N2_>,
+        Diff<J1, J2>,
+    >;
+}
+
+// The dimension of the square root of a measure of `Self`.
+// It is implemented only if all the exponents are even.
+pub trait DimensionSquareRoot {
+    type Output: DimensionVector;
+}
+
+impl<L, M, T, I, Th, N, J> DimensionSquareRoot for Dimension<L, M, T, I, Th, N, J>
+where
+    L: PartialDiv<P2>,
+    M: PartialDiv<P2>,
+    T: PartialDiv<P2>,
+    I: PartialDiv<P2>,
+    Th: PartialDiv<P2>,
+    N: PartialDiv<P2>,
+    J: PartialDiv<P2>,
+    PartialQuot<L, P2>: Integer,
+    PartialQuot<M, P2>: Integer,
+    PartialQuot<T, P2>: Integer,
+    PartialQuot<I, P2>: Integer,
+    PartialQuot<Th, P2>: Integer,
+    PartialQuot<N, P2>: Integer,
+    PartialQuot<J, P2>: Integer,
+{
+    type Output = Dimension<
+        PartialQuot<L, P2>,
+        PartialQuot<M, P2>,
+        PartialQuot<T, P2>,
+        PartialQuot<I, P2>,
+        PartialQuot<Th, P2>,
+        PartialQuot<N, P2>,
+        PartialQuot<J, P2>,
+    >;
+}
+
+// The coherent SI unit of the specified dimension.
+// Its suffix is made of the symbols of the base units, with their exponents,
+// like " m\u{b7}kg\u{b7}s\u{207b}\u{b2}" for the newton.
+pub struct SiUnit<D>(PhantomData<D>);
+
+impl<D: DimensionVector> SiUnit<D> {
+    const SUFFIX_BUFFER: SuffixBuffer = SuffixBuffer::from_exponents(D::EXPONENTS);
+}
+
+impl<D: DimensionVector> MeasurementUnit for SiUnit<D> {
+    type Property = D;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = Self::SUFFIX_BUFFER.as_str();
+}
+
+// The symbols of the coherent SI units of the base dimensions.
+const BASE_SYMBOLS: [&str; 7] = ["m", "kg", "s", "A", "K", "mol", "cd"];
+
+// The superscript digits from 0 to 9.
+const SUPERSCRIPT_DIGITS: [&str; 10] = [
+    "\u{2070}", "\u{b9}", "\u{b2}", "\u{b3}", "\u{2074}", "\u{2075}", "\u{2076}", "\u{2077}",
+    "\u{2078}", "\u{2079}",
+];
+
+const SUPERSCRIPT_MINUS: &str = "\u{207b}";
+
+const PRODUCT_SEPARATOR: &str = "\u{b7}";
+
+// Enough for seven symbols, every one having an exponent of ten digits.
+const SUFFIX_CAPACITY: usize = 320;
+
+// A suffix of a coherent SI unit, built at compile time.
+struct SuffixBuffer {
+    bytes: [u8; SUFFIX_CAPACITY],
+    len: usize,
+}
+
+impl SuffixBuffer {
+    const fn from_exponents(exponents: [i32; 7]) -> Self {
+        let mut buffer = Self {
+            bytes: [0; SUFFIX_CAPACITY],
+            len: 0,
+        };
+        let mut i = 0;
+        while i < exponents.len() {
+            let exponent = exponents[i];
+            if exponent != 0 {
+                buffer.push(if buffer.len == 0 {
+                    " "
+                } else {
+                    PRODUCT_SEPARATOR
+                });
+                buffer.push(BASE_SYMBOLS[i]);
+                if exponent != 1 {
+                    if exponent < 0 {
+                        buffer.push(SUPERSCRIPT_MINUS);
+                    }
+                    buffer.push_superscript(exponent.unsigned_abs());
+                }
+            }
+            i += 1;
+        }
+        buffer
+    }
+
+    const fn push_superscript(&mut self, n: u32) {
+        if n >= 10 {
+            self.push_superscript(n / 10);
+        }
+        self.push(SUPERSCRIPT_DIGITS[(n % 10) as usize]);
+    }
+
+    const fn push(&mut self, s: &str) {
+        let s = s.as_bytes();
+        let mut i = 0;
+        while i < s.len() {
+            self.bytes[self.len] = s[i];
+            self.len += 1;
+            i += 1;
+        }
+    }
+
+    const fn as_str(&'static self) -> &'static str {
+        match core::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(s) => s,
+            Err(_) => panic!("invalid suffix"),
+        }
+    }
+}
+
+// The properties of the base dimensions.
+pub type Dimensionless = Dimension<Z0, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Length = Dimension<P1, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Mass = Dimension<Z0, P1, Z0, Z0, Z0, Z0, Z0>;
+pub type Time = Dimension<Z0, Z0, P1, Z0, Z0, Z0, Z0>;
+pub type ElectricCurrent = Dimension<Z0, Z0, Z0, P1, Z0, Z0, Z0>;
+pub type Temperature = Dimension<Z0, Z0, Z0, Z0, P1, Z0, Z0>;
+pub type Amount = Dimension<Z0, Z0, Z0, Z0, Z0, P1, Z0>;
+pub type LuminousIntensity = Dimension<Z0, Z0, Z0, Z0, Z0, Z0, P1>;
+
+// Some commonly used derived properties.
+pub type Area = Dimension<P2, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Volume = Dimension<P3, Z0, Z0, Z0, Z0, Z0, Z0>;
+pub type Frequency = Dimension<Z0, Z0, N1, Z0, Z0, Z0, Z0>;
+pub type Velocity = Dimension<P1, Z0, N1, Z0, Z0, Z0, Z0>;
+pub type Acceleration = Dimension<P1, Z0, N2, Z0, Z0, Z0, Z0>;
+pub type Momentum = Dimension<P1, P1, N1, Z0, Z0, Z0, Z0>;
+pub type Force = Dimension<P1, P1, N2, Z0, Z0, Z0, Z0>;
+pub type Pressure = Dimension<N1, P1, N2, Z0, Z0, Z0, Z0>;
+pub type Energy = Dimension<P2, P1, N2, Z0, Z0, Z0, Z0>;
+pub type Power = Dimension<P2, P1, N3, Z0, Z0, Z0, Z0>;
+pub type Density = Dimension<N3, P1, Z0, Z0, Z0, Z0, Z0>;
+pub type ElectricCharge = Dimension<Z0, Z0, P1, P1, Z0, Z0, Z0>;
+pub type ElectricPotential = Dimension<P2, P1, N3, N1, Z0, Z0, Z0>;
+pub type ElectricalResistance = Dimension<P2, P1, N3, N2, Z0, Z0, Z0>;
+
+// It defines the operations among the measures of coherent SI units,
+// for any dimensions.
+// It must be invoked after the macro `define_measure_types`,
+// specifying the same values of `with_2d` and `with_3d`.
+// Example:
+// ```
+// rs_measures::define_dimension_relationships! {
+//     with_2d: true,
+//     with_3d: true,
+// }
+// ```
+#[macro_export]
+macro_rules! define_dimension_relationships {
+    {
+        with_2d: $with_2d:tt,
+        with_3d: $with_3d:tt $(,)?
+    } => {
+        // Measure<SiUnit<D1>> * Measure<SiUnit<D2>> -> Measure<SiUnit<D1 * D2>>
+        impl<D1, D2, Number> Mul<Measure<rs_measures::dimensions::SiUnit<D2>, Number>>
+            for Measure<rs_measures::dimensions::SiUnit<D1>, Number>
+        where
+            D1: rs_measures::dimensions::DimensionProduct<D2> + rs_measures::dimensions::DimensionVector,
+            D2: rs_measures::dimensions::DimensionVector,
+            Number: ArithmeticOps,
+        {
+            type Output = Measure<
+                rs_measures::dimensions::SiUnit<
+                    <D1 as rs_measures::dimensions::DimensionProduct<D2>>::Output
+                >,
+                Number,
+            >;
+            fn mul(self, other: Measure<rs_measures::dimensions::SiUnit<D2>, Number>) -> Self::Output {
+                self.product_into(other)
+            }
+        }
+
+        // The generic division `Measure<U> / Measure<U> -> Number`
+        // prevents to use the operator `/` for measures of different dimensions.
+        impl<D1, Number> Measure<rs_measures::dimensions::SiUnit<D1>, Number>
+        where
+            D1: rs_measures::dimensions::DimensionVector,
+            Number: ArithmeticOps,
+        {
+            // Measure<SiUnit<D1>>.divided_by(Measure<SiUnit<D2>>) -> Measure<SiUnit<D1 / D2>>
+            pub fn divided_by<D2>(
+                self,
+                other: Measure<rs_measures::dimensions::SiUnit<D2>, Number>,
+            ) -> Measure<
+                rs_measures::dimensions::SiUnit<
+                    <D1 as rs_measures::dimensions::DimensionQuotient<D2>>::Output
+                >,
+                Number,
+            >
+            where
+                D1: rs_measures::dimensions::DimensionQuotient<D2>,
+                D2: rs_measures::dimensions::DimensionVector,
+            {
+                self.quotient_into(other)
+            }
+        }
+
+        // Measure<SiUnit<D>>.sqrt() -> Measure<SiUnit<sqrt(D)>>
+        impl<D, Number> Sqrt for Measure<rs_measures::dimensions::SiUnit<D>, Number>
+        where
+            D: rs_measures::dimensions::DimensionSquareRoot + rs_measures::dimensions::DimensionVector,
+            Number: ArithmeticOps,
+        {
+            type Output = Measure<
+                rs_measures::dimensions::SiUnit<
+                    <D as rs_measures::dimensions::DimensionSquareRoot>::Output
+                >,
+                Number,
+            >;
+            fn sqrt(self) -> Self::Output {
+                self.sqrt_into()
+            }
+        }
+
+        rs_measures::if_true! { $with_2d,
+            // Measure<SiUnit<D1>> * Measure2d<SiUnit<D2>> -> Measure2d<SiUnit<D1 * D2>>
+            impl<D1, D2, Number> Mul<Measure2d<rs_measures::dimensions::SiUnit<D2>, Number>>
+                for Measure<rs_measures::dimensions::SiUnit<D1>, Number>
+            where
+                D1: rs_measures::dimensions::DimensionProduct<D2> + rs_measures::dimensions::DimensionVector,
+                D2: rs_measures::dimensions::DimensionVector,
+                Number: ArithmeticOps,
+            {
+                type Output = Measure2d<
+                    rs_measures::dimensions::SiUnit<
+                        <D1 as rs_measures::dimensions::DimensionProduct<D2>>::Output
+                    >,
+                    Number,
+                >;
+                fn mul(self, other: Measure2d<rs_measures::dimensions::SiUnit<D2>, Number>) -> Self::Output {
+                    other.scaled_into(self)
+                }
+            }
+        }
+
+        rs_measures::if_true! { $with_2d,
+            // Measure2d<SiUnit<D1>> * Measure<SiUnit<D2>> -> Measure2d<SiUnit<D1 * D2>>
+            impl<D1, D2, Number> Mul<Measure<rs_measures::dimensions::SiUnit<D2>, Number>>
+                for Measure2d<rs_measures::dimensions::SiUnit<D1>, Number>
+            where
+                D1: rs_measures::dimensions::DimensionProduct<D2> + rs_measures::dimensions::DimensionVector,
+                D2: rs_measures::dimensions::DimensionVector,
+                Number: ArithmeticOps,
+            {
+                type Output = Measure2d<
+                    rs_measures::dimensions::SiUnit<
+                        <D1 as rs_measures::dimensions::DimensionProduct<D2>>::Output
+                    >,
+                    Number,
+                >;
+                fn mul(self, other: Measure<rs_measures::dimensions::SiUnit<D2>, Number>) -> Self::Output {
+                    self.scaled_into(other)
+                }
+            }
+        }
+
+        rs_measures::if_true! { $with_2d,
+            impl<D1, Number> Measure2d<rs_measures::dimensions::SiUnit<D1>, Number>
+            where
+                D1: rs_measures::dimensions::DimensionVector,
+                Number: ArithmeticOps,
+            {
+                // Measure2d<SiUnit<D1>>.divided_by(Measure<SiUnit<D2>>) -> Measure2d<SiUnit<D1 / D2>>
+                pub fn divided_by<D2>(
+                    self,
+                    other: Measure<rs_measures::dimensions::SiUnit<D2>, Number>,
+                ) -> Measure2d<
+                    rs_measures::dimensions::SiUnit<
+                        <D1 as rs_measures::dimensions::DimensionQuotient<D2>>::Output
+                    >,
+                    Number,
+                >
+                where
+                    D1: rs_measures::dimensions::DimensionQuotient<D2>,
+                    D2: rs_measures::dimensions::DimensionVector,
+                {
+                    self.divided_into(other)
+                }
+            }
+        }
+
+        rs_measures::if_true! { $with_3d,
+            // Measure<SiUnit<D1>> * Measure3d<SiUnit<D2>> -> Measure3d<SiUnit<D1 * D2>>
+            impl<D1, D2, Number> Mul<Measure3d<rs_measures::dimensions::SiUnit<D2>, Number>>
+                for Measure<rs_measures::dimensions::SiUnit<D1>, Number>
+            where
+                D1: rs_measures::dimensions::DimensionProduct<D2> + rs_measures::dimensions::DimensionVector,
+                D2: rs_measures::dimensions::DimensionVector,
+                Number: ArithmeticOps,
+            {
+                type Output = Measure3d<
+                    rs_measures::dimensions::SiUnit<
+                        <D1 as rs_measures::dimensions::DimensionProduct<D2>>::Output
+                    >,
+                    Number,
+                >;
+                fn mul(self, other: Measure3d<rs_measures::dimensions::SiUnit<D2>, Number>) -> Self::Output {
+                    other.scaled_into(self)
+                }
+            }
+        }
+
+        rs_measures::if_true! { $with_3d,
+            // Measure3d<SiUnit<D1>> * Measure<SiUnit<D2>> -> Measure3d<SiUnit<D1 * D2>>
+            impl<D1, D2, Number> Mul<Measure<rs_measures::dimensions::SiUnit<D2>, Number>>
+                for Measure3d<rs_measures::dimensions::SiUnit<D1>, Number>
+            where
+                D1: rs_measures::dimensions::DimensionProduct<D2> + rs_measures::dimensions::DimensionVector,
+                D2: rs_measures::dimensions::DimensionVector,
+                Number: ArithmeticOps,
+            {
+                type Output = Measure3d<
+                    rs_measures::dimensions::SiUnit<
+                        <D1 as rs_measures::dimensions::DimensionProduct<D2>>::Output
+                    >,
+                    Number,
+                >;
+                fn mul(self, other: Measure<rs_measures::dimensions::SiUnit<D2>, Number>) -> Self::Output {
+                    self.scaled_into(other)
+                }
+            }
+        }
+
+        rs_measures::if_true! { $with_3d,
+            impl<D1, Number> Measure3d<rs_measures::dimensions::SiUnit<D1>, Number>
+            where
+                D1: rs_measures::dimensions::DimensionVector,
+                Number: ArithmeticOps,
+            {
+                // Measure3d<SiUnit<D1>>.divided_by(Measure<SiUnit<D2>>) -> Measure3d<SiUnit<D1 / D2>>
+                pub fn divided_by<D2>(
+                    self,
+                    other: Measure<rs_measures::dimensions::SiUnit<D2>, Number>,
+                ) -> Measure3d<
+                    rs_measures::dimensions::SiUnit<
+                        <D1 as rs_measures::dimensions::DimensionQuotient<D2>>::Output
+                    >,
+                    Number,
+                >
+                where
+                    D1: rs_measures::dimensions::DimensionQuotient<D2>,
+                    D2: rs_measures::dimensions::DimensionVector,
+                {
+                    self.divided_into(other)
+                }
+            }
+        }
+    };
+}
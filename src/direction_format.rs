@@ -0,0 +1,213 @@
+// Formats and parsers of directions used in navigation:
+// degrees, minutes and seconds, compass points, and nautical bearings.
+// All the angles handled by these functions are in degrees.
+use crate::parsing::{ParseMeasureError, Parser};
+use crate::traits::ArithmeticOps;
+use std::fmt;
+
+// The text appended to a formatted direction to describe its range of values,
+// like " (in 0°..360°)", where `cycle_fraction` is the value of a cycle
+// in the unit of measurement having the specified suffix.
+pub fn direction_range(suffix: &str, cycle_fraction: f64, signed: bool) -> String {
+    let (cycle, half_cycle) = if cycle_fraction == std::f64::consts::TAU {
+        ("2\u{3c0}".to_string(), "\u{3c0}".to_string())
+    } else {
+        (
+            format!("{}", cycle_fraction),
+            format!("{}", cycle_fraction / 2.),
+        )
+    };
+    if signed {
+        format!(" (in -{half_cycle}{suffix}..{half_cycle}{suffix})")
+    } else {
+        format!(" (in 0{suffix}..{cycle}{suffix})")
+    }
+}
+
+// The text that was appended to every formatted direction before `direction_range`,
+// which described the range of values in degrees whatever the unit of measurement.
+// It is still accepted when parsing a direction.
+pub fn legacy_direction_range(signed: bool) -> &'static str {
+    if signed {
+        " (in -180\u{b0}..180\u{b0})"
+    } else {
+        " (in 0\u{b0}..360\u{b0})"
+    }
+}
+
+// The number of points of a compass rose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompassPoints {
+    // N, E, S, W.
+    Four = 4,
+    // N, NE, E, SE, S, SW, W, NW.
+    Eight = 8,
+    // N, NNE, NE, ENE, E, and so on.
+    Sixteen = 16,
+    // N, NbE, NNE, NEbN, NE, and so on.
+    ThirtyTwo = 32,
+}
+
+// The names of the 32 points of the compass rose, clockwise from north.
+const COMPASS_POINT_NAMES: [&str; 32] = [
+    "N", "NbE", "NNE", "NEbN", "NE", "NEbE", "ENE", "EbN", "E", "EbS", "ESE", "SEbE", "SE", "SEbS",
+    "SSE", "SbE", "S", "SbW", "SSW", "SWbS", "SW", "SWbW", "WSW", "WbS", "W", "WbN", "WNW", "NWbW",
+    "NW", "NWbN", "NNW", "NbW",
+];
+
+// The name of the compass point nearest to the specified bearing,
+// which must be between 0 and 360 degrees.
+pub fn compass_point<Number: ArithmeticOps>(
+    bearing: Number,
+    points: CompassPoints,
+) -> &'static str {
+    let count = points as usize;
+    let step = Number::from_f64(360. / count as f64);
+    let index = (0..count)
+        .find(|k| bearing < (Number::from_f64(*k as f64) + Number::HALF) * step)
+        .unwrap_or(0);
+    COMPASS_POINT_NAMES[index * (32 / count)]
+}
+
+// The bearing of the compass point having the specified name, ignoring case.
+pub fn parse_compass_point<Number: ArithmeticOps>(text: &str) -> Result<Number, ParseMeasureError> {
+    let name = text.trim();
+    COMPASS_POINT_NAMES
+        .iter()
+        .position(|n| n.eq_ignore_ascii_case(name))
+        .map(|index| Number::from_f64(index as f64 * 11.25))
+        .ok_or_else(|| ParseMeasureError::UnexpectedText {
+            expected: "a compass point",
+            found: name.to_string(),
+        })
+}
+
+// A non-negative value rounded to an integer.
+fn round<Number: ArithmeticOps>(x: Number) -> Number {
+    let y = x + Number::HALF;
+    y - y % Number::ONE
+}
+
+// Ten raised to the specified number of decimals.
+fn decimal_scale<Number: ArithmeticOps>(decimals: usize) -> Number {
+    (0..decimals).fold(Number::ONE, |scale, _| scale * Number::from_f64(10.))
+}
+
+// An angle formatted as degrees, minutes and seconds, like 45°30'15.2".
+// The precision of the formatter is the number of decimals of the seconds,
+// and it is zero by default.
+pub struct Dms<Number> {
+    degrees: Number,
+}
+
+impl<Number: ArithmeticOps> Dms<Number> {
+    pub fn new(degrees: Number) -> Self {
+        Self { degrees }
+    }
+}
+
+impl<Number: ArithmeticOps> fmt::Display for Dms<Number> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimals = formatter.precision().unwrap_or(0);
+        let scale = decimal_scale::<Number>(decimals);
+        let negative = self.degrees < Number::ZERO;
+        let abs = if negative {
+            -self.degrees
+        } else {
+            self.degrees
+        };
+        // The rounding is performed on the count of the smallest printed fractions of second,
+        // and so a carry is propagated to the minutes and to the degrees.
+        let count = round(abs * Number::from_f64(3600.) * scale);
+        let per_minute = Number::from_f64(60.) * scale;
+        let total_minutes = (count - count % per_minute) / per_minute;
+        let seconds = (count - total_minutes * per_minute) / scale;
+        let sixty = Number::from_f64(60.);
+        let degrees = (total_minutes - total_minutes % sixty) / sixty;
+        let minutes = total_minutes - degrees * sixty;
+        if negative && count > Number::ZERO {
+            formatter.write_str("-")?;
+        }
+        let width = if decimals > 0 { decimals + 3 } else { 2 };
+        write!(
+            formatter,
+            "{:.0}\u{b0}{:02.0}'{:0width$.decimals$}\"",
+            degrees, minutes, seconds
+        )
+    }
+}
+
+// It parses an angle expressed in degrees, optionally followed by minutes and seconds,
+// like 45°30'15.2", -12°5', or 7.5°.
+// Minutes may be marked also by ′, and seconds also by ″.
+pub fn parse_dms<Number: ArithmeticOps>(text: &str) -> Result<Number, ParseMeasureError> {
+    let mut parser = Parser::new(text);
+    let negative = parser.optional_text("-");
+    let mut value: Number = parser.number()?;
+    parser.text("\u{b0}")?;
+    let sixty = Number::from_f64(60.);
+    let mut divisor = Number::ONE;
+    for (mark, alternative_mark) in [("'", "\u{2032}"), ("\"", "\u{2033}")] {
+        if parser.is_at_end() {
+            break;
+        }
+        let part: Number = parser.number()?;
+        if !parser.optional_text(alternative_mark) {
+            parser.text(mark)?;
+        }
+        if part >= sixty {
+            return Err(ParseMeasureError::InvalidNumber(text.trim().to_string()));
+        }
+        divisor *= sixty;
+        value += part / divisor;
+    }
+    if !parser.is_at_end() {
+        return Err(ParseMeasureError::UnexpectedText {
+            expected: "",
+            found: parser.rest().to_string(),
+        });
+    }
+    Ok(if negative { -value } else { value })
+}
+
+// A nautical bearing, that is an angle clockwise from north, between 0 and 360 degrees,
+// formatted with three integer digits, like 045°.
+// The precision of the formatter is the number of decimals, and it is zero by default.
+pub struct Bearing<Number> {
+    degrees: Number,
+}
+
+impl<Number: ArithmeticOps> Bearing<Number> {
+    pub fn new(degrees: Number) -> Self {
+        Self { degrees }
+    }
+}
+
+impl<Number: ArithmeticOps> fmt::Display for Bearing<Number> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimals = formatter.precision().unwrap_or(0);
+        let scale = decimal_scale::<Number>(decimals);
+        let full_circle = Number::from_f64(360.) * scale;
+        let mut count = round(self.degrees * scale);
+        if count >= full_circle {
+            count -= full_circle;
+        }
+        let width = if decimals > 0 { decimals + 4 } else { 3 };
+        write!(formatter, "{:0width$.decimals$}\u{b0}", count / scale)
+    }
+}
+
+// It parses a nautical bearing in degrees, optionally followed by the degree sign,
+// like 045° or 312.5.
+pub fn parse_bearing<Number: ArithmeticOps>(text: &str) -> Result<Number, ParseMeasureError> {
+    let mut parser = Parser::new(text);
+    let value = parser.number()?;
+    parser.optional_text("\u{b0}");
+    if !parser.is_at_end() {
+        return Err(ParseMeasureError::UnexpectedText {
+            expected: "\u{b0}",
+            found: parser.rest().to_string(),
+        });
+    }
+    Ok(value)
+}
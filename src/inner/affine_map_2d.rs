@@ -184,10 +184,7 @@ macro_rules! inner_define_affine_map_2d {
             }
 
             pub fn apply_to(&self, m: MeasurePoint2d<Unit, Number>) -> MeasurePoint2d<Unit, Number> {
-                MeasurePoint2d::<Unit, Number>::new(
-                    self.c[0][0] * m.x + self.c[0][1] * m.y + self.c[0][2],
-                    self.c[1][0] * m.x + self.c[1][1] * m.y + self.c[1][2],
-                )
+                m.affinely_transformed(&self.c)
             }
 
             fn rotation_by_radians(fp_x: Number, fp_y: Number, radians: Number) -> Self {
@@ -267,5 +264,38 @@ macro_rules! inner_define_affine_map_2d {
                 )
             }
         }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for AffineMap2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                for AffineMap2d<Unit, Number>
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::MapRepr {
+                            coefficients: self.c,
+                            unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for AffineMap2d
+            impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                for AffineMap2d<Unit, Number>
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::MapRepr<[[Number; 3]; 2]> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                    Ok(Self {
+                        c: repr.coefficients,
+                        phantom: std::marker::PhantomData,
+                    })
+                }
+            }
+        }
     };
 }
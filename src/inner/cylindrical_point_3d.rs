@@ -0,0 +1,213 @@
+#[macro_export]
+macro_rules! inner_define_cylindrical_point_3d {
+    {} => {
+        // A point in the space, expressed in cylindrical coordinates:
+        // the polar coordinates of its projection on the XY plane, and its Z coordinate.
+        pub struct CylindricalPoint3d<LengthUnit: MeasurementUnit, AngleUnit, Number: ArithmeticOps = f64> {
+            pub radius: Measure<LengthUnit, Number>,
+            pub azimuth: UnsignedDirection<AngleUnit, Number>,
+            pub z: Measure<LengthUnit, Number>,
+        }
+
+        impl<LengthUnit, AngleUnit, Number> CylindricalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            pub const fn new(
+                radius: Measure<LengthUnit, Number>,
+                azimuth: UnsignedDirection<AngleUnit, Number>,
+                z: Measure<LengthUnit, Number>,
+            ) -> Self {
+                Self { radius, azimuth, z }
+            }
+
+            // The cylindrical coordinates of a point expressed in cartesian coordinates.
+            // For the points on the Z axis, the azimuth is zero.
+            pub fn from_point(point: MeasurePoint3d<LengthUnit, Number>) -> Self {
+                Self::new(
+                    Measure::<LengthUnit, Number>::new((point.x * point.x + point.y * point.y).sqrt()),
+                    UnsignedDirection::<AngleUnit, Number>::new(
+                        point.y.atan2(point.x) / Number::from_f64(AngleUnit::RATIO),
+                    ),
+                    Measure::<LengthUnit, Number>::new(point.z),
+                )
+            }
+
+            // The cartesian coordinates of the point.
+            pub fn to_point(&self) -> MeasurePoint3d<LengthUnit, Number> {
+                let (sin, cos) = (self.azimuth.value * Number::from_f64(AngleUnit::RATIO)).sin_cos();
+                MeasurePoint3d::<LengthUnit, Number>::new(
+                    self.radius.value * cos,
+                    self.radius.value * sin,
+                    self.z.value,
+                )
+            }
+
+            pub fn convert<
+                DestLengthUnit: MeasurementUnit<Property = LengthUnit::Property>,
+                DestAngleUnit: AngleMeasurementUnit<Property = Angle>,
+            >(
+                &self,
+            ) -> CylindricalPoint3d<DestLengthUnit, DestAngleUnit, Number>
+            where
+                DestLengthUnit::Property: VectorProperty,
+            {
+                CylindricalPoint3d::<DestLengthUnit, DestAngleUnit, Number>::new(
+                    self.radius.convert::<DestLengthUnit>(),
+                    self.azimuth.convert::<DestAngleUnit>(),
+                    self.z.convert::<DestLengthUnit>(),
+                )
+            }
+        }
+
+        // CylindricalPoint3d -> MeasurePoint3d
+        impl<LengthUnit, AngleUnit, Number> From<CylindricalPoint3d<LengthUnit, AngleUnit, Number>>
+            for MeasurePoint3d<LengthUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn from(point: CylindricalPoint3d<LengthUnit, AngleUnit, Number>) -> Self {
+                point.to_point()
+            }
+        }
+
+        // MeasurePoint3d -> CylindricalPoint3d
+        impl<LengthUnit, AngleUnit, Number> From<MeasurePoint3d<LengthUnit, Number>>
+            for CylindricalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn from(point: MeasurePoint3d<LengthUnit, Number>) -> Self {
+                Self::from_point(point)
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> Default for CylindricalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            // It returns the origin.
+            fn default() -> Self {
+                Self::new(
+                    Measure::<LengthUnit, Number>::new(Number::ZERO),
+                    UnsignedDirection::<AngleUnit, Number>::new(Number::ZERO),
+                    Measure::<LengthUnit, Number>::new(Number::ZERO),
+                )
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> Clone for CylindricalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> Copy for CylindricalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+        }
+
+        impl<LengthUnit, AngleUnit, Number> PartialEq for CylindricalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.radius.value == other.radius.value
+                    && self.azimuth.value == other.azimuth.value
+                    && self.z.value == other.z.value
+            }
+        }
+
+        // format!("{}", CylindricalPoint3d)
+        impl<LengthUnit, AngleUnit, Number> fmt::Display for CylindricalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "radius ")?;
+                fmt::Display::fmt(&self.radius.value, formatter)?;
+                write!(formatter, "{}, azimuth ", LengthUnit::SUFFIX)?;
+                fmt::Display::fmt(&self.azimuth.value, formatter)?;
+                write!(formatter, "{}, z ", AngleUnit::SUFFIX)?;
+                fmt::Display::fmt(&self.z.value, formatter)?;
+                write!(formatter, "{}", LengthUnit::SUFFIX)
+            }
+        }
+
+        // format!("{:?}", CylindricalPoint3d)
+        impl<LengthUnit, AngleUnit, Number> fmt::Debug for CylindricalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for CylindricalPoint3d
+            impl<LengthUnit: MeasurementUnit, AngleUnit, Number: ArithmeticOps> rs_measures::serde::Serialize
+                for CylindricalPoint3d<LengthUnit, AngleUnit, Number>
+            where
+                Measure<LengthUnit, Number>: rs_measures::serde::Serialize,
+                UnsignedDirection<AngleUnit, Number>: rs_measures::serde::Serialize,
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::CylindricalRepr {
+                            radius: &self.radius,
+                            azimuth: &self.azimuth,
+                            z: &self.z,
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for CylindricalPoint3d
+            impl<'de, LengthUnit: MeasurementUnit, AngleUnit, Number: ArithmeticOps> rs_measures::serde::Deserialize<'de>
+                for CylindricalPoint3d<LengthUnit, AngleUnit, Number>
+            where
+                Measure<LengthUnit, Number>: rs_measures::serde::Deserialize<'de>,
+                UnsignedDirection<AngleUnit, Number>: rs_measures::serde::Deserialize<'de>,
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::CylindricalRepr<Measure<LengthUnit, Number>, UnsignedDirection<AngleUnit, Number>> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    Ok(Self {
+                        radius: repr.radius,
+                        azimuth: repr.azimuth,
+                        z: repr.z,
+                    })
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,183 @@
+#[macro_export]
+macro_rules! inner_define_direction_3d {
+    {} => {
+        // A direction in the space, identified by an azimuth and an elevation.
+        // The azimuth is the angle of the projection on the XY plane,
+        // counterclockwise from the X axis.
+        // The elevation is the angle from the XY plane, positive towards the Z axis.
+        // It is represented by the components of a unit vector,
+        // and so the directions near the poles are as precise as the others.
+        pub struct Direction3d<AngleUnit, Number: ArithmeticOps = f64> {
+            unit_vector: [Number; 3],
+            phantom: PhantomData<AngleUnit>,
+        }
+
+        impl<AngleUnit: AngleMeasurementUnit<Property = Angle>, Number: ArithmeticOps>
+            Direction3d<AngleUnit, Number>
+        {
+            // It builds a direction from its azimuth and its elevation,
+            // expressed in `AngleUnit`.
+            pub fn new(azimuth: Number, elevation: Number) -> Self {
+                let factor = Number::from_f64(AngleUnit::RATIO);
+                let (sin_az, cos_az) = (azimuth * factor).sin_cos();
+                let (sin_el, cos_el) = (elevation * factor).sin_cos();
+                Self {
+                    unit_vector: [cos_el * cos_az, cos_el * sin_az, sin_el],
+                    phantom: PhantomData,
+                }
+            }
+
+            // The direction of a non-zero vector.
+            fn from_vector(v: [Number; 3]) -> Self {
+                let norm = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
+                Self {
+                    unit_vector: v.map(|c| c / norm),
+                    phantom: PhantomData,
+                }
+            }
+
+            // The angle of the projection on the XY plane, counterclockwise from the X axis.
+            // For the vertical directions, it is zero.
+            pub fn azimuth(&self) -> UnsignedDirection<AngleUnit, Number> {
+                let [x, y, _] = self.unit_vector;
+                UnsignedDirection::<AngleUnit, Number>::new(
+                    y.atan2(x) / Number::from_f64(AngleUnit::RATIO),
+                )
+            }
+
+            // The angle from the XY plane, between -90 and +90 degrees.
+            pub fn elevation(&self) -> Measure<AngleUnit, Number> {
+                let [x, y, z] = self.unit_vector;
+                Measure::<AngleUnit, Number>::new(
+                    z.atan2((x * x + y * y).sqrt()) / Number::from_f64(AngleUnit::RATIO),
+                )
+            }
+
+            // The unit vector having this direction.
+            pub fn to_unit_vector<Unit: MeasurementUnit>(&self) -> Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                Measure3d::<Unit, Number>::from_direction(*self)
+            }
+
+            pub fn convert<DestUnit: AngleMeasurementUnit<Property = Angle>>(
+                &self,
+            ) -> Direction3d<DestUnit, Number> {
+                Direction3d::<DestUnit, Number> {
+                    unit_vector: self.unit_vector,
+                    phantom: PhantomData,
+                }
+            }
+
+            // The angle between two directions, between 0 and 180 degrees.
+            pub fn angular_distance(&self, other: &Direction3d<AngleUnit, Number>) -> Measure<AngleUnit, Number> {
+                let [x1, y1, z1] = self.unit_vector;
+                let [x2, y2, z2] = other.unit_vector;
+                // Using the arctangent of the norm of the cross product over the dot product
+                // is more precise than using the arccosine of the dot product for small angles.
+                let cx = y1 * z2 - z1 * y2;
+                let cy = z1 * x2 - x1 * z2;
+                let cz = x1 * y2 - y1 * x2;
+                let sin = (cx * cx + cy * cy + cz * cz).sqrt();
+                let cos = x1 * x2 + y1 * y2 + z1 * z2;
+                Measure::<AngleUnit, Number>::new(sin.atan2(cos) / Number::from_f64(AngleUnit::RATIO))
+            }
+
+            // Spherical linear interpolation, moving at constant angular speed
+            // along the great circle from `self`, when `t == 0`, to `other`, when `t == 1`.
+            // For opposite directions, the great circle is undetermined.
+            pub fn slerp(&self, other: &Direction3d<AngleUnit, Number>, t: Number) -> Self {
+                let angle = self.angular_distance(other).value * Number::from_f64(AngleUnit::RATIO);
+                let sin = angle.sin();
+                let (k1, k2) = if sin.abs() < Number::from_f64(1e-6) {
+                    // For nearby directions, linear interpolation avoids a division by almost zero.
+                    (Number::ONE - t, t)
+                } else {
+                    (((Number::ONE - t) * angle).sin() / sin, (t * angle).sin() / sin)
+                };
+                Self::from_vector(core::array::from_fn(|i| {
+                    k1 * self.unit_vector[i] + k2 * other.unit_vector[i]
+                }))
+            }
+        }
+
+        impl<AngleUnit: AngleMeasurementUnit<Property = Angle>, Number: ArithmeticOps> Default
+            for Direction3d<AngleUnit, Number>
+        {
+            // It returns the direction of the X axis.
+            fn default() -> Self {
+                Self::new(Number::ZERO, Number::ZERO)
+            }
+        }
+
+        impl<AngleUnit, Number: ArithmeticOps> Clone for Direction3d<AngleUnit, Number> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<AngleUnit, Number: ArithmeticOps> Copy for Direction3d<AngleUnit, Number> {}
+
+        impl<AngleUnit, Number: ArithmeticOps> PartialEq for Direction3d<AngleUnit, Number> {
+            fn eq(&self, other: &Self) -> bool {
+                self.unit_vector == other.unit_vector
+            }
+        }
+
+        // format!("{}", Direction3d)
+        impl<AngleUnit: AngleMeasurementUnit<Property = Angle>, Number: ArithmeticOps> fmt::Display
+            for Direction3d<AngleUnit, Number>
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "azimuth ")?;
+                fmt::Display::fmt(&self.azimuth().value, formatter)?;
+                write!(formatter, "{}, elevation ", AngleUnit::SUFFIX)?;
+                fmt::Display::fmt(&self.elevation().value, formatter)?;
+                write!(formatter, "{}", AngleUnit::SUFFIX)
+            }
+        }
+
+        // format!("{:?}", Direction3d)
+        impl<AngleUnit: AngleMeasurementUnit<Property = Angle>, Number: ArithmeticOps> fmt::Debug
+            for Direction3d<AngleUnit, Number>
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for Direction3d
+            impl<AngleUnit: AngleMeasurementUnit<Property = Angle>, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                for Direction3d<AngleUnit, Number>
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::Direction3dRepr {
+                            unit_vector: self.unit_vector,
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for Direction3d
+            impl<'de, AngleUnit: AngleMeasurementUnit<Property = Angle>, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                for Direction3d<AngleUnit, Number>
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::Direction3dRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    if repr.unit_vector.iter().all(|c| *c == Number::ZERO) {
+                        return Err(<D::Error as rs_measures::serde::de::Error>::custom(
+                            "the vector of a direction cannot be null",
+                        ));
+                    }
+                    Ok(Self::from_vector(repr.unit_vector))
+                }
+            }
+        }
+    };
+}
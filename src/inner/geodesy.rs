@@ -0,0 +1,502 @@
+#[macro_export]
+macro_rules! inner_define_geodesy {
+    { $with_transformations:tt } => {
+        // A reference ellipsoid of revolution, approximating the shape of the Earth,
+        // like the WGS84 ellipsoid used by GPS.
+        pub struct Ellipsoid<LengthUnit: MeasurementUnit, Number: ArithmeticOps = f64> {
+            // The equatorial radius.
+            pub semi_major_axis: Measure<LengthUnit, Number>,
+            // The ratio (a - b) / a, where a is the equatorial radius
+            // and b is the polar radius. It is zero for a sphere.
+            pub flattening: Number,
+        }
+
+        // A point expressed in geodetic coordinates, relative to an `Ellipsoid`:
+        // the latitude, positive northwards, the longitude, positive eastwards,
+        // and the height above the surface of the ellipsoid.
+        pub struct GeodeticPoint<LengthUnit: MeasurementUnit, AngleUnit, Number: ArithmeticOps = f64> {
+            // It is between -90 and +90 degrees.
+            pub latitude: SignedDirection<AngleUnit, Number>,
+            pub longitude: SignedDirection<AngleUnit, Number>,
+            pub height: Measure<LengthUnit, Number>,
+        }
+
+        impl<LengthUnit, Number> Ellipsoid<LengthUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            Number: ArithmeticOps,
+        {
+            pub const fn new(semi_major_axis: Measure<LengthUnit, Number>, flattening: Number) -> Self {
+                Self {
+                    semi_major_axis,
+                    flattening,
+                }
+            }
+
+            // The ellipsoid of the World Geodetic System 1984.
+            // Its size is computed assuming that the unit of length having ratio 1 is the metre.
+            pub fn wgs84() -> Self {
+                Self::new(
+                    Measure::<LengthUnit, Number>::new(Number::from_f64(6_378_137. / LengthUnit::RATIO)),
+                    Number::from_f64(1. / 298.257_223_563),
+                )
+            }
+
+            // The polar radius.
+            pub fn semi_minor_axis(&self) -> Measure<LengthUnit, Number> {
+                Measure::<LengthUnit, Number>::new(self.semi_major_axis.value * (Number::ONE - self.flattening))
+            }
+
+            pub fn convert<DestUnit: MeasurementUnit<Property = LengthUnit::Property>>(
+                &self,
+            ) -> Ellipsoid<DestUnit, Number> {
+                Ellipsoid::<DestUnit, Number>::new(self.semi_major_axis.convert::<DestUnit>(), self.flattening)
+            }
+
+            fn eccentricity_squared(&self) -> Number {
+                self.flattening * (Number::ONE + Number::ONE - self.flattening)
+            }
+
+            // The radius of curvature in the prime vertical, at a latitude having the specified sine.
+            fn prime_vertical_radius(&self, sin_latitude: Number) -> Number {
+                self.semi_major_axis.value
+                    / (Number::ONE - self.eccentricity_squared() * sin_latitude * sin_latitude).sqrt()
+            }
+
+            // The Earth-centred Earth-fixed cartesian coordinates of a point.
+            // The X axis points to latitude 0 and longitude 0,
+            // and the Z axis points to the north pole.
+            pub fn to_ecef<AngleUnit: AngleMeasurementUnit<Property = Angle>>(
+                &self,
+                point: &GeodeticPoint<LengthUnit, AngleUnit, Number>,
+            ) -> MeasurePoint3d<LengthUnit, Number> {
+                let factor = Number::from_f64(AngleUnit::RATIO);
+                let (sin_lat, cos_lat) = (point.latitude.value * factor).sin_cos();
+                let (sin_lon, cos_lon) = (point.longitude.value * factor).sin_cos();
+                let n = self.prime_vertical_radius(sin_lat);
+                let h = point.height.value;
+                MeasurePoint3d::<LengthUnit, Number>::new(
+                    (n + h) * cos_lat * cos_lon,
+                    (n + h) * cos_lat * sin_lon,
+                    (n * (Number::ONE - self.eccentricity_squared()) + h) * sin_lat,
+                )
+            }
+
+            // The geodetic coordinates of a point expressed in Earth-centred Earth-fixed
+            // cartesian coordinates.
+            // For the points on the polar axis, the longitude is zero.
+            pub fn from_ecef<AngleUnit: AngleMeasurementUnit<Property = Angle>>(
+                &self,
+                point: MeasurePoint3d<LengthUnit, Number>,
+            ) -> GeodeticPoint<LengthUnit, AngleUnit, Number> {
+                let e2 = self.eccentricity_squared();
+                let p = (point.x * point.x + point.y * point.y).sqrt();
+                // The fixed-point iteration on tan(lat) = (z + e2 N sin(lat)) / p
+                // converges to the precision of the numbers in a few steps,
+                // for every point not too near to the centre of the Earth.
+                let mut latitude = point.z.atan2(p * (Number::ONE - e2));
+                for _ in 0..10 {
+                    let sin_lat = latitude.sin();
+                    latitude = (point.z + e2 * self.prime_vertical_radius(sin_lat) * sin_lat).atan2(p);
+                }
+                let (sin_lat, cos_lat) = latitude.sin_cos();
+                // This formula for the height is accurate also near the poles.
+                let height = p * cos_lat + point.z * sin_lat
+                    - self.semi_major_axis.value * (Number::ONE - e2 * sin_lat * sin_lat).sqrt();
+                let factor = Number::ONE / Number::from_f64(AngleUnit::RATIO);
+                GeodeticPoint::<LengthUnit, AngleUnit, Number>::new(
+                    SignedDirection::<AngleUnit, Number>::new(latitude * factor),
+                    SignedDirection::<AngleUnit, Number>::new(point.y.atan2(point.x) * factor),
+                    Measure::<LengthUnit, Number>::new(height),
+                )
+            }
+
+            // The length of the shortest path on the surface of the ellipsoid
+            // between the projections of two points, ignoring their heights.
+            // It uses the Vincenty's inverse formula, which is accurate to less than a millimetre,
+            // but it may be less accurate for almost antipodal points.
+            pub fn geodesic_distance<AngleUnit: AngleMeasurementUnit<Property = Angle>>(
+                &self,
+                from: &GeodeticPoint<LengthUnit, AngleUnit, Number>,
+                to: &GeodeticPoint<LengthUnit, AngleUnit, Number>,
+            ) -> Measure<LengthUnit, Number> {
+                Measure::<LengthUnit, Number>::new(self.vincenty_inverse(from, to).0)
+            }
+
+            // The direction in which the shortest path to `to` leaves `from`,
+            // measured clockwise from the north, like a compass bearing.
+            // For coincident points, it is zero.
+            pub fn initial_bearing<AngleUnit: AngleMeasurementUnit<Property = Angle>>(
+                &self,
+                from: &GeodeticPoint<LengthUnit, AngleUnit, Number>,
+                to: &GeodeticPoint<LengthUnit, AngleUnit, Number>,
+            ) -> UnsignedDirection<AngleUnit, Number> {
+                UnsignedDirection::<AngleUnit, Number>::new(
+                    self.vincenty_inverse(from, to).1 / Number::from_f64(AngleUnit::RATIO),
+                )
+            }
+
+            // The same direction returned by `initial_bearing`,
+            // but measured in the local East-North plane counterclockwise from the east,
+            // like the directions of `Measure2d`.
+            // Its method `bearing` formats it clockwise from north.
+            // For coincident points, it is the north.
+            pub fn initial_direction<AngleUnit: AngleMeasurementUnit<Property = Angle>>(
+                &self,
+                from: &GeodeticPoint<LengthUnit, AngleUnit, Number>,
+                to: &GeodeticPoint<LengthUnit, AngleUnit, Number>,
+            ) -> UnsignedDirection<AngleUnit, Number> {
+                UnsignedDirection::<AngleUnit, Number>::new(
+                    Number::from_f64(AngleUnit::CYCLE_FRACTION / 4.)
+                        - self.vincenty_inverse(from, to).1 / Number::from_f64(AngleUnit::RATIO),
+                )
+            }
+
+            // The geodesic distance and the initial bearing in radians.
+            fn vincenty_inverse<AngleUnit: AngleMeasurementUnit<Property = Angle>>(
+                &self,
+                from: &GeodeticPoint<LengthUnit, AngleUnit, Number>,
+                to: &GeodeticPoint<LengthUnit, AngleUnit, Number>,
+            ) -> (Number, Number) {
+                let one = Number::ONE;
+                let two = one + one;
+                let three = two + one;
+                let four = two + two;
+                let factor = Number::from_f64(AngleUnit::RATIO);
+                let f = self.flattening;
+                let a = self.semi_major_axis.value;
+                let b = a * (one - f);
+                // The reduced latitudes.
+                let (sin_lat1, cos_lat1) = (from.latitude.value * factor).sin_cos();
+                let (sin_lat2, cos_lat2) = (to.latitude.value * factor).sin_cos();
+                let (sin_u1, cos_u1) = ((one - f) * sin_lat1).atan2(cos_lat1).sin_cos();
+                let (sin_u2, cos_u2) = ((one - f) * sin_lat2).atan2(cos_lat2).sin_cos();
+                let l = (to.longitude.value - from.longitude.value) * factor;
+
+                let mut lambda = l;
+                let mut sin_sigma;
+                let mut cos_sigma;
+                let mut sigma;
+                let mut cos2_alpha;
+                let mut cos_2sigma_m;
+                let mut iterations = 0;
+                loop {
+                    let (sin_lambda, cos_lambda) = lambda.sin_cos();
+                    let t1 = cos_u2 * sin_lambda;
+                    let t2 = cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda;
+                    sin_sigma = (t1 * t1 + t2 * t2).sqrt();
+                    if sin_sigma == Number::ZERO {
+                        // Coincident points.
+                        return (Number::ZERO, Number::ZERO);
+                    }
+                    cos_sigma = sin_u1 * sin_u2 + cos_u1 * cos_u2 * cos_lambda;
+                    sigma = sin_sigma.atan2(cos_sigma);
+                    let sin_alpha = cos_u1 * cos_u2 * sin_lambda / sin_sigma;
+                    cos2_alpha = one - sin_alpha * sin_alpha;
+                    cos_2sigma_m = if cos2_alpha == Number::ZERO {
+                        // Both points on the equator.
+                        Number::ZERO
+                    } else {
+                        cos_sigma - two * sin_u1 * sin_u2 / cos2_alpha
+                    };
+                    let c = f / Number::from_f64(16.) * cos2_alpha * (four + f * (four - three * cos2_alpha));
+                    let previous_lambda = lambda;
+                    lambda = l
+                        + (one - c)
+                            * f
+                            * sin_alpha
+                            * (sigma
+                                + c * sin_sigma
+                                    * (cos_2sigma_m
+                                        + c * cos_sigma * (two * cos_2sigma_m * cos_2sigma_m - one)));
+                    iterations += 1;
+                    if (lambda - previous_lambda).abs() < Number::from_f64(1e-12) || iterations >= 200 {
+                        break;
+                    }
+                }
+
+                let u2 = cos2_alpha * (a * a - b * b) / (b * b);
+                let big_a = one
+                    + u2 / Number::from_f64(16384.)
+                        * (Number::from_f64(4096.)
+                            + u2 * (Number::from_f64(-768.)
+                                + u2 * (Number::from_f64(320.) - Number::from_f64(175.) * u2)));
+                let big_b = u2 / Number::from_f64(1024.)
+                    * (Number::from_f64(256.)
+                        + u2 * (Number::from_f64(-128.)
+                            + u2 * (Number::from_f64(74.) - Number::from_f64(47.) * u2)));
+                let delta_sigma = big_b
+                    * sin_sigma
+                    * (cos_2sigma_m
+                        + big_b / four
+                            * (cos_sigma * (two * cos_2sigma_m * cos_2sigma_m - one)
+                                - big_b / Number::from_f64(6.)
+                                    * cos_2sigma_m
+                                    * (four * sin_sigma * sin_sigma - three)
+                                    * (four * cos_2sigma_m * cos_2sigma_m - three)));
+                let distance = b * big_a * (sigma - delta_sigma);
+
+                let (sin_lambda, cos_lambda) = lambda.sin_cos();
+                let bearing =
+                    (cos_u2 * sin_lambda).atan2(cos_u1 * sin_u2 - sin_u1 * cos_u2 * cos_lambda);
+                (distance, bearing)
+            }
+        }
+
+        rs_measures::if_true! { $with_transformations,
+            impl<LengthUnit, Number> Ellipsoid<LengthUnit, Number>
+            where
+                LengthUnit: MeasurementUnit,
+                LengthUnit::Property: VectorProperty,
+                Number: ArithmeticOps,
+            {
+                // The transformation from the Earth-centred Earth-fixed cartesian coordinates
+                // to the local East-North-Up cartesian coordinates, having `origin` as origin.
+                // Its inverse transforms local coordinates to Earth-centred coordinates.
+                pub fn enu_frame<AngleUnit: AngleMeasurementUnit<Property = Angle>>(
+                    &self,
+                    origin: &GeodeticPoint<LengthUnit, AngleUnit, Number>,
+                ) -> AffineMap3d<LengthUnit, Number> {
+                    let factor = Number::from_f64(AngleUnit::RATIO);
+                    let (sin_lat, cos_lat) = (origin.latitude.value * factor).sin_cos();
+                    let (sin_lon, cos_lon) = (origin.longitude.value * factor).sin_cos();
+                    let rotation = [
+                        [-sin_lon, cos_lon, Number::ZERO],
+                        [-sin_lat * cos_lon, -sin_lat * sin_lon, cos_lat],
+                        [cos_lat * cos_lon, cos_lat * sin_lon, sin_lat],
+                    ];
+                    let o = self.to_ecef(origin);
+                    AffineMap3d::<LengthUnit, Number>::new(rotation.map(|r| {
+                        [r[0], r[1], r[2], -(r[0] * o.x + r[1] * o.y + r[2] * o.z)]
+                    }))
+                }
+            }
+        }
+
+        impl<LengthUnit, Number> Default for Ellipsoid<LengthUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            Number: ArithmeticOps,
+        {
+            // It returns the WGS84 ellipsoid.
+            fn default() -> Self {
+                Self::wgs84()
+            }
+        }
+
+        impl<LengthUnit: MeasurementUnit, Number: ArithmeticOps> Clone for Ellipsoid<LengthUnit, Number> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<LengthUnit: MeasurementUnit, Number: ArithmeticOps> Copy for Ellipsoid<LengthUnit, Number> {}
+
+        impl<LengthUnit: MeasurementUnit, Number: ArithmeticOps> PartialEq for Ellipsoid<LengthUnit, Number> {
+            fn eq(&self, other: &Self) -> bool {
+                self.semi_major_axis.value == other.semi_major_axis.value
+                    && self.flattening == other.flattening
+            }
+        }
+
+        // format!("{:?}", Ellipsoid)
+        impl<LengthUnit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Ellipsoid<LengthUnit, Number> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "ellipsoid with semi-major axis ")?;
+                fmt::Display::fmt(&self.semi_major_axis.value, formatter)?;
+                write!(formatter, "{} and flattening ", LengthUnit::SUFFIX)?;
+                fmt::Display::fmt(&self.flattening, formatter)
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> GeodeticPoint<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            pub const fn new(
+                latitude: SignedDirection<AngleUnit, Number>,
+                longitude: SignedDirection<AngleUnit, Number>,
+                height: Measure<LengthUnit, Number>,
+            ) -> Self {
+                Self {
+                    latitude,
+                    longitude,
+                    height,
+                }
+            }
+
+            pub fn convert<
+                DestLengthUnit: MeasurementUnit<Property = LengthUnit::Property>,
+                DestAngleUnit: AngleMeasurementUnit<Property = Angle>,
+            >(
+                &self,
+            ) -> GeodeticPoint<DestLengthUnit, DestAngleUnit, Number> {
+                GeodeticPoint::<DestLengthUnit, DestAngleUnit, Number>::new(
+                    self.latitude.convert::<DestAngleUnit>(),
+                    self.longitude.convert::<DestAngleUnit>(),
+                    self.height.convert::<DestLengthUnit>(),
+                )
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> Default for GeodeticPoint<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            // It returns the point at latitude 0, longitude 0, and height 0.
+            fn default() -> Self {
+                Self::new(
+                    SignedDirection::<AngleUnit, Number>::new(Number::ZERO),
+                    SignedDirection::<AngleUnit, Number>::new(Number::ZERO),
+                    Measure::<LengthUnit, Number>::new(Number::ZERO),
+                )
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> Clone for GeodeticPoint<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> Copy for GeodeticPoint<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+        }
+
+        impl<LengthUnit, AngleUnit, Number> PartialEq for GeodeticPoint<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.latitude.value == other.latitude.value
+                    && self.longitude.value == other.longitude.value
+                    && self.height.value == other.height.value
+            }
+        }
+
+        // format!("{}", GeodeticPoint)
+        impl<LengthUnit, AngleUnit, Number> fmt::Display for GeodeticPoint<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "latitude ")?;
+                fmt::Display::fmt(&self.latitude.value, formatter)?;
+                write!(formatter, "{}, longitude ", AngleUnit::SUFFIX)?;
+                fmt::Display::fmt(&self.longitude.value, formatter)?;
+                write!(formatter, "{}, height ", AngleUnit::SUFFIX)?;
+                fmt::Display::fmt(&self.height.value, formatter)?;
+                write!(formatter, "{}", LengthUnit::SUFFIX)
+            }
+        }
+
+        // format!("{:?}", GeodeticPoint)
+        impl<LengthUnit, AngleUnit, Number> fmt::Debug for GeodeticPoint<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for Ellipsoid
+            impl<LengthUnit: MeasurementUnit, Number: ArithmeticOps> rs_measures::serde::Serialize
+                for Ellipsoid<LengthUnit, Number>
+            where
+                Measure<LengthUnit, Number>: rs_measures::serde::Serialize,
+                Number: rs_measures::serde::Serialize,
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::EllipsoidRepr {
+                            semi_major_axis: &self.semi_major_axis,
+                            flattening: &self.flattening,
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for Ellipsoid
+            impl<'de, LengthUnit: MeasurementUnit, Number: ArithmeticOps> rs_measures::serde::Deserialize<'de>
+                for Ellipsoid<LengthUnit, Number>
+            where
+                Measure<LengthUnit, Number>: rs_measures::serde::Deserialize<'de>,
+                Number: rs_measures::serde::Deserialize<'de>,
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::EllipsoidRepr<Measure<LengthUnit, Number>, Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    Ok(Self {
+                        semi_major_axis: repr.semi_major_axis,
+                        flattening: repr.flattening,
+                    })
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for GeodeticPoint
+            impl<LengthUnit: MeasurementUnit, AngleUnit, Number: ArithmeticOps> rs_measures::serde::Serialize
+                for GeodeticPoint<LengthUnit, AngleUnit, Number>
+            where
+                SignedDirection<AngleUnit, Number>: rs_measures::serde::Serialize,
+                Measure<LengthUnit, Number>: rs_measures::serde::Serialize,
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::GeodeticRepr {
+                            latitude: &self.latitude,
+                            longitude: &self.longitude,
+                            height: &self.height,
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for GeodeticPoint
+            impl<'de, LengthUnit: MeasurementUnit, AngleUnit, Number: ArithmeticOps> rs_measures::serde::Deserialize<'de>
+                for GeodeticPoint<LengthUnit, AngleUnit, Number>
+            where
+                SignedDirection<AngleUnit, Number>: rs_measures::serde::Deserialize<'de>,
+                Measure<LengthUnit, Number>: rs_measures::serde::Deserialize<'de>,
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::GeodeticRepr<SignedDirection<AngleUnit, Number>, Measure<LengthUnit, Number>> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    Ok(Self {
+                        latitude: repr.latitude,
+                        longitude: repr.longitude,
+                        height: repr.height,
+                    })
+                }
+            }
+        }
+    };
+}
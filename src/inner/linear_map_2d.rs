@@ -102,19 +102,8 @@ macro_rules! inner_define_linear_map_2d {
             //// Inversion
 
             pub fn inverted(&self) -> Self {
-                let inv_determinant =
-                    Number::ONE / (self.c[0][0] * self.c[1][1] - self.c[0][1] * self.c[1][0]);
                 Self {
-                    c: [
-                        [
-                            self.c[1][1] * inv_determinant,
-                            self.c[0][1] * -inv_determinant,
-                        ],
-                        [
-                            self.c[1][0] * -inv_determinant,
-                            self.c[0][0] * inv_determinant,
-                        ],
-                    ],
+                    c: rs_measures::matrix_utils::inverse_2x2(&self.c),
                 }
             }
 
@@ -143,10 +132,7 @@ macro_rules! inner_define_linear_map_2d {
             where
                 Unit::Property: VectorProperty,
             {
-                Measure2d::<Unit, Number>::new(
-                    self.c[0][0] * m.x + self.c[0][1] * m.y,
-                    self.c[1][0] * m.x + self.c[1][1] * m.y,
-                )
+                m.linearly_transformed(&self.c)
             }
 
             fn rotation_by_radians(a: Number) -> Self {
@@ -218,5 +204,31 @@ macro_rules! inner_define_linear_map_2d {
                 )
             }
         }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for LinearMap2d
+            impl<Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize for LinearMap2d<Number> {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::MapRepr {
+                            coefficients: self.c,
+                            unit: None,
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for LinearMap2d
+            impl<'de, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de> for LinearMap2d<Number> {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::MapRepr<[[Number; 2]; 2]> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, "")?;
+                    Ok(Self::new(repr.coefficients))
+                }
+            }
+        }
     };
 }
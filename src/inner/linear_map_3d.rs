@@ -156,28 +156,8 @@ macro_rules! inner_define_linear_map_3d {
             // Inversion
 
             pub fn inverted(&self) -> Self {
-                let inv_determinant = Number::ONE
-                    / (self.c[0][0] * (self.c[1][1] * self.c[2][2] - self.c[1][2] * self.c[2][1])
-                        - self.c[0][1] * (self.c[1][0] * self.c[2][2] - self.c[1][2] * self.c[2][0])
-                        + self.c[0][2] * (self.c[1][0] * self.c[2][1] - self.c[1][1] * self.c[2][0]));
                 Self {
-                    c: [
-                        [
-                            (self.c[1][1] * self.c[2][2] - self.c[1][2] * self.c[2][1]) * inv_determinant,
-                            -(self.c[0][1] * self.c[2][2] - self.c[0][2] * self.c[2][1]) * inv_determinant,
-                            (self.c[0][1] * self.c[1][2] - self.c[0][2] * self.c[1][1]) * inv_determinant,
-                        ],
-                        [
-                            -(self.c[1][0] * self.c[2][2] - self.c[1][2] * self.c[2][0]) * inv_determinant,
-                            (self.c[0][0] * self.c[2][2] - self.c[0][2] * self.c[2][0]) * inv_determinant,
-                            -(self.c[0][0] * self.c[1][2] - self.c[0][2] * self.c[1][0]) * inv_determinant,
-                        ],
-                        [
-                            (self.c[1][0] * self.c[2][1] - self.c[1][1] * self.c[2][0]) * inv_determinant,
-                            -(self.c[0][0] * self.c[2][1] - self.c[0][1] * self.c[2][0]) * inv_determinant,
-                            (self.c[0][0] * self.c[1][1] - self.c[0][1] * self.c[1][0]) * inv_determinant,
-                        ],
-                    ],
+                    c: rs_measures::matrix_utils::inverse_3x3(&self.c),
                 }
             }
 
@@ -231,11 +211,7 @@ macro_rules! inner_define_linear_map_3d {
             where
                 Unit::Property: VectorProperty,
             {
-                Measure3d::<Unit, Number>::new(
-                    self.c[0][0] * m.x + self.c[0][1] * m.y + self.c[0][2] * m.z,
-                    self.c[1][0] * m.x + self.c[1][1] * m.y + self.c[1][2] * m.z,
-                    self.c[2][0] * m.x + self.c[2][1] * m.y + self.c[2][2] * m.z,
-                )
+                m.linearly_transformed(&self.c)
             }
 
             fn rotation_by_radians_around_unit_vector(
@@ -303,5 +279,31 @@ macro_rules! inner_define_linear_map_3d {
                 )
             }
         }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for LinearMap3d
+            impl<Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize for LinearMap3d<Number> {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::MapRepr {
+                            coefficients: self.c,
+                            unit: None,
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for LinearMap3d
+            impl<'de, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de> for LinearMap3d<Number> {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::MapRepr<[[Number; 3]; 3]> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, "")?;
+                    Ok(Self::new(repr.coefficients))
+                }
+            }
+        }
     };
 }
@@ -1,13 +1,43 @@
 #[macro_export]
 macro_rules! inner_define_measure {
-    {} => {
-        pub struct Measure<Unit, Number = f64>
-        where
-            Unit: MeasurementUnit,
-            Number: ArithmeticOps,
-        {
-            pub value: Number,
-            phantom: PhantomData<Unit>,
+    { $with_uncertainty:tt } => {
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            pub struct Measure<Unit, Number = f64>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                pub value: Number,
+                phantom: PhantomData<Unit>,
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            pub struct Measure<Unit, Number = f64>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                pub value: Number,
+                // The standard uncertainty of `value`. It is never negative.
+                pub uncertainty: Number,
+                phantom: PhantomData<Unit>,
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            pub struct Measure<Unit, Number = f64>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                pub value: Number,
+                // The uncertainty below `value`. It is never negative.
+                pub lower_uncertainty: Number,
+                // The uncertainty above `value`. It is never negative.
+                pub upper_uncertainty: Number,
+                phantom: PhantomData<Unit>,
+            }
         }
 
         impl<Unit, Number> Measure<Unit, Number>
@@ -15,41 +45,215 @@ macro_rules! inner_define_measure {
             Unit: MeasurementUnit,
             Number: ArithmeticOps,
         {
-            /// Measure::new(Number) -> Measure
-            pub const fn new(value: Number) -> Self {
-                Self {
-                    value,
-                    phantom: PhantomData::<Unit>,
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                /// Measure::new(Number) -> Measure
+                pub const fn new(value: Number) -> Self {
+                    Self {
+                        value,
+                        phantom: PhantomData::<Unit>,
+                    }
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                /// Measure::new(Number) -> Measure, with no uncertainty
+                pub const fn new(value: Number) -> Self {
+                    Self {
+                        value,
+                        uncertainty: Number::ZERO,
+                        phantom: PhantomData::<Unit>,
+                    }
+                }
+            }
+
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                /// Measure::new(Number) -> Measure, with no uncertainty
+                pub const fn new(value: Number) -> Self {
+                    Self {
+                        value,
+                        lower_uncertainty: Number::ZERO,
+                        upper_uncertainty: Number::ZERO,
+                        phantom: PhantomData::<Unit>,
+                    }
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                /// Measure::with_uncertainty(Number, Number) -> Measure
+                /// Precondition: uncertainty >= 0
+                pub const fn with_uncertainty(value: Number, uncertainty: Number) -> Self {
+                    Self {
+                        value,
+                        uncertainty,
+                        phantom: PhantomData::<Unit>,
+                    }
+                }
+            }
+
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                /// Measure::with_uncertainty(Number, Number, Number) -> Measure
+                /// Precondition: lower_uncertainty >= 0 && upper_uncertainty >= 0
+                pub const fn with_uncertainty(
+                    value: Number,
+                    lower_uncertainty: Number,
+                    upper_uncertainty: Number,
+                ) -> Self {
+                    Self {
+                        value,
+                        lower_uncertainty,
+                        upper_uncertainty,
+                        phantom: PhantomData::<Unit>,
+                    }
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                /// Measure.relative_uncertainty() -> Number
+                pub fn relative_uncertainty(self) -> Number {
+                    self.uncertainty / self.value.abs()
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                /// Measure.sample(Sampler) -> Measure
+                /// It returns a measure having no uncertainty, whose value is drawn
+                /// from a normal distribution having the value of `self` as mean
+                /// and its uncertainty as standard deviation.
+                pub fn sample(self, sampler: &mut rs_measures::monte_carlo::Sampler) -> Self {
+                    Self::new(sampler.normal(self.value, self.uncertainty))
+                }
+            }
+
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                /// Measure.sample(Sampler) -> Measure
+                /// It returns a measure having no uncertainty, whose value is drawn
+                /// from a split normal distribution having the value of `self` as mode
+                /// and its uncertainties as standard deviations of its two halves.
+                pub fn sample(self, sampler: &mut rs_measures::monte_carlo::Sampler) -> Self {
+                    Self::new(sampler.split_normal(
+                        self.value,
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                    ))
+                }
+            }
+
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                /// Measure.convert() -> Measure
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> Measure<DestUnit, Number> {
+                    Measure::<DestUnit, Number> {
+                        value: self.value * Number::from_f64(Unit::RATIO / DestUnit::RATIO),
+                        phantom: PhantomData,
+                    }
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                /// Measure.convert() -> Measure
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> Measure<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    Measure::<DestUnit, Number> {
+                        value: self.value * factor,
+                        uncertainty: self.uncertainty * factor.abs(),
+                        phantom: PhantomData,
+                    }
+                }
+            }
+
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                /// Measure.convert() -> Measure
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> Measure<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    Measure::<DestUnit, Number> {
+                        value: self.value * factor,
+                        lower_uncertainty: self.lower_uncertainty * factor,
+                        upper_uncertainty: self.upper_uncertainty * factor,
+                        phantom: PhantomData,
+                    }
+                }
+            }
+
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // Measure.lossless_into() -> Measure
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> Measure<Unit, DestNumber> {
+                    Measure::<Unit, DestNumber> {
+                        value: DestNumber::from(self.value),
+                        phantom: PhantomData,
+                    }
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // Measure.lossless_into() -> Measure
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> Measure<Unit, DestNumber> {
+                    Measure::<Unit, DestNumber> {
+                        value: DestNumber::from(self.value),
+                        uncertainty: DestNumber::from(self.uncertainty),
+                        phantom: PhantomData,
+                    }
                 }
             }
 
-            /// Measure.convert() -> Measure
-            pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
-                &self,
-            ) -> Measure<DestUnit, Number> {
-                Measure::<DestUnit, Number> {
-                    value: self.value * Number::from_f64(Unit::RATIO / DestUnit::RATIO),
-                    phantom: PhantomData,
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // Measure.lossless_into() -> Measure
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> Measure<Unit, DestNumber> {
+                    Measure::<Unit, DestNumber> {
+                        value: DestNumber::from(self.value),
+                        lower_uncertainty: DestNumber::from(self.lower_uncertainty),
+                        upper_uncertainty: DestNumber::from(self.upper_uncertainty),
+                        phantom: PhantomData,
+                    }
                 }
             }
 
-            // Measure.lossless_into() -> Measure
-            pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
-                &self,
-            ) -> Measure<Unit, DestNumber> {
-                Measure::<Unit, DestNumber> {
-                    value: DestNumber::from(self.value),
-                    phantom: PhantomData,
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // Measure.lossy_into() -> Measure
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> Measure<Unit, DestNumber> {
+                    Measure::<Unit, DestNumber> {
+                        value: DestNumber::lossy_from(self.value),
+                        phantom: PhantomData,
+                    }
                 }
             }
 
-            // Measure.lossy_into() -> Measure
-            pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
-                &self,
-            ) -> Measure<Unit, DestNumber> {
-                Measure::<Unit, DestNumber> {
-                    value: DestNumber::lossy_from(self.value),
-                    phantom: PhantomData,
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // Measure.lossy_into() -> Measure
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> Measure<Unit, DestNumber> {
+                    Measure::<Unit, DestNumber> {
+                        value: DestNumber::lossy_from(self.value),
+                        uncertainty: DestNumber::lossy_from(self.uncertainty),
+                        phantom: PhantomData,
+                    }
+                }
+            }
+
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // Measure.lossy_into() -> Measure
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> Measure<Unit, DestNumber> {
+                    Measure::<Unit, DestNumber> {
+                        value: DestNumber::lossy_from(self.value),
+                        lower_uncertainty: DestNumber::lossy_from(self.lower_uncertainty),
+                        upper_uncertainty: DestNumber::lossy_from(self.upper_uncertainty),
+                        phantom: PhantomData,
+                    }
                 }
             }
 
@@ -86,13 +290,330 @@ macro_rules! inner_define_measure {
             pub fn format_decibel(self) -> DecibelFormattedMeasure<Unit, Number> {
                 DecibelFormattedMeasure(self)
             }
+
+            // The following methods are used by `define_units_relationship!`.
+
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // Measure<U1>.squared_into() -> Measure<U3>
+                fn squared_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    Measure::<DestUnit, Number>::new(self.value * self.value)
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // Measure<U1>.squared_into() -> Measure<U3>
+                fn squared_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        self.value * self.value,
+                        (self.value + self.value).abs() * self.uncertainty,
+                    )
+                }
+            }
+
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // Measure<U1>.squared_into() -> Measure<U3>
+                fn squared_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    let (lower, upper) = rs_measures::uncertainty::scaled_two_sided(
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                        self.value + self.value,
+                    );
+                    Measure::<DestUnit, Number>::with_uncertainty(self.value * self.value, lower, upper)
+                }
+            }
+
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // Measure<U3>.sqrt_into() -> Measure<U1>
+                fn sqrt_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    Measure::<DestUnit, Number>::new(self.value.sqrt())
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // Measure<U3>.sqrt_into() -> Measure<U1>
+                fn sqrt_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    // For a zero value, the first-order propagation would divide by zero,
+                    // and so the uncertainty is the square root of the uncertainty,
+                    // i.e. the change of the root when the value moves by its uncertainty.
+                    let root = self.value.sqrt();
+                    let uncertainty = if root == Number::ZERO {
+                        self.uncertainty.sqrt()
+                    } else {
+                        self.uncertainty * Number::HALF / root
+                    };
+                    Measure::<DestUnit, Number>::with_uncertainty(root, uncertainty)
+                }
+            }
+
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // Measure<U3>.sqrt_into() -> Measure<U1>
+                fn sqrt_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    // For a zero value, the first-order propagation would divide by zero,
+                    // and so the uncertainties are the square roots of the uncertainties,
+                    // i.e. the changes of the root when the value moves by its uncertainties.
+                    let root = self.value.sqrt();
+                    if root == Number::ZERO {
+                        return Measure::<DestUnit, Number>::with_uncertainty(
+                            root,
+                            self.lower_uncertainty.sqrt(),
+                            self.upper_uncertainty.sqrt(),
+                        );
+                    }
+                    let factor = Number::HALF / root;
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        root,
+                        self.lower_uncertainty * factor,
+                        self.upper_uncertainty * factor,
+                    )
+                }
+            }
+
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // Measure<U1>.cubed_into() -> Measure<U3>
+                fn cubed_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    Measure::<DestUnit, Number>::new(self.value * self.value * self.value)
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // Measure<U1>.cubed_into() -> Measure<U3>
+                fn cubed_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    let square = self.value * self.value;
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        square * self.value,
+                        (square + square + square) * self.uncertainty,
+                    )
+                }
+            }
+
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // Measure<U1>.cubed_into() -> Measure<U3>
+                fn cubed_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    let square = self.value * self.value;
+                    let (lower, upper) = rs_measures::uncertainty::scaled_two_sided(
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                        square + square + square,
+                    );
+                    Measure::<DestUnit, Number>::with_uncertainty(square * self.value, lower, upper)
+                }
+            }
+
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // Measure<U3>.cubic_root_into() -> Measure<U1>
+                fn cubic_root_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    Measure::<DestUnit, Number>::new(self.value.cubic_root())
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // Measure<U3>.cubic_root_into() -> Measure<U1>
+                fn cubic_root_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    // For a zero value, the first-order propagation would divide by zero,
+                    // and so the uncertainty is the cubic root of the uncertainty,
+                    // i.e. the change of the root when the value moves by its uncertainty.
+                    let root = self.value.cubic_root();
+                    let uncertainty = if root == Number::ZERO {
+                        self.uncertainty.cubic_root()
+                    } else {
+                        self.uncertainty / (root * root * Number::from_f64(3.))
+                    };
+                    Measure::<DestUnit, Number>::with_uncertainty(root, uncertainty)
+                }
+            }
+
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // Measure<U3>.cubic_root_into() -> Measure<U1>
+                fn cubic_root_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    // For a zero value, the first-order propagation would divide by zero,
+                    // and so the uncertainties are the cubic roots of the uncertainties,
+                    // i.e. the changes of the root when the value moves by its uncertainties.
+                    let root = self.value.cubic_root();
+                    if root == Number::ZERO {
+                        return Measure::<DestUnit, Number>::with_uncertainty(
+                            root,
+                            self.lower_uncertainty.cubic_root(),
+                            self.upper_uncertainty.cubic_root(),
+                        );
+                    }
+                    let factor = Number::ONE / (root * root * Number::from_f64(3.));
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        root,
+                        self.lower_uncertainty * factor,
+                        self.upper_uncertainty * factor,
+                    )
+                }
+            }
+
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // Measure<U1>.product_into(Measure<U2>) -> Measure<U3>
+                fn product_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure<OtherUnit, Number>,
+                ) -> Measure<DestUnit, Number> {
+                    Measure::<DestUnit, Number>::new(self.value * other.value)
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // Measure<U1>.product_into(Measure<U2>) -> Measure<U3>
+                // The uncertainties are assumed to be independent,
+                // and so their relative values are combined in quadrature.
+                fn product_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure<OtherUnit, Number>,
+                ) -> Measure<DestUnit, Number> {
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        self.value * other.value,
+                        rs_measures::uncertainty::in_quadrature(
+                            other.value * self.uncertainty,
+                            self.value * other.uncertainty,
+                        ),
+                    )
+                }
+            }
+
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // Measure<U1>.product_into(Measure<U2>) -> Measure<U3>
+                // The uncertainties are assumed to be independent.
+                fn product_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure<OtherUnit, Number>,
+                ) -> Measure<DestUnit, Number> {
+                    let (lower1, upper1) = rs_measures::uncertainty::scaled_two_sided(
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                        other.value,
+                    );
+                    let (lower2, upper2) = rs_measures::uncertainty::scaled_two_sided(
+                        other.lower_uncertainty,
+                        other.upper_uncertainty,
+                        self.value,
+                    );
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        self.value * other.value,
+                        rs_measures::uncertainty::in_quadrature(lower1, lower2),
+                        rs_measures::uncertainty::in_quadrature(upper1, upper2),
+                    )
+                }
+            }
+
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // Measure<U3>.quotient_into(Measure<U1>) -> Measure<U2>
+                fn quotient_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure<OtherUnit, Number>,
+                ) -> Measure<DestUnit, Number> {
+                    Measure::<DestUnit, Number>::new(self.value / other.value)
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // Measure<U3>.quotient_into(Measure<U1>) -> Measure<U2>
+                // The uncertainties are assumed to be independent,
+                // and so their relative values are combined in quadrature.
+                fn quotient_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure<OtherUnit, Number>,
+                ) -> Measure<DestUnit, Number> {
+                    let quotient = self.value / other.value;
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        quotient,
+                        rs_measures::uncertainty::in_quadrature(
+                            self.uncertainty,
+                            quotient * other.uncertainty,
+                        ) / other.value.abs(),
+                    )
+                }
+            }
+
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // Measure<U3>.quotient_into(Measure<U1>) -> Measure<U2>
+                // The uncertainties are assumed to be independent.
+                fn quotient_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure<OtherUnit, Number>,
+                ) -> Measure<DestUnit, Number> {
+                    let quotient = self.value / other.value;
+                    let (lower1, upper1) = rs_measures::uncertainty::scaled_two_sided(
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                        Number::ONE / other.value,
+                    );
+                    let (lower2, upper2) = rs_measures::uncertainty::scaled_two_sided(
+                        other.lower_uncertainty,
+                        other.upper_uncertainty,
+                        -quotient / other.value,
+                    );
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        quotient,
+                        rs_measures::uncertainty::in_quadrature(lower1, lower2),
+                        rs_measures::uncertainty::in_quadrature(upper1, upper2),
+                    )
+                }
+            }
+
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // Measure<U1>.reciprocal_into(Number) -> Measure<U2>
+                fn reciprocal_into<DestUnit: MeasurementUnit>(
+                    self,
+                    numerator: Number,
+                ) -> Measure<DestUnit, Number> {
+                    Measure::<DestUnit, Number>::new(numerator / self.value)
+                }
+            }
+
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // Measure<U1>.reciprocal_into(Number) -> Measure<U2>
+                fn reciprocal_into<DestUnit: MeasurementUnit>(
+                    self,
+                    numerator: Number,
+                ) -> Measure<DestUnit, Number> {
+                    let quotient = numerator / self.value;
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        quotient,
+                        (quotient / self.value).abs() * self.uncertainty,
+                    )
+                }
+            }
+
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // Measure<U1>.reciprocal_into(Number) -> Measure<U2>
+                fn reciprocal_into<DestUnit: MeasurementUnit>(
+                    self,
+                    numerator: Number,
+                ) -> Measure<DestUnit, Number> {
+                    let quotient = numerator / self.value;
+                    let (lower, upper) = rs_measures::uncertainty::scaled_two_sided(
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                        -quotient / self.value,
+                    );
+                    Measure::<DestUnit, Number>::with_uncertainty(quotient, lower, upper)
+                }
+            }
         }
 
-        pub fn max<Unit>(a: Measure<Unit, f64>, b: Measure<Unit, f64>) -> Measure<Unit, f64>
-        where
-            Unit: MeasurementUnit,
-        {
-            Measure::<Unit, f64>::new(a.value.max(b.value))
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            pub fn max<Unit>(a: Measure<Unit, f64>, b: Measure<Unit, f64>) -> Measure<Unit, f64>
+            where
+                Unit: MeasurementUnit,
+            {
+                Measure::<Unit, f64>::new(a.value.max(b.value))
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            pub fn max<Unit>(a: Measure<Unit, f64>, b: Measure<Unit, f64>) -> Measure<Unit, f64>
+            where
+                Unit: MeasurementUnit,
+            {
+                if a.value.is_nan() || b.value > a.value {
+                    b
+                } else {
+                    a
+                }
+            }
         }
 
         impl<Unit, Number> Default for Measure<Unit, Number>
@@ -106,82 +627,278 @@ macro_rules! inner_define_measure {
             }
         }
 
-        // -Measure -> Measure
-        impl<Unit, Number> Neg for Measure<Unit, Number>
-        where
-            Unit: MeasurementUnit,
-            Number: ArithmeticOps,
-        {
-            type Output = Self;
-            fn neg(self) -> Self::Output {
-                Self::new(-self.value)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // -Measure -> Measure
+            impl<Unit, Number> Neg for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self::new(-self.value)
+                }
             }
         }
 
-        // Measure + Measure -> Measure
-        impl<Unit, Number> Add<Measure<Unit, Number>> for Measure<Unit, Number>
-        where
-            Unit: MeasurementUnit,
-            Number: ArithmeticOps,
-        {
-            type Output = Self;
-            fn add(self, other: Measure<Unit, Number>) -> Self::Output {
-                Self::new(self.value + other.value)
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // -Measure -> Measure
+            impl<Unit, Number> Neg for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self::with_uncertainty(-self.value, self.uncertainty)
+                }
             }
         }
 
-        // Measure += Measure
-        impl<Unit, Number> AddAssign<Measure<Unit, Number>> for Measure<Unit, Number>
-        where
-            Unit: MeasurementUnit,
-            Number: ArithmeticOps,
-        {
-            fn add_assign(&mut self, other: Measure<Unit, Number>) {
-                self.value += other.value;
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // -Measure -> Measure
+            impl<Unit, Number> Neg for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self::with_uncertainty(-self.value, self.upper_uncertainty, self.lower_uncertainty)
+                }
             }
         }
 
-        // Measure - Measure -> Measure
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure<Unit, Number>>
-            for Measure<Unit, Number>
-        {
-            type Output = Self;
-            fn sub(self, other: Measure<Unit, Number>) -> Self::Output {
-                Self::new(self.value - other.value)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure + Measure -> Measure
+            impl<Unit, Number> Add<Measure<Unit, Number>> for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Output = Self;
+                fn add(self, other: Measure<Unit, Number>) -> Self::Output {
+                    Self::new(self.value + other.value)
+                }
             }
         }
 
-        // Measure -= Measure
-        impl<Unit, Number> SubAssign<Measure<Unit, Number>> for Measure<Unit, Number>
-        where
-            Unit: MeasurementUnit,
-            Number: ArithmeticOps,
-        {
-            fn sub_assign(&mut self, other: Measure<Unit, Number>) {
-                self.value -= other.value;
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure + Measure -> Measure
+            // The uncertainties are assumed to be independent.
+            impl<Unit, Number> Add<Measure<Unit, Number>> for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Output = Self;
+                fn add(self, other: Measure<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.value + other.value,
+                        rs_measures::uncertainty::in_quadrature(self.uncertainty, other.uncertainty),
+                    )
+                }
             }
         }
 
-        // Measure * Number -> Measure
-        impl<Unit, Number> Mul<Number> for Measure<Unit, Number>
-        where
-            Unit: MeasurementUnit,
-            Number: ArithmeticOps,
-        {
-            type Output = Self;
-            fn mul(self, n: Number) -> Self::Output {
-                Self::new(self.value * n)
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure + Measure -> Measure
+            // The uncertainties are assumed to be independent.
+            impl<Unit, Number> Add<Measure<Unit, Number>> for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Output = Self;
+                fn add(self, other: Measure<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.value + other.value,
+                        rs_measures::uncertainty::in_quadrature(
+                            self.lower_uncertainty,
+                            other.lower_uncertainty,
+                        ),
+                        rs_measures::uncertainty::in_quadrature(
+                            self.upper_uncertainty,
+                            other.upper_uncertainty,
+                        ),
+                    )
+                }
             }
         }
 
-        // Measure *= Number
-        impl<Unit, Number> MulAssign<Number> for Measure<Unit, Number>
-        where
-            Unit: MeasurementUnit,
-            Number: ArithmeticOps,
-        {
-            fn mul_assign(&mut self, n: Number) {
-                self.value *= n;
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure += Measure
+            impl<Unit, Number> AddAssign<Measure<Unit, Number>> for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                fn add_assign(&mut self, other: Measure<Unit, Number>) {
+                    self.value += other.value;
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure += Measure
+            impl<Unit, Number> AddAssign<Measure<Unit, Number>> for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                fn add_assign(&mut self, other: Measure<Unit, Number>) {
+                    *self = *self + other;
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure - Measure -> Measure
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure<Unit, Number>>
+                for Measure<Unit, Number>
+            {
+                type Output = Self;
+                fn sub(self, other: Measure<Unit, Number>) -> Self::Output {
+                    Self::new(self.value - other.value)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure - Measure -> Measure
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure<Unit, Number>>
+                for Measure<Unit, Number>
+            {
+                type Output = Self;
+                fn sub(self, other: Measure<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.value - other.value,
+                        rs_measures::uncertainty::in_quadrature(self.uncertainty, other.uncertainty),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure - Measure -> Measure
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure<Unit, Number>>
+                for Measure<Unit, Number>
+            {
+                type Output = Self;
+                fn sub(self, other: Measure<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.value - other.value,
+                        rs_measures::uncertainty::in_quadrature(
+                            self.lower_uncertainty,
+                            other.upper_uncertainty,
+                        ),
+                        rs_measures::uncertainty::in_quadrature(
+                            self.upper_uncertainty,
+                            other.lower_uncertainty,
+                        ),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure -= Measure
+            impl<Unit, Number> SubAssign<Measure<Unit, Number>> for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                fn sub_assign(&mut self, other: Measure<Unit, Number>) {
+                    self.value -= other.value;
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure -= Measure
+            impl<Unit, Number> SubAssign<Measure<Unit, Number>> for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                fn sub_assign(&mut self, other: Measure<Unit, Number>) {
+                    *self = *self - other;
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure * Number -> Measure
+            impl<Unit, Number> Mul<Number> for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Output = Self;
+                fn mul(self, n: Number) -> Self::Output {
+                    Self::new(self.value * n)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure * Number -> Measure
+            impl<Unit, Number> Mul<Number> for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Output = Self;
+                fn mul(self, n: Number) -> Self::Output {
+                    Self::with_uncertainty(self.value * n, self.uncertainty * n.abs())
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure * Number -> Measure
+            impl<Unit, Number> Mul<Number> for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                type Output = Self;
+                fn mul(self, n: Number) -> Self::Output {
+                    let (lower, upper) = rs_measures::uncertainty::scaled_two_sided(
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                        n,
+                    );
+                    Self::with_uncertainty(self.value * n, lower, upper)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure *= Number
+            impl<Unit, Number> MulAssign<Number> for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                fn mul_assign(&mut self, n: Number) {
+                    self.value *= n;
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure *= Number
+            impl<Unit, Number> MulAssign<Number> for Measure<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                fn mul_assign(&mut self, n: Number) {
+                    *self = *self * n;
+                }
             }
         }
 
@@ -192,7 +909,7 @@ macro_rules! inner_define_measure {
         {
             type Output = Measure<Unit, f64>;
             fn mul(self, other: Measure<Unit, f64>) -> Self::Output {
-                Self::Output::new(self * other.value)
+                other * self
             }
         }
 
@@ -200,22 +917,60 @@ macro_rules! inner_define_measure {
         impl<Unit: MeasurementUnit> Mul<Measure<Unit, f32>> for f32 {
             type Output = Measure<Unit, f32>;
             fn mul(self, other: Measure<Unit, f32>) -> Self::Output {
-                Self::Output::new(self * other.value)
+                other * self
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure / Number -> Measure
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Measure<Unit, Number> {
+                type Output = Self;
+                fn div(self, n: Number) -> Self::Output {
+                    Self::new(self.value / n)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure / Number -> Measure
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Measure<Unit, Number> {
+                type Output = Self;
+                fn div(self, n: Number) -> Self::Output {
+                    Self::with_uncertainty(self.value / n, self.uncertainty / n.abs())
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure / Number -> Measure
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Measure<Unit, Number> {
+                type Output = Self;
+                fn div(self, n: Number) -> Self::Output {
+                    let (lower, upper) = rs_measures::uncertainty::scaled_two_sided(
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                        Number::ONE / n,
+                    );
+                    Self::with_uncertainty(self.value / n, lower, upper)
+                }
             }
         }
 
-        // Measure / Number -> Measure
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Measure<Unit, Number> {
-            type Output = Self;
-            fn div(self, n: Number) -> Self::Output {
-                Self::new(self.value / n)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure /= Number
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> DivAssign<Number> for Measure<Unit, Number> {
+                fn div_assign(&mut self, n: Number) {
+                    self.value /= n;
+                }
             }
         }
 
-        // Measure /= Number
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> DivAssign<Number> for Measure<Unit, Number> {
-            fn div_assign(&mut self, n: Number) {
-                self.value /= n;
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure /= Number
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> DivAssign<Number> for Measure<Unit, Number> {
+                fn div_assign(&mut self, n: Number) {
+                    *self = *self / n;
+                }
             }
         }
 
@@ -229,21 +984,64 @@ macro_rules! inner_define_measure {
             }
         }
 
-        // Measure == Measure -> bool
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<Measure<Unit, Number>>
-            for Measure<Unit, Number>
-        {
-            fn eq(&self, other: &Measure<Unit, Number>) -> bool {
-                self.value == other.value
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure == Measure -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<Measure<Unit, Number>>
+                for Measure<Unit, Number>
+            {
+                fn eq(&self, other: &Measure<Unit, Number>) -> bool {
+                    self.value == other.value
+                }
             }
         }
 
-        // Measure < Measure -> bool
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialOrd<Measure<Unit, Number>>
-            for Measure<Unit, Number>
-        {
-            fn partial_cmp(&self, other: &Measure<Unit, Number>) -> Option<std::cmp::Ordering> {
-                self.value.partial_cmp(&other.value)
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure == Measure -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<Measure<Unit, Number>>
+                for Measure<Unit, Number>
+            {
+                fn eq(&self, other: &Measure<Unit, Number>) -> bool {
+                    self.value == other.value && self.uncertainty == other.uncertainty
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure == Measure -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<Measure<Unit, Number>>
+                for Measure<Unit, Number>
+            {
+                fn eq(&self, other: &Measure<Unit, Number>) -> bool {
+                    self.value == other.value
+                        && self.lower_uncertainty == other.lower_uncertainty
+                        && self.upper_uncertainty == other.upper_uncertainty
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure < Measure -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialOrd<Measure<Unit, Number>>
+                for Measure<Unit, Number>
+            {
+                fn partial_cmp(&self, other: &Measure<Unit, Number>) -> Option<std::cmp::Ordering> {
+                    self.value.partial_cmp(&other.value)
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure < Measure -> bool
+            // Measures having the same value but different uncertainties are not comparable.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialOrd<Measure<Unit, Number>>
+                for Measure<Unit, Number>
+            {
+                fn partial_cmp(&self, other: &Measure<Unit, Number>) -> Option<std::cmp::Ordering> {
+                    match self.value.partial_cmp(&other.value) {
+                        Some(std::cmp::Ordering::Equal) if self != other => None,
+                        ordering => ordering,
+                    }
+                }
             }
         }
 
@@ -257,19 +1055,75 @@ macro_rules! inner_define_measure {
         // Measure = Measure
         impl<Unit: MeasurementUnit, Number: ArithmeticOps> Copy for Measure<Unit, Number> {}
 
-        // format!("{}", Measure)
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Measure<Unit, Number> {
-            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt::Display::fmt(&self.value, formatter)?;
-                formatter.write_str(Unit::SUFFIX)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // format!("{}", Measure)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Measure<Unit, Number> {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.value, formatter)?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
             }
         }
 
-        // format!("{:?}", Measure)
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Measure<Unit, Number> {
-            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                fmt::Display::fmt(&self.value, formatter)?;
-                formatter.write_str(Unit::SUFFIX)
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // format!("{}", Measure)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Measure<Unit, Number> {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.value, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.uncertainty, formatter)?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // format!("{}", Measure)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Measure<Unit, Number> {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.value, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty, formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty, formatter)?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // format!("{:?}", Measure)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Measure<Unit, Number> {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.value, formatter)?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // format!("{:?}", Measure)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Measure<Unit, Number> {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.value, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.uncertainty, formatter)?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // format!("{:?}", Measure)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Measure<Unit, Number> {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.value, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty, formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty, formatter)?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
             }
         }
 
@@ -298,5 +1152,174 @@ macro_rules! inner_define_measure {
                 formatter.write_str(Unit::SUFFIX)
             }
         }
+
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure<Unit, Number> {
+            /// Measure::parse_in_any_unit(&str, &UnitRegistry) -> Result<Measure, ParseMeasureError>
+            /// It parses a number followed by the suffix of any unit registered
+            /// in the specified registry, and it converts the value to `Unit`.
+            pub fn parse_in_any_unit(
+                text: &str,
+                registry: &rs_measures::unit_registry::UnitRegistry<Unit::Property>,
+            ) -> Result<Self, rs_measures::parsing::ParseMeasureError> {
+                let (value, unit) = registry.parse::<Number>(text)?;
+                Ok(Self::new(value * Number::from_f64(unit.ratio / Unit::RATIO)))
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // "...".parse::<Measure>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for Measure<Unit, Number> {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    let value = parser.number()?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::new(value))
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // "...".parse::<Measure>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for Measure<Unit, Number> {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    let (value, uncertainty) = parser.number_with_uncertainty()?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::with_uncertainty(value, uncertainty))
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // "...".parse::<Measure>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for Measure<Unit, Number> {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    let (value, lower_uncertainty, upper_uncertainty) = parser.number_with_two_sided_uncertainty()?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::with_uncertainty(value, lower_uncertainty, upper_uncertainty))
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // serde::Serialize for Measure
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for Measure<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::ScalarRepr {
+                                value: self.value,
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for Measure
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for Measure<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::ScalarRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            value: repr.value,
+                            phantom: PhantomData,
+                        })
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // serde::Serialize for Measure
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for Measure<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::SymmetricScalarRepr {
+                                value: self.value,
+                                uncertainty: Some(self.uncertainty),
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for Measure
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for Measure<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::SymmetricScalarRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            value: repr.value,
+                            uncertainty: repr.uncertainty.unwrap_or(Number::ZERO),
+                            phantom: PhantomData,
+                        })
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // serde::Serialize for Measure
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for Measure<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::TwoSidedScalarRepr {
+                                value: self.value,
+                                lower_uncertainty: Some(self.lower_uncertainty),
+                                upper_uncertainty: Some(self.upper_uncertainty),
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for Measure
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for Measure<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::TwoSidedScalarRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            value: repr.value,
+                            lower_uncertainty: repr.lower_uncertainty.unwrap_or(Number::ZERO),
+                            upper_uncertainty: repr.upper_uncertainty.unwrap_or(Number::ZERO),
+                            phantom: PhantomData,
+                        })
+                    }
+                }
+            }
+        }
     };
 }
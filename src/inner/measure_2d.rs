@@ -1,71 +1,327 @@
 #[macro_export]
 macro_rules! inner_define_measure_2d {
-    { $with_points:tt $with_directions:tt } => {
-        pub struct Measure2d<Unit, Number = f64> {
-            pub x: Number,
-            pub y: Number,
-            phantom: std::marker::PhantomData<Unit>,
+    { $with_points:tt $with_directions:tt $with_uncertainty:tt } => {
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            pub struct Measure2d<Unit, Number = f64> {
+                pub x: Number,
+                pub y: Number,
+                phantom: std::marker::PhantomData<Unit>,
+            }
         }
 
-        impl<Unit, Number> Measure2d<Unit, Number>
-        where
-            Unit: MeasurementUnit,
-            Unit::Property: VectorProperty,
-            Number: ArithmeticOps,
-        {
-            /// measure 2d :: new(number, number) -> measure 2d
-            pub const fn new(x: Number, y: Number) -> Self {
-                Self {
-                    x,
-                    y,
-                    phantom: PhantomData,
-                }
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            pub struct Measure2d<Unit, Number = f64> {
+                pub x: Number,
+                pub y: Number,
+                // The covariance matrix of the components, in squared units.
+                // It is symmetric and positive semi-definite.
+                pub covariance: [[Number; 2]; 2],
+                phantom: std::marker::PhantomData<Unit>,
             }
+        }
 
-            /// measure 2d .x() -> measure
-            pub const fn x(self) -> Measure<Unit, Number> {
-                Measure::<Unit, Number>::new(self.x)
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            pub struct Measure2d<Unit, Number = f64> {
+                pub x: Number,
+                pub y: Number,
+                // The uncertainties below the components. They are never negative.
+                pub lower_uncertainty: [Number; 2],
+                // The uncertainties above the components. They are never negative.
+                pub upper_uncertainty: [Number; 2],
+                phantom: std::marker::PhantomData<Unit>,
             }
+        }
 
-            /// measure 2d .x() -> measure
-            pub const fn y(self) -> Measure<Unit, Number> {
-                Measure::<Unit, Number>::new(self.y)
-            }
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            impl<Unit, Number> Measure2d<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Unit::Property: VectorProperty,
+                Number: ArithmeticOps,
+            {
+                /// measure 2d :: new(number, number) -> measure 2d
+                pub const fn new(x: Number, y: Number) -> Self {
+                    Self {
+                        x,
+                        y,
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 2d .x() -> measure
+                pub const fn x(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::new(self.x)
+                }
+
+                /// measure 2d .x() -> measure
+                pub const fn y(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::new(self.y)
+                }
+
+                /// measure 2d .convert() -> measure 2d
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> Measure2d<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    Measure2d::<DestUnit, Number> {
+                        x: self.x * factor,
+                        y: self.y * factor,
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 2d .lossy_into() -> measure 2d
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> Measure2d<Unit, DestNumber> {
+                    Measure2d::<Unit, DestNumber> {
+                        x: DestNumber::from(self.x),
+                        y: DestNumber::from(self.y),
+                        phantom: PhantomData,
+                    }
+                }
 
-            /// measure 2d .convert() -> measure 2d
-            pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
-                &self,
-            ) -> Measure2d<DestUnit, Number> {
-                let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
-                Measure2d::<DestUnit, Number> {
-                    x: self.x * factor,
-                    y: self.y * factor,
-                    phantom: PhantomData,
+                /// measure 2d .lossy_into() -> measure 2d
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> Measure2d<Unit, DestNumber> {
+                    Measure2d::<Unit, DestNumber> {
+                        x: DestNumber::lossy_from(self.x),
+                        y: DestNumber::lossy_from(self.y),
+                        phantom: PhantomData,
+                    }
                 }
             }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            impl<Unit, Number> Measure2d<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Unit::Property: VectorProperty,
+                Number: ArithmeticOps,
+            {
+                /// measure 2d :: new(number, number) -> measure 2d, with no uncertainty
+                pub const fn new(x: Number, y: Number) -> Self {
+                    Self {
+                        x,
+                        y,
+                        covariance: [[Number::ZERO; 2]; 2],
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 2d :: with_covariance(number, number, [[number]]) -> measure 2d
+                /// Precondition: covariance is symmetric and positive semi-definite
+                pub const fn with_covariance(
+                    x: Number,
+                    y: Number,
+                    covariance: [[Number; 2]; 2],
+                ) -> Self {
+                    Self {
+                        x,
+                        y,
+                        covariance,
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 2d :: with_uncertainty(number, number, [number]) -> measure 2d
+                /// The components are assumed to be independent.
+                /// Precondition: all the uncertainties are non-negative
+                pub fn with_uncertainty(
+                    x: Number,
+                    y: Number,
+                    uncertainty: [Number; 2],
+                ) -> Self {
+                    Self::with_covariance(
+                        x,
+                        y,
+                        rs_measures::uncertainty::diagonal_covariance(uncertainty),
+                    )
+                }
+
+                /// measure 2d .x() -> measure
+                pub fn x(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::with_uncertainty(self.x, self.covariance[0][0].sqrt())
+                }
+
+                /// measure 2d .y() -> measure
+                pub fn y(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::with_uncertainty(self.y, self.covariance[1][1].sqrt())
+                }
+
+                /// measure 2d .convert() -> measure 2d
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> Measure2d<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    Measure2d::<DestUnit, Number> {
+                        x: self.x * factor,
+                        y: self.y * factor,
+                        covariance: rs_measures::uncertainty::scaled_covariance(&self.covariance, factor),
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 2d .lossless_into() -> measure 2d
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> Measure2d<Unit, DestNumber> {
+                    Measure2d::<Unit, DestNumber> {
+                        x: DestNumber::from(self.x),
+                        y: DestNumber::from(self.y),
+                        covariance: self.covariance.map(|row| row.map(DestNumber::from)),
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 2d .lossy_into() -> measure 2d
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> Measure2d<Unit, DestNumber> {
+                    Measure2d::<Unit, DestNumber> {
+                        x: DestNumber::lossy_from(self.x),
+                        y: DestNumber::lossy_from(self.y),
+                        covariance: self.covariance.map(|row| row.map(DestNumber::lossy_from)),
+                        phantom: PhantomData,
+                    }
+                }
 
-            /// measure 2d .lossy_into() -> measure 2d
-            pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
-                &self,
-            ) -> Measure2d<Unit, DestNumber> {
-                Measure2d::<Unit, DestNumber> {
-                    x: DestNumber::from(self.x),
-                    y: DestNumber::from(self.y),
-                    phantom: PhantomData,
+                /// measure 2d .error_ellipse() -> [measure 2d]
+                /// It returns the principal semi-axes of the standard error ellipse,
+                /// the longest first.
+                pub fn error_ellipse(self) -> [Measure2d<Unit, Number>; 2] {
+                    rs_measures::uncertainty::principal_semi_axes(&self.covariance)
+                        .map(|[x, y]| Measure2d::<Unit, Number>::new(x, y))
+                }
+
+                /// measure 2d .sample(Sampler) -> measure 2d
+                /// It returns a vector having no uncertainty, drawn from
+                /// a multivariate normal distribution having the components of `self`
+                /// as mean and its covariance matrix.
+                pub fn sample(self, sampler: &mut rs_measures::monte_carlo::Sampler) -> Self {
+                    let [x, y] = sampler.multivariate_normal([self.x, self.y], &self.covariance);
+                    Self::new(x, y)
                 }
             }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            impl<Unit, Number> Measure2d<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Unit::Property: VectorProperty,
+                Number: ArithmeticOps,
+            {
+                /// measure 2d :: new(number, number) -> measure 2d, with no uncertainty
+                pub const fn new(x: Number, y: Number) -> Self {
+                    Self {
+                        x,
+                        y,
+                        lower_uncertainty: [Number::ZERO; 2],
+                        upper_uncertainty: [Number::ZERO; 2],
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 2d :: with_uncertainty(number, number, [number], [number]) -> measure 2d
+                /// Precondition: all the uncertainties are non-negative
+                pub const fn with_uncertainty(
+                    x: Number,
+                    y: Number,
+                    lower_uncertainty: [Number; 2],
+                    upper_uncertainty: [Number; 2],
+                ) -> Self {
+                    Self {
+                        x,
+                        y,
+                        lower_uncertainty,
+                        upper_uncertainty,
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 2d .x() -> measure
+                pub const fn x(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::with_uncertainty(
+                        self.x,
+                        self.lower_uncertainty[0],
+                        self.upper_uncertainty[0],
+                    )
+                }
+
+                /// measure 2d .y() -> measure
+                pub const fn y(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::with_uncertainty(
+                        self.y,
+                        self.lower_uncertainty[1],
+                        self.upper_uncertainty[1],
+                    )
+                }
+
+                /// measure 2d .convert() -> measure 2d
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> Measure2d<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    Measure2d::<DestUnit, Number> {
+                        x: self.x * factor,
+                        y: self.y * factor,
+                        lower_uncertainty: self.lower_uncertainty.map(|u| u * factor),
+                        upper_uncertainty: self.upper_uncertainty.map(|u| u * factor),
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 2d .lossless_into() -> measure 2d
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> Measure2d<Unit, DestNumber> {
+                    Measure2d::<Unit, DestNumber> {
+                        x: DestNumber::from(self.x),
+                        y: DestNumber::from(self.y),
+                        lower_uncertainty: self.lower_uncertainty.map(DestNumber::from),
+                        upper_uncertainty: self.upper_uncertainty.map(DestNumber::from),
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 2d .lossy_into() -> measure 2d
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> Measure2d<Unit, DestNumber> {
+                    Measure2d::<Unit, DestNumber> {
+                        x: DestNumber::lossy_from(self.x),
+                        y: DestNumber::lossy_from(self.y),
+                        lower_uncertainty: self.lower_uncertainty.map(DestNumber::lossy_from),
+                        upper_uncertainty: self.upper_uncertainty.map(DestNumber::lossy_from),
+                        phantom: PhantomData,
+                    }
+                }
 
-            /// measure 2d .lossy_into() -> measure 2d
-            pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
-                &self,
-            ) -> Measure2d<Unit, DestNumber> {
-                Measure2d::<Unit, DestNumber> {
-                    x: DestNumber::lossy_from(self.x),
-                    y: DestNumber::lossy_from(self.y),
-                    phantom: PhantomData,
+                /// measure 2d .sample(Sampler) -> measure 2d
+                /// It returns a vector having no uncertainty, whose components are drawn
+                /// independently from split normal distributions.
+                pub fn sample(self, sampler: &mut rs_measures::monte_carlo::Sampler) -> Self {
+                    let [x, y] = core::array::from_fn(|i| {
+                        sampler.split_normal(
+                            [self.x, self.y][i],
+                            self.lower_uncertainty[i],
+                            self.upper_uncertainty[i],
+                        )
+                    });
+                    Self::new(x, y)
                 }
             }
+        }
 
+        impl<Unit, Number> Measure2d<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Unit::Property: VectorProperty,
+            Number: ArithmeticOps,
+        {
             /// measure 2d .squared_norm() -> number
             pub fn squared_norm(self) -> Number {
                 self.x * self.x + self.y * self.y
@@ -106,6 +362,239 @@ macro_rules! inner_define_measure_2d {
             }
         }
 
+        // The following methods are used by `define_units_relationship!`.
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure2d<Unit, Number> {
+                // Measure2d.components() -> [Measure]
+                fn components(self) -> [Measure<Unit, Number>; 2] {
+                    [Measure::<Unit, Number>::new(self.x), Measure::<Unit, Number>::new(self.y)]
+                }
+
+                // Measure2d::from_components(Measure, Measure) -> Measure2d
+                fn from_components(x: Measure<Unit, Number>, y: Measure<Unit, Number>) -> Self {
+                    Self {
+                        x: x.value,
+                        y: y.value,
+                        phantom: PhantomData,
+                    }
+                }
+
+                // Measure2d.linearly_transformed([[Number]]) -> Measure2d
+                fn linearly_transformed<DestUnit: MeasurementUnit>(self, c: &[[Number; 2]; 2]) -> Measure2d<DestUnit, Number> {
+                    Measure2d::<DestUnit, Number> {
+                        x: c[0][0] * self.x + c[0][1] * self.y,
+                        y: c[1][0] * self.x + c[1][1] * self.y,
+                        phantom: PhantomData,
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure2d<Unit, Number> {
+                // Measure2d.linearly_transformed([[Number]]) -> Measure2d
+                fn linearly_transformed<DestUnit: MeasurementUnit>(self, c: &[[Number; 2]; 2]) -> Measure2d<DestUnit, Number> {
+                    Measure2d::<DestUnit, Number> {
+                        x: c[0][0] * self.x + c[0][1] * self.y,
+                        y: c[1][0] * self.x + c[1][1] * self.y,
+                        covariance: rs_measures::uncertainty::transformed_covariance(c, &self.covariance),
+                        phantom: PhantomData,
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure2d<Unit, Number> {
+                // Measure2d.components() -> [Measure]
+                fn components(self) -> [Measure<Unit, Number>; 2] {
+                    core::array::from_fn(|i| {
+                        Measure::<Unit, Number>::with_uncertainty(
+                            [self.x, self.y][i],
+                            self.lower_uncertainty[i],
+                            self.upper_uncertainty[i],
+                        )
+                    })
+                }
+
+                // Measure2d::from_components(Measure, Measure) -> Measure2d
+                fn from_components(x: Measure<Unit, Number>, y: Measure<Unit, Number>) -> Self {
+                    Self {
+                        x: x.value,
+                        y: y.value,
+                        lower_uncertainty: [x.lower_uncertainty, y.lower_uncertainty],
+                        upper_uncertainty: [x.upper_uncertainty, y.upper_uncertainty],
+                        phantom: PhantomData,
+                    }
+                }
+
+                // Measure2d.linearly_transformed([[Number]]) -> Measure2d
+                // The uncertainties of the components are assumed to be independent.
+                fn linearly_transformed<DestUnit: MeasurementUnit>(self, c: &[[Number; 2]; 2]) -> Measure2d<DestUnit, Number> {
+                    let values = [self.x, self.y];
+                    let mut lower_uncertainty = [Number::ZERO; 2];
+                    let mut upper_uncertainty = [Number::ZERO; 2];
+                    for i in 0..2 {
+                        for j in 0..2 {
+                            let (lower, upper) = rs_measures::uncertainty::scaled_two_sided(
+                                self.lower_uncertainty[j],
+                                self.upper_uncertainty[j],
+                                c[i][j],
+                            );
+                            lower_uncertainty[i] += lower * lower;
+                            upper_uncertainty[i] += upper * upper;
+                        }
+                    }
+                    let [x, y] = core::array::from_fn(|i| (0..2).map(|j| c[i][j] * values[j]).sum());
+                    Measure2d::<DestUnit, Number> {
+                        x,
+                        y,
+                        lower_uncertainty: lower_uncertainty.map(|u| u.sqrt()),
+                        upper_uncertainty: upper_uncertainty.map(|u| u.sqrt()),
+                        phantom: PhantomData,
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_not_symmetric_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure2d<Unit, Number> {
+                // Measure2d<U1>.dot_product_into(Measure2d<U2>) -> Measure<U3>
+                fn dot_product_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure2d<OtherUnit, Number>,
+                ) -> Measure<DestUnit, Number> {
+                    let [x1, y1] = self.components();
+                    let [x2, y2] = other.components();
+                    x1.product_into::<OtherUnit, DestUnit>(x2)
+                        + y1.product_into::<OtherUnit, DestUnit>(y2)
+                }
+
+                // Measure2d<U1>.cross_product_into(Measure2d<U2>) -> Measure<U3>
+                fn cross_product_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure2d<OtherUnit, Number>,
+                ) -> Measure<DestUnit, Number> {
+                    let [x1, y1] = self.components();
+                    let [x2, y2] = other.components();
+                    x1.product_into::<OtherUnit, DestUnit>(y2)
+                        - y1.product_into::<OtherUnit, DestUnit>(x2)
+                }
+
+                // Measure2d<U1>.squared_into() -> Measure<U3>
+                fn squared_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    let [x, y] = self.components();
+                    x.squared_into::<DestUnit>() + y.squared_into::<DestUnit>()
+                }
+
+                // Measure2d<U2>.scaled_into(Measure<U1>) -> Measure2d<U3>
+                fn scaled_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    factor: Measure<OtherUnit, Number>,
+                ) -> Measure2d<DestUnit, Number> {
+                    let [x, y] = self.components();
+                    Measure2d::<DestUnit, Number>::from_components(
+                        x.product_into::<OtherUnit, DestUnit>(factor),
+                        y.product_into::<OtherUnit, DestUnit>(factor),
+                    )
+                }
+
+                // Measure2d<U3>.divided_into(Measure<U1>) -> Measure2d<U2>
+                fn divided_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    divisor: Measure<OtherUnit, Number>,
+                ) -> Measure2d<DestUnit, Number> {
+                    let [x, y] = self.components();
+                    Measure2d::<DestUnit, Number>::from_components(
+                        x.quotient_into::<OtherUnit, DestUnit>(divisor),
+                        y.quotient_into::<OtherUnit, DestUnit>(divisor),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // The two operands are assumed to be independent,
+            // while the correlations among the components of a vector are propagated
+            // through the Jacobian matrix of the operation.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure2d<Unit, Number> {
+                // Measure2d<U1>.dot_product_into(Measure2d<U2>) -> Measure<U3>
+                fn dot_product_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure2d<OtherUnit, Number>,
+                ) -> Measure<DestUnit, Number> {
+                    let a = [self.x, self.y];
+                    let b = [other.x, other.y];
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        a[0] * b[0] + a[1] * b[1],
+                        (rs_measures::uncertainty::propagated_variance(&self.covariance, b)
+                            + rs_measures::uncertainty::propagated_variance(&other.covariance, a))
+                        .sqrt(),
+                    )
+                }
+
+                // Measure2d<U1>.cross_product_into(Measure2d<U2>) -> Measure<U3>
+                fn cross_product_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure2d<OtherUnit, Number>,
+                ) -> Measure<DestUnit, Number> {
+                    let (x1, y1, x2, y2) = (self.x, self.y, other.x, other.y);
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        x1 * y2 - y1 * x2,
+                        (rs_measures::uncertainty::propagated_variance(&self.covariance, [y2, -x2])
+                            + rs_measures::uncertainty::propagated_variance(&other.covariance, [-y1, x1]))
+                        .sqrt(),
+                    )
+                }
+
+                // Measure2d<U1>.squared_into() -> Measure<U3>
+                fn squared_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    let v = [self.x, self.y];
+                    let norm_uncertainty = rs_measures::uncertainty::propagated_variance(&self.covariance, v).sqrt();
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        v[0] * v[0] + v[1] * v[1],
+                        norm_uncertainty + norm_uncertainty,
+                    )
+                }
+
+                // Measure2d<U2>.scaled_into(Measure<U1>) -> Measure2d<U3>
+                fn scaled_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    factor: Measure<OtherUnit, Number>,
+                ) -> Measure2d<DestUnit, Number> {
+                    let v = [self.x, self.y];
+                    Measure2d::<DestUnit, Number> {
+                        x: v[0] * factor.value,
+                        y: v[1] * factor.value,
+                        covariance: rs_measures::uncertainty::summed_covariances(
+                            &rs_measures::uncertainty::scaled_covariance(&self.covariance, factor.value),
+                            &rs_measures::uncertainty::collinear_covariance(v, factor.uncertainty),
+                        ),
+                        phantom: PhantomData,
+                    }
+                }
+
+                // Measure2d<U3>.divided_into(Measure<U1>) -> Measure2d<U2>
+                fn divided_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    divisor: Measure<OtherUnit, Number>,
+                ) -> Measure2d<DestUnit, Number> {
+                    let inverse = Number::ONE / divisor.value;
+                    let quotient = [self.x * inverse, self.y * inverse];
+                    Measure2d::<DestUnit, Number> {
+                        x: quotient[0],
+                        y: quotient[1],
+                        covariance: rs_measures::uncertainty::summed_covariances(
+                            &rs_measures::uncertainty::scaled_covariance(&self.covariance, inverse),
+                            &rs_measures::uncertainty::collinear_covariance(quotient, divisor.uncertainty * inverse),
+                        ),
+                        phantom: PhantomData,
+                    }
+                }
+            }
+        }
+
         impl<Unit, Number> Default for Measure2d<Unit, Number>
         where
             Unit: MeasurementUnit,
@@ -118,144 +607,453 @@ macro_rules! inner_define_measure_2d {
             }
         }
 
-        // -Measure2d -> Measure2d
-        impl<Unit, Number> Neg for Measure2d<Unit, Number>
-        where
-            Unit: MeasurementUnit,
-            Unit::Property: VectorProperty,
-            Number: ArithmeticOps,
-        {
-            type Output = Self;
-            fn neg(self) -> Self::Output {
-                Self::new(-self.x, -self.y)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // -Measure2d -> Measure2d
+            impl<Unit, Number> Neg for Measure2d<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Unit::Property: VectorProperty,
+                Number: ArithmeticOps,
+            {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self::new(-self.x, -self.y)
+                }
             }
         }
 
-        // Measure2d + Measure2d -> Measure2d
-        impl<Unit, Number> Add<Measure2d<Unit, Number>> for Measure2d<Unit, Number>
-        where
-            Unit: MeasurementUnit,
-            Unit::Property: VectorProperty,
-            Number: ArithmeticOps,
-        {
-            type Output = Self;
-            fn add(self, other: Measure2d<Unit, Number>) -> Self::Output {
-                Self::new(self.x + other.x, self.y + other.y)
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // -Measure2d -> Measure2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Neg for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self::with_covariance(-self.x, -self.y, self.covariance)
+                }
             }
         }
 
-        // Measure2d += Measure2d
-        impl<Unit, Number> AddAssign<Measure2d<Unit, Number>> for Measure2d<Unit, Number>
-        where
-            Unit: MeasurementUnit,
-            Unit::Property: VectorProperty,
-            Number: ArithmeticOps,
-        {
-            fn add_assign(&mut self, other: Measure2d<Unit, Number>) {
-                self.x += other.x;
-                self.y += other.y;
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // -Measure2d -> Measure2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Neg for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self::with_uncertainty(-self.x, -self.y, self.upper_uncertainty, self.lower_uncertainty)
+                }
             }
         }
 
-        // Measure2d - Measure2d -> Measure2d
-        impl<Unit, Number> Sub<Measure2d<Unit, Number>> for Measure2d<Unit, Number>
-        where
-            Unit: MeasurementUnit,
-            Unit::Property: VectorProperty,
-            Number: ArithmeticOps,
-        {
-            type Output = Self;
-            fn sub(self, other: Measure2d<Unit, Number>) -> Self::Output {
-                Self::new(self.x - other.x, self.y - other.y)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure2d + Measure2d -> Measure2d
+            impl<Unit, Number> Add<Measure2d<Unit, Number>> for Measure2d<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Unit::Property: VectorProperty,
+                Number: ArithmeticOps,
+            {
+                type Output = Self;
+                fn add(self, other: Measure2d<Unit, Number>) -> Self::Output {
+                    Self::new(self.x + other.x, self.y + other.y)
+                }
             }
         }
 
-        // Measure2d -= Measure2d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> SubAssign<Measure2d<Unit, Number>>
-            for Measure2d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn sub_assign(&mut self, other: Measure2d<Unit, Number>) {
-                self.x -= other.x;
-                self.y -= other.y;
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure2d + Measure2d -> Measure2d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure2d<Unit, Number>>
+                for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn add(self, other: Measure2d<Unit, Number>) -> Self::Output {
+                    Self::with_covariance(
+                        self.x + other.x, self.y + other.y,
+                        rs_measures::uncertainty::summed_covariances(&self.covariance, &other.covariance),
+                    )
+                }
             }
         }
 
-        // Measure2d * Number -> Measure2d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Mul<Number> for Measure2d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Self;
-            fn mul(self, n: Number) -> Self::Output {
-                Self::new(self.x * n, self.y * n)
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure2d + Measure2d -> Measure2d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure2d<Unit, Number>>
+                for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn add(self, other: Measure2d<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.x + other.x, self.y + other.y,
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.lower_uncertainty,
+                            other.lower_uncertainty,
+                        ),
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.upper_uncertainty,
+                            other.upper_uncertainty,
+                        ),
+                    )
+                }
             }
         }
 
-        // Measure2d *= Number
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> MulAssign<Number> for Measure2d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn mul_assign(&mut self, n: Number) {
-                self.x *= n;
-                self.y *= n;
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure2d += Measure2d
+            impl<Unit, Number> AddAssign<Measure2d<Unit, Number>> for Measure2d<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Unit::Property: VectorProperty,
+                Number: ArithmeticOps,
+            {
+                fn add_assign(&mut self, other: Measure2d<Unit, Number>) {
+                    self.x += other.x;
+                    self.y += other.y;
+                }
             }
         }
 
-        // f64 * Measure2d -> Measure2d
-        impl<Unit: MeasurementUnit> Mul<Measure2d<Unit, f64>> for f64
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Measure2d<Unit, f64>;
-            fn mul(self, other: Measure2d<Unit, f64>) -> Self::Output {
-                Self::Output::new(self * other.x, self * other.y)
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure2d += Measure2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> AddAssign<Measure2d<Unit, Number>>
+                for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn add_assign(&mut self, other: Measure2d<Unit, Number>) {
+                    *self = *self + other;
+                }
             }
         }
 
-        // f32 * Measure2d -> Measure2d
-        impl<Unit: MeasurementUnit> Mul<Measure2d<Unit, f32>> for f32
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Measure2d<Unit, f32>;
-            fn mul(self, other: Measure2d<Unit, f32>) -> Self::Output {
-                Self::Output::new(self * other.x, self * other.y)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure2d - Measure2d -> Measure2d
+            impl<Unit, Number> Sub<Measure2d<Unit, Number>> for Measure2d<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Unit::Property: VectorProperty,
+                Number: ArithmeticOps,
+            {
+                type Output = Self;
+                fn sub(self, other: Measure2d<Unit, Number>) -> Self::Output {
+                    Self::new(self.x - other.x, self.y - other.y)
+                }
             }
         }
 
-        // Measure2d / Number -> Measure2d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Measure2d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Self;
-            fn div(self, n: Number) -> Self::Output {
-                Self::new(self.x / n, self.y / n)
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure2d - Measure2d -> Measure2d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure2d<Unit, Number>>
+                for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn sub(self, other: Measure2d<Unit, Number>) -> Self::Output {
+                    Self::with_covariance(
+                        self.x - other.x, self.y - other.y,
+                        rs_measures::uncertainty::summed_covariances(&self.covariance, &other.covariance),
+                    )
+                }
             }
         }
 
-        // Measure2d /= Number
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> DivAssign<Number> for Measure2d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn div_assign(&mut self, n: Number) {
-                self.x /= n;
-                self.y /= n;
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure2d - Measure2d -> Measure2d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure2d<Unit, Number>>
+                for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn sub(self, other: Measure2d<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.x - other.x, self.y - other.y,
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.lower_uncertainty,
+                            other.upper_uncertainty,
+                        ),
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.upper_uncertainty,
+                            other.lower_uncertainty,
+                        ),
+                    )
+                }
             }
         }
 
-        // Measure2d == Measure2d -> bool
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<Measure2d<Unit, Number>>
-            for Measure2d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn eq(&self, other: &Measure2d<Unit, Number>) -> bool {
-                self.x == other.x && self.y == other.y
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure2d -= Measure2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> SubAssign<Measure2d<Unit, Number>>
+                for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn sub_assign(&mut self, other: Measure2d<Unit, Number>) {
+                    self.x -= other.x;
+                    self.y -= other.y;
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure2d -= Measure2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> SubAssign<Measure2d<Unit, Number>>
+                for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn sub_assign(&mut self, other: Measure2d<Unit, Number>) {
+                    *self = *self - other;
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure2d * Number -> Measure2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Mul<Number> for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn mul(self, n: Number) -> Self::Output {
+                    Self::new(self.x * n, self.y * n)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure2d * Number -> Measure2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Mul<Number> for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn mul(self, n: Number) -> Self::Output {
+                    Self::with_covariance(
+                        self.x * n, self.y * n,
+                        rs_measures::uncertainty::scaled_covariance(&self.covariance, n),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure2d * Number -> Measure2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Mul<Number> for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn mul(self, n: Number) -> Self::Output {
+                    let (lower, upper) = rs_measures::uncertainty::scaled_two_sided_array(
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                        n,
+                    );
+                    Self::with_uncertainty(self.x * n, self.y * n, lower, upper)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure2d *= Number
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> MulAssign<Number> for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn mul_assign(&mut self, n: Number) {
+                    self.x *= n;
+                    self.y *= n;
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure2d *= Number
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> MulAssign<Number> for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn mul_assign(&mut self, n: Number) {
+                    *self = *self * n;
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // f64 * Measure2d -> Measure2d
+            impl<Unit: MeasurementUnit> Mul<Measure2d<Unit, f64>> for f64
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Measure2d<Unit, f64>;
+                fn mul(self, other: Measure2d<Unit, f64>) -> Self::Output {
+                    Self::Output::new(self * other.x, self * other.y)
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // f64 * Measure2d -> Measure2d
+            impl<Unit: MeasurementUnit> Mul<Measure2d<Unit, f64>> for f64
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Measure2d<Unit, f64>;
+                fn mul(self, other: Measure2d<Unit, f64>) -> Self::Output {
+                    other * self
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // f32 * Measure2d -> Measure2d
+            impl<Unit: MeasurementUnit> Mul<Measure2d<Unit, f32>> for f32
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Measure2d<Unit, f32>;
+                fn mul(self, other: Measure2d<Unit, f32>) -> Self::Output {
+                    Self::Output::new(self * other.x, self * other.y)
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // f32 * Measure2d -> Measure2d
+            impl<Unit: MeasurementUnit> Mul<Measure2d<Unit, f32>> for f32
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Measure2d<Unit, f32>;
+                fn mul(self, other: Measure2d<Unit, f32>) -> Self::Output {
+                    other * self
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure2d / Number -> Measure2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn div(self, n: Number) -> Self::Output {
+                    Self::new(self.x / n, self.y / n)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure2d / Number -> Measure2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn div(self, n: Number) -> Self::Output {
+                    Self::with_covariance(
+                        self.x / n, self.y / n,
+                        rs_measures::uncertainty::scaled_covariance(&self.covariance, Number::ONE / n),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure2d / Number -> Measure2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn div(self, n: Number) -> Self::Output {
+                    let (lower, upper) = rs_measures::uncertainty::scaled_two_sided_array(
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                        Number::ONE / n,
+                    );
+                    Self::with_uncertainty(self.x / n, self.y / n, lower, upper)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure2d /= Number
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> DivAssign<Number> for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn div_assign(&mut self, n: Number) {
+                    self.x /= n;
+                    self.y /= n;
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure2d /= Number
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> DivAssign<Number> for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn div_assign(&mut self, n: Number) {
+                    *self = *self / n;
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure2d == Measure2d -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<Measure2d<Unit, Number>>
+                for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn eq(&self, other: &Measure2d<Unit, Number>) -> bool {
+                    self.x == other.x && self.y == other.y
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure2d == Measure2d -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<Measure2d<Unit, Number>>
+                for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn eq(&self, other: &Measure2d<Unit, Number>) -> bool {
+                    self.x == other.x && self.y == other.y
+                        && self.covariance == other.covariance
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure2d == Measure2d -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<Measure2d<Unit, Number>>
+                for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn eq(&self, other: &Measure2d<Unit, Number>) -> bool {
+                    self.x == other.x && self.y == other.y
+                        && self.lower_uncertainty == other.lower_uncertainty
+                        && self.upper_uncertainty == other.upper_uncertainty
+                }
             }
         }
 
@@ -275,33 +1073,312 @@ macro_rules! inner_define_measure_2d {
         {
         }
 
-        // format!("{}", Measure2d)
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Measure2d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("(")?;
-                fmt::Display::fmt(&self.x, formatter)?;
-                formatter.write_str(", ")?;
-                fmt::Display::fmt(&self.y, formatter)?;
-                formatter.write_str(")")?;
-                formatter.write_str(Unit::SUFFIX)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // format!("{}", Measure2d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("(")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
             }
         }
 
-        // format!("{:?}", Measure2d)
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Measure2d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("(")?;
-                fmt::Display::fmt(&self.x, formatter)?;
-                formatter.write_str(", ")?;
-                fmt::Display::fmt(&self.y, formatter)?;
-                formatter.write_str(")")?;
-                formatter.write_str(Unit::SUFFIX)
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // format!("{}", Measure2d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("(")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[0][0].sqrt(), formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[1][1].sqrt(), formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // format!("{}", Measure2d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("(")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[0], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[0], formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[1], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[1], formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // format!("{:?}", Measure2d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("(")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // format!("{:?}", Measure2d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("(")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[0][0].sqrt(), formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[1][1].sqrt(), formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // format!("{:?}", Measure2d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("(")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[0], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[0], formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[1], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[1], formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // "...".parse::<Measure2d>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    parser.text("(")?;
+                    let x = parser.number()?;
+                    parser.text(",")?;
+                    let y = parser.number()?;
+                    parser.text(")")?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::new(x, y))
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // "...".parse::<Measure2d>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    parser.text("(")?;
+                    let (x, ux) = parser.number_with_uncertainty()?;
+                    parser.text(",")?;
+                    let (y, uy) = parser.number_with_uncertainty()?;
+                    parser.text(")")?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::with_uncertainty(x, y, [ux, uy]))
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // "...".parse::<Measure2d>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for Measure2d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    parser.text("(")?;
+                    let (x, lower_x, upper_x) = parser.number_with_two_sided_uncertainty()?;
+                    parser.text(",")?;
+                    let (y, lower_y, upper_y) = parser.number_with_two_sided_uncertainty()?;
+                    parser.text(")")?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::with_uncertainty(x, y, [lower_x, lower_y], [upper_x, upper_y]))
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // serde::Serialize for Measure2d
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for Measure2d<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::Vector2dRepr {
+                                x: self.x,
+                                y: self.y,
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for Measure2d
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for Measure2d<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::Vector2dRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            x: repr.x,
+                            y: repr.y,
+                            phantom: PhantomData,
+                        })
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // serde::Serialize for Measure2d
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for Measure2d<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::SymmetricVector2dRepr {
+                                x: self.x,
+                                y: self.y,
+                                covariance: Some(self.covariance),
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for Measure2d
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for Measure2d<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::SymmetricVector2dRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            x: repr.x,
+                            y: repr.y,
+                            covariance: repr.covariance.unwrap_or([[Number::ZERO; 2]; 2]),
+                            phantom: PhantomData,
+                        })
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // serde::Serialize for Measure2d
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for Measure2d<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::TwoSidedVector2dRepr {
+                                x: self.x,
+                                y: self.y,
+                                lower_uncertainty: Some(self.lower_uncertainty),
+                                upper_uncertainty: Some(self.upper_uncertainty),
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for Measure2d
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for Measure2d<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::TwoSidedVector2dRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            x: repr.x,
+                            y: repr.y,
+                            lower_uncertainty: repr.lower_uncertainty.unwrap_or([Number::ZERO; 2]),
+                            upper_uncertainty: repr.upper_uncertainty.unwrap_or([Number::ZERO; 2]),
+                            phantom: PhantomData,
+                        })
+                    }
+                }
             }
         }
     };
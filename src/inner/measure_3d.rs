@@ -1,78 +1,359 @@
 #[macro_export]
 macro_rules! inner_define_measure_3d {
-    {} => {
-        pub struct Measure3d<Unit, Number: ArithmeticOps = f64> {
-            pub x: Number,
-            pub y: Number,
-            pub z: Number,
-            phantom: std::marker::PhantomData<Unit>,
-        }
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            /// measure 3d :: new(number, number, number) -> measure 3d
-            pub const fn new(x: Number, y: Number, z: Number) -> Self {
-                Self {
-                    x,
-                    y,
-                    z,
-                    phantom: PhantomData,
-                }
+    { $with_directions:tt $with_uncertainty:tt } => {
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            pub struct Measure3d<Unit, Number: ArithmeticOps = f64> {
+                pub x: Number,
+                pub y: Number,
+                pub z: Number,
+                phantom: std::marker::PhantomData<Unit>,
             }
+        }
 
-            /// measure 3d .x() -> measure
-            pub const fn x(self) -> Measure<Unit, Number> {
-                Measure::<Unit, Number>::new(self.x)
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            pub struct Measure3d<Unit, Number: ArithmeticOps = f64> {
+                pub x: Number,
+                pub y: Number,
+                pub z: Number,
+                // The covariance matrix of the components, in squared units.
+                // It is symmetric and positive semi-definite.
+                pub covariance: [[Number; 3]; 3],
+                phantom: std::marker::PhantomData<Unit>,
             }
+        }
 
-            /// measure 3d .y() -> measure
-            pub const fn y(self) -> Measure<Unit, Number> {
-                Measure::<Unit, Number>::new(self.y)
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            pub struct Measure3d<Unit, Number: ArithmeticOps = f64> {
+                pub x: Number,
+                pub y: Number,
+                pub z: Number,
+                // The uncertainties below the components. They are never negative.
+                pub lower_uncertainty: [Number; 3],
+                // The uncertainties above the components. They are never negative.
+                pub upper_uncertainty: [Number; 3],
+                phantom: std::marker::PhantomData<Unit>,
             }
+        }
 
-            /// measure 3d .z() -> measure
-            pub const fn z(self) -> Measure<Unit, Number> {
-                Measure::<Unit, Number>::new(self.z)
-            }
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                /// measure 3d :: new(number, number, number) -> measure 3d
+                pub const fn new(x: Number, y: Number, z: Number) -> Self {
+                    Self {
+                        x,
+                        y,
+                        z,
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 3d .x() -> measure
+                pub const fn x(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::new(self.x)
+                }
+
+                /// measure 3d .y() -> measure
+                pub const fn y(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::new(self.y)
+                }
+
+                /// measure 3d .z() -> measure
+                pub const fn z(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::new(self.z)
+                }
+
+                /// measure 3d .convert() -> measure 3d
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> Measure3d<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    Measure3d::<DestUnit, Number> {
+                        x: self.x * factor,
+                        y: self.y * factor,
+                        z: self.z * factor,
+                        phantom: PhantomData,
+                    }
+                }
 
-            /// measure 3d .convert() -> measure 3d
-            pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
-                &self,
-            ) -> Measure3d<DestUnit, Number> {
-                let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
-                Measure3d::<DestUnit, Number> {
-                    x: self.x * factor,
-                    y: self.y * factor,
-                    z: self.z * factor,
-                    phantom: PhantomData,
+                /// measure 3d .lossless_into() -> measure 3d
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> Measure3d<Unit, DestNumber> {
+                    Measure3d::<Unit, DestNumber> {
+                        x: DestNumber::from(self.x),
+                        y: DestNumber::from(self.y),
+                        z: DestNumber::from(self.z),
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 3d .lossy_into() -> measure 3d
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> Measure3d<Unit, DestNumber> {
+                    Measure3d::<Unit, DestNumber> {
+                        x: DestNumber::lossy_from(self.x),
+                        y: DestNumber::lossy_from(self.y),
+                        z: DestNumber::lossy_from(self.z),
+                        phantom: PhantomData,
+                    }
                 }
             }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                /// measure 3d :: new(number, number, number) -> measure 3d, with no uncertainty
+                pub const fn new(x: Number, y: Number, z: Number) -> Self {
+                    Self {
+                        x,
+                        y,
+                        z,
+                        covariance: [[Number::ZERO; 3]; 3],
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 3d :: with_covariance(number, number, number, [[number]]) -> measure 3d
+                /// Precondition: covariance is symmetric and positive semi-definite
+                pub const fn with_covariance(
+                    x: Number,
+                    y: Number,
+                    z: Number,
+                    covariance: [[Number; 3]; 3],
+                ) -> Self {
+                    Self {
+                        x,
+                        y,
+                        z,
+                        covariance,
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 3d :: with_uncertainty(number, number, number, [number]) -> measure 3d
+                /// The components are assumed to be independent.
+                /// Precondition: all the uncertainties are non-negative
+                pub fn with_uncertainty(
+                    x: Number,
+                    y: Number,
+                    z: Number,
+                    uncertainty: [Number; 3],
+                ) -> Self {
+                    Self::with_covariance(
+                        x,
+                        y,
+                        z,
+                        rs_measures::uncertainty::diagonal_covariance(uncertainty),
+                    )
+                }
+
+                /// measure 3d .x() -> measure
+                pub fn x(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::with_uncertainty(self.x, self.covariance[0][0].sqrt())
+                }
+
+                /// measure 3d .y() -> measure
+                pub fn y(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::with_uncertainty(self.y, self.covariance[1][1].sqrt())
+                }
+
+                /// measure 3d .z() -> measure
+                pub fn z(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::with_uncertainty(self.z, self.covariance[2][2].sqrt())
+                }
+
+                /// measure 3d .convert() -> measure 3d
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> Measure3d<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    Measure3d::<DestUnit, Number> {
+                        x: self.x * factor,
+                        y: self.y * factor,
+                        z: self.z * factor,
+                        covariance: rs_measures::uncertainty::scaled_covariance(&self.covariance, factor),
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 3d .lossless_into() -> measure 3d
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> Measure3d<Unit, DestNumber> {
+                    Measure3d::<Unit, DestNumber> {
+                        x: DestNumber::from(self.x),
+                        y: DestNumber::from(self.y),
+                        z: DestNumber::from(self.z),
+                        covariance: self.covariance.map(|row| row.map(DestNumber::from)),
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 3d .lossy_into() -> measure 3d
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> Measure3d<Unit, DestNumber> {
+                    Measure3d::<Unit, DestNumber> {
+                        x: DestNumber::lossy_from(self.x),
+                        y: DestNumber::lossy_from(self.y),
+                        z: DestNumber::lossy_from(self.z),
+                        covariance: self.covariance.map(|row| row.map(DestNumber::lossy_from)),
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 3d .error_ellipsoid() -> [measure 3d]
+                /// It returns the principal semi-axes of the standard error ellipsoid,
+                /// the longest first.
+                pub fn error_ellipsoid(self) -> [Measure3d<Unit, Number>; 3] {
+                    rs_measures::uncertainty::principal_semi_axes(&self.covariance)
+                        .map(|[x, y, z]| Measure3d::<Unit, Number>::new(x, y, z))
+                }
 
-            /// measure 3d .lossless_into() -> measure 3d
-            pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
-                &self,
-            ) -> Measure3d<Unit, DestNumber> {
-                Measure3d::<Unit, DestNumber> {
-                    x: DestNumber::from(self.x),
-                    y: DestNumber::from(self.y),
-                    z: DestNumber::from(self.z),
-                    phantom: PhantomData,
+                /// measure 3d .sample(Sampler) -> measure 3d
+                /// It returns a vector having no uncertainty, drawn from
+                /// a multivariate normal distribution having the components of `self`
+                /// as mean and its covariance matrix.
+                pub fn sample(self, sampler: &mut rs_measures::monte_carlo::Sampler) -> Self {
+                    let [x, y, z] = sampler.multivariate_normal([self.x, self.y, self.z], &self.covariance);
+                    Self::new(x, y, z)
                 }
             }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                /// measure 3d :: new(number, number, number) -> measure 3d, with no uncertainty
+                pub const fn new(x: Number, y: Number, z: Number) -> Self {
+                    Self {
+                        x,
+                        y,
+                        z,
+                        lower_uncertainty: [Number::ZERO; 3],
+                        upper_uncertainty: [Number::ZERO; 3],
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 3d :: with_uncertainty(number, number, number, [number], [number]) -> measure 3d
+                /// Precondition: all the uncertainties are non-negative
+                pub const fn with_uncertainty(
+                    x: Number,
+                    y: Number,
+                    z: Number,
+                    lower_uncertainty: [Number; 3],
+                    upper_uncertainty: [Number; 3],
+                ) -> Self {
+                    Self {
+                        x,
+                        y,
+                        z,
+                        lower_uncertainty,
+                        upper_uncertainty,
+                        phantom: PhantomData,
+                    }
+                }
 
-            /// measure 3d .lossy_into() -> measure 3d
-            pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
-                &self,
-            ) -> Measure3d<Unit, DestNumber> {
-                Measure3d::<Unit, DestNumber> {
-                    x: DestNumber::lossy_from(self.x),
-                    y: DestNumber::lossy_from(self.y),
-                    z: DestNumber::lossy_from(self.z),
-                    phantom: PhantomData,
+                /// measure 3d .x() -> measure
+                pub const fn x(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::with_uncertainty(
+                        self.x,
+                        self.lower_uncertainty[0],
+                        self.upper_uncertainty[0],
+                    )
+                }
+
+                /// measure 3d .y() -> measure
+                pub const fn y(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::with_uncertainty(
+                        self.y,
+                        self.lower_uncertainty[1],
+                        self.upper_uncertainty[1],
+                    )
+                }
+
+                /// measure 3d .z() -> measure
+                pub const fn z(self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::with_uncertainty(
+                        self.z,
+                        self.lower_uncertainty[2],
+                        self.upper_uncertainty[2],
+                    )
+                }
+
+                /// measure 3d .convert() -> measure 3d
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> Measure3d<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    Measure3d::<DestUnit, Number> {
+                        x: self.x * factor,
+                        y: self.y * factor,
+                        z: self.z * factor,
+                        lower_uncertainty: self.lower_uncertainty.map(|u| u * factor),
+                        upper_uncertainty: self.upper_uncertainty.map(|u| u * factor),
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 3d .lossless_into() -> measure 3d
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> Measure3d<Unit, DestNumber> {
+                    Measure3d::<Unit, DestNumber> {
+                        x: DestNumber::from(self.x),
+                        y: DestNumber::from(self.y),
+                        z: DestNumber::from(self.z),
+                        lower_uncertainty: self.lower_uncertainty.map(DestNumber::from),
+                        upper_uncertainty: self.upper_uncertainty.map(DestNumber::from),
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 3d .lossy_into() -> measure 3d
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> Measure3d<Unit, DestNumber> {
+                    Measure3d::<Unit, DestNumber> {
+                        x: DestNumber::lossy_from(self.x),
+                        y: DestNumber::lossy_from(self.y),
+                        z: DestNumber::lossy_from(self.z),
+                        lower_uncertainty: self.lower_uncertainty.map(DestNumber::lossy_from),
+                        upper_uncertainty: self.upper_uncertainty.map(DestNumber::lossy_from),
+                        phantom: PhantomData,
+                    }
+                }
+
+                /// measure 3d .sample(Sampler) -> measure 3d
+                /// It returns a vector having no uncertainty, whose components are drawn
+                /// independently from split normal distributions.
+                pub fn sample(self, sampler: &mut rs_measures::monte_carlo::Sampler) -> Self {
+                    let [x, y, z] = core::array::from_fn(|i| {
+                        sampler.split_normal(
+                            [self.x, self.y, self.z][i],
+                            self.lower_uncertainty[i],
+                            self.upper_uncertainty[i],
+                        )
+                    });
+                    Self::new(x, y, z)
                 }
             }
+        }
 
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure3d<Unit, Number>
+        where
+            Unit::Property: VectorProperty,
+        {
             /// measure 3d .squared_norm() -> number
             pub fn squared_norm(self) -> Number {
                 self.x * self.x + self.y * self.y + self.z * self.z
@@ -83,6 +364,290 @@ macro_rules! inner_define_measure_3d {
                 let k = Number::ONE / self.squared_norm().sqrt();
                 Self::new(self.x * k, self.y * k, self.z * k)
             }
+
+            rs_measures::if_true! { $with_directions,
+                /// Measure3d::from_direction(Direction3d) -> Measure3d
+                pub fn from_direction<AngleUnit: AngleMeasurementUnit<Property = Angle>>(
+                    direction: Direction3d<AngleUnit, Number>,
+                ) -> Self {
+                    let [x, y, z] = direction.unit_vector;
+                    Self::new(x, y, z)
+                }
+            }
+
+            rs_measures::if_true! { $with_directions,
+                /// Measure3d.direction() -> Direction3d
+                pub fn direction<AngleUnit: AngleMeasurementUnit<Property = Angle>>(
+                    self,
+                ) -> Direction3d<AngleUnit, Number> {
+                    Direction3d::<AngleUnit, Number>::from_vector([self.x, self.y, self.z])
+                }
+            }
+        }
+
+        // The following methods are used by `define_units_relationship!`.
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure3d<Unit, Number> {
+                // Measure3d.components() -> [Measure]
+                fn components(self) -> [Measure<Unit, Number>; 3] {
+                    [Measure::<Unit, Number>::new(self.x), Measure::<Unit, Number>::new(self.y), Measure::<Unit, Number>::new(self.z)]
+                }
+
+                // Measure3d::from_components(Measure, Measure, Measure) -> Measure3d
+                fn from_components(x: Measure<Unit, Number>, y: Measure<Unit, Number>, z: Measure<Unit, Number>) -> Self {
+                    Self {
+                        x: x.value,
+                        y: y.value,
+                        z: z.value,
+                        phantom: PhantomData,
+                    }
+                }
+
+                // Measure3d.linearly_transformed([[Number]]) -> Measure3d
+                fn linearly_transformed<DestUnit: MeasurementUnit>(self, c: &[[Number; 3]; 3]) -> Measure3d<DestUnit, Number> {
+                    Measure3d::<DestUnit, Number> {
+                        x: c[0][0] * self.x + c[0][1] * self.y + c[0][2] * self.z,
+                        y: c[1][0] * self.x + c[1][1] * self.y + c[1][2] * self.z,
+                        z: c[2][0] * self.x + c[2][1] * self.y + c[2][2] * self.z,
+                        phantom: PhantomData,
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure3d<Unit, Number> {
+                // Measure3d.linearly_transformed([[Number]]) -> Measure3d
+                fn linearly_transformed<DestUnit: MeasurementUnit>(self, c: &[[Number; 3]; 3]) -> Measure3d<DestUnit, Number> {
+                    Measure3d::<DestUnit, Number> {
+                        x: c[0][0] * self.x + c[0][1] * self.y + c[0][2] * self.z,
+                        y: c[1][0] * self.x + c[1][1] * self.y + c[1][2] * self.z,
+                        z: c[2][0] * self.x + c[2][1] * self.y + c[2][2] * self.z,
+                        covariance: rs_measures::uncertainty::transformed_covariance(c, &self.covariance),
+                        phantom: PhantomData,
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure3d<Unit, Number> {
+                // Measure3d.components() -> [Measure]
+                fn components(self) -> [Measure<Unit, Number>; 3] {
+                    core::array::from_fn(|i| {
+                        Measure::<Unit, Number>::with_uncertainty(
+                            [self.x, self.y, self.z][i],
+                            self.lower_uncertainty[i],
+                            self.upper_uncertainty[i],
+                        )
+                    })
+                }
+
+                // Measure3d::from_components(Measure, Measure, Measure) -> Measure3d
+                fn from_components(x: Measure<Unit, Number>, y: Measure<Unit, Number>, z: Measure<Unit, Number>) -> Self {
+                    Self {
+                        x: x.value,
+                        y: y.value,
+                        z: z.value,
+                        lower_uncertainty: [x.lower_uncertainty, y.lower_uncertainty, z.lower_uncertainty],
+                        upper_uncertainty: [x.upper_uncertainty, y.upper_uncertainty, z.upper_uncertainty],
+                        phantom: PhantomData,
+                    }
+                }
+
+                // Measure3d.linearly_transformed([[Number]]) -> Measure3d
+                // The uncertainties of the components are assumed to be independent.
+                fn linearly_transformed<DestUnit: MeasurementUnit>(self, c: &[[Number; 3]; 3]) -> Measure3d<DestUnit, Number> {
+                    let values = [self.x, self.y, self.z];
+                    let mut lower_uncertainty = [Number::ZERO; 3];
+                    let mut upper_uncertainty = [Number::ZERO; 3];
+                    for i in 0..3 {
+                        for j in 0..3 {
+                            let (lower, upper) = rs_measures::uncertainty::scaled_two_sided(
+                                self.lower_uncertainty[j],
+                                self.upper_uncertainty[j],
+                                c[i][j],
+                            );
+                            lower_uncertainty[i] += lower * lower;
+                            upper_uncertainty[i] += upper * upper;
+                        }
+                    }
+                    let [x, y, z] = core::array::from_fn(|i| (0..3).map(|j| c[i][j] * values[j]).sum());
+                    Measure3d::<DestUnit, Number> {
+                        x,
+                        y,
+                        z,
+                        lower_uncertainty: lower_uncertainty.map(|u| u.sqrt()),
+                        upper_uncertainty: upper_uncertainty.map(|u| u.sqrt()),
+                        phantom: PhantomData,
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_not_symmetric_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure3d<Unit, Number> {
+                // Measure3d<U1>.dot_product_into(Measure3d<U2>) -> Measure<U3>
+                fn dot_product_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure3d<OtherUnit, Number>,
+                ) -> Measure<DestUnit, Number> {
+                    let [x1, y1, z1] = self.components();
+                    let [x2, y2, z2] = other.components();
+                    x1.product_into::<OtherUnit, DestUnit>(x2)
+                        + y1.product_into::<OtherUnit, DestUnit>(y2)
+                        + z1.product_into::<OtherUnit, DestUnit>(z2)
+                }
+
+                // Measure3d<U1>.cross_product_into(Measure3d<U2>) -> Measure3d<U3>
+                fn cross_product_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure3d<OtherUnit, Number>,
+                ) -> Measure3d<DestUnit, Number> {
+                    let [x1, y1, z1] = self.components();
+                    let [x2, y2, z2] = other.components();
+                    Measure3d::<DestUnit, Number>::from_components(
+                        y1.product_into::<OtherUnit, DestUnit>(z2)
+                            - z1.product_into::<OtherUnit, DestUnit>(y2),
+                        z1.product_into::<OtherUnit, DestUnit>(x2)
+                            - x1.product_into::<OtherUnit, DestUnit>(z2),
+                        x1.product_into::<OtherUnit, DestUnit>(y2)
+                            - y1.product_into::<OtherUnit, DestUnit>(x2),
+                    )
+                }
+
+                // Measure3d<U1>.squared_into() -> Measure<U3>
+                fn squared_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    let [x, y, z] = self.components();
+                    x.squared_into::<DestUnit>()
+                        + y.squared_into::<DestUnit>()
+                        + z.squared_into::<DestUnit>()
+                }
+
+                // Measure3d<U2>.scaled_into(Measure<U1>) -> Measure3d<U3>
+                fn scaled_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    factor: Measure<OtherUnit, Number>,
+                ) -> Measure3d<DestUnit, Number> {
+                    let [x, y, z] = self.components();
+                    Measure3d::<DestUnit, Number>::from_components(
+                        x.product_into::<OtherUnit, DestUnit>(factor),
+                        y.product_into::<OtherUnit, DestUnit>(factor),
+                        z.product_into::<OtherUnit, DestUnit>(factor),
+                    )
+                }
+
+                // Measure3d<U3>.divided_into(Measure<U1>) -> Measure3d<U2>
+                fn divided_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    divisor: Measure<OtherUnit, Number>,
+                ) -> Measure3d<DestUnit, Number> {
+                    let [x, y, z] = self.components();
+                    Measure3d::<DestUnit, Number>::from_components(
+                        x.quotient_into::<OtherUnit, DestUnit>(divisor),
+                        y.quotient_into::<OtherUnit, DestUnit>(divisor),
+                        z.quotient_into::<OtherUnit, DestUnit>(divisor),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // The two operands are assumed to be independent,
+            // while the correlations among the components of a vector are propagated
+            // through the Jacobian matrix of the operation.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Measure3d<Unit, Number> {
+                // Measure3d<U1>.dot_product_into(Measure3d<U2>) -> Measure<U3>
+                fn dot_product_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure3d<OtherUnit, Number>,
+                ) -> Measure<DestUnit, Number> {
+                    let a = [self.x, self.y, self.z];
+                    let b = [other.x, other.y, other.z];
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        a[0] * b[0] + a[1] * b[1] + a[2] * b[2],
+                        (rs_measures::uncertainty::propagated_variance(&self.covariance, b)
+                            + rs_measures::uncertainty::propagated_variance(&other.covariance, a))
+                        .sqrt(),
+                    )
+                }
+
+                // Measure3d<U1>.cross_product_into(Measure3d<U2>) -> Measure3d<U3>
+                fn cross_product_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    other: Measure3d<OtherUnit, Number>,
+                ) -> Measure3d<DestUnit, Number> {
+                    let (x1, y1, z1) = (self.x, self.y, self.z);
+                    let (x2, y2, z2) = (other.x, other.y, other.z);
+                    // The cross product by a vector v is the linear map `skew(v)`,
+                    // and so the Jacobian matrices are `-skew(other)` and `skew(self)`.
+                    let skew = |x: Number, y: Number, z: Number| {
+                        [
+                            [Number::ZERO, -z, y],
+                            [z, Number::ZERO, -x],
+                            [-y, x, Number::ZERO],
+                        ]
+                    };
+                    Measure3d::<DestUnit, Number> {
+                        x: y1 * z2 - z1 * y2,
+                        y: z1 * x2 - x1 * z2,
+                        z: x1 * y2 - y1 * x2,
+                        covariance: rs_measures::uncertainty::summed_covariances(
+                            &rs_measures::uncertainty::transformed_covariance(&skew(x2, y2, z2), &self.covariance),
+                            &rs_measures::uncertainty::transformed_covariance(&skew(x1, y1, z1), &other.covariance),
+                        ),
+                        phantom: PhantomData,
+                    }
+                }
+
+                // Measure3d<U1>.squared_into() -> Measure<U3>
+                fn squared_into<DestUnit: MeasurementUnit>(self) -> Measure<DestUnit, Number> {
+                    let v = [self.x, self.y, self.z];
+                    let norm_uncertainty = rs_measures::uncertainty::propagated_variance(&self.covariance, v).sqrt();
+                    Measure::<DestUnit, Number>::with_uncertainty(
+                        v[0] * v[0] + v[1] * v[1] + v[2] * v[2],
+                        norm_uncertainty + norm_uncertainty,
+                    )
+                }
+
+                // Measure3d<U2>.scaled_into(Measure<U1>) -> Measure3d<U3>
+                fn scaled_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    factor: Measure<OtherUnit, Number>,
+                ) -> Measure3d<DestUnit, Number> {
+                    let v = [self.x, self.y, self.z];
+                    Measure3d::<DestUnit, Number> {
+                        x: v[0] * factor.value,
+                        y: v[1] * factor.value,
+                        z: v[2] * factor.value,
+                        covariance: rs_measures::uncertainty::summed_covariances(
+                            &rs_measures::uncertainty::scaled_covariance(&self.covariance, factor.value),
+                            &rs_measures::uncertainty::collinear_covariance(v, factor.uncertainty),
+                        ),
+                        phantom: PhantomData,
+                    }
+                }
+
+                // Measure3d<U3>.divided_into(Measure<U1>) -> Measure3d<U2>
+                fn divided_into<OtherUnit: MeasurementUnit, DestUnit: MeasurementUnit>(
+                    self,
+                    divisor: Measure<OtherUnit, Number>,
+                ) -> Measure3d<DestUnit, Number> {
+                    let inverse = Number::ONE / divisor.value;
+                    let quotient = [self.x * inverse, self.y * inverse, self.z * inverse];
+                    Measure3d::<DestUnit, Number> {
+                        x: quotient[0],
+                        y: quotient[1],
+                        z: quotient[2],
+                        covariance: rs_measures::uncertainty::summed_covariances(
+                            &rs_measures::uncertainty::scaled_covariance(&self.covariance, inverse),
+                            &rs_measures::uncertainty::collinear_covariance(quotient, divisor.uncertainty * inverse),
+                        ),
+                        phantom: PhantomData,
+                    }
+                }
+            }
         }
 
         impl<Unit, Number> Default for Measure3d<Unit, Number>
@@ -97,144 +662,453 @@ macro_rules! inner_define_measure_3d {
             }
         }
 
-        // -Measure3d -> Measure3d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Neg for Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Self;
-            fn neg(self) -> Self::Output {
-                Self::new(-self.x, -self.y, -self.z)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // -Measure3d -> Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Neg for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self::new(-self.x, -self.y, -self.z)
+                }
             }
         }
 
-        // Measure3d + Measure3d -> Measure3d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure3d<Unit, Number>>
-            for Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Self;
-            fn add(self, other: Measure3d<Unit, Number>) -> Self::Output {
-                Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // -Measure3d -> Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Neg for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self::with_covariance(-self.x, -self.y, -self.z, self.covariance)
+                }
             }
         }
 
-        // Measure3d += Measure3d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> AddAssign<Measure3d<Unit, Number>>
-            for Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn add_assign(&mut self, other: Measure3d<Unit, Number>) {
-                self.x += other.x;
-                self.y += other.y;
-                self.z += other.z;
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // -Measure3d -> Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Neg for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn neg(self) -> Self::Output {
+                    Self::with_uncertainty(-self.x, -self.y, -self.z, self.upper_uncertainty, self.lower_uncertainty)
+                }
             }
         }
 
-        // Measure3d - Measure3d -> Measure3d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure3d<Unit, Number>>
-            for Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Self;
-            fn sub(self, other: Measure3d<Unit, Number>) -> Self::Output {
-                Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure3d + Measure3d -> Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn add(self, other: Measure3d<Unit, Number>) -> Self::Output {
+                    Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+                }
             }
         }
 
-        // Measure3d -= Measure3d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> SubAssign<Measure3d<Unit, Number>>
-            for Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn sub_assign(&mut self, other: Measure3d<Unit, Number>) {
-                self.x -= other.x;
-                self.y -= other.y;
-                self.z -= other.z;
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure3d + Measure3d -> Measure3d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn add(self, other: Measure3d<Unit, Number>) -> Self::Output {
+                    Self::with_covariance(
+                        self.x + other.x, self.y + other.y, self.z + other.z,
+                        rs_measures::uncertainty::summed_covariances(&self.covariance, &other.covariance),
+                    )
+                }
             }
         }
 
-        // Measure3d * Number -> Measure3d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Mul<Number> for Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Self;
-            fn mul(self, n: Number) -> Self::Output {
-                Self::new(self.x * n, self.y * n, self.z * n)
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure3d + Measure3d -> Measure3d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn add(self, other: Measure3d<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.x + other.x, self.y + other.y, self.z + other.z,
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.lower_uncertainty,
+                            other.lower_uncertainty,
+                        ),
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.upper_uncertainty,
+                            other.upper_uncertainty,
+                        ),
+                    )
+                }
             }
         }
 
-        // Measure3d *= Number
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> MulAssign<Number> for Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn mul_assign(&mut self, n: Number) {
-                self.x *= n;
-                self.y *= n;
-                self.z *= n;
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure3d += Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> AddAssign<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn add_assign(&mut self, other: Measure3d<Unit, Number>) {
+                    self.x += other.x;
+                    self.y += other.y;
+                    self.z += other.z;
+                }
             }
         }
 
-        // f64 * Measure3d -> Measure3d
-        impl<Unit: MeasurementUnit> Mul<Measure3d<Unit, f64>> for f64
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Measure3d<Unit, f64>;
-            fn mul(self, other: Measure3d<Unit, f64>) -> Self::Output {
-                Self::Output::new(self * other.x, self * other.y, self * other.z)
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure3d += Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> AddAssign<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn add_assign(&mut self, other: Measure3d<Unit, Number>) {
+                    *self = *self + other;
+                }
             }
         }
 
-        // f32 * Measure3d -> Measure3d
-        impl<Unit: MeasurementUnit> Mul<Measure3d<Unit, f32>> for f32
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Measure3d<Unit, f32>;
-            fn mul(self, other: Measure3d<Unit, f32>) -> Self::Output {
-                Self::Output::new(self * other.x, self * other.y, self * other.z)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure3d - Measure3d -> Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn sub(self, other: Measure3d<Unit, Number>) -> Self::Output {
+                    Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+                }
             }
         }
 
-        // Measure3d / Number -> Measure3d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Self;
-            fn div(self, n: Number) -> Self::Output {
-                let factor = Number::ONE / n;
-                Self::new(self.x * factor, self.y * factor, self.z * factor)
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure3d - Measure3d -> Measure3d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn sub(self, other: Measure3d<Unit, Number>) -> Self::Output {
+                    Self::with_covariance(
+                        self.x - other.x, self.y - other.y, self.z - other.z,
+                        rs_measures::uncertainty::summed_covariances(&self.covariance, &other.covariance),
+                    )
+                }
             }
         }
 
-        // Measure3d /= Number
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> DivAssign<Number> for Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn div_assign(&mut self, n: Number) {
-                self.x /= n;
-                self.y /= n;
-                self.z /= n;
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure3d - Measure3d -> Measure3d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn sub(self, other: Measure3d<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.x - other.x, self.y - other.y, self.z - other.z,
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.lower_uncertainty,
+                            other.upper_uncertainty,
+                        ),
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.upper_uncertainty,
+                            other.lower_uncertainty,
+                        ),
+                    )
+                }
             }
         }
 
-        // Measure3d == Measure3d -> bool
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<Measure3d<Unit, Number>>
-            for Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn eq(&self, other: &Measure3d<Unit, Number>) -> bool {
-                self.x == other.x && self.y == other.y && self.z == other.z
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure3d -= Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> SubAssign<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn sub_assign(&mut self, other: Measure3d<Unit, Number>) {
+                    self.x -= other.x;
+                    self.y -= other.y;
+                    self.z -= other.z;
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure3d -= Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> SubAssign<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn sub_assign(&mut self, other: Measure3d<Unit, Number>) {
+                    *self = *self - other;
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure3d * Number -> Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Mul<Number> for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn mul(self, n: Number) -> Self::Output {
+                    Self::new(self.x * n, self.y * n, self.z * n)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure3d * Number -> Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Mul<Number> for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn mul(self, n: Number) -> Self::Output {
+                    Self::with_covariance(
+                        self.x * n, self.y * n, self.z * n,
+                        rs_measures::uncertainty::scaled_covariance(&self.covariance, n),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure3d * Number -> Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Mul<Number> for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn mul(self, n: Number) -> Self::Output {
+                    let (lower, upper) = rs_measures::uncertainty::scaled_two_sided_array(
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                        n,
+                    );
+                    Self::with_uncertainty(self.x * n, self.y * n, self.z * n, lower, upper)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure3d *= Number
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> MulAssign<Number> for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn mul_assign(&mut self, n: Number) {
+                    self.x *= n;
+                    self.y *= n;
+                    self.z *= n;
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure3d *= Number
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> MulAssign<Number> for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn mul_assign(&mut self, n: Number) {
+                    *self = *self * n;
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // f64 * Measure3d -> Measure3d
+            impl<Unit: MeasurementUnit> Mul<Measure3d<Unit, f64>> for f64
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Measure3d<Unit, f64>;
+                fn mul(self, other: Measure3d<Unit, f64>) -> Self::Output {
+                    Self::Output::new(self * other.x, self * other.y, self * other.z)
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // f64 * Measure3d -> Measure3d
+            impl<Unit: MeasurementUnit> Mul<Measure3d<Unit, f64>> for f64
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Measure3d<Unit, f64>;
+                fn mul(self, other: Measure3d<Unit, f64>) -> Self::Output {
+                    other * self
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // f32 * Measure3d -> Measure3d
+            impl<Unit: MeasurementUnit> Mul<Measure3d<Unit, f32>> for f32
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Measure3d<Unit, f32>;
+                fn mul(self, other: Measure3d<Unit, f32>) -> Self::Output {
+                    Self::Output::new(self * other.x, self * other.y, self * other.z)
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // f32 * Measure3d -> Measure3d
+            impl<Unit: MeasurementUnit> Mul<Measure3d<Unit, f32>> for f32
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Measure3d<Unit, f32>;
+                fn mul(self, other: Measure3d<Unit, f32>) -> Self::Output {
+                    other * self
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure3d / Number -> Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn div(self, n: Number) -> Self::Output {
+                    let factor = Number::ONE / n;
+                    Self::new(self.x * factor, self.y * factor, self.z * factor)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure3d / Number -> Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn div(self, n: Number) -> Self::Output {
+                    Self::with_covariance(
+                        self.x / n, self.y / n, self.z / n,
+                        rs_measures::uncertainty::scaled_covariance(&self.covariance, Number::ONE / n),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure3d / Number -> Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn div(self, n: Number) -> Self::Output {
+                    let (lower, upper) = rs_measures::uncertainty::scaled_two_sided_array(
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                        Number::ONE / n,
+                    );
+                    Self::with_uncertainty(self.x / n, self.y / n, self.z / n, lower, upper)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure3d /= Number
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> DivAssign<Number> for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn div_assign(&mut self, n: Number) {
+                    self.x /= n;
+                    self.y /= n;
+                    self.z /= n;
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Measure3d /= Number
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> DivAssign<Number> for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn div_assign(&mut self, n: Number) {
+                    *self = *self / n;
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // Measure3d == Measure3d -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn eq(&self, other: &Measure3d<Unit, Number>) -> bool {
+                    self.x == other.x && self.y == other.y && self.z == other.z
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // Measure3d == Measure3d -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn eq(&self, other: &Measure3d<Unit, Number>) -> bool {
+                    self.x == other.x && self.y == other.y && self.z == other.z
+                        && self.covariance == other.covariance
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // Measure3d == Measure3d -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<Measure3d<Unit, Number>>
+                for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn eq(&self, other: &Measure3d<Unit, Number>) -> bool {
+                    self.x == other.x && self.y == other.y && self.z == other.z
+                        && self.lower_uncertainty == other.lower_uncertainty
+                        && self.upper_uncertainty == other.upper_uncertainty
+                }
             }
         }
 
@@ -254,37 +1128,348 @@ macro_rules! inner_define_measure_3d {
         {
         }
 
-        // format!("{}", Measure3d)
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("(")?;
-                fmt::Display::fmt(&self.x, formatter)?;
-                formatter.write_str(", ")?;
-                fmt::Display::fmt(&self.y, formatter)?;
-                formatter.write_str(", ")?;
-                fmt::Display::fmt(&self.z, formatter)?;
-                formatter.write_str(")")?;
-                formatter.write_str(Unit::SUFFIX)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // format!("{}", Measure3d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("(")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.z, formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
             }
         }
 
-        // format!("{:?}", Measure3d)
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Measure3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("(")?;
-                fmt::Display::fmt(&self.x, formatter)?;
-                formatter.write_str(", ")?;
-                fmt::Display::fmt(&self.y, formatter)?;
-                formatter.write_str(", ")?;
-                fmt::Display::fmt(&self.z, formatter)?;
-                formatter.write_str(")")?;
-                formatter.write_str(Unit::SUFFIX)
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // format!("{}", Measure3d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("(")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[0][0].sqrt(), formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[1][1].sqrt(), formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.z, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[2][2].sqrt(), formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // format!("{}", Measure3d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("(")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[0], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[0], formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[1], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[1], formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.z, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[2], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[2], formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // format!("{:?}", Measure3d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("(")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.z, formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // format!("{:?}", Measure3d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("(")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[0][0].sqrt(), formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[1][1].sqrt(), formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.z, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[2][2].sqrt(), formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // format!("{:?}", Measure3d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("(")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[0], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[0], formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[1], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[1], formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.z, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[2], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[2], formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // "...".parse::<Measure3d>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    parser.text("(")?;
+                    let x = parser.number()?;
+                    parser.text(",")?;
+                    let y = parser.number()?;
+                    parser.text(",")?;
+                    let z = parser.number()?;
+                    parser.text(")")?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::new(x, y, z))
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // "...".parse::<Measure3d>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    parser.text("(")?;
+                    let (x, ux) = parser.number_with_uncertainty()?;
+                    parser.text(",")?;
+                    let (y, uy) = parser.number_with_uncertainty()?;
+                    parser.text(",")?;
+                    let (z, uz) = parser.number_with_uncertainty()?;
+                    parser.text(")")?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::with_uncertainty(x, y, z, [ux, uy, uz]))
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // "...".parse::<Measure3d>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    parser.text("(")?;
+                    let (x, lower_x, upper_x) = parser.number_with_two_sided_uncertainty()?;
+                    parser.text(",")?;
+                    let (y, lower_y, upper_y) = parser.number_with_two_sided_uncertainty()?;
+                    parser.text(",")?;
+                    let (z, lower_z, upper_z) = parser.number_with_two_sided_uncertainty()?;
+                    parser.text(")")?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::with_uncertainty(x, y, z, [lower_x, lower_y, lower_z], [upper_x, upper_y, upper_z]))
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // serde::Serialize for Measure3d
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for Measure3d<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::Vector3dRepr {
+                                x: self.x,
+                                y: self.y,
+                                z: self.z,
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for Measure3d
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for Measure3d<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::Vector3dRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            x: repr.x,
+                            y: repr.y,
+                            z: repr.z,
+                            phantom: PhantomData,
+                        })
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // serde::Serialize for Measure3d
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for Measure3d<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::SymmetricVector3dRepr {
+                                x: self.x,
+                                y: self.y,
+                                z: self.z,
+                                covariance: Some(self.covariance),
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for Measure3d
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for Measure3d<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::SymmetricVector3dRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            x: repr.x,
+                            y: repr.y,
+                            z: repr.z,
+                            covariance: repr.covariance.unwrap_or([[Number::ZERO; 3]; 3]),
+                            phantom: PhantomData,
+                        })
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // serde::Serialize for Measure3d
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for Measure3d<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::TwoSidedVector3dRepr {
+                                x: self.x,
+                                y: self.y,
+                                z: self.z,
+                                lower_uncertainty: Some(self.lower_uncertainty),
+                                upper_uncertainty: Some(self.upper_uncertainty),
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for Measure3d
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for Measure3d<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::TwoSidedVector3dRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            x: repr.x,
+                            y: repr.y,
+                            z: repr.z,
+                            lower_uncertainty: repr.lower_uncertainty.unwrap_or([Number::ZERO; 3]),
+                            upper_uncertainty: repr.upper_uncertainty.unwrap_or([Number::ZERO; 3]),
+                            phantom: PhantomData,
+                        })
+                    }
+                }
             }
         }
     };
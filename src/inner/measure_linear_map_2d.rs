@@ -0,0 +1,154 @@
+#[macro_export]
+macro_rules! inner_define_measure_linear_map_2d {
+    {} => {
+        // A linear transformation from the 2D measures in `InUnit`
+        // to the 2D measures in `OutUnit`, like a conductivity tensor of a plate,
+        // which maps electric fields to current densities.
+        // Every coefficient is the ratio between a value in `OutUnit` and a value in `InUnit`.
+        pub struct MeasureLinearMap2d<InUnit, OutUnit, Number: ArithmeticOps = f64> {
+            c: [[Number; 2]; 2],
+            phantom: PhantomData<(InUnit, OutUnit)>,
+        }
+
+        impl<InUnit: MeasurementUnit, OutUnit: MeasurementUnit, Number: ArithmeticOps>
+            MeasureLinearMap2d<InUnit, OutUnit, Number>
+        {
+            pub const fn new(coefficients: [[Number; 2]; 2]) -> Self {
+                Self {
+                    c: coefficients,
+                    phantom: PhantomData,
+                }
+            }
+
+            pub fn coefficients(&self) -> [[Number; 2]; 2] {
+                self.c
+            }
+
+            // The same transformation, applied to measures in other units of the same properties.
+            pub fn convert<
+                DestInUnit: MeasurementUnit<Property = InUnit::Property>,
+                DestOutUnit: MeasurementUnit<Property = OutUnit::Property>,
+            >(
+                &self,
+            ) -> MeasureLinearMap2d<DestInUnit, DestOutUnit, Number> {
+                let factor = Number::from_f64(
+                    DestInUnit::RATIO / InUnit::RATIO * OutUnit::RATIO / DestOutUnit::RATIO,
+                );
+                MeasureLinearMap2d::<DestInUnit, DestOutUnit, Number>::new(
+                    self.c.map(|row| row.map(|c| c * factor)),
+                )
+            }
+
+            // Inversion, mapping the measures in `OutUnit` back to the measures in `InUnit`.
+            pub fn inverted(&self) -> MeasureLinearMap2d<OutUnit, InUnit, Number> {
+                MeasureLinearMap2d::<OutUnit, InUnit, Number>::new(
+                    rs_measures::matrix_utils::inverse_2x2(&self.c),
+                )
+            }
+
+            // Composition of linear transformations between units.
+            // Applying the resulting transformation is equivalent to apply first
+            // `other` and then `self`.
+            pub fn combined_with<OtherInUnit: MeasurementUnit>(
+                &self,
+                other: &MeasureLinearMap2d<OtherInUnit, InUnit, Number>,
+            ) -> MeasureLinearMap2d<OtherInUnit, OutUnit, Number> {
+                MeasureLinearMap2d::<OtherInUnit, OutUnit, Number>::new(
+                    rs_measures::matrix_utils::matrix_product(&self.c, &other.c),
+                )
+            }
+
+            pub fn apply_to(&self, m: Measure2d<InUnit, Number>) -> Measure2d<OutUnit, Number> {
+                m.linearly_transformed(&self.c)
+            }
+        }
+
+        // MeasureLinearMap2d<U1, U2> * Measure2d<U1> -> Measure2d<U2>
+        impl<InUnit: MeasurementUnit, OutUnit: MeasurementUnit, Number: ArithmeticOps>
+            Mul<Measure2d<InUnit, Number>> for MeasureLinearMap2d<InUnit, OutUnit, Number>
+        {
+            type Output = Measure2d<OutUnit, Number>;
+            fn mul(self, other: Measure2d<InUnit, Number>) -> Self::Output {
+                self.apply_to(other)
+            }
+        }
+
+        impl<InUnit, OutUnit, Number: ArithmeticOps> Clone
+            for MeasureLinearMap2d<InUnit, OutUnit, Number>
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<InUnit, OutUnit, Number: ArithmeticOps> Copy
+            for MeasureLinearMap2d<InUnit, OutUnit, Number>
+        {
+        }
+
+        impl<InUnit, OutUnit, Number: ArithmeticOps> PartialEq
+            for MeasureLinearMap2d<InUnit, OutUnit, Number>
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.c == other.c
+            }
+        }
+
+        // format!("{}", MeasureLinearMap2d)
+        impl<InUnit: MeasurementUnit, OutUnit: MeasurementUnit, Number: ArithmeticOps> fmt::Display
+            for MeasureLinearMap2d<InUnit, OutUnit, Number>
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "{}",
+                    rs_measures::matrix_utils::format_matrix::<2, 2, Number>(
+                        &self.c,
+                        &format!("{}/{}", OutUnit::SUFFIX, InUnit::SUFFIX.trim()),
+                    )
+                )
+            }
+        }
+
+        // format!("{:?}", MeasureLinearMap2d)
+        impl<InUnit: MeasurementUnit, OutUnit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug
+            for MeasureLinearMap2d<InUnit, OutUnit, Number>
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for MeasureLinearMap2d
+            impl<InUnit: MeasurementUnit, OutUnit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                for MeasureLinearMap2d<InUnit, OutUnit, Number>
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::MeasureMapRepr {
+                            coefficients: self.c,
+                            in_unit: rs_measures::serde_support::unit_name(InUnit::SUFFIX),
+                            out_unit: rs_measures::serde_support::unit_name(OutUnit::SUFFIX),
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for MeasureLinearMap2d
+            impl<'de, InUnit: MeasurementUnit, OutUnit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                for MeasureLinearMap2d<InUnit, OutUnit, Number>
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::MeasureMapRepr<[[Number; 2]; 2]> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    rs_measures::serde_support::check_unit::<D::Error>(&repr.in_unit, InUnit::SUFFIX)?;
+                    rs_measures::serde_support::check_unit::<D::Error>(&repr.out_unit, OutUnit::SUFFIX)?;
+                    Ok(Self::new(repr.coefficients))
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,154 @@
+#[macro_export]
+macro_rules! inner_define_measure_linear_map_3d {
+    {} => {
+        // A linear transformation from the 3D measures in `InUnit`
+        // to the 3D measures in `OutUnit`, like an inertia tensor,
+        // which maps angular velocities to angular momenta.
+        // Every coefficient is the ratio between a value in `OutUnit` and a value in `InUnit`.
+        pub struct MeasureLinearMap3d<InUnit, OutUnit, Number: ArithmeticOps = f64> {
+            c: [[Number; 3]; 3],
+            phantom: PhantomData<(InUnit, OutUnit)>,
+        }
+
+        impl<InUnit: MeasurementUnit, OutUnit: MeasurementUnit, Number: ArithmeticOps>
+            MeasureLinearMap3d<InUnit, OutUnit, Number>
+        {
+            pub const fn new(coefficients: [[Number; 3]; 3]) -> Self {
+                Self {
+                    c: coefficients,
+                    phantom: PhantomData,
+                }
+            }
+
+            pub fn coefficients(&self) -> [[Number; 3]; 3] {
+                self.c
+            }
+
+            // The same transformation, applied to measures in other units of the same properties.
+            pub fn convert<
+                DestInUnit: MeasurementUnit<Property = InUnit::Property>,
+                DestOutUnit: MeasurementUnit<Property = OutUnit::Property>,
+            >(
+                &self,
+            ) -> MeasureLinearMap3d<DestInUnit, DestOutUnit, Number> {
+                let factor = Number::from_f64(
+                    DestInUnit::RATIO / InUnit::RATIO * OutUnit::RATIO / DestOutUnit::RATIO,
+                );
+                MeasureLinearMap3d::<DestInUnit, DestOutUnit, Number>::new(
+                    self.c.map(|row| row.map(|c| c * factor)),
+                )
+            }
+
+            // Inversion, mapping the measures in `OutUnit` back to the measures in `InUnit`.
+            pub fn inverted(&self) -> MeasureLinearMap3d<OutUnit, InUnit, Number> {
+                MeasureLinearMap3d::<OutUnit, InUnit, Number>::new(
+                    rs_measures::matrix_utils::inverse_3x3(&self.c),
+                )
+            }
+
+            // Composition of linear transformations between units.
+            // Applying the resulting transformation is equivalent to apply first
+            // `other` and then `self`.
+            pub fn combined_with<OtherInUnit: MeasurementUnit>(
+                &self,
+                other: &MeasureLinearMap3d<OtherInUnit, InUnit, Number>,
+            ) -> MeasureLinearMap3d<OtherInUnit, OutUnit, Number> {
+                MeasureLinearMap3d::<OtherInUnit, OutUnit, Number>::new(
+                    rs_measures::matrix_utils::matrix_product(&self.c, &other.c),
+                )
+            }
+
+            pub fn apply_to(&self, m: Measure3d<InUnit, Number>) -> Measure3d<OutUnit, Number> {
+                m.linearly_transformed(&self.c)
+            }
+        }
+
+        // MeasureLinearMap3d<U1, U2> * Measure3d<U1> -> Measure3d<U2>
+        impl<InUnit: MeasurementUnit, OutUnit: MeasurementUnit, Number: ArithmeticOps>
+            Mul<Measure3d<InUnit, Number>> for MeasureLinearMap3d<InUnit, OutUnit, Number>
+        {
+            type Output = Measure3d<OutUnit, Number>;
+            fn mul(self, other: Measure3d<InUnit, Number>) -> Self::Output {
+                self.apply_to(other)
+            }
+        }
+
+        impl<InUnit, OutUnit, Number: ArithmeticOps> Clone
+            for MeasureLinearMap3d<InUnit, OutUnit, Number>
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<InUnit, OutUnit, Number: ArithmeticOps> Copy
+            for MeasureLinearMap3d<InUnit, OutUnit, Number>
+        {
+        }
+
+        impl<InUnit, OutUnit, Number: ArithmeticOps> PartialEq
+            for MeasureLinearMap3d<InUnit, OutUnit, Number>
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.c == other.c
+            }
+        }
+
+        // format!("{}", MeasureLinearMap3d)
+        impl<InUnit: MeasurementUnit, OutUnit: MeasurementUnit, Number: ArithmeticOps> fmt::Display
+            for MeasureLinearMap3d<InUnit, OutUnit, Number>
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "{}",
+                    rs_measures::matrix_utils::format_matrix::<3, 3, Number>(
+                        &self.c,
+                        &format!("{}/{}", OutUnit::SUFFIX, InUnit::SUFFIX.trim()),
+                    )
+                )
+            }
+        }
+
+        // format!("{:?}", MeasureLinearMap3d)
+        impl<InUnit: MeasurementUnit, OutUnit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug
+            for MeasureLinearMap3d<InUnit, OutUnit, Number>
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for MeasureLinearMap3d
+            impl<InUnit: MeasurementUnit, OutUnit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                for MeasureLinearMap3d<InUnit, OutUnit, Number>
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::MeasureMapRepr {
+                            coefficients: self.c,
+                            in_unit: rs_measures::serde_support::unit_name(InUnit::SUFFIX),
+                            out_unit: rs_measures::serde_support::unit_name(OutUnit::SUFFIX),
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for MeasureLinearMap3d
+            impl<'de, InUnit: MeasurementUnit, OutUnit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                for MeasureLinearMap3d<InUnit, OutUnit, Number>
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::MeasureMapRepr<[[Number; 3]; 3]> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    rs_measures::serde_support::check_unit::<D::Error>(&repr.in_unit, InUnit::SUFFIX)?;
+                    rs_measures::serde_support::check_unit::<D::Error>(&repr.out_unit, OutUnit::SUFFIX)?;
+                    Ok(Self::new(repr.coefficients))
+                }
+            }
+        }
+    };
+}
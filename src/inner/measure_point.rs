@@ -1,48 +1,205 @@
 #[macro_export]
 macro_rules! inner_define_measure_point {
-    {} => {
-        pub struct MeasurePoint<Unit, Number = f64> {
-            pub value: Number,
-            phantom: PhantomData<Unit>,
+    { $with_uncertainty:tt } => {
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            pub struct MeasurePoint<Unit, Number = f64> {
+                pub value: Number,
+                phantom: PhantomData<Unit>,
+            }
         }
 
-        impl<Unit, Number> MeasurePoint<Unit, Number>
-        where
-            Unit: MeasurementUnit,
-            Number: ArithmeticOps,
-        {
-            pub const fn new(value: Number) -> Self {
-                Self {
-                    value,
-                    phantom: PhantomData,
-                }
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            pub struct MeasurePoint<Unit, Number = f64> {
+                pub value: Number,
+                // The standard uncertainty of `value`. It is never negative.
+                pub uncertainty: Number,
+                phantom: PhantomData<Unit>,
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            pub struct MeasurePoint<Unit, Number = f64> {
+                pub value: Number,
+                // The uncertainty below `value`. It is never negative.
+                pub lower_uncertainty: Number,
+                // The uncertainty above `value`. It is never negative.
+                pub upper_uncertainty: Number,
+                phantom: PhantomData<Unit>,
             }
-            pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
-                &self,
-            ) -> MeasurePoint<DestUnit, Number> {
-                MeasurePoint::<DestUnit, Number> {
-                    value: self.value * Number::from_f64(Unit::RATIO / DestUnit::RATIO)
-                        + Number::from_f64((Unit::OFFSET - DestUnit::OFFSET) / DestUnit::RATIO),
-                    phantom: PhantomData,
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            impl<Unit, Number> MeasurePoint<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                pub const fn new(value: Number) -> Self {
+                    Self {
+                        value,
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> MeasurePoint<DestUnit, Number> {
+                    MeasurePoint::<DestUnit, Number> {
+                        value: self.value * Number::from_f64(Unit::RATIO / DestUnit::RATIO)
+                            + Number::from_f64((Unit::OFFSET - DestUnit::OFFSET) / DestUnit::RATIO),
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> MeasurePoint<Unit, DestNumber> {
+                    MeasurePoint::<Unit, DestNumber> {
+                        value: DestNumber::from(self.value),
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> MeasurePoint<Unit, DestNumber> {
+                    MeasurePoint::<Unit, DestNumber> {
+                        value: DestNumber::lossy_from(self.value),
+                        phantom: PhantomData,
+                    }
                 }
             }
-            pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
-                &self,
-            ) -> MeasurePoint<Unit, DestNumber> {
-                MeasurePoint::<Unit, DestNumber> {
-                    value: DestNumber::from(self.value),
-                    phantom: PhantomData,
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            impl<Unit, Number> MeasurePoint<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                pub const fn new(value: Number) -> Self {
+                    Self {
+                        value,
+                        uncertainty: Number::ZERO,
+                        phantom: PhantomData,
+                    }
+                }
+                // Precondition: uncertainty >= 0
+                pub const fn with_uncertainty(value: Number, uncertainty: Number) -> Self {
+                    Self {
+                        value,
+                        uncertainty,
+                        phantom: PhantomData,
+                    }
+                }
+                // It returns a point having no uncertainty, drawn from a normal distribution.
+                pub fn sample(self, sampler: &mut rs_measures::monte_carlo::Sampler) -> Self {
+                    Self::new(sampler.normal(self.value, self.uncertainty))
+                }
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> MeasurePoint<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    MeasurePoint::<DestUnit, Number> {
+                        value: self.value * factor
+                            + Number::from_f64((Unit::OFFSET - DestUnit::OFFSET) / DestUnit::RATIO),
+                        uncertainty: self.uncertainty * factor.abs(),
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> MeasurePoint<Unit, DestNumber> {
+                    MeasurePoint::<Unit, DestNumber> {
+                        value: DestNumber::from(self.value),
+                        uncertainty: DestNumber::from(self.uncertainty),
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> MeasurePoint<Unit, DestNumber> {
+                    MeasurePoint::<Unit, DestNumber> {
+                        value: DestNumber::lossy_from(self.value),
+                        uncertainty: DestNumber::lossy_from(self.uncertainty),
+                        phantom: PhantomData,
+                    }
                 }
             }
-            pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
-                &self,
-            ) -> MeasurePoint<Unit, DestNumber> {
-                MeasurePoint::<Unit, DestNumber> {
-                    value: DestNumber::lossy_from(self.value),
-                    phantom: PhantomData,
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            impl<Unit, Number> MeasurePoint<Unit, Number>
+            where
+                Unit: MeasurementUnit,
+                Number: ArithmeticOps,
+            {
+                pub const fn new(value: Number) -> Self {
+                    Self {
+                        value,
+                        lower_uncertainty: Number::ZERO,
+                        upper_uncertainty: Number::ZERO,
+                        phantom: PhantomData,
+                    }
+                }
+                // Precondition: lower_uncertainty >= 0 && upper_uncertainty >= 0
+                pub const fn with_uncertainty(
+                    value: Number,
+                    lower_uncertainty: Number,
+                    upper_uncertainty: Number,
+                ) -> Self {
+                    Self {
+                        value,
+                        lower_uncertainty,
+                        upper_uncertainty,
+                        phantom: PhantomData,
+                    }
+                }
+                // It returns a point having no uncertainty, drawn from a split normal distribution.
+                pub fn sample(self, sampler: &mut rs_measures::monte_carlo::Sampler) -> Self {
+                    Self::new(sampler.split_normal(
+                        self.value,
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                    ))
+                }
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> MeasurePoint<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    MeasurePoint::<DestUnit, Number> {
+                        value: self.value * factor
+                            + Number::from_f64((Unit::OFFSET - DestUnit::OFFSET) / DestUnit::RATIO),
+                        lower_uncertainty: self.lower_uncertainty * factor,
+                        upper_uncertainty: self.upper_uncertainty * factor,
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> MeasurePoint<Unit, DestNumber> {
+                    MeasurePoint::<Unit, DestNumber> {
+                        value: DestNumber::from(self.value),
+                        lower_uncertainty: DestNumber::from(self.lower_uncertainty),
+                        upper_uncertainty: DestNumber::from(self.upper_uncertainty),
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> MeasurePoint<Unit, DestNumber> {
+                    MeasurePoint::<Unit, DestNumber> {
+                        value: DestNumber::lossy_from(self.value),
+                        lower_uncertainty: DestNumber::lossy_from(self.lower_uncertainty),
+                        upper_uncertainty: DestNumber::lossy_from(self.upper_uncertainty),
+                        phantom: PhantomData,
+                    }
                 }
             }
+        }
 
+        impl<Unit, Number> MeasurePoint<Unit, Number>
+        where
+            Unit: MeasurementUnit,
+            Number: ArithmeticOps,
+        {
             pub fn min(self, other: Self) -> Self {
                 if self <= other {
                     self
@@ -75,92 +232,354 @@ macro_rules! inner_define_measure_point {
             }
         }
 
-        // MeasurePoint + Measure -> MeasurePoint
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure<Unit, Number>>
-            for MeasurePoint<Unit, Number>
-        {
-            type Output = Self;
-            fn add(self, other: Measure<Unit, Number>) -> Self::Output {
-                Self::new(self.value + other.value)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // MeasurePoint + Measure -> MeasurePoint
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                type Output = Self;
+                fn add(self, other: Measure<Unit, Number>) -> Self::Output {
+                    Self::new(self.value + other.value)
+                }
             }
         }
 
-        // MeasurePoint += Measure
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> AddAssign<Measure<Unit, Number>>
-            for MeasurePoint<Unit, Number>
-        {
-            fn add_assign(&mut self, other: Measure<Unit, Number>) {
-                self.value += other.value;
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // MeasurePoint + Measure -> MeasurePoint
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                type Output = Self;
+                fn add(self, other: Measure<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.value + other.value,
+                        rs_measures::uncertainty::in_quadrature(self.uncertainty, other.uncertainty),
+                    )
+                }
             }
         }
 
-        // MeasurePoint - Measure -> MeasurePoint
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure<Unit, Number>>
-            for MeasurePoint<Unit, Number>
-        {
-            type Output = Self;
-            fn sub(self, other: Measure<Unit, Number>) -> Self::Output {
-                Self::new(self.value - other.value)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // MeasurePoint += Measure
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> AddAssign<Measure<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                fn add_assign(&mut self, other: Measure<Unit, Number>) {
+                    self.value += other.value;
+                }
             }
         }
 
-        // MeasurePoint -= Measure
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> SubAssign<Measure<Unit, Number>>
-            for MeasurePoint<Unit, Number>
-        {
-            fn sub_assign(&mut self, other: Measure<Unit, Number>) {
-                self.value -= other.value;
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // MeasurePoint - Measure -> MeasurePoint
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                type Output = Self;
+                fn sub(self, other: Measure<Unit, Number>) -> Self::Output {
+                    Self::new(self.value - other.value)
+                }
             }
         }
 
-        // MeasurePoint - MeasurePoint -> Measure
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<MeasurePoint<Unit, Number>>
-            for MeasurePoint<Unit, Number>
-        {
-            type Output = Measure<Unit, Number>;
-            fn sub(self, other: MeasurePoint<Unit, Number>) -> Self::Output {
-                Self::Output::new(self.value - other.value)
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // MeasurePoint - Measure -> MeasurePoint
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                type Output = Self;
+                fn sub(self, other: Measure<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.value - other.value,
+                        rs_measures::uncertainty::in_quadrature(self.uncertainty, other.uncertainty),
+                    )
+                }
             }
         }
 
-        // weighted_midpoint(MeasurePoint, MeasurePoint, weight) -> MeasurePoint
-        pub fn weighted_midpoint<Unit: MeasurementUnit, Number: ArithmeticOps>(
-            p1: MeasurePoint<Unit, Number>,
-            p2: MeasurePoint<Unit, Number>,
-            weight1: Number,
-        ) -> MeasurePoint<Unit, Number> {
-            MeasurePoint::<Unit, Number>::new(p1.value * weight1 + p2.value * (Number::ONE - weight1))
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // MeasurePoint -= Measure
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> SubAssign<Measure<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                fn sub_assign(&mut self, other: Measure<Unit, Number>) {
+                    self.value -= other.value;
+                }
+            }
         }
 
-        // midpoint(MeasurePoint, MeasurePoint) -> MeasurePoint
-        pub fn midpoint<Unit: MeasurementUnit, Number: ArithmeticOps>(
-            p1: MeasurePoint<Unit, Number>,
-            p2: MeasurePoint<Unit, Number>,
-        ) -> MeasurePoint<Unit, Number> {
-            MeasurePoint::<Unit, Number>::new((p1.value + p2.value) * Number::HALF)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // MeasurePoint - MeasurePoint -> Measure
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<MeasurePoint<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                type Output = Measure<Unit, Number>;
+                fn sub(self, other: MeasurePoint<Unit, Number>) -> Self::Output {
+                    Self::Output::new(self.value - other.value)
+                }
+            }
         }
 
-        // barycentric_combination([MeasurePoint], [Number]) -> MeasurePoint
-        pub fn barycentric_combination<Unit: MeasurementUnit, Number: ArithmeticOps>(
-            points: &[MeasurePoint<Unit, Number>],
-            weights: &[Number],
-        ) -> MeasurePoint<Unit, Number> {
-            MeasurePoint::<Unit, Number>::new(points.iter().zip(weights).map(|(p, &w)| p.value * w).sum())
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // MeasurePoint - MeasurePoint -> Measure
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<MeasurePoint<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                type Output = Measure<Unit, Number>;
+                fn sub(self, other: MeasurePoint<Unit, Number>) -> Self::Output {
+                    Self::Output::with_uncertainty(
+                        self.value - other.value,
+                        rs_measures::uncertainty::in_quadrature(self.uncertainty, other.uncertainty),
+                    )
+                }
+            }
         }
 
-        impl<Unit, Number: ArithmeticOps> PartialEq<MeasurePoint<Unit, Number>>
-            for MeasurePoint<Unit, Number>
-        {
-            fn eq(&self, other: &MeasurePoint<Unit, Number>) -> bool {
-                self.value == other.value
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // weighted_midpoint(MeasurePoint, MeasurePoint, weight) -> MeasurePoint
+            pub fn weighted_midpoint<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                p1: MeasurePoint<Unit, Number>,
+                p2: MeasurePoint<Unit, Number>,
+                weight1: Number,
+            ) -> MeasurePoint<Unit, Number> {
+                MeasurePoint::<Unit, Number>::new(p1.value * weight1 + p2.value * (Number::ONE - weight1))
             }
         }
 
-        impl<Unit, Number: ArithmeticOps> PartialOrd<MeasurePoint<Unit, Number>>
-            for MeasurePoint<Unit, Number>
-        {
-            fn partial_cmp(&self, other: &MeasurePoint<Unit, Number>) -> Option<std::cmp::Ordering> {
-                self.value.partial_cmp(&other.value)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // midpoint(MeasurePoint, MeasurePoint) -> MeasurePoint
+            pub fn midpoint<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                p1: MeasurePoint<Unit, Number>,
+                p2: MeasurePoint<Unit, Number>,
+            ) -> MeasurePoint<Unit, Number> {
+                MeasurePoint::<Unit, Number>::new((p1.value + p2.value) * Number::HALF)
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // barycentric_combination([MeasurePoint], [Number]) -> MeasurePoint
+            pub fn barycentric_combination<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                points: &[MeasurePoint<Unit, Number>],
+                weights: &[Number],
+            ) -> MeasurePoint<Unit, Number> {
+                MeasurePoint::<Unit, Number>::new(points.iter().zip(weights).map(|(p, &w)| p.value * w).sum())
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            impl<Unit, Number: ArithmeticOps> PartialEq<MeasurePoint<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                fn eq(&self, other: &MeasurePoint<Unit, Number>) -> bool {
+                    self.value == other.value
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            impl<Unit, Number: ArithmeticOps> PartialEq<MeasurePoint<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                fn eq(&self, other: &MeasurePoint<Unit, Number>) -> bool {
+                    self.value == other.value && self.uncertainty == other.uncertainty
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            impl<Unit, Number: ArithmeticOps> PartialOrd<MeasurePoint<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                fn partial_cmp(&self, other: &MeasurePoint<Unit, Number>) -> Option<std::cmp::Ordering> {
+                    self.value.partial_cmp(&other.value)
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // MeasurePoint + Measure -> MeasurePoint
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                type Output = Self;
+                fn add(self, other: Measure<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.value + other.value,
+                        rs_measures::uncertainty::in_quadrature(
+                            self.lower_uncertainty,
+                            other.lower_uncertainty,
+                        ),
+                        rs_measures::uncertainty::in_quadrature(
+                            self.upper_uncertainty,
+                            other.upper_uncertainty,
+                        ),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // MeasurePoint += Measure
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> AddAssign<Measure<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                fn add_assign(&mut self, other: Measure<Unit, Number>) {
+                    *self = *self + other;
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // MeasurePoint - Measure -> MeasurePoint
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                type Output = Self;
+                fn sub(self, other: Measure<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.value - other.value,
+                        rs_measures::uncertainty::in_quadrature(
+                            self.lower_uncertainty,
+                            other.upper_uncertainty,
+                        ),
+                        rs_measures::uncertainty::in_quadrature(
+                            self.upper_uncertainty,
+                            other.lower_uncertainty,
+                        ),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // MeasurePoint -= Measure
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> SubAssign<Measure<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                fn sub_assign(&mut self, other: Measure<Unit, Number>) {
+                    *self = *self - other;
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // MeasurePoint - MeasurePoint -> Measure
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<MeasurePoint<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                type Output = Measure<Unit, Number>;
+                fn sub(self, other: MeasurePoint<Unit, Number>) -> Self::Output {
+                    Self::Output::with_uncertainty(
+                        self.value - other.value,
+                        rs_measures::uncertainty::in_quadrature(
+                            self.lower_uncertainty,
+                            other.upper_uncertainty,
+                        ),
+                        rs_measures::uncertainty::in_quadrature(
+                            self.upper_uncertainty,
+                            other.lower_uncertainty,
+                        ),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // weighted_midpoint(MeasurePoint, MeasurePoint, weight) -> MeasurePoint
+            pub fn weighted_midpoint<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                p1: MeasurePoint<Unit, Number>,
+                p2: MeasurePoint<Unit, Number>,
+                weight1: Number,
+            ) -> MeasurePoint<Unit, Number> {
+                barycentric_combination(&[p1, p2], &[weight1, Number::ONE - weight1])
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // midpoint(MeasurePoint, MeasurePoint) -> MeasurePoint
+            pub fn midpoint<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                p1: MeasurePoint<Unit, Number>,
+                p2: MeasurePoint<Unit, Number>,
+            ) -> MeasurePoint<Unit, Number> {
+                weighted_midpoint(p1, p2, Number::HALF)
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // barycentric_combination([MeasurePoint], [Number]) -> MeasurePoint
+            // The uncertainties of the points are assumed to be independent.
+            pub fn barycentric_combination<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                points: &[MeasurePoint<Unit, Number>],
+                weights: &[Number],
+            ) -> MeasurePoint<Unit, Number> {
+                let mut value = Number::ZERO;
+                let mut squared_uncertainty = Number::ZERO;
+                for (p, &w) in points.iter().zip(weights) {
+                    let uncertainty = p.uncertainty * w;
+                    value += p.value * w;
+                    squared_uncertainty += uncertainty * uncertainty;
+                }
+                MeasurePoint::<Unit, Number>::with_uncertainty(value, squared_uncertainty.sqrt())
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // barycentric_combination([MeasurePoint], [Number]) -> MeasurePoint
+            // The uncertainties of the points are assumed to be independent.
+            pub fn barycentric_combination<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                points: &[MeasurePoint<Unit, Number>],
+                weights: &[Number],
+            ) -> MeasurePoint<Unit, Number> {
+                let mut value = Number::ZERO;
+                let mut squared_lower = Number::ZERO;
+                let mut squared_upper = Number::ZERO;
+                for (p, &w) in points.iter().zip(weights) {
+                    let (lower, upper) = rs_measures::uncertainty::scaled_two_sided(
+                        p.lower_uncertainty,
+                        p.upper_uncertainty,
+                        w,
+                    );
+                    value += p.value * w;
+                    squared_lower += lower * lower;
+                    squared_upper += upper * upper;
+                }
+                MeasurePoint::<Unit, Number>::with_uncertainty(
+                    value,
+                    squared_lower.sqrt(),
+                    squared_upper.sqrt(),
+                )
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            impl<Unit, Number: ArithmeticOps> PartialEq<MeasurePoint<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                fn eq(&self, other: &MeasurePoint<Unit, Number>) -> bool {
+                    self.value == other.value
+                        && self.lower_uncertainty == other.lower_uncertainty
+                        && self.upper_uncertainty == other.upper_uncertainty
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // Points having the same value but different uncertainties are not comparable.
+            impl<Unit, Number: ArithmeticOps> PartialOrd<MeasurePoint<Unit, Number>>
+                for MeasurePoint<Unit, Number>
+            {
+                fn partial_cmp(&self, other: &MeasurePoint<Unit, Number>) -> Option<std::cmp::Ordering> {
+                    match self.value.partial_cmp(&other.value) {
+                        Some(std::cmp::Ordering::Equal) if self != other => None,
+                        ordering => ordering,
+                    }
+                }
             }
         }
 
@@ -172,21 +591,256 @@ macro_rules! inner_define_measure_point {
 
         impl<Unit, Number: ArithmeticOps> Copy for MeasurePoint<Unit, Number> {}
 
-        // format!("{}", MeasurePoint)
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for MeasurePoint<Unit, Number> {
-            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("at ")?;
-                fmt::Display::fmt(&self.value, formatter)?;
-                formatter.write_str(Unit::SUFFIX)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // format!("{}", MeasurePoint)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for MeasurePoint<Unit, Number> {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("at ")?;
+                    fmt::Display::fmt(&self.value, formatter)?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // format!("{}", MeasurePoint)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for MeasurePoint<Unit, Number> {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("at ")?;
+                    fmt::Display::fmt(&self.value, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.uncertainty, formatter)?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // format!("{:?}", MeasurePoint)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for MeasurePoint<Unit, Number> {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("at ")?;
+                    fmt::Display::fmt(&self.value, formatter)?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // format!("{:?}", MeasurePoint)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for MeasurePoint<Unit, Number> {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("at ")?;
+                    fmt::Display::fmt(&self.value, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.uncertainty, formatter)?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // format!("{}", MeasurePoint)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for MeasurePoint<Unit, Number> {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("at ")?;
+                    fmt::Display::fmt(&self.value, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty, formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty, formatter)?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // format!("{:?}", MeasurePoint)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for MeasurePoint<Unit, Number> {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("at ")?;
+                    fmt::Display::fmt(&self.value, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty, formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty, formatter)?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> MeasurePoint<Unit, Number> {
+            /// MeasurePoint::parse_in_any_unit(&str, &UnitRegistry) -> Result<MeasurePoint, ParseMeasureError>
+            /// It parses a number followed by the suffix of any unit registered
+            /// in the specified registry, and it converts the value to `Unit`.
+            pub fn parse_in_any_unit(
+                text: &str,
+                registry: &rs_measures::unit_registry::UnitRegistry<Unit::Property>,
+            ) -> Result<Self, rs_measures::parsing::ParseMeasureError> {
+                let (value, unit) = registry.parse::<Number>(text)?;
+                Ok(Self::new(
+                    value * Number::from_f64(unit.ratio / Unit::RATIO)
+                        + Number::from_f64((unit.offset - Unit::OFFSET) / Unit::RATIO),
+                ))
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // "...".parse::<MeasurePoint>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for MeasurePoint<Unit, Number> {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    parser.text("at")?;
+                    let value = parser.number()?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::new(value))
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // "...".parse::<MeasurePoint>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for MeasurePoint<Unit, Number> {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    parser.text("at")?;
+                    let (value, uncertainty) = parser.number_with_uncertainty()?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::with_uncertainty(value, uncertainty))
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // "...".parse::<MeasurePoint>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for MeasurePoint<Unit, Number> {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    parser.text("at")?;
+                    let (value, lower_uncertainty, upper_uncertainty) = parser.number_with_two_sided_uncertainty()?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::with_uncertainty(value, lower_uncertainty, upper_uncertainty))
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // serde::Serialize for MeasurePoint
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for MeasurePoint<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::ScalarRepr {
+                                value: self.value,
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
             }
         }
 
-        // format!("{:?}", MeasurePoint)
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for MeasurePoint<Unit, Number> {
-            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("at ")?;
-                fmt::Display::fmt(&self.value, formatter)?;
-                formatter.write_str(Unit::SUFFIX)
+        rs_measures::if_serde! {
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for MeasurePoint
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for MeasurePoint<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::ScalarRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            value: repr.value,
+                            phantom: PhantomData,
+                        })
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // serde::Serialize for MeasurePoint
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for MeasurePoint<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::SymmetricScalarRepr {
+                                value: self.value,
+                                uncertainty: Some(self.uncertainty),
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for MeasurePoint
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for MeasurePoint<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::SymmetricScalarRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            value: repr.value,
+                            uncertainty: repr.uncertainty.unwrap_or(Number::ZERO),
+                            phantom: PhantomData,
+                        })
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // serde::Serialize for MeasurePoint
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for MeasurePoint<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::TwoSidedScalarRepr {
+                                value: self.value,
+                                lower_uncertainty: Some(self.lower_uncertainty),
+                                upper_uncertainty: Some(self.upper_uncertainty),
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for MeasurePoint
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for MeasurePoint<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::TwoSidedScalarRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            value: repr.value,
+                            lower_uncertainty: repr.lower_uncertainty.unwrap_or(Number::ZERO),
+                            upper_uncertainty: repr.upper_uncertainty.unwrap_or(Number::ZERO),
+                            phantom: PhantomData,
+                        })
+                    }
+                }
             }
         }
     };
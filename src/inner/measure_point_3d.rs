@@ -1,68 +1,381 @@
 #[macro_export]
 macro_rules! inner_define_measure_point_3d {
-    {} => {
-        pub struct MeasurePoint3d<Unit, Number = f64> {
-            pub x: Number,
-            pub y: Number,
-            pub z: Number,
-            phantom: PhantomData<Unit>,
+    { $with_uncertainty:tt } => {
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            pub struct MeasurePoint3d<Unit, Number = f64> {
+                pub x: Number,
+                pub y: Number,
+                pub z: Number,
+                phantom: PhantomData<Unit>,
+            }
         }
 
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> MeasurePoint3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            pub const fn new(x: Number, y: Number, z: Number) -> Self {
-                Self {
-                    x,
-                    y,
-                    z,
-                    phantom: PhantomData,
-                }
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            pub struct MeasurePoint3d<Unit, Number = f64> {
+                pub x: Number,
+                pub y: Number,
+                pub z: Number,
+                // The covariance matrix of the coordinates, in squared units.
+                // It is symmetric and positive semi-definite.
+                pub covariance: [[Number; 3]; 3],
+                phantom: PhantomData<Unit>,
             }
+        }
 
-            pub const fn x(self) -> MeasurePoint<Unit, Number> {
-                MeasurePoint::<Unit, Number>::new(self.x)
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            pub struct MeasurePoint3d<Unit, Number = f64> {
+                pub x: Number,
+                pub y: Number,
+                pub z: Number,
+                // The uncertainties below the coordinates. They are never negative.
+                pub lower_uncertainty: [Number; 3],
+                // The uncertainties above the coordinates. They are never negative.
+                pub upper_uncertainty: [Number; 3],
+                phantom: PhantomData<Unit>,
             }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                pub const fn new(x: Number, y: Number, z: Number) -> Self {
+                    Self {
+                        x,
+                        y,
+                        z,
+                        phantom: PhantomData,
+                    }
+                }
+
+                pub const fn x(self) -> MeasurePoint<Unit, Number> {
+                    MeasurePoint::<Unit, Number>::new(self.x)
+                }
+
+                pub const fn y(self) -> MeasurePoint<Unit, Number> {
+                    MeasurePoint::<Unit, Number>::new(self.y)
+                }
+
+                pub const fn z(self) -> MeasurePoint<Unit, Number> {
+                    MeasurePoint::<Unit, Number>::new(self.z)
+                }
 
-            pub const fn y(self) -> MeasurePoint<Unit, Number> {
-                MeasurePoint::<Unit, Number>::new(self.y)
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> MeasurePoint3d<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    let offset = Number::from_f64((Unit::OFFSET - DestUnit::OFFSET) / DestUnit::RATIO);
+                    MeasurePoint3d::<DestUnit, Number> {
+                        x: self.x * factor + offset,
+                        y: self.y * factor + offset,
+                        z: self.z * factor + offset,
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> MeasurePoint3d<Unit, DestNumber> {
+                    MeasurePoint3d::<Unit, DestNumber> {
+                        x: DestNumber::from(self.x),
+                        y: DestNumber::from(self.y),
+                        z: DestNumber::from(self.z),
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> MeasurePoint3d<Unit, DestNumber> {
+                    MeasurePoint3d::<Unit, DestNumber> {
+                        x: DestNumber::lossy_from(self.x),
+                        y: DestNumber::lossy_from(self.y),
+                        z: DestNumber::lossy_from(self.z),
+                        phantom: PhantomData,
+                    }
+                }
             }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                pub const fn new(x: Number, y: Number, z: Number) -> Self {
+                    Self {
+                        x,
+                        y,
+                        z,
+                        covariance: [[Number::ZERO; 3]; 3],
+                        phantom: PhantomData,
+                    }
+                }
 
-            pub const fn z(self) -> MeasurePoint<Unit, Number> {
-                MeasurePoint::<Unit, Number>::new(self.z)
+                // Precondition: covariance is symmetric and positive semi-definite
+                pub const fn with_covariance(
+                    x: Number,
+                    y: Number,
+                    z: Number,
+                    covariance: [[Number; 3]; 3],
+                ) -> Self {
+                    Self {
+                        x,
+                        y,
+                        z,
+                        covariance,
+                        phantom: PhantomData,
+                    }
+                }
+
+                // The coordinates are assumed to be independent.
+                // Precondition: all the uncertainties are non-negative
+                pub fn with_uncertainty(
+                    x: Number,
+                    y: Number,
+                    z: Number,
+                    uncertainty: [Number; 3],
+                ) -> Self {
+                    Self::with_covariance(
+                        x,
+                        y,
+                        z,
+                        rs_measures::uncertainty::diagonal_covariance(uncertainty),
+                    )
+                }
+
+                pub fn x(self) -> MeasurePoint<Unit, Number> {
+                    MeasurePoint::<Unit, Number>::with_uncertainty(self.x, self.covariance[0][0].sqrt())
+                }
+
+                pub fn y(self) -> MeasurePoint<Unit, Number> {
+                    MeasurePoint::<Unit, Number>::with_uncertainty(self.y, self.covariance[1][1].sqrt())
+                }
+
+                pub fn z(self) -> MeasurePoint<Unit, Number> {
+                    MeasurePoint::<Unit, Number>::with_uncertainty(self.z, self.covariance[2][2].sqrt())
+                }
+
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> MeasurePoint3d<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    let offset = Number::from_f64((Unit::OFFSET - DestUnit::OFFSET) / DestUnit::RATIO);
+                    MeasurePoint3d::<DestUnit, Number> {
+                        x: self.x * factor + offset,
+                        y: self.y * factor + offset,
+                        z: self.z * factor + offset,
+                        covariance: rs_measures::uncertainty::scaled_covariance(&self.covariance, factor),
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> MeasurePoint3d<Unit, DestNumber> {
+                    MeasurePoint3d::<Unit, DestNumber> {
+                        x: DestNumber::from(self.x),
+                        y: DestNumber::from(self.y),
+                        z: DestNumber::from(self.z),
+                        covariance: self.covariance.map(|row| row.map(DestNumber::from)),
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> MeasurePoint3d<Unit, DestNumber> {
+                    MeasurePoint3d::<Unit, DestNumber> {
+                        x: DestNumber::lossy_from(self.x),
+                        y: DestNumber::lossy_from(self.y),
+                        z: DestNumber::lossy_from(self.z),
+                        covariance: self.covariance.map(|row| row.map(DestNumber::lossy_from)),
+                        phantom: PhantomData,
+                    }
+                }
+
+                // It returns the principal semi-axes of the standard error ellipsoid,
+                // the longest first.
+                pub fn error_ellipsoid(self) -> [Measure3d<Unit, Number>; 3] {
+                    rs_measures::uncertainty::principal_semi_axes(&self.covariance)
+                        .map(|[x, y, z]| Measure3d::<Unit, Number>::new(x, y, z))
+                }
+
+                // It returns a point having no uncertainty, drawn from
+                // a multivariate normal distribution.
+                pub fn sample(self, sampler: &mut rs_measures::monte_carlo::Sampler) -> Self {
+                    let [x, y, z] = sampler.multivariate_normal([self.x, self.y, self.z], &self.covariance);
+                    Self::new(x, y, z)
+                }
             }
+        }
 
-            pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
-                &self,
-            ) -> MeasurePoint3d<DestUnit, Number> {
-                let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
-                let offset = Number::from_f64((Unit::OFFSET - DestUnit::OFFSET) / DestUnit::RATIO);
-                MeasurePoint3d::<DestUnit, Number> {
-                    x: self.x * factor + offset,
-                    y: self.y * factor + offset,
-                    z: self.z * factor + offset,
-                    phantom: PhantomData,
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                pub const fn new(x: Number, y: Number, z: Number) -> Self {
+                    Self {
+                        x,
+                        y,
+                        z,
+                        lower_uncertainty: [Number::ZERO; 3],
+                        upper_uncertainty: [Number::ZERO; 3],
+                        phantom: PhantomData,
+                    }
+                }
+
+                // Precondition: all the uncertainties are non-negative
+                pub const fn with_uncertainty(
+                    x: Number,
+                    y: Number,
+                    z: Number,
+                    lower_uncertainty: [Number; 3],
+                    upper_uncertainty: [Number; 3],
+                ) -> Self {
+                    Self {
+                        x,
+                        y,
+                        z,
+                        lower_uncertainty,
+                        upper_uncertainty,
+                        phantom: PhantomData,
+                    }
+                }
+
+                pub const fn x(self) -> MeasurePoint<Unit, Number> {
+                    MeasurePoint::<Unit, Number>::with_uncertainty(
+                        self.x,
+                        self.lower_uncertainty[0],
+                        self.upper_uncertainty[0],
+                    )
+                }
+
+                pub const fn y(self) -> MeasurePoint<Unit, Number> {
+                    MeasurePoint::<Unit, Number>::with_uncertainty(
+                        self.y,
+                        self.lower_uncertainty[1],
+                        self.upper_uncertainty[1],
+                    )
+                }
+
+                pub const fn z(self) -> MeasurePoint<Unit, Number> {
+                    MeasurePoint::<Unit, Number>::with_uncertainty(
+                        self.z,
+                        self.lower_uncertainty[2],
+                        self.upper_uncertainty[2],
+                    )
+                }
+
+                pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                    &self,
+                ) -> MeasurePoint3d<DestUnit, Number> {
+                    let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                    let offset = Number::from_f64((Unit::OFFSET - DestUnit::OFFSET) / DestUnit::RATIO);
+                    MeasurePoint3d::<DestUnit, Number> {
+                        x: self.x * factor + offset,
+                        y: self.y * factor + offset,
+                        z: self.z * factor + offset,
+                        lower_uncertainty: self.lower_uncertainty.map(|u| u * factor),
+                        upper_uncertainty: self.upper_uncertainty.map(|u| u * factor),
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
+                    &self,
+                ) -> MeasurePoint3d<Unit, DestNumber> {
+                    MeasurePoint3d::<Unit, DestNumber> {
+                        x: DestNumber::from(self.x),
+                        y: DestNumber::from(self.y),
+                        z: DestNumber::from(self.z),
+                        lower_uncertainty: self.lower_uncertainty.map(DestNumber::from),
+                        upper_uncertainty: self.upper_uncertainty.map(DestNumber::from),
+                        phantom: PhantomData,
+                    }
+                }
+                pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
+                    &self,
+                ) -> MeasurePoint3d<Unit, DestNumber> {
+                    MeasurePoint3d::<Unit, DestNumber> {
+                        x: DestNumber::lossy_from(self.x),
+                        y: DestNumber::lossy_from(self.y),
+                        z: DestNumber::lossy_from(self.z),
+                        lower_uncertainty: self.lower_uncertainty.map(DestNumber::lossy_from),
+                        upper_uncertainty: self.upper_uncertainty.map(DestNumber::lossy_from),
+                        phantom: PhantomData,
+                    }
+                }
+
+                // It returns a point having no uncertainty, whose coordinates are drawn
+                // independently from split normal distributions.
+                pub fn sample(self, sampler: &mut rs_measures::monte_carlo::Sampler) -> Self {
+                    let [x, y, z] = core::array::from_fn(|i| {
+                        sampler.split_normal(
+                            [self.x, self.y, self.z][i],
+                            self.lower_uncertainty[i],
+                            self.upper_uncertainty[i],
+                        )
+                    });
+                    Self::new(x, y, z)
                 }
             }
-            pub fn lossless_into<DestNumber: ArithmeticOps + From<Number>>(
-                &self,
-            ) -> MeasurePoint3d<Unit, DestNumber> {
-                MeasurePoint3d::<Unit, DestNumber> {
-                    x: DestNumber::from(self.x),
-                    y: DestNumber::from(self.y),
-                    z: DestNumber::from(self.z),
-                    phantom: PhantomData,
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // The following method is used by the affine maps.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                // MeasurePoint3d.affinely_transformed([[Number]]) -> MeasurePoint3d
+                fn affinely_transformed(self, c: &[[Number; 4]; 3]) -> Self {
+                    Self::new(
+                        c[0][0] * self.x + c[0][1] * self.y + c[0][2] * self.z + c[0][3],
+                        c[1][0] * self.x + c[1][1] * self.y + c[1][2] * self.z + c[1][3],
+                        c[2][0] * self.x + c[2][1] * self.y + c[2][2] * self.z + c[2][3],
+                    )
                 }
             }
-            pub fn lossy_into<DestNumber: ArithmeticOps + LossyFrom<Number>>(
-                &self,
-            ) -> MeasurePoint3d<Unit, DestNumber> {
-                MeasurePoint3d::<Unit, DestNumber> {
-                    x: DestNumber::lossy_from(self.x),
-                    y: DestNumber::lossy_from(self.y),
-                    z: DestNumber::lossy_from(self.z),
-                    phantom: PhantomData,
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // The following method is used by the affine maps.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                // MeasurePoint3d.affinely_transformed([[Number]]) -> MeasurePoint3d
+                fn affinely_transformed(self, c: &[[Number; 4]; 3]) -> Self {
+                    let linear_part: [[Number; 3]; 3] = core::array::from_fn(|i| core::array::from_fn(|j| c[i][j]));
+                    Self::with_covariance(
+                        c[0][0] * self.x + c[0][1] * self.y + c[0][2] * self.z + c[0][3],
+                        c[1][0] * self.x + c[1][1] * self.y + c[1][2] * self.z + c[1][3],
+                        c[2][0] * self.x + c[2][1] * self.y + c[2][2] * self.z + c[2][3],
+                        rs_measures::uncertainty::transformed_covariance(&linear_part, &self.covariance),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // The following method is used by the affine maps.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                // MeasurePoint3d.affinely_transformed([[Number]]) -> MeasurePoint3d
+                // The uncertainties of the coordinates are assumed to be independent.
+                fn affinely_transformed(self, c: &[[Number; 4]; 3]) -> Self {
+                    let linear_part: [[Number; 3]; 3] = core::array::from_fn(|i| core::array::from_fn(|j| c[i][j]));
+                    let v = Measure3d::<Unit, Number>::with_uncertainty(
+                        self.x, self.y, self.z,
+                        self.lower_uncertainty,
+                        self.upper_uncertainty,
+                    )
+                    .linearly_transformed::<Unit>(&linear_part);
+                    Self::with_uncertainty(
+                        v.x + c[0][3], v.y + c[1][3], v.z + c[2][3],
+                        v.lower_uncertainty,
+                        v.upper_uncertainty,
+                    )
                 }
             }
         }
@@ -79,123 +392,442 @@ macro_rules! inner_define_measure_point_3d {
             }
         }
 
-        // MeasurePoint3d + Measure3d -> MeasurePoint3d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure3d<Unit, Number>>
-            for MeasurePoint3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Self;
-            fn add(self, other: Measure3d<Unit, Number>) -> Self::Output {
-                Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d + Measure3d -> MeasurePoint3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn add(self, other: Measure3d<Unit, Number>) -> Self::Output {
+                    Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+                }
             }
         }
 
-        // MeasurePoint3d += Measure3d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> AddAssign<Measure3d<Unit, Number>>
-            for MeasurePoint3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn add_assign(&mut self, other: Measure3d<Unit, Number>) {
-                self.x += other.x;
-                self.y += other.y;
-                self.z += other.z;
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d + Measure3d -> MeasurePoint3d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn add(self, other: Measure3d<Unit, Number>) -> Self::Output {
+                    Self::with_covariance(
+                        self.x + other.x,
+                        self.y + other.y,
+                        self.z + other.z,
+                        rs_measures::uncertainty::summed_covariances(&self.covariance, &other.covariance),
+                    )
+                }
             }
         }
 
-        // MeasurePoint3d - Measure3d -> MeasurePoint3d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure3d<Unit, Number>>
-            for MeasurePoint3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Self;
-            fn sub(self, other: Measure3d<Unit, Number>) -> Self::Output {
-                Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d + Measure3d -> MeasurePoint3d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Measure3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn add(self, other: Measure3d<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.x + other.x,
+                        self.y + other.y,
+                        self.z + other.z,
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.lower_uncertainty,
+                            other.lower_uncertainty,
+                        ),
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.upper_uncertainty,
+                            other.upper_uncertainty,
+                        ),
+                    )
+                }
             }
         }
 
-        // MeasurePoint3d -= Measure3d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> SubAssign<Measure3d<Unit, Number>>
-            for MeasurePoint3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn sub_assign(&mut self, other: Measure3d<Unit, Number>) {
-                self.x -= other.x;
-                self.y -= other.y;
-                self.z -= other.z;
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d += Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> AddAssign<Measure3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn add_assign(&mut self, other: Measure3d<Unit, Number>) {
+                    self.x += other.x;
+                    self.y += other.y;
+                    self.z += other.z;
+                }
             }
         }
 
-        /// measure point 3d - measure point 3d -> measure 3d
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<MeasurePoint3d<Unit, Number>>
-            for MeasurePoint3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            type Output = Measure3d<Unit, Number>;
-            fn sub(self, other: MeasurePoint3d<Unit, Number>) -> Self::Output {
-                Self::Output::new(self.x - other.x, self.y - other.y, self.z - other.z)
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d += Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> AddAssign<Measure3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn add_assign(&mut self, other: Measure3d<Unit, Number>) {
+                    *self = *self + other;
+                }
             }
         }
 
-        /// weighted_midpoint_3d(measure point 3d, measure point 3d, weight) -> measure point 3d
-        pub fn weighted_midpoint_3d<Unit: MeasurementUnit, Number: ArithmeticOps>(
-            p1: MeasurePoint3d<Unit, Number>,
-            p2: MeasurePoint3d<Unit, Number>,
-            weight1: Number,
-        ) -> MeasurePoint3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            let weight2 = Number::ONE - weight1;
-            MeasurePoint3d::<Unit, Number>::new(
-                p1.x * weight1 + p2.x * weight2,
-                p1.y * weight1 + p2.y * weight2,
-                p1.z * weight1 + p2.z * weight2,
-            )
-        }
-
-        /// midpoint_3d(measure point 3d, measure point 3d) -> measure point 3d
-        pub fn midpoint_3d<Unit: MeasurementUnit, Number: ArithmeticOps>(
-            p1: MeasurePoint3d<Unit, Number>,
-            p2: MeasurePoint3d<Unit, Number>,
-        ) -> MeasurePoint3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            MeasurePoint3d::<Unit, Number>::new(
-                (p1.x + p2.x) * Number::HALF,
-                (p1.y + p2.y) * Number::HALF,
-                (p1.z + p2.z) * Number::HALF,
-            )
-        }
-
-        /// barycentric_combination_3d(array of 3d measure points, array of weights) -> 3d measure point
-        pub fn barycentric_combination_3d<Unit: MeasurementUnit, Number: ArithmeticOps>(
-            points: &[MeasurePoint3d<Unit, Number>],
-            weights: &[Number],
-        ) -> MeasurePoint3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            MeasurePoint3d::<Unit, Number>::new(
-                points.iter().zip(weights).map(|(p, &w)| p.x * w).sum(),
-                points.iter().zip(weights).map(|(p, &w)| p.y * w).sum(),
-                points.iter().zip(weights).map(|(p, &w)| p.z * w).sum(),
-            )
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d - Measure3d -> MeasurePoint3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn sub(self, other: Measure3d<Unit, Number>) -> Self::Output {
+                    Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+                }
+            }
         }
 
-        // MeasurePoint3d == MeasurePoint3d -> bool
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<MeasurePoint3d<Unit, Number>>
-            for MeasurePoint3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn eq(&self, other: &MeasurePoint3d<Unit, Number>) -> bool {
-                self.x == other.x && self.y == other.y && self.z == other.z
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d - Measure3d -> MeasurePoint3d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn sub(self, other: Measure3d<Unit, Number>) -> Self::Output {
+                    Self::with_covariance(
+                        self.x - other.x,
+                        self.y - other.y,
+                        self.z - other.z,
+                        rs_measures::uncertainty::summed_covariances(&self.covariance, &other.covariance),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d - Measure3d -> MeasurePoint3d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Measure3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Self;
+                fn sub(self, other: Measure3d<Unit, Number>) -> Self::Output {
+                    Self::with_uncertainty(
+                        self.x - other.x,
+                        self.y - other.y,
+                        self.z - other.z,
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.lower_uncertainty,
+                            other.upper_uncertainty,
+                        ),
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.upper_uncertainty,
+                            other.lower_uncertainty,
+                        ),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d -= Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> SubAssign<Measure3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn sub_assign(&mut self, other: Measure3d<Unit, Number>) {
+                    self.x -= other.x;
+                    self.y -= other.y;
+                    self.z -= other.z;
+                }
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d -= Measure3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> SubAssign<Measure3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn sub_assign(&mut self, other: Measure3d<Unit, Number>) {
+                    *self = *self - other;
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            /// measure point 3d - measure point 3d -> measure 3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<MeasurePoint3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Measure3d<Unit, Number>;
+                fn sub(self, other: MeasurePoint3d<Unit, Number>) -> Self::Output {
+                    Self::Output::new(self.x - other.x, self.y - other.y, self.z - other.z)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // None
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<MeasurePoint3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Measure3d<Unit, Number>;
+                fn sub(self, other: MeasurePoint3d<Unit, Number>) -> Self::Output {
+                    Self::Output::with_covariance(
+                        self.x - other.x,
+                        self.y - other.y,
+                        self.z - other.z,
+                        rs_measures::uncertainty::summed_covariances(&self.covariance, &other.covariance),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            /// measure point 3d - measure point 3d -> measure 3d
+            // The uncertainties are assumed to be independent.
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<MeasurePoint3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Output = Measure3d<Unit, Number>;
+                fn sub(self, other: MeasurePoint3d<Unit, Number>) -> Self::Output {
+                    Self::Output::with_uncertainty(
+                        self.x - other.x,
+                        self.y - other.y,
+                        self.z - other.z,
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.lower_uncertainty,
+                            other.upper_uncertainty,
+                        ),
+                        rs_measures::uncertainty::in_quadrature_array(
+                            self.upper_uncertainty,
+                            other.lower_uncertainty,
+                        ),
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            /// weighted_midpoint_3d(measure point 3d, measure point 3d, weight) -> measure point 3d
+            pub fn weighted_midpoint_3d<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                p1: MeasurePoint3d<Unit, Number>,
+                p2: MeasurePoint3d<Unit, Number>,
+                weight1: Number,
+            ) -> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                let weight2 = Number::ONE - weight1;
+                MeasurePoint3d::<Unit, Number>::new(
+                    p1.x * weight1 + p2.x * weight2,
+                    p1.y * weight1 + p2.y * weight2,
+                    p1.z * weight1 + p2.z * weight2,
+                )
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            /// weighted_midpoint_3d(measure point 3d, measure point 3d, weight) -> measure point 3d
+            pub fn weighted_midpoint_3d<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                p1: MeasurePoint3d<Unit, Number>,
+                p2: MeasurePoint3d<Unit, Number>,
+                weight1: Number,
+            ) -> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                let weight2 = Number::ONE - weight1;
+                MeasurePoint3d::<Unit, Number>::with_covariance(
+                    p1.x * weight1 + p2.x * weight2,
+                    p1.y * weight1 + p2.y * weight2,
+                    p1.z * weight1 + p2.z * weight2,
+                    rs_measures::uncertainty::summed_covariances(
+                        &rs_measures::uncertainty::scaled_covariance(&p1.covariance, weight1),
+                        &rs_measures::uncertainty::scaled_covariance(&p2.covariance, weight2),
+                    ),
+                )
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            /// weighted_midpoint_3d(measure point 3d, measure point 3d, weight) -> measure point 3d
+            pub fn weighted_midpoint_3d<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                p1: MeasurePoint3d<Unit, Number>,
+                p2: MeasurePoint3d<Unit, Number>,
+                weight1: Number,
+            ) -> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                barycentric_combination_3d(&[p1, p2], &[weight1, Number::ONE - weight1])
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            /// midpoint_3d(measure point 3d, measure point 3d) -> measure point 3d
+            pub fn midpoint_3d<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                p1: MeasurePoint3d<Unit, Number>,
+                p2: MeasurePoint3d<Unit, Number>,
+            ) -> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                MeasurePoint3d::<Unit, Number>::new(
+                    (p1.x + p2.x) * Number::HALF,
+                    (p1.y + p2.y) * Number::HALF,
+                    (p1.z + p2.z) * Number::HALF,
+                )
+            }
+        }
+
+        rs_measures::if_some_uncertainty! { $with_uncertainty,
+            /// midpoint_3d(measure point 3d, measure point 3d) -> measure point 3d
+            pub fn midpoint_3d<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                p1: MeasurePoint3d<Unit, Number>,
+                p2: MeasurePoint3d<Unit, Number>,
+            ) -> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                weighted_midpoint_3d(p1, p2, Number::HALF)
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            /// barycentric_combination_3d(array of 3d measure points, array of weights) -> 3d measure point
+            pub fn barycentric_combination_3d<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                points: &[MeasurePoint3d<Unit, Number>],
+                weights: &[Number],
+            ) -> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                MeasurePoint3d::<Unit, Number>::new(
+                    points.iter().zip(weights).map(|(p, &w)| p.x * w).sum(),
+                    points.iter().zip(weights).map(|(p, &w)| p.y * w).sum(),
+                    points.iter().zip(weights).map(|(p, &w)| p.z * w).sum(),
+                )
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            /// barycentric_combination_3d(array of 3d measure points, array of weights) -> 3d measure point
+            pub fn barycentric_combination_3d<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                points: &[MeasurePoint3d<Unit, Number>],
+                weights: &[Number],
+            ) -> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                MeasurePoint3d::<Unit, Number>::with_covariance(
+                    points.iter().zip(weights).map(|(p, &w)| p.x * w).sum(),
+                    points.iter().zip(weights).map(|(p, &w)| p.y * w).sum(),
+                    points.iter().zip(weights).map(|(p, &w)| p.z * w).sum(),
+                    points.iter().zip(weights).fold([[Number::ZERO; 3]; 3], |sum, (p, &w)| {
+                        rs_measures::uncertainty::summed_covariances(&sum, &rs_measures::uncertainty::scaled_covariance(&p.covariance, w))
+                    }),
+                )
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            /// barycentric_combination_3d(array of 3d measure points, array of weights) -> 3d measure point
+            // The uncertainties of the points are assumed to be independent.
+            pub fn barycentric_combination_3d<Unit: MeasurementUnit, Number: ArithmeticOps>(
+                points: &[MeasurePoint3d<Unit, Number>],
+                weights: &[Number],
+            ) -> MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                let mut coordinates = [Number::ZERO; 3];
+                let mut squared_lower = [Number::ZERO; 3];
+                let mut squared_upper = [Number::ZERO; 3];
+                for (p, &w) in points.iter().zip(weights) {
+                    let values = [p.x, p.y, p.z];
+                    let (lower, upper) = rs_measures::uncertainty::scaled_two_sided_array(
+                        p.lower_uncertainty,
+                        p.upper_uncertainty,
+                        w,
+                    );
+                    for i in 0..3 {
+                        coordinates[i] += values[i] * w;
+                        squared_lower[i] += lower[i] * lower[i];
+                        squared_upper[i] += upper[i] * upper[i];
+                    }
+                }
+                let [x, y, z] = coordinates;
+                MeasurePoint3d::<Unit, Number>::with_uncertainty(
+                    x, y, z,
+                    squared_lower.map(|u| u.sqrt()),
+                    squared_upper.map(|u| u.sqrt()),
+                )
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d == MeasurePoint3d -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<MeasurePoint3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn eq(&self, other: &MeasurePoint3d<Unit, Number>) -> bool {
+                    self.x == other.x && self.y == other.y && self.z == other.z
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d == MeasurePoint3d -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<MeasurePoint3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn eq(&self, other: &MeasurePoint3d<Unit, Number>) -> bool {
+                    self.x == other.x && self.y == other.y && self.z == other.z
+                        && self.covariance == other.covariance
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // MeasurePoint3d == MeasurePoint3d -> bool
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> PartialEq<MeasurePoint3d<Unit, Number>>
+                for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn eq(&self, other: &MeasurePoint3d<Unit, Number>) -> bool {
+                    self.x == other.x && self.y == other.y && self.z == other.z
+                        && self.lower_uncertainty == other.lower_uncertainty
+                        && self.upper_uncertainty == other.upper_uncertainty
+                }
             }
         }
 
@@ -215,37 +847,352 @@ macro_rules! inner_define_measure_point_3d {
         {
         }
 
-        // format!("{}", MeasurePoint3d)
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for MeasurePoint3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("at (")?;
-                fmt::Display::fmt(&self.x, formatter)?;
-                formatter.write_str(", ")?;
-                fmt::Display::fmt(&self.y, formatter)?;
-                formatter.write_str(", ")?;
-                fmt::Display::fmt(&self.z, formatter)?;
-                formatter.write_str(")")?;
-                formatter.write_str(Unit::SUFFIX)
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // format!("{}", MeasurePoint3d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("at (")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.z, formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
             }
         }
 
-        // format!("{:?}", MeasurePoint3d)
-        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for MeasurePoint3d<Unit, Number>
-        where
-            Unit::Property: VectorProperty,
-        {
-            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-                formatter.write_str("at (")?;
-                fmt::Display::fmt(&self.x, formatter)?;
-                formatter.write_str(", ")?;
-                fmt::Display::fmt(&self.y, formatter)?;
-                formatter.write_str(", ")?;
-                fmt::Display::fmt(&self.z, formatter)?;
-                formatter.write_str(")")?;
-                formatter.write_str(Unit::SUFFIX)
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // format!("{}", MeasurePoint3d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("at (")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[0][0].sqrt(), formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[1][1].sqrt(), formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.z, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[2][2].sqrt(), formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // format!("{}", MeasurePoint3d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("at (")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[0], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[0], formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[1], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[1], formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.z, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[2], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[2], formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // format!("{:?}", MeasurePoint3d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("at (")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.z, formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // format!("{:?}", MeasurePoint3d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("at (")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[0][0].sqrt(), formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[1][1].sqrt(), formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.z, formatter)?;
+                    formatter.write_str(" \u{b1} ")?;
+                    fmt::Display::fmt(&self.covariance[2][2].sqrt(), formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // format!("{:?}", MeasurePoint3d)
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    formatter.write_str("at (")?;
+                    fmt::Display::fmt(&self.x, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[0], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[0], formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.y, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[1], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[1], formatter)?;
+                    formatter.write_str(", ")?;
+                    fmt::Display::fmt(&self.z, formatter)?;
+                    formatter.write_str(" +")?;
+                    fmt::Display::fmt(&self.upper_uncertainty[2], formatter)?;
+                    formatter.write_str("/-")?;
+                    fmt::Display::fmt(&self.lower_uncertainty[2], formatter)?;
+                    formatter.write_str(")")?;
+                    formatter.write_str(Unit::SUFFIX)
+                }
+            }
+        }
+
+        rs_measures::if_no_uncertainty! { $with_uncertainty,
+            // "...".parse::<MeasurePoint3d>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    parser.text("at (")?;
+                    let x = parser.number()?;
+                    parser.text(",")?;
+                    let y = parser.number()?;
+                    parser.text(",")?;
+                    let z = parser.number()?;
+                    parser.text(")")?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::new(x, y, z))
+                }
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            // "...".parse::<MeasurePoint3d>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    parser.text("at (")?;
+                    let (x, ux) = parser.number_with_uncertainty()?;
+                    parser.text(",")?;
+                    let (y, uy) = parser.number_with_uncertainty()?;
+                    parser.text(",")?;
+                    let (z, uz) = parser.number_with_uncertainty()?;
+                    parser.text(")")?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::with_uncertainty(x, y, z, [ux, uy, uz]))
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            // "...".parse::<MeasurePoint3d>()
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> std::str::FromStr for MeasurePoint3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                type Err = rs_measures::parsing::ParseMeasureError;
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let mut parser = rs_measures::parsing::Parser::new(s);
+                    parser.text("at (")?;
+                    let (x, lower_x, upper_x) = parser.number_with_two_sided_uncertainty()?;
+                    parser.text(",")?;
+                    let (y, lower_y, upper_y) = parser.number_with_two_sided_uncertainty()?;
+                    parser.text(",")?;
+                    let (z, lower_z, upper_z) = parser.number_with_two_sided_uncertainty()?;
+                    parser.text(")")?;
+                    parser.suffix(Unit::SUFFIX, "")?;
+                    Ok(Self::with_uncertainty(
+                        x, y, z,
+                        [lower_x, lower_y, lower_z],
+                        [upper_x, upper_y, upper_z],
+                    ))
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // serde::Serialize for MeasurePoint3d
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for MeasurePoint3d<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::Vector3dRepr {
+                                x: self.x,
+                                y: self.y,
+                                z: self.z,
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_no_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for MeasurePoint3d
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for MeasurePoint3d<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::Vector3dRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            x: repr.x,
+                            y: repr.y,
+                            z: repr.z,
+                            phantom: PhantomData,
+                        })
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // serde::Serialize for MeasurePoint3d
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for MeasurePoint3d<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::SymmetricVector3dRepr {
+                                x: self.x,
+                                y: self.y,
+                                z: self.z,
+                                covariance: Some(self.covariance),
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for MeasurePoint3d
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for MeasurePoint3d<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::SymmetricVector3dRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            x: repr.x,
+                            y: repr.y,
+                            z: repr.z,
+                            covariance: repr.covariance.unwrap_or([[Number::ZERO; 3]; 3]),
+                            phantom: PhantomData,
+                        })
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // serde::Serialize for MeasurePoint3d
+                impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                    for MeasurePoint3d<Unit, Number>
+                {
+                    fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        rs_measures::serde::Serialize::serialize(
+                            &rs_measures::serde_support::TwoSidedVector3dRepr {
+                                x: self.x,
+                                y: self.y,
+                                z: self.z,
+                                lower_uncertainty: Some(self.lower_uncertainty),
+                                upper_uncertainty: Some(self.upper_uncertainty),
+                                unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                            },
+                            serializer,
+                        )
+                    }
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+                // serde::Deserialize for MeasurePoint3d
+                impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                    for MeasurePoint3d<Unit, Number>
+                {
+                    fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                        let repr = <rs_measures::serde_support::TwoSidedVector3dRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                        rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                        Ok(Self {
+                            x: repr.x,
+                            y: repr.y,
+                            z: repr.z,
+                            lower_uncertainty: repr.lower_uncertainty.unwrap_or([Number::ZERO; 3]),
+                            upper_uncertainty: repr.upper_uncertainty.unwrap_or([Number::ZERO; 3]),
+                            phantom: PhantomData,
+                        })
+                    }
+                }
             }
         }
     };
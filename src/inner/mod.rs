@@ -1,5 +1,6 @@
 pub mod measure;
 pub mod measure_point;
+pub mod monte_carlo;
 
 pub mod signed_direction;
 pub mod unsigned_direction;
@@ -7,9 +8,19 @@ pub mod unsigned_direction;
 pub mod affine_map_2d;
 pub mod linear_map_2d;
 pub mod measure_2d;
+pub mod measure_linear_map_2d;
 pub mod measure_point_2d;
+pub mod polar_point_2d;
+pub mod tensor_2d;
 
 pub mod affine_map_3d;
+pub mod cylindrical_point_3d;
+pub mod direction_3d;
+pub mod geodesy;
 pub mod linear_map_3d;
 pub mod measure_3d;
+pub mod measure_linear_map_3d;
 pub mod measure_point_3d;
+pub mod rotation_3d;
+pub mod spherical_point_3d;
+pub mod tensor_3d;
@@ -0,0 +1,144 @@
+#[macro_export]
+macro_rules! inner_define_monte_carlo {
+    { $with_directions:tt $with_uncertainty:tt } => {
+        // The outcome of a Monte Carlo evaluation of the uncertainty of a measure.
+        pub struct MonteCarloResult<Unit, Number = f64> {
+            // The values of the evaluated measure, in increasing order.
+            pub sorted_values: Vec<Number>,
+            mean: Number,
+            standard_deviation: Number,
+            phantom: PhantomData<Unit>,
+        }
+
+        /// monte_carlo(number of trials, sampler, closure returning a measure) -> Monte Carlo result
+        /// The closure is called once per trial, and it should draw its inputs
+        /// from the sampler, typically by calling `sample` on measures having uncertainty.
+        /// The values are averaged linearly, and so the closure should not return
+        /// the value of a direction, which wraps around; use `monte_carlo_direction` instead.
+        /// Precondition: trials >= 2
+        pub fn monte_carlo<Unit: MeasurementUnit, Number: ArithmeticOps>(
+            trials: usize,
+            sampler: &mut rs_measures::monte_carlo::Sampler,
+            mut evaluate: impl FnMut(&mut rs_measures::monte_carlo::Sampler) -> Measure<Unit, Number>,
+        ) -> MonteCarloResult<Unit, Number> {
+            MonteCarloResult::<Unit, Number>::from_values(
+                (0..trials).map(|_| evaluate(sampler).value).collect(),
+            )
+        }
+
+        rs_measures::if_true! { $with_directions,
+            /// monte_carlo_direction(number of trials, sampler, closure returning a signed direction) -> Monte Carlo result
+            /// Every returned direction is taken as its circular mean plus its angle from it,
+            /// between minus half cycle and plus half cycle, and so the results near
+            /// the wrap-around are not split into two groups.
+            /// Therefore, the mean and the ends of the coverage intervals may be beyond half cycle.
+            /// Precondition: trials >= 2
+            pub fn monte_carlo_direction<
+                Unit: AngleMeasurementUnit<Property = Angle>,
+                Number: ArithmeticOps,
+            >(
+                trials: usize,
+                sampler: &mut rs_measures::monte_carlo::Sampler,
+                mut evaluate: impl FnMut(&mut rs_measures::monte_carlo::Sampler) -> SignedDirection<Unit, Number>,
+            ) -> MonteCarloResult<Unit, Number> {
+                let directions: Vec<Number> = (0..trials).map(|_| evaluate(sampler).value).collect();
+                let (circular_mean, _) = rs_measures::circular_statistics::mean_resultant(
+                    directions.iter().copied(),
+                    Unit::CYCLE_FRACTION,
+                )
+                .unwrap_or((Number::ZERO, Number::ZERO));
+                MonteCarloResult::<Unit, Number>::from_values(
+                    directions
+                        .iter()
+                        .map(|&d| circular_mean + SignedDirection::<Unit, Number>::new(d - circular_mean).value)
+                        .collect(),
+                )
+            }
+        }
+
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> MonteCarloResult<Unit, Number> {
+            fn from_values(mut sorted_values: Vec<Number>) -> Self {
+                sorted_values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(core::cmp::Ordering::Equal));
+                let (mean, standard_deviation) =
+                    rs_measures::monte_carlo::mean_and_standard_deviation(&sorted_values);
+                Self {
+                    sorted_values,
+                    mean,
+                    standard_deviation,
+                    phantom: PhantomData,
+                }
+            }
+
+            /// Monte Carlo result .mean() -> measure
+            pub fn mean(&self) -> Measure<Unit, Number> {
+                Measure::<Unit, Number>::new(self.mean)
+            }
+
+            /// Monte Carlo result .standard_deviation() -> measure
+            pub fn standard_deviation(&self) -> Measure<Unit, Number> {
+                Measure::<Unit, Number>::new(self.standard_deviation)
+            }
+
+            /// Monte Carlo result .coverage_interval(probability) -> (measure, measure)
+            /// It returns the probabilistically symmetric coverage interval,
+            /// i.e. the one leaving out the same fraction of values at each side.
+            /// Precondition: 0 <= probability <= 1
+            pub fn coverage_interval(&self, probability: f64) -> (Measure<Unit, Number>, Measure<Unit, Number>) {
+                (
+                    Measure::<Unit, Number>::new(rs_measures::monte_carlo::quantile(
+                        &self.sorted_values,
+                        (1. - probability) * 0.5,
+                    )),
+                    Measure::<Unit, Number>::new(rs_measures::monte_carlo::quantile(
+                        &self.sorted_values,
+                        (1. + probability) * 0.5,
+                    )),
+                )
+            }
+
+            /// Monte Carlo result .shortest_coverage_interval(probability) -> (measure, measure)
+            /// Precondition: 0 <= probability <= 1
+            pub fn shortest_coverage_interval(
+                &self,
+                probability: f64,
+            ) -> (Measure<Unit, Number>, Measure<Unit, Number>) {
+                let (low, high) =
+                    rs_measures::monte_carlo::shortest_interval(&self.sorted_values, probability);
+                (
+                    Measure::<Unit, Number>::new(low),
+                    Measure::<Unit, Number>::new(high),
+                )
+            }
+        }
+
+        rs_measures::if_symmetric_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> MonteCarloResult<Unit, Number> {
+                /// Monte Carlo result .to_measure() -> measure
+                /// It returns the mean, having the standard deviation as uncertainty.
+                pub fn to_measure(&self) -> Measure<Unit, Number> {
+                    Measure::<Unit, Number>::with_uncertainty(self.mean, self.standard_deviation)
+                }
+            }
+        }
+
+        rs_measures::if_two_sided_uncertainty! { $with_uncertainty,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> MonteCarloResult<Unit, Number> {
+                /// Monte Carlo result .to_measure() -> measure
+                /// It returns the mean, having as uncertainties its distances
+                /// from the ends of the probabilistically symmetric coverage interval
+                /// having the probability of one standard deviation (about 68.27%).
+                pub fn to_measure(&self) -> Measure<Unit, Number> {
+                    const ONE_SIGMA_PROBABILITY: f64 = 0.682_689_492_137_086;
+                    let (low, high) = self.coverage_interval(ONE_SIGMA_PROBABILITY);
+                    let lower = self.mean - low.value;
+                    let upper = high.value - self.mean;
+                    Measure::<Unit, Number>::with_uncertainty(
+                        self.mean,
+                        if lower > Number::ZERO { lower } else { Number::ZERO },
+                        if upper > Number::ZERO { upper } else { Number::ZERO },
+                    )
+                }
+            }
+        }
+    };
+}
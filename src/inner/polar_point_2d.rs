@@ -0,0 +1,202 @@
+#[macro_export]
+macro_rules! inner_define_polar_point_2d {
+    {} => {
+        // A point in a plane, expressed in polar coordinates:
+        // its distance from the origin, and the direction of its position vector,
+        // counterclockwise from the X axis.
+        pub struct PolarPoint2d<LengthUnit: MeasurementUnit, AngleUnit, Number: ArithmeticOps = f64> {
+            pub radius: Measure<LengthUnit, Number>,
+            pub azimuth: UnsignedDirection<AngleUnit, Number>,
+        }
+
+        impl<LengthUnit, AngleUnit, Number> PolarPoint2d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            pub const fn new(
+                radius: Measure<LengthUnit, Number>,
+                azimuth: UnsignedDirection<AngleUnit, Number>,
+            ) -> Self {
+                Self { radius, azimuth }
+            }
+
+            // The polar coordinates of a point expressed in cartesian coordinates.
+            // For the origin, the azimuth is zero.
+            pub fn from_point(point: MeasurePoint2d<LengthUnit, Number>) -> Self {
+                Self::new(
+                    Measure::<LengthUnit, Number>::new((point.x * point.x + point.y * point.y).sqrt()),
+                    UnsignedDirection::<AngleUnit, Number>::new(
+                        point.y.atan2(point.x) / Number::from_f64(AngleUnit::RATIO),
+                    ),
+                )
+            }
+
+            // The cartesian coordinates of the point.
+            pub fn to_point(&self) -> MeasurePoint2d<LengthUnit, Number> {
+                let (sin, cos) = (self.azimuth.value * Number::from_f64(AngleUnit::RATIO)).sin_cos();
+                MeasurePoint2d::<LengthUnit, Number>::new(
+                    self.radius.value * cos,
+                    self.radius.value * sin,
+                )
+            }
+
+            pub fn convert<
+                DestLengthUnit: MeasurementUnit<Property = LengthUnit::Property>,
+                DestAngleUnit: AngleMeasurementUnit<Property = Angle>,
+            >(
+                &self,
+            ) -> PolarPoint2d<DestLengthUnit, DestAngleUnit, Number>
+            where
+                DestLengthUnit::Property: VectorProperty,
+            {
+                PolarPoint2d::<DestLengthUnit, DestAngleUnit, Number>::new(
+                    self.radius.convert::<DestLengthUnit>(),
+                    self.azimuth.convert::<DestAngleUnit>(),
+                )
+            }
+        }
+
+        // PolarPoint2d -> MeasurePoint2d
+        impl<LengthUnit, AngleUnit, Number> From<PolarPoint2d<LengthUnit, AngleUnit, Number>>
+            for MeasurePoint2d<LengthUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn from(point: PolarPoint2d<LengthUnit, AngleUnit, Number>) -> Self {
+                point.to_point()
+            }
+        }
+
+        // MeasurePoint2d -> PolarPoint2d
+        impl<LengthUnit, AngleUnit, Number> From<MeasurePoint2d<LengthUnit, Number>>
+            for PolarPoint2d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn from(point: MeasurePoint2d<LengthUnit, Number>) -> Self {
+                Self::from_point(point)
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> Default for PolarPoint2d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            // It returns the origin.
+            fn default() -> Self {
+                Self::new(
+                    Measure::<LengthUnit, Number>::new(Number::ZERO),
+                    UnsignedDirection::<AngleUnit, Number>::new(Number::ZERO),
+                )
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> Clone for PolarPoint2d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> Copy for PolarPoint2d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+        }
+
+        impl<LengthUnit, AngleUnit, Number> PartialEq for PolarPoint2d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.radius.value == other.radius.value && self.azimuth.value == other.azimuth.value
+            }
+        }
+
+        // format!("{}", PolarPoint2d)
+        impl<LengthUnit, AngleUnit, Number> fmt::Display for PolarPoint2d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "radius ")?;
+                fmt::Display::fmt(&self.radius.value, formatter)?;
+                write!(formatter, "{}, azimuth ", LengthUnit::SUFFIX)?;
+                fmt::Display::fmt(&self.azimuth.value, formatter)?;
+                write!(formatter, "{}", AngleUnit::SUFFIX)
+            }
+        }
+
+        // format!("{:?}", PolarPoint2d)
+        impl<LengthUnit, AngleUnit, Number> fmt::Debug for PolarPoint2d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for PolarPoint2d
+            impl<LengthUnit: MeasurementUnit, AngleUnit, Number: ArithmeticOps> rs_measures::serde::Serialize
+                for PolarPoint2d<LengthUnit, AngleUnit, Number>
+            where
+                Measure<LengthUnit, Number>: rs_measures::serde::Serialize,
+                UnsignedDirection<AngleUnit, Number>: rs_measures::serde::Serialize,
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::PolarRepr {
+                            radius: &self.radius,
+                            azimuth: &self.azimuth,
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for PolarPoint2d
+            impl<'de, LengthUnit: MeasurementUnit, AngleUnit, Number: ArithmeticOps> rs_measures::serde::Deserialize<'de>
+                for PolarPoint2d<LengthUnit, AngleUnit, Number>
+            where
+                Measure<LengthUnit, Number>: rs_measures::serde::Deserialize<'de>,
+                UnsignedDirection<AngleUnit, Number>: rs_measures::serde::Deserialize<'de>,
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::PolarRepr<Measure<LengthUnit, Number>, UnsignedDirection<AngleUnit, Number>> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    Ok(Self {
+                        radius: repr.radius,
+                        azimuth: repr.azimuth,
+                    })
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,341 @@
+#[macro_export]
+macro_rules! inner_define_rotation_3d {
+    { $with_points:tt } => {
+        // A rotation in the space around the origin, represented by a unit quaternion.
+        // The results of every operation are normalized, and so the errors of rounding
+        // do not accumulate into a deformation of the rotated objects,
+        // like it happens with the composition of many rotation matrices.
+        pub struct Rotation3d<Number: ArithmeticOps = f64> {
+            // The components w, x, y, z of the quaternion w + xi + yj + zk.
+            q: [Number; 4],
+        }
+
+        impl<Number: ArithmeticOps> Rotation3d<Number> {
+            // It builds a rotation from the components w, x, y, z
+            // of a non-zero quaternion, which is normalized.
+            pub fn from_quaternion(quaternion: [Number; 4]) -> Self {
+                let norm = quaternion.iter().map(|c| *c * *c).sum::<Number>().sqrt();
+                Self {
+                    q: quaternion.map(|c| c / norm),
+                }
+            }
+
+            // The components w, x, y, z of the unit quaternion.
+            pub fn quaternion(&self) -> [Number; 4] {
+                self.q
+            }
+
+            pub fn identity() -> Self {
+                Self {
+                    q: [Number::ONE, Number::ZERO, Number::ZERO, Number::ZERO],
+                }
+            }
+
+            // Rotation by an angle measure around an axis,
+            // according to the right-hand rule.
+            // The axis vector does not need to be a unit vector.
+            // If it is a zero vector, the identity rotation is returned.
+            pub fn from_axis_angle<
+                AngleUnit: AngleMeasurementUnit<Property = Angle>,
+                AxisUnit: MeasurementUnit,
+            >(
+                axis: Measure3d<AxisUnit, Number>,
+                angle: Measure<AngleUnit, Number>,
+            ) -> Self
+            where
+                AxisUnit::Property: VectorProperty,
+            {
+                let norm = (axis.x * axis.x + axis.y * axis.y + axis.z * axis.z).sqrt();
+                if norm == Number::ZERO {
+                    return Self::identity();
+                }
+                let (sin, cos) = (angle.convert::<Radian>().value * Number::HALF).sin_cos();
+                let factor = sin / norm;
+                Self::from_quaternion([cos, axis.x * factor, axis.y * factor, axis.z * factor])
+            }
+
+            // Rotation by the Tait-Bryan angles used in aeronautics:
+            // first by `yaw` around the Z axis, then by `pitch` around the new Y axis,
+            // and then by `roll` around the newest X axis.
+            pub fn from_euler_angles<AngleUnit: AngleMeasurementUnit<Property = Angle>>(
+                roll: Measure<AngleUnit, Number>,
+                pitch: Measure<AngleUnit, Number>,
+                yaw: Measure<AngleUnit, Number>,
+            ) -> Self {
+                let (sr, cr) = (roll.convert::<Radian>().value * Number::HALF).sin_cos();
+                let (sp, cp) = (pitch.convert::<Radian>().value * Number::HALF).sin_cos();
+                let (sy, cy) = (yaw.convert::<Radian>().value * Number::HALF).sin_cos();
+                Self::from_quaternion([
+                    cr * cp * cy + sr * sp * sy,
+                    sr * cp * cy - cr * sp * sy,
+                    cr * sp * cy + sr * cp * sy,
+                    cr * cp * sy - sr * sp * cy,
+                ])
+            }
+
+            // The angles `(roll, pitch, yaw)` accepted by `from_euler_angles`.
+            // The pitch is between -90 and +90 degrees.
+            pub fn to_euler_angles<AngleUnit: AngleMeasurementUnit<Property = Angle>>(
+                &self,
+            ) -> (
+                Measure<AngleUnit, Number>,
+                Measure<AngleUnit, Number>,
+                Measure<AngleUnit, Number>,
+            ) {
+                let [w, x, y, z] = self.q;
+                let two = Number::ONE + Number::ONE;
+                let roll = (two * (w * x + y * z)).atan2(Number::ONE - two * (x * x + y * y));
+                let sin_pitch = two * (w * y - z * x);
+                let pitch = if sin_pitch > Number::ONE {
+                    Number::ONE
+                } else if sin_pitch < -Number::ONE {
+                    -Number::ONE
+                } else {
+                    sin_pitch
+                }
+                .asin();
+                let yaw = (two * (w * z + x * y)).atan2(Number::ONE - two * (y * y + z * z));
+                (
+                    Measure::<Radian, Number>::new(roll).convert::<AngleUnit>(),
+                    Measure::<Radian, Number>::new(pitch).convert::<AngleUnit>(),
+                    Measure::<Radian, Number>::new(yaw).convert::<AngleUnit>(),
+                )
+            }
+
+            // The angle of rotation, between 0 and 360 degrees.
+            pub fn angle<AngleUnit: AngleMeasurementUnit<Property = Angle>>(
+                &self,
+            ) -> Measure<AngleUnit, Number> {
+                let [w, x, y, z] = self.q;
+                let sin = (x * x + y * y + z * z).sqrt();
+                Measure::<Radian, Number>::new((Number::ONE + Number::ONE) * sin.atan2(w))
+                    .convert::<AngleUnit>()
+            }
+
+            // The unit vector of the axis of rotation.
+            // For the identity rotation, it is the X axis.
+            pub fn axis<AxisUnit: MeasurementUnit>(&self) -> Measure3d<AxisUnit, Number>
+            where
+                AxisUnit::Property: VectorProperty,
+            {
+                let [_, x, y, z] = self.q;
+                let sin = (x * x + y * y + z * z).sqrt();
+                if sin == Number::ZERO {
+                    Measure3d::<AxisUnit, Number>::new(Number::ONE, Number::ZERO, Number::ZERO)
+                } else {
+                    Measure3d::<AxisUnit, Number>::new(x / sin, y / sin, z / sin)
+                }
+            }
+
+            // Inversion
+
+            pub fn inverted(&self) -> Self {
+                let [w, x, y, z] = self.q;
+                Self { q: [w, -x, -y, -z] }
+            }
+
+            // Composition of rotations.
+            // Applying the resulting rotation is equivalent to apply first
+            // `other` and then `self`.
+            pub fn combined_with(&self, other: &Rotation3d<Number>) -> Self {
+                let [w1, x1, y1, z1] = self.q;
+                let [w2, x2, y2, z2] = other.q;
+                Self::from_quaternion([
+                    w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2,
+                    w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+                    w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+                    w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+                ])
+            }
+
+            // Spherical linear interpolation, moving at constant angular speed
+            // along the shortest path from `self`, when `t == 0`, to `other`, when `t == 1`.
+            pub fn slerp(&self, other: &Rotation3d<Number>, t: Number) -> Self {
+                let mut cos = (0..4).map(|i| self.q[i] * other.q[i]).sum::<Number>();
+                // The quaternions q and -q represent the same rotation.
+                let mut end = other.q;
+                if cos < Number::ZERO {
+                    cos = -cos;
+                    end = end.map(|c| -c);
+                }
+                let (k1, k2) = if cos > Number::from_f64(0.9995) {
+                    // For nearby rotations, linear interpolation avoids a division by almost zero.
+                    (Number::ONE - t, t)
+                } else {
+                    let angle = cos.acos();
+                    let sin = angle.sin();
+                    (((Number::ONE - t) * angle).sin() / sin, (t * angle).sin() / sin)
+                };
+                Self::from_quaternion(core::array::from_fn(|i| k1 * self.q[i] + k2 * end[i]))
+            }
+
+            // The rotation matrix.
+            pub fn to_linear_map(&self) -> LinearMap3d<Number> {
+                LinearMap3d::new(self.matrix())
+            }
+
+            // The rotation represented by a rotation matrix.
+            // Precondition: the map is a rotation, that is an orthogonal map with determinant 1.
+            pub fn from_linear_map(map: &LinearMap3d<Number>) -> Self {
+                let c = &map.c;
+                let one = Number::ONE;
+                let trace = c[0][0] + c[1][1] + c[2][2];
+                // The largest component is computed first, to minimize rounding errors.
+                let q = if trace > Number::ZERO {
+                    let s = (trace + one).sqrt() * (one + one);
+                    [
+                        Number::HALF * Number::HALF * s,
+                        (c[2][1] - c[1][2]) / s,
+                        (c[0][2] - c[2][0]) / s,
+                        (c[1][0] - c[0][1]) / s,
+                    ]
+                } else if c[0][0] > c[1][1] && c[0][0] > c[2][2] {
+                    let s = (one + c[0][0] - c[1][1] - c[2][2]).sqrt() * (one + one);
+                    [
+                        (c[2][1] - c[1][2]) / s,
+                        Number::HALF * Number::HALF * s,
+                        (c[0][1] + c[1][0]) / s,
+                        (c[0][2] + c[2][0]) / s,
+                    ]
+                } else if c[1][1] > c[2][2] {
+                    let s = (one + c[1][1] - c[0][0] - c[2][2]).sqrt() * (one + one);
+                    [
+                        (c[0][2] - c[2][0]) / s,
+                        (c[0][1] + c[1][0]) / s,
+                        Number::HALF * Number::HALF * s,
+                        (c[1][2] + c[2][1]) / s,
+                    ]
+                } else {
+                    let s = (one + c[2][2] - c[0][0] - c[1][1]).sqrt() * (one + one);
+                    [
+                        (c[1][0] - c[0][1]) / s,
+                        (c[0][2] + c[2][0]) / s,
+                        (c[1][2] + c[2][1]) / s,
+                        Number::HALF * Number::HALF * s,
+                    ]
+                };
+                Self::from_quaternion(q)
+            }
+
+            pub fn apply_to<Unit: MeasurementUnit>(
+                &self,
+                m: Measure3d<Unit, Number>,
+            ) -> Measure3d<Unit, Number>
+            where
+                Unit::Property: VectorProperty,
+            {
+                m.linearly_transformed(&self.matrix())
+            }
+
+            fn matrix(&self) -> [[Number; 3]; 3] {
+                let [w, x, y, z] = self.q;
+                let one = Number::ONE;
+                let two = one + one;
+                [
+                    [
+                        one - two * (y * y + z * z),
+                        two * (x * y - w * z),
+                        two * (x * z + w * y),
+                    ],
+                    [
+                        two * (x * y + w * z),
+                        one - two * (x * x + z * z),
+                        two * (y * z - w * x),
+                    ],
+                    [
+                        two * (x * z - w * y),
+                        two * (y * z + w * x),
+                        one - two * (x * x + y * y),
+                    ],
+                ]
+            }
+        }
+
+        rs_measures::if_true! { $with_points,
+            impl<Number: ArithmeticOps> Rotation3d<Number> {
+                // Rotation of a point around the origin.
+                pub fn apply_to_point<Unit: MeasurementUnit>(
+                    &self,
+                    p: MeasurePoint3d<Unit, Number>,
+                ) -> MeasurePoint3d<Unit, Number>
+                where
+                    Unit::Property: VectorProperty,
+                {
+                    let m = self.matrix();
+                    p.affinely_transformed(&core::array::from_fn(|i| {
+                        [m[i][0], m[i][1], m[i][2], Number::ZERO]
+                    }))
+                }
+            }
+        }
+
+        impl<Number: ArithmeticOps> Default for Rotation3d<Number> {
+            // It returns the identity rotation.
+            fn default() -> Self {
+                Self::identity()
+            }
+        }
+
+        impl<Number: ArithmeticOps> From<Rotation3d<Number>> for LinearMap3d<Number> {
+            fn from(rotation: Rotation3d<Number>) -> Self {
+                rotation.to_linear_map()
+            }
+        }
+
+        impl<Number: ArithmeticOps> Clone for Rotation3d<Number> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<Number: ArithmeticOps> Copy for Rotation3d<Number> {}
+
+        impl<Number: ArithmeticOps> PartialEq for Rotation3d<Number> {
+            // The quaternions q and -q represent the same rotation.
+            fn eq(&self, other: &Self) -> bool {
+                self.q == other.q || self.q == other.q.map(|c| -c)
+            }
+        }
+
+        // format!("{}", Rotation3d)
+        impl<Number: ArithmeticOps> fmt::Display for Rotation3d<Number> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let [w, x, y, z] = self.q;
+                write!(formatter, "quaternion({}, {}, {}, {})", w, x, y, z)
+            }
+        }
+
+        // format!("{:?}", Rotation3d)
+        impl<Number: ArithmeticOps> fmt::Debug for Rotation3d<Number> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for Rotation3d
+            impl<Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                for Rotation3d<Number>
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::RotationRepr { quaternion: self.q },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for Rotation3d
+            impl<'de, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                for Rotation3d<Number>
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::RotationRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    Ok(Self::from_quaternion(repr.quaternion))
+                }
+            }
+        }
+    };
+}
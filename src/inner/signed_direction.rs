@@ -178,31 +178,213 @@ macro_rules! inner_define_signed_direction {
         {
         }
 
+        // Circular statistics of samples of directions.
+        impl<Unit: AngleMeasurementUnit<Property = Angle>, Number: ArithmeticOps>
+            SignedDirection<Unit, Number>
+        {
+            // The direction of the mean of the unit vectors having the specified directions.
+            // For an empty sample, or for directions spread evenly,
+            // so that the mean resultant length is practically zero, it returns `None`.
+            pub fn circular_mean<I: IntoIterator<Item = Self>>(directions: I) -> Option<Self> {
+                let (mean, length) = rs_measures::circular_statistics::mean_resultant(
+                    directions.into_iter().map(|d| d.value),
+                    Unit::CYCLE_FRACTION,
+                )?;
+                if length < Number::from_f64(1e-6) {
+                    None
+                } else {
+                    Some(Self::new(mean))
+                }
+            }
+
+            // The length of the mean of the unit vectors having the specified directions,
+            // between 0, for directions spread evenly, and 1, for coincident directions.
+            // For an empty sample, it returns `None`.
+            pub fn mean_resultant_length<I: IntoIterator<Item = Self>>(directions: I) -> Option<Number> {
+                rs_measures::circular_statistics::mean_resultant(
+                    directions.into_iter().map(|d| d.value),
+                    Unit::CYCLE_FRACTION,
+                )
+                .map(|(_, length)| length)
+            }
+
+            // The circular variance, that is one minus the mean resultant length,
+            // between 0, for coincident directions, and 1, for directions spread evenly.
+            // For an empty sample, it returns `None`.
+            pub fn circular_variance<I: IntoIterator<Item = Self>>(directions: I) -> Option<Number> {
+                Self::mean_resultant_length(directions).map(|length| Number::ONE - length)
+            }
+
+            // The circular standard deviation, that is the square root of
+            // minus twice the natural logarithm of the mean resultant length.
+            // For a small spread, it is near to the standard deviation of the angles.
+            // For an empty sample, it returns `None`.
+            pub fn circular_standard_deviation<I: IntoIterator<Item = Self>>(
+                directions: I,
+            ) -> Option<Measure<Unit, Number>> {
+                Self::mean_resultant_length(directions).map(|length| {
+                    // For coincident directions, it is zero, and not minus zero.
+                    let radians = if length < Number::ONE {
+                        let ln_length = length.ln();
+                        (-(ln_length + ln_length)).sqrt()
+                    } else {
+                        Number::ZERO
+                    };
+                    Measure::<Unit, Number>::new(
+                        radians * Number::from_f64(Unit::CYCLE_FRACTION / core::f64::consts::TAU),
+                    )
+                })
+            }
+
+            // The direction of the sample which minimizes the sum of the angular distances
+            // from all the directions of the sample.
+            // For an empty sample, it returns `None`.
+            pub fn circular_median<I: IntoIterator<Item = Self>>(directions: I) -> Option<Self> {
+                let values: Vec<Number> = directions.into_iter().map(|d| d.value).collect();
+                rs_measures::circular_statistics::median(&values, Unit::CYCLE_FRACTION).map(Self::new)
+            }
+        }
+
+        impl<Unit: AngleMeasurementUnit<Property = Angle>, Number: ArithmeticOps>
+            SignedDirection<Unit, Number>
+        {
+            // The direction formatted as degrees, minutes and seconds, like 45°30'15.2".
+            pub fn dms(&self) -> rs_measures::direction_format::Dms<Number> {
+                rs_measures::direction_format::Dms::new(
+                    self.value * Number::from_f64(360. / Unit::CYCLE_FRACTION),
+                )
+            }
+
+            // The direction parsed from degrees, minutes and seconds, like 45°30'15.2".
+            pub fn parse_dms(text: &str) -> Result<Self, rs_measures::parsing::ParseMeasureError> {
+                let degrees = rs_measures::direction_format::parse_dms::<Number>(text)?;
+                Ok(Self::new(degrees * Number::from_f64(Unit::CYCLE_FRACTION / 360.)))
+            }
+
+            // The angle clockwise from north, that is from the Y axis, in degrees.
+            fn bearing_degrees(&self) -> Number {
+                let quarter_cycle = Number::from_f64(Unit::CYCLE_FRACTION / 4.);
+                UnsignedDirection::<Unit, Number>::new(quarter_cycle - self.value).value
+                    * Number::from_f64(360. / Unit::CYCLE_FRACTION)
+            }
+
+            // The direction formatted as a nautical bearing, clockwise from north, like 045°.
+            pub fn bearing(&self) -> rs_measures::direction_format::Bearing<Number> {
+                rs_measures::direction_format::Bearing::new(self.bearing_degrees())
+            }
+
+            // The direction parsed from a nautical bearing, clockwise from north, like 045°.
+            pub fn parse_bearing(text: &str) -> Result<Self, rs_measures::parsing::ParseMeasureError> {
+                let degrees = rs_measures::direction_format::parse_bearing::<Number>(text)?;
+                Ok(Self::new(
+                    Number::from_f64(Unit::CYCLE_FRACTION / 4.)
+                        - degrees * Number::from_f64(Unit::CYCLE_FRACTION / 360.),
+                ))
+            }
+
+            // The name of the nearest point of a compass rose having the specified resolution,
+            // like "NE" or "NNW".
+            pub fn compass_point(&self, points: rs_measures::direction_format::CompassPoints) -> &'static str {
+                rs_measures::direction_format::compass_point(self.bearing_degrees(), points)
+            }
+
+            // The direction of a point of the compass rose, like "NE" or "NNW".
+            pub fn parse_compass_point(text: &str) -> Result<Self, rs_measures::parsing::ParseMeasureError> {
+                let degrees = rs_measures::direction_format::parse_compass_point::<Number>(text)?;
+                Ok(Self::new(
+                    Number::from_f64(Unit::CYCLE_FRACTION / 4.)
+                        - degrees * Number::from_f64(Unit::CYCLE_FRACTION / 360.),
+                ))
+            }
+        }
+
         // format!("{}", SignedDirection)
         impl<Unit, Number> fmt::Display for SignedDirection<Unit, Number>
         where
-            Unit: MeasurementUnit,
+            Unit: AngleMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("at ")?;
                 fmt::Display::fmt(&self.value, formatter)?;
                 formatter.write_str(Unit::SUFFIX)?;
-                formatter.write_str(" (in -180°..180°)")
+                formatter.write_str(&rs_measures::direction_format::direction_range(
+                    Unit::SUFFIX,
+                    Unit::CYCLE_FRACTION,
+                    true,
+                ))
             }
         }
 
         // format!("{:?}", SignedDirection)
         impl<Unit, Number> fmt::Debug for SignedDirection<Unit, Number>
         where
-            Unit: MeasurementUnit,
+            Unit: AngleMeasurementUnit,
             Number: ArithmeticOps,
         {
             fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
                 formatter.write_str("at ")?;
                 fmt::Display::fmt(&self.value, formatter)?;
                 formatter.write_str(Unit::SUFFIX)?;
-                formatter.write_str(" (in -180°..180°)")
+                formatter.write_str(&rs_measures::direction_format::direction_range(
+                    Unit::SUFFIX,
+                    Unit::CYCLE_FRACTION,
+                    true,
+                ))
+            }
+        }
+
+        // "...".parse::<SignedDirection>()
+        impl<Unit: AngleMeasurementUnit<Property = Angle>, Number: ArithmeticOps> std::str::FromStr
+            for SignedDirection<Unit, Number>
+        {
+            type Err = rs_measures::parsing::ParseMeasureError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut parser = rs_measures::parsing::Parser::new(s);
+                parser.text("at")?;
+                let value = parser.number()?;
+                parser.suffix_with_trailers(
+                    Unit::SUFFIX,
+                    &[
+                        &rs_measures::direction_format::direction_range(
+                            Unit::SUFFIX,
+                            Unit::CYCLE_FRACTION,
+                            true,
+                        ),
+                        rs_measures::direction_format::legacy_direction_range(true),
+                    ],
+                )?;
+                Ok(Self::new(value))
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for SignedDirection
+            impl<Unit: AngleMeasurementUnit<Property = Angle>, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                for SignedDirection<Unit, Number>
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::ScalarRepr {
+                            value: self.value,
+                            unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for SignedDirection
+            impl<'de, Unit: AngleMeasurementUnit<Property = Angle>, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                for SignedDirection<Unit, Number>
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::ScalarRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                    Ok(Self::new(repr.value))
+                }
             }
         }
     };
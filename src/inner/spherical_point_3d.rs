@@ -0,0 +1,233 @@
+#[macro_export]
+macro_rules! inner_define_spherical_point_3d {
+    {} => {
+        // A point in the space, expressed in spherical coordinates:
+        // its distance from the origin, the azimuth of its position vector,
+        // counterclockwise from the X axis, and the elevation of its position vector
+        // from the XY plane, positive towards the Z axis, like in `Direction3d`.
+        pub struct SphericalPoint3d<LengthUnit: MeasurementUnit, AngleUnit, Number: ArithmeticOps = f64> {
+            pub radius: Measure<LengthUnit, Number>,
+            pub azimuth: UnsignedDirection<AngleUnit, Number>,
+            // It is between -90 and +90 degrees.
+            pub elevation: SignedDirection<AngleUnit, Number>,
+        }
+
+        impl<LengthUnit, AngleUnit, Number> SphericalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            pub const fn new(
+                radius: Measure<LengthUnit, Number>,
+                azimuth: UnsignedDirection<AngleUnit, Number>,
+                elevation: SignedDirection<AngleUnit, Number>,
+            ) -> Self {
+                Self {
+                    radius,
+                    azimuth,
+                    elevation,
+                }
+            }
+
+            // The spherical coordinates of a point expressed in cartesian coordinates.
+            // For the points on the Z axis, the azimuth is zero,
+            // and for the origin, also the elevation is zero.
+            pub fn from_point(point: MeasurePoint3d<LengthUnit, Number>) -> Self {
+                let horizontal = (point.x * point.x + point.y * point.y).sqrt();
+                let factor = Number::ONE / Number::from_f64(AngleUnit::RATIO);
+                Self::new(
+                    Measure::<LengthUnit, Number>::new(
+                        (horizontal * horizontal + point.z * point.z).sqrt(),
+                    ),
+                    UnsignedDirection::<AngleUnit, Number>::new(point.y.atan2(point.x) * factor),
+                    SignedDirection::<AngleUnit, Number>::new(point.z.atan2(horizontal) * factor),
+                )
+            }
+
+            // The cartesian coordinates of the point.
+            pub fn to_point(&self) -> MeasurePoint3d<LengthUnit, Number> {
+                let factor = Number::from_f64(AngleUnit::RATIO);
+                let (sin_az, cos_az) = (self.azimuth.value * factor).sin_cos();
+                let (sin_el, cos_el) = (self.elevation.value * factor).sin_cos();
+                let horizontal = self.radius.value * cos_el;
+                MeasurePoint3d::<LengthUnit, Number>::new(
+                    horizontal * cos_az,
+                    horizontal * sin_az,
+                    self.radius.value * sin_el,
+                )
+            }
+
+            // The direction of the position vector of the point.
+            pub fn direction(&self) -> Direction3d<AngleUnit, Number> {
+                Direction3d::<AngleUnit, Number>::new(self.azimuth.value, self.elevation.value)
+            }
+
+            pub fn convert<
+                DestLengthUnit: MeasurementUnit<Property = LengthUnit::Property>,
+                DestAngleUnit: AngleMeasurementUnit<Property = Angle>,
+            >(
+                &self,
+            ) -> SphericalPoint3d<DestLengthUnit, DestAngleUnit, Number>
+            where
+                DestLengthUnit::Property: VectorProperty,
+            {
+                SphericalPoint3d::<DestLengthUnit, DestAngleUnit, Number>::new(
+                    self.radius.convert::<DestLengthUnit>(),
+                    self.azimuth.convert::<DestAngleUnit>(),
+                    self.elevation.convert::<DestAngleUnit>(),
+                )
+            }
+        }
+
+        // SphericalPoint3d -> MeasurePoint3d
+        impl<LengthUnit, AngleUnit, Number> From<SphericalPoint3d<LengthUnit, AngleUnit, Number>>
+            for MeasurePoint3d<LengthUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn from(point: SphericalPoint3d<LengthUnit, AngleUnit, Number>) -> Self {
+                point.to_point()
+            }
+        }
+
+        // MeasurePoint3d -> SphericalPoint3d
+        impl<LengthUnit, AngleUnit, Number> From<MeasurePoint3d<LengthUnit, Number>>
+            for SphericalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn from(point: MeasurePoint3d<LengthUnit, Number>) -> Self {
+                Self::from_point(point)
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> Default for SphericalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            LengthUnit::Property: VectorProperty,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            // It returns the origin.
+            fn default() -> Self {
+                Self::new(
+                    Measure::<LengthUnit, Number>::new(Number::ZERO),
+                    UnsignedDirection::<AngleUnit, Number>::new(Number::ZERO),
+                    SignedDirection::<AngleUnit, Number>::new(Number::ZERO),
+                )
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> Clone for SphericalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<LengthUnit, AngleUnit, Number> Copy for SphericalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+        }
+
+        impl<LengthUnit, AngleUnit, Number> PartialEq for SphericalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn eq(&self, other: &Self) -> bool {
+                self.radius.value == other.radius.value
+                    && self.azimuth.value == other.azimuth.value
+                    && self.elevation.value == other.elevation.value
+            }
+        }
+
+        // format!("{}", SphericalPoint3d)
+        impl<LengthUnit, AngleUnit, Number> fmt::Display for SphericalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(formatter, "radius ")?;
+                fmt::Display::fmt(&self.radius.value, formatter)?;
+                write!(formatter, "{}, azimuth ", LengthUnit::SUFFIX)?;
+                fmt::Display::fmt(&self.azimuth.value, formatter)?;
+                write!(formatter, "{}, elevation ", AngleUnit::SUFFIX)?;
+                fmt::Display::fmt(&self.elevation.value, formatter)?;
+                write!(formatter, "{}", AngleUnit::SUFFIX)
+            }
+        }
+
+        // format!("{:?}", SphericalPoint3d)
+        impl<LengthUnit, AngleUnit, Number> fmt::Debug for SphericalPoint3d<LengthUnit, AngleUnit, Number>
+        where
+            LengthUnit: MeasurementUnit,
+            AngleUnit: AngleMeasurementUnit<Property = Angle>,
+            Number: ArithmeticOps,
+        {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, formatter)
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for SphericalPoint3d
+            impl<LengthUnit: MeasurementUnit, AngleUnit, Number: ArithmeticOps> rs_measures::serde::Serialize
+                for SphericalPoint3d<LengthUnit, AngleUnit, Number>
+            where
+                Measure<LengthUnit, Number>: rs_measures::serde::Serialize,
+                UnsignedDirection<AngleUnit, Number>: rs_measures::serde::Serialize,
+                SignedDirection<AngleUnit, Number>: rs_measures::serde::Serialize,
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::SphericalRepr {
+                            radius: &self.radius,
+                            azimuth: &self.azimuth,
+                            elevation: &self.elevation,
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for SphericalPoint3d
+            impl<'de, LengthUnit: MeasurementUnit, AngleUnit, Number: ArithmeticOps> rs_measures::serde::Deserialize<'de>
+                for SphericalPoint3d<LengthUnit, AngleUnit, Number>
+            where
+                Measure<LengthUnit, Number>: rs_measures::serde::Deserialize<'de>,
+                UnsignedDirection<AngleUnit, Number>: rs_measures::serde::Deserialize<'de>,
+                SignedDirection<AngleUnit, Number>: rs_measures::serde::Deserialize<'de>,
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::SphericalRepr<Measure<LengthUnit, Number>, UnsignedDirection<AngleUnit, Number>, SignedDirection<AngleUnit, Number>> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    Ok(Self {
+                        radius: repr.radius,
+                        azimuth: repr.azimuth,
+                        elevation: repr.elevation,
+                    })
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,211 @@
+#[macro_export]
+macro_rules! inner_define_tensor_2d {
+    { $with_transformations:tt } => {
+        // A symmetric tensor of rank 2 in a plane, like a plane stress tensor,
+        // represented by the three independent components of its matrix.
+        pub struct Tensor2d<Unit, Number: ArithmeticOps = f64> {
+            pub xx: Number,
+            pub yy: Number,
+            pub xy: Number,
+            phantom: PhantomData<Unit>,
+        }
+
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Tensor2d<Unit, Number> {
+            pub const fn new(xx: Number, yy: Number, xy: Number) -> Self {
+                Self {
+                    xx,
+                    yy,
+                    xy,
+                    phantom: PhantomData,
+                }
+            }
+
+            // It builds a tensor from a matrix, which is assumed to be symmetric.
+            // Only the diagonal and the upper triangle of the matrix are used.
+            pub fn from_matrix(matrix: [[Number; 2]; 2]) -> Self {
+                Self::new(matrix[0][0], matrix[1][1], matrix[0][1])
+            }
+
+            pub fn matrix(&self) -> [[Number; 2]; 2] {
+                [[self.xx, self.xy], [self.xy, self.yy]]
+            }
+
+            pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                &self,
+            ) -> Tensor2d<DestUnit, Number> {
+                let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                Tensor2d::<DestUnit, Number>::new(self.xx * factor, self.yy * factor, self.xy * factor)
+            }
+
+            // The sum of the diagonal components, which is invariant by rotations.
+            pub fn trace(&self) -> Measure<Unit, Number> {
+                Measure::<Unit, Number>::new(self.xx + self.yy)
+            }
+
+            // The eigenvalues of the tensor, in decreasing order.
+            pub fn principal_values(&self) -> [Measure<Unit, Number>; 2] {
+                rs_measures::matrix_utils::symmetric_eigen_decomposition(&self.matrix())
+                    .0
+                    .map(Measure::<Unit, Number>::new)
+            }
+
+            // The unit vectors of the principal axes of the tensor,
+            // in the order of the values returned by `principal_values`.
+            // The sign of every vector is arbitrary.
+            pub fn principal_directions<DirectionUnit: MeasurementUnit>(
+                &self,
+            ) -> [Measure2d<DirectionUnit, Number>; 2]
+            where
+                DirectionUnit::Property: VectorProperty,
+            {
+                rs_measures::matrix_utils::symmetric_eigen_decomposition(&self.matrix())
+                    .1
+                    .map(|v| Measure2d::<DirectionUnit, Number>::new(v[0], v[1]))
+            }
+
+            // The von Mises equivalent of a plane stress tensor, that is the uniaxial stress
+            // having the same distortion energy.
+            pub fn von_mises(&self) -> Measure<Unit, Number> {
+                Measure::<Unit, Number>::new(
+                    (self.xx * self.xx - self.xx * self.yy
+                        + self.yy * self.yy
+                        + Number::from_f64(3.) * self.xy * self.xy)
+                        .sqrt(),
+                )
+            }
+        }
+
+        rs_measures::if_true! { $with_transformations,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Tensor2d<Unit, Number> {
+                // The same tensor, expressed in a frame rotated by the specified map,
+                // that is R T R', where R is the matrix of the map.
+                // Precondition: the map is a rotation.
+                pub fn rotated(&self, rotation: &LinearMap2d<Number>) -> Self {
+                    let matrix = rs_measures::matrix_utils::matrix_product(&rotation.c, &self.matrix());
+                    let transposed: [[Number; 2]; 2] = core::array::from_fn(|i| core::array::from_fn(|j| rotation.c[j][i]));
+                    Self::from_matrix(rs_measures::matrix_utils::matrix_product(&matrix, &transposed))
+                }
+            }
+        }
+
+        // -Tensor2d -> Tensor2d
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Neg for Tensor2d<Unit, Number> {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                Self::new(-self.xx, -self.yy, -self.xy)
+            }
+        }
+
+        // Tensor2d + Tensor2d -> Tensor2d
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Tensor2d<Unit, Number>> for Tensor2d<Unit, Number> {
+            type Output = Self;
+            fn add(self, other: Tensor2d<Unit, Number>) -> Self::Output {
+                Self::new(self.xx + other.xx, self.yy + other.yy, self.xy + other.xy)
+            }
+        }
+
+        // Tensor2d - Tensor2d -> Tensor2d
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Tensor2d<Unit, Number>> for Tensor2d<Unit, Number> {
+            type Output = Self;
+            fn sub(self, other: Tensor2d<Unit, Number>) -> Self::Output {
+                self + -other
+            }
+        }
+
+        // Tensor2d * Number -> Tensor2d
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Mul<Number> for Tensor2d<Unit, Number> {
+            type Output = Self;
+            fn mul(self, n: Number) -> Self::Output {
+                Self::new(self.xx * n, self.yy * n, self.xy * n)
+            }
+        }
+
+        // Tensor2d / Number -> Tensor2d
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Tensor2d<Unit, Number> {
+            type Output = Self;
+            fn div(self, n: Number) -> Self::Output {
+                self * (Number::ONE / n)
+            }
+        }
+
+        impl<Unit, Number: ArithmeticOps> Default for Tensor2d<Unit, Number> {
+            // It returns the zero tensor.
+            fn default() -> Self {
+                Self {
+                    xx: Number::ZERO,
+                    yy: Number::ZERO,
+                    xy: Number::ZERO,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<Unit, Number: ArithmeticOps> Clone for Tensor2d<Unit, Number> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<Unit, Number: ArithmeticOps> Copy for Tensor2d<Unit, Number> {}
+
+        impl<Unit, Number: ArithmeticOps> PartialEq for Tensor2d<Unit, Number> {
+            fn eq(&self, other: &Self) -> bool {
+                self.xx == other.xx && self.yy == other.yy && self.xy == other.xy
+            }
+        }
+
+        // format!("{}", Tensor2d)
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Tensor2d<Unit, Number> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "{}",
+                    rs_measures::matrix_utils::format_matrix::<2, 2, Number>(&self.matrix(), Unit::SUFFIX)
+                )
+            }
+        }
+
+        // format!("{:?}", Tensor2d)
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Tensor2d<Unit, Number> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "{}",
+                    rs_measures::matrix_utils::format_matrix::<2, 2, Number>(&self.matrix(), Unit::SUFFIX)
+                )
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for Tensor2d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                for Tensor2d<Unit, Number>
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::Tensor2dRepr {
+                            xx: self.xx,
+                            yy: self.yy,
+                            xy: self.xy,
+                            unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for Tensor2d
+            impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                for Tensor2d<Unit, Number>
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::Tensor2dRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                    Ok(Self::new(repr.xx, repr.yy, repr.xy))
+                }
+            }
+        }
+    };
+}
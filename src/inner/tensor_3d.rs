@@ -0,0 +1,256 @@
+#[macro_export]
+macro_rules! inner_define_tensor_3d {
+    { $with_transformations:tt } => {
+        // A symmetric tensor of rank 2 in the space, like a stress tensor,
+        // represented by the six independent components of its matrix.
+        pub struct Tensor3d<Unit, Number: ArithmeticOps = f64> {
+            pub xx: Number,
+            pub yy: Number,
+            pub zz: Number,
+            pub xy: Number,
+            pub xz: Number,
+            pub yz: Number,
+            phantom: PhantomData<Unit>,
+        }
+
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Tensor3d<Unit, Number> {
+            pub const fn new(xx: Number, yy: Number, zz: Number, xy: Number, xz: Number, yz: Number) -> Self {
+                Self {
+                    xx,
+                    yy,
+                    zz,
+                    xy,
+                    xz,
+                    yz,
+                    phantom: PhantomData,
+                }
+            }
+
+            // It builds a tensor from a matrix, which is assumed to be symmetric.
+            // Only the diagonal and the upper triangle of the matrix are used.
+            pub fn from_matrix(matrix: [[Number; 3]; 3]) -> Self {
+                Self::new(
+                    matrix[0][0],
+                    matrix[1][1],
+                    matrix[2][2],
+                    matrix[0][1],
+                    matrix[0][2],
+                    matrix[1][2],
+                )
+            }
+
+            pub fn matrix(&self) -> [[Number; 3]; 3] {
+                [
+                    [self.xx, self.xy, self.xz],
+                    [self.xy, self.yy, self.yz],
+                    [self.xz, self.yz, self.zz],
+                ]
+            }
+
+            pub fn convert<DestUnit: MeasurementUnit<Property = Unit::Property>>(
+                &self,
+            ) -> Tensor3d<DestUnit, Number> {
+                let factor = Number::from_f64(Unit::RATIO / DestUnit::RATIO);
+                Tensor3d::<DestUnit, Number>::new(
+                    self.xx * factor,
+                    self.yy * factor,
+                    self.zz * factor,
+                    self.xy * factor,
+                    self.xz * factor,
+                    self.yz * factor,
+                )
+            }
+
+            // The sum of the diagonal components, which is invariant by rotations.
+            pub fn trace(&self) -> Measure<Unit, Number> {
+                Measure::<Unit, Number>::new(self.xx + self.yy + self.zz)
+            }
+
+            // The eigenvalues of the tensor, in decreasing order.
+            pub fn principal_values(&self) -> [Measure<Unit, Number>; 3] {
+                rs_measures::matrix_utils::symmetric_eigen_decomposition(&self.matrix())
+                    .0
+                    .map(Measure::<Unit, Number>::new)
+            }
+
+            // The unit vectors of the principal axes of the tensor,
+            // in the order of the values returned by `principal_values`.
+            // The sign of every vector is arbitrary.
+            pub fn principal_directions<DirectionUnit: MeasurementUnit>(
+                &self,
+            ) -> [Measure3d<DirectionUnit, Number>; 3]
+            where
+                DirectionUnit::Property: VectorProperty,
+            {
+                rs_measures::matrix_utils::symmetric_eigen_decomposition(&self.matrix())
+                    .1
+                    .map(|v| Measure3d::<DirectionUnit, Number>::new(v[0], v[1], v[2]))
+            }
+
+            // The von Mises equivalent of a stress tensor, that is the uniaxial stress
+            // having the same distortion energy.
+            pub fn von_mises(&self) -> Measure<Unit, Number> {
+                let dxy = self.xx - self.yy;
+                let dyz = self.yy - self.zz;
+                let dzx = self.zz - self.xx;
+                let shear = self.xy * self.xy + self.xz * self.xz + self.yz * self.yz;
+                Measure::<Unit, Number>::new(
+                    (Number::HALF * (dxy * dxy + dyz * dyz + dzx * dzx)
+                        + Number::from_f64(3.) * shear)
+                        .sqrt(),
+                )
+            }
+        }
+
+        rs_measures::if_true! { $with_transformations,
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps> Tensor3d<Unit, Number> {
+                // The same tensor, expressed in a frame rotated by the specified map,
+                // that is R T R', where R is the matrix of the map.
+                // Precondition: the map is a rotation.
+                pub fn rotated(&self, rotation: &LinearMap3d<Number>) -> Self {
+                    let matrix = rs_measures::matrix_utils::matrix_product(&rotation.c, &self.matrix());
+                    let transposed: [[Number; 3]; 3] = core::array::from_fn(|i| core::array::from_fn(|j| rotation.c[j][i]));
+                    Self::from_matrix(rs_measures::matrix_utils::matrix_product(&matrix, &transposed))
+                }
+            }
+        }
+
+        // -Tensor3d -> Tensor3d
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Neg for Tensor3d<Unit, Number> {
+            type Output = Self;
+            fn neg(self) -> Self::Output {
+                Self::new(-self.xx, -self.yy, -self.zz, -self.xy, -self.xz, -self.yz)
+            }
+        }
+
+        // Tensor3d + Tensor3d -> Tensor3d
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Add<Tensor3d<Unit, Number>> for Tensor3d<Unit, Number> {
+            type Output = Self;
+            fn add(self, other: Tensor3d<Unit, Number>) -> Self::Output {
+                Self::new(
+                    self.xx + other.xx,
+                    self.yy + other.yy,
+                    self.zz + other.zz,
+                    self.xy + other.xy,
+                    self.xz + other.xz,
+                    self.yz + other.yz,
+                )
+            }
+        }
+
+        // Tensor3d - Tensor3d -> Tensor3d
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Sub<Tensor3d<Unit, Number>> for Tensor3d<Unit, Number> {
+            type Output = Self;
+            fn sub(self, other: Tensor3d<Unit, Number>) -> Self::Output {
+                self + -other
+            }
+        }
+
+        // Tensor3d * Number -> Tensor3d
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Mul<Number> for Tensor3d<Unit, Number> {
+            type Output = Self;
+            fn mul(self, n: Number) -> Self::Output {
+                Self::new(self.xx * n, self.yy * n, self.zz * n, self.xy * n, self.xz * n, self.yz * n)
+            }
+        }
+
+        // Tensor3d / Number -> Tensor3d
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> Div<Number> for Tensor3d<Unit, Number> {
+            type Output = Self;
+            fn div(self, n: Number) -> Self::Output {
+                self * (Number::ONE / n)
+            }
+        }
+
+        impl<Unit, Number: ArithmeticOps> Default for Tensor3d<Unit, Number> {
+            // It returns the zero tensor.
+            fn default() -> Self {
+                Self {
+                    xx: Number::ZERO,
+                    yy: Number::ZERO,
+                    zz: Number::ZERO,
+                    xy: Number::ZERO,
+                    xz: Number::ZERO,
+                    yz: Number::ZERO,
+                    phantom: PhantomData,
+                }
+            }
+        }
+
+        impl<Unit, Number: ArithmeticOps> Clone for Tensor3d<Unit, Number> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<Unit, Number: ArithmeticOps> Copy for Tensor3d<Unit, Number> {}
+
+        impl<Unit, Number: ArithmeticOps> PartialEq for Tensor3d<Unit, Number> {
+            fn eq(&self, other: &Self) -> bool {
+                self.xx == other.xx
+                    && self.yy == other.yy
+                    && self.zz == other.zz
+                    && self.xy == other.xy
+                    && self.xz == other.xz
+                    && self.yz == other.yz
+            }
+        }
+
+        // format!("{}", Tensor3d)
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Display for Tensor3d<Unit, Number> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "{}",
+                    rs_measures::matrix_utils::format_matrix::<3, 3, Number>(&self.matrix(), Unit::SUFFIX)
+                )
+            }
+        }
+
+        // format!("{:?}", Tensor3d)
+        impl<Unit: MeasurementUnit, Number: ArithmeticOps> fmt::Debug for Tensor3d<Unit, Number> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(
+                    formatter,
+                    "{}",
+                    rs_measures::matrix_utils::format_matrix::<3, 3, Number>(&self.matrix(), Unit::SUFFIX)
+                )
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Serialize for Tensor3d
+            impl<Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Serialize> rs_measures::serde::Serialize
+                for Tensor3d<Unit, Number>
+            {
+                fn serialize<S: rs_measures::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    rs_measures::serde::Serialize::serialize(
+                        &rs_measures::serde_support::Tensor3dRepr {
+                            xx: self.xx,
+                            yy: self.yy,
+                            zz: self.zz,
+                            xy: self.xy,
+                            xz: self.xz,
+                            yz: self.yz,
+                            unit: rs_measures::serde_support::unit_name(Unit::SUFFIX),
+                        },
+                        serializer,
+                    )
+                }
+            }
+        }
+
+        rs_measures::if_serde! {
+            // serde::Deserialize for Tensor3d
+            impl<'de, Unit: MeasurementUnit, Number: ArithmeticOps + rs_measures::serde::Deserialize<'de>> rs_measures::serde::Deserialize<'de>
+                for Tensor3d<Unit, Number>
+            {
+                fn deserialize<D: rs_measures::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let repr = <rs_measures::serde_support::Tensor3dRepr<Number> as rs_measures::serde::Deserialize>::deserialize(deserializer)?;
+                    rs_measures::serde_support::check_unit::<D::Error>(&repr.unit, Unit::SUFFIX)?;
+                    Ok(Self::new(repr.xx, repr.yy, repr.zz, repr.xy, repr.xz, repr.yz))
+                }
+            }
+        }
+    };
+}
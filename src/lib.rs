@@ -1,6 +1,23 @@
 pub mod angle;
+#[cfg(feature = "catalog")]
+pub mod catalog;
+pub mod circular_statistics;
 pub mod define_measure_types;
 pub mod define_units_relationship;
+#[cfg(feature = "dimensions")]
+pub mod dimensions;
+pub mod direction_format;
 pub mod inner;
 pub mod matrix_utils;
+pub mod monte_carlo;
+pub mod parsing;
+#[cfg(feature = "serde")]
+pub mod serde_support;
 pub mod traits;
+pub mod uncertainty;
+pub mod unit_registry;
+
+#[cfg(feature = "derive")]
+pub use rs_measures_derive::{MeasurementUnit, VectorProperty};
+#[cfg(feature = "serde")]
+pub use serde;
@@ -65,3 +65,104 @@ fn format_column<const ROW_COUNT: usize, const COLUMN_COUNT: usize, Number: Arit
     }
     padded_cells
 }
+
+// It returns the product of two square matrices.
+// Applying the result to a column vector is equivalent to applying first `b` and then `a`.
+pub fn matrix_product<const N: usize, Number: ArithmeticOps>(
+    a: &[[Number; N]; N],
+    b: &[[Number; N]; N],
+) -> [[Number; N]; N] {
+    core::array::from_fn(|i| {
+        core::array::from_fn(|j| {
+            let mut sum = Number::ZERO;
+            for k in 0..N {
+                sum += a[i][k] * b[k][j];
+            }
+            sum
+        })
+    })
+}
+
+// It returns the inverse of a 2x2 matrix.
+pub fn inverse_2x2<Number: ArithmeticOps>(c: &[[Number; 2]; 2]) -> [[Number; 2]; 2] {
+    let inv_determinant = Number::ONE / (c[0][0] * c[1][1] - c[0][1] * c[1][0]);
+    [
+        [c[1][1] * inv_determinant, -c[0][1] * inv_determinant],
+        [-c[1][0] * inv_determinant, c[0][0] * inv_determinant],
+    ]
+}
+
+// It returns the inverse of a 3x3 matrix, computed by its adjugate matrix.
+pub fn inverse_3x3<Number: ArithmeticOps>(c: &[[Number; 3]; 3]) -> [[Number; 3]; 3] {
+    let cofactor = |i: usize, j: usize| {
+        let (i1, i2) = ((i + 1) % 3, (i + 2) % 3);
+        let (j1, j2) = ((j + 1) % 3, (j + 2) % 3);
+        c[i1][j1] * c[i2][j2] - c[i1][j2] * c[i2][j1]
+    };
+    let inv_determinant = Number::ONE
+        / (c[0][0] * cofactor(0, 0) + c[0][1] * cofactor(0, 1) + c[0][2] * cofactor(0, 2));
+    core::array::from_fn(|i| core::array::from_fn(|j| cofactor(j, i) * inv_determinant))
+}
+
+// It returns the eigenvalues of a symmetric matrix, in decreasing order,
+// and the corresponding unit eigenvectors, computed by the cyclic Jacobi method.
+// The eigenvector of the eigenvalue `values[i]` is `vectors[i]`.
+pub fn symmetric_eigen_decomposition<const N: usize, Number: ArithmeticOps>(
+    matrix: &[[Number; N]; N],
+) -> ([Number; N], [[Number; N]; N]) {
+    const MAX_SWEEPS: usize = 50;
+    let mut a = *matrix;
+    // The columns of `v` are the eigenvectors.
+    let mut v: [[Number; N]; N] = core::array::from_fn(|i| {
+        core::array::from_fn(|j| if i == j { Number::ONE } else { Number::ZERO })
+    });
+    let hundred = Number::from_f64(100.);
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+        for p in 0..N {
+            for q in p + 1..N {
+                let g = hundred * a[p][q].abs();
+                if a[p][p].abs() + g == a[p][p].abs() && a[q][q].abs() + g == a[q][q].abs() {
+                    a[p][q] = Number::ZERO;
+                    a[q][p] = Number::ZERO;
+                    continue;
+                }
+                rotated = true;
+                let theta = (a[q][q] - a[p][p]) / (a[p][q] + a[p][q]);
+                let t = if theta < Number::ZERO {
+                    -Number::ONE
+                } else {
+                    Number::ONE
+                } / (theta.abs() + (theta * theta + Number::ONE).sqrt());
+                let cos = Number::ONE / (t * t + Number::ONE).sqrt();
+                let sin = t * cos;
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = cos * akp - sin * akq;
+                    row[q] = sin * akp + cos * akq;
+                }
+                let (row_p, row_q) = (a[p], a[q]);
+                a[p] = core::array::from_fn(|k| cos * row_p[k] - sin * row_q[k]);
+                a[q] = core::array::from_fn(|k| sin * row_p[k] + cos * row_q[k]);
+                for row in v.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = cos * vkp - sin * vkq;
+                    row[q] = sin * vkp + cos * vkq;
+                }
+            }
+        }
+        if !rotated {
+            break;
+        }
+    }
+    let mut order: [usize; N] = core::array::from_fn(|i| i);
+    order.sort_by(|&i, &j| {
+        a[j][j]
+            .partial_cmp(&a[i][i])
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+    (
+        core::array::from_fn(|i| a[order[i]][order[i]]),
+        core::array::from_fn(|i| core::array::from_fn(|k| v[k][order[i]])),
+    )
+}
@@ -0,0 +1,156 @@
+use crate::traits::ArithmeticOps;
+use crate::uncertainty::principal_semi_axes;
+
+// A deterministic pseudo-random number generator,
+// used to draw the samples of a Monte Carlo evaluation of uncertainty.
+// It implements the algorithm xoshiro256**, seeded by the algorithm SplitMix64.
+// The same seed always produces the same sequence of samples.
+pub struct Sampler {
+    state: [u64; 4],
+    spare_standard_normal: Option<f64>,
+}
+
+impl Sampler {
+    pub fn new(seed: u64) -> Self {
+        let mut splitmix_state = seed;
+        Self {
+            state: core::array::from_fn(|_| {
+                splitmix_state = splitmix_state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+                let mut z = splitmix_state;
+                z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+                z ^ (z >> 31)
+            }),
+            spare_standard_normal: None,
+        }
+    }
+
+    // It returns the next 64 pseudo-random bits.
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+        result
+    }
+
+    // It returns a sample of the uniform distribution in the interval [0, 1).
+    pub fn uniform(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1. / (1_u64 << 53) as f64)
+    }
+
+    // It returns a sample of the normal distribution having mean 0
+    // and standard deviation 1.
+    // It uses the polar method by Marsaglia, which generates two samples at a time.
+    pub fn standard_normal(&mut self) -> f64 {
+        if let Some(spare) = self.spare_standard_normal.take() {
+            return spare;
+        }
+        loop {
+            let u = self.uniform() * 2. - 1.;
+            let v = self.uniform() * 2. - 1.;
+            let s = u * u + v * v;
+            if s > 0. && s < 1. {
+                let factor = (-2. * s.ln() / s).sqrt();
+                self.spare_standard_normal = Some(v * factor);
+                return u * factor;
+            }
+        }
+    }
+
+    // It returns a sample of the normal distribution
+    // having the specified mean and standard deviation.
+    pub fn normal<Number: ArithmeticOps>(
+        &mut self,
+        mean: Number,
+        standard_deviation: Number,
+    ) -> Number {
+        mean + standard_deviation * Number::from_f64(self.standard_normal())
+    }
+
+    // It returns a sample of the split normal distribution
+    // having the specified mode, and made of two halves of normal distributions
+    // having the specified standard deviations below and above the mode.
+    pub fn split_normal<Number: ArithmeticOps>(
+        &mut self,
+        mode: Number,
+        lower_standard_deviation: Number,
+        upper_standard_deviation: Number,
+    ) -> Number {
+        let total = lower_standard_deviation + upper_standard_deviation;
+        if total == Number::ZERO {
+            return mode;
+        }
+        let deviation = Number::from_f64(self.standard_normal().abs());
+        if Number::from_f64(self.uniform()) * total < lower_standard_deviation {
+            mode - deviation * lower_standard_deviation
+        } else {
+            mode + deviation * upper_standard_deviation
+        }
+    }
+
+    // It returns a sample of the multivariate normal distribution
+    // having the specified mean and covariance matrix.
+    pub fn multivariate_normal<Number: ArithmeticOps, const N: usize>(
+        &mut self,
+        mean: [Number; N],
+        covariance: &[[Number; N]; N],
+    ) -> [Number; N] {
+        let axes = principal_semi_axes(covariance);
+        let mut result = mean;
+        for axis in axes.iter() {
+            let z = Number::from_f64(self.standard_normal());
+            for (r, &a) in result.iter_mut().zip(axis.iter()) {
+                *r += a * z;
+            }
+        }
+        result
+    }
+}
+
+// It returns the arithmetic mean and the standard deviation of some samples.
+// Precondition: samples.len() >= 2
+pub fn mean_and_standard_deviation<Number: ArithmeticOps>(samples: &[Number]) -> (Number, Number) {
+    let count = Number::from_f64(samples.len() as f64);
+    let mean = samples.iter().copied().sum::<Number>() / count;
+    let sum_of_squares = samples
+        .iter()
+        .map(|&s| (s - mean) * (s - mean))
+        .sum::<Number>();
+    (mean, (sum_of_squares / (count - Number::ONE)).sqrt())
+}
+
+// It returns the quantile of some sorted samples, for the specified probability,
+// interpolating linearly between adjacent samples.
+// Precondition: !sorted_samples.is_empty() && 0 <= probability <= 1
+pub fn quantile<Number: ArithmeticOps>(sorted_samples: &[Number], probability: f64) -> Number {
+    let position = probability * (sorted_samples.len() - 1) as f64;
+    let index = position.floor() as usize;
+    if index + 1 >= sorted_samples.len() {
+        return sorted_samples[sorted_samples.len() - 1];
+    }
+    let fraction = Number::from_f64(position - index as f64);
+    sorted_samples[index] + (sorted_samples[index + 1] - sorted_samples[index]) * fraction
+}
+
+// It returns the shortest interval containing the specified fraction
+// of some sorted samples.
+// Precondition: !sorted_samples.is_empty() && 0 <= probability <= 1
+pub fn shortest_interval<Number: ArithmeticOps>(
+    sorted_samples: &[Number],
+    probability: f64,
+) -> (Number, Number) {
+    let count = sorted_samples.len();
+    let width = ((probability * count as f64).ceil() as usize).clamp(1, count);
+    let mut best = (sorted_samples[0], sorted_samples[width - 1]);
+    for window in sorted_samples.windows(width).skip(1) {
+        if window[width - 1] - window[0] < best.1 - best.0 {
+            best = (window[0], window[width - 1]);
+        }
+    }
+    best
+}
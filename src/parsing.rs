@@ -0,0 +1,173 @@
+use crate::traits::ArithmeticOps;
+use std::fmt;
+
+// The error returned when parsing a measure from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseMeasureError {
+    // A number was expected, but the specified text was found.
+    InvalidNumber(String),
+
+    // Some specified text was expected, but other text was found.
+    UnexpectedText {
+        expected: &'static str,
+        found: String,
+    },
+
+    // The unit of measurement having the specified suffix was expected,
+    // but the specified suffix was found.
+    WrongSuffix {
+        expected: &'static str,
+        found: String,
+    },
+
+    // No unit of measurement having the specified suffix is registered.
+    UnknownSuffix(String),
+
+    // Several units of measurement having the specified suffix are registered.
+    AmbiguousSuffix(String),
+}
+
+impl fmt::Display for ParseMeasureError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseMeasureError::InvalidNumber(found) => {
+                write!(formatter, "invalid number: \"{}\"", found)
+            }
+            ParseMeasureError::UnexpectedText { expected, found } => {
+                write!(formatter, "expected \"{}\", found \"{}\"", expected, found)
+            }
+            ParseMeasureError::WrongSuffix { expected, found } => write!(
+                formatter,
+                "mismatched unit of measurement: expected \"{}\", found \"{}\"",
+                expected, found
+            ),
+            ParseMeasureError::UnknownSuffix(found) => {
+                write!(formatter, "unknown unit of measurement: \"{}\"", found)
+            }
+            ParseMeasureError::AmbiguousSuffix(found) => {
+                write!(formatter, "ambiguous unit of measurement: \"{}\"", found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseMeasureError {}
+
+// A scanner of the strings produced by the implementations of `Display`
+// of the types generated by `define_measure_types!`.
+// Spaces are ignored before and after every number and every piece of text.
+pub struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self { rest: text }
+    }
+
+    // It skips the specified text, failing if it is not found.
+    pub fn text(&mut self, expected: &'static str) -> Result<(), ParseMeasureError> {
+        let rest = self.rest.trim_start();
+        match rest.strip_prefix(expected.trim()) {
+            Some(rest) => {
+                self.rest = rest;
+                Ok(())
+            }
+            None => Err(ParseMeasureError::UnexpectedText {
+                expected: expected.trim(),
+                found: rest.to_string(),
+            }),
+        }
+    }
+
+    // It skips the specified text, if it is found, and it returns whether it has been found.
+    pub fn optional_text(&mut self, expected: &'static str) -> bool {
+        self.text(expected).is_ok()
+    }
+
+    // It reads the longest number found at the current position.
+    pub fn number<Number: ArithmeticOps>(&mut self) -> Result<Number, ParseMeasureError> {
+        let rest = self.rest.trim_start();
+        let candidate_len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.'))
+            .unwrap_or(rest.len());
+        for len in (1..=candidate_len).rev() {
+            if let Ok(value) = rest[..len].parse::<f64>() {
+                self.rest = &rest[len..];
+                return Ok(Number::from_f64(value));
+            }
+        }
+        Err(ParseMeasureError::InvalidNumber(rest.to_string()))
+    }
+
+    // It reads a number, optionally followed by its symmetric uncertainty,
+    // like in "12.5 ± 0.1".
+    // A missing uncertainty is taken as zero.
+    pub fn number_with_uncertainty<Number: ArithmeticOps>(
+        &mut self,
+    ) -> Result<(Number, Number), ParseMeasureError> {
+        let value = self.number()?;
+        let uncertainty = if self.optional_text("\u{b1}") {
+            self.number()?
+        } else {
+            Number::ZERO
+        };
+        Ok((value, uncertainty))
+    }
+
+    // It reads a number, optionally followed by its upper and lower uncertainties,
+    // like in "12.5 +0.2/-0.1".
+    // It returns the value, the lower uncertainty, and the upper uncertainty.
+    // Missing uncertainties are taken as zero.
+    pub fn number_with_two_sided_uncertainty<Number: ArithmeticOps>(
+        &mut self,
+    ) -> Result<(Number, Number, Number), ParseMeasureError> {
+        let value = self.number()?;
+        if !self.optional_text("+") {
+            return Ok((value, Number::ZERO, Number::ZERO));
+        }
+        let upper_uncertainty = self.number()?;
+        self.text("/-")?;
+        let lower_uncertainty = self.number()?;
+        Ok((value, lower_uncertainty, upper_uncertainty))
+    }
+
+    // It returns whether only spaces are left.
+    pub fn is_at_end(&self) -> bool {
+        self.rest.trim().is_empty()
+    }
+
+    // It returns the rest of the string, without surrounding spaces.
+    pub fn rest(self) -> &'a str {
+        self.rest.trim()
+    }
+
+    // It checks that the rest of the string is the specified suffix,
+    // optionally followed by the specified trailer.
+    pub fn suffix(self, suffix: &'static str, trailer: &str) -> Result<(), ParseMeasureError> {
+        self.suffix_with_trailers(suffix, &[trailer])
+    }
+
+    // It checks that the rest of the string is the specified suffix,
+    // optionally followed by one of the specified trailers.
+    pub fn suffix_with_trailers(
+        self,
+        suffix: &'static str,
+        trailers: &[&str],
+    ) -> Result<(), ParseMeasureError> {
+        let rest = self.rest.trim();
+        let found = trailers
+            .iter()
+            .find_map(|trailer| rest.strip_suffix(trailer.trim()))
+            .unwrap_or(rest)
+            .trim_end();
+        if found == suffix.trim() {
+            Ok(())
+        } else {
+            Err(ParseMeasureError::WrongSuffix {
+                expected: suffix.trim(),
+                found: found.to_string(),
+            })
+        }
+    }
+}
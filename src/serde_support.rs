@@ -0,0 +1,240 @@
+// Serialized representations of the types generated by `define_measure_types!`.
+// They are used only when the feature `serde` is enabled.
+use serde::{de, Deserialize, Serialize};
+use std::borrow::Cow;
+
+// The name of a unit of measurement, as it is serialized.
+// It is the suffix of the unit, without surrounding spaces,
+// and it is omitted for units having an empty suffix.
+pub type UnitName = Option<Cow<'static, str>>;
+
+// It returns the serialized name of a unit having the specified suffix.
+pub fn unit_name(suffix: &'static str) -> UnitName {
+    let name = suffix.trim();
+    if name.is_empty() {
+        None
+    } else {
+        Some(Cow::Borrowed(name))
+    }
+}
+
+// It checks that a deserialized unit name, if present,
+// matches the unit having the specified suffix.
+pub fn check_unit<E: de::Error>(found: &UnitName, suffix: &'static str) -> Result<(), E> {
+    match found {
+        Some(found) if found.as_ref() != suffix.trim() => Err(E::custom(format_args!(
+            "mismatched unit of measurement: expected \"{}\", found \"{}\"",
+            suffix.trim(),
+            found
+        ))),
+        _ => Ok(()),
+    }
+}
+
+// Representation of `Measure`, `MeasurePoint`, `SignedDirection`, and `UnsignedDirection`,
+// when they have no uncertainty.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ScalarRepr<Number> {
+    pub value: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: UnitName,
+}
+
+// Representation of `Measure` and `MeasurePoint`, when they have symmetric uncertainty.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SymmetricScalarRepr<Number> {
+    pub value: Number,
+    pub uncertainty: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: UnitName,
+}
+
+// Representation of `Measure` and `MeasurePoint`, when they have two-sided uncertainty.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TwoSidedScalarRepr<Number> {
+    pub value: Number,
+    pub lower_uncertainty: Option<Number>,
+    pub upper_uncertainty: Option<Number>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: UnitName,
+}
+
+// Representation of `Measure2d` and `MeasurePoint2d`, when they have no uncertainty.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Vector2dRepr<Number> {
+    pub x: Number,
+    pub y: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: UnitName,
+}
+
+// Representation of `Measure2d` and `MeasurePoint2d`, when they have a covariance matrix.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SymmetricVector2dRepr<Number> {
+    pub x: Number,
+    pub y: Number,
+    pub covariance: Option<[[Number; 2]; 2]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: UnitName,
+}
+
+// Representation of `Measure2d` and `MeasurePoint2d`, when they have two-sided uncertainty.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TwoSidedVector2dRepr<Number> {
+    pub x: Number,
+    pub y: Number,
+    pub lower_uncertainty: Option<[Number; 2]>,
+    pub upper_uncertainty: Option<[Number; 2]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: UnitName,
+}
+
+// Representation of `Measure3d` and `MeasurePoint3d`, when they have no uncertainty.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Vector3dRepr<Number> {
+    pub x: Number,
+    pub y: Number,
+    pub z: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: UnitName,
+}
+
+// Representation of `Measure3d` and `MeasurePoint3d`, when they have a covariance matrix.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SymmetricVector3dRepr<Number> {
+    pub x: Number,
+    pub y: Number,
+    pub z: Number,
+    pub covariance: Option<[[Number; 3]; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: UnitName,
+}
+
+// Representation of `Measure3d` and `MeasurePoint3d`, when they have two-sided uncertainty.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TwoSidedVector3dRepr<Number> {
+    pub x: Number,
+    pub y: Number,
+    pub z: Number,
+    pub lower_uncertainty: Option<[Number; 3]>,
+    pub upper_uncertainty: Option<[Number; 3]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: UnitName,
+}
+
+// Representation of `LinearMap2d`, `LinearMap3d`, `AffineMap2d`, and `AffineMap3d`.
+// The linear maps have no unit of measurement.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MapRepr<Coefficients> {
+    pub coefficients: Coefficients,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: UnitName,
+}
+
+// Representation of `MeasureLinearMap2d` and `MeasureLinearMap3d`,
+// having a unit of measurement for their input and one for their output.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MeasureMapRepr<Coefficients> {
+    pub coefficients: Coefficients,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_unit: UnitName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub out_unit: UnitName,
+}
+
+// Representation of `Rotation3d`, by the components w, x, y, z of its quaternion.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RotationRepr<Number> {
+    pub quaternion: [Number; 4],
+}
+
+// Representation of `Tensor2d`.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Tensor2dRepr<Number> {
+    pub xx: Number,
+    pub yy: Number,
+    pub xy: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: UnitName,
+}
+
+// Representation of `Tensor3d`.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Tensor3dRepr<Number> {
+    pub xx: Number,
+    pub yy: Number,
+    pub zz: Number,
+    pub xy: Number,
+    pub xz: Number,
+    pub yz: Number,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: UnitName,
+}
+
+// Representation of `Direction3d`, by its unit vector,
+// which does not depend on the unit of angle.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Direction3dRepr<Number> {
+    pub unit_vector: [Number; 3],
+}
+
+// Representations of the types made of measures and directions,
+// in which every field is represented as the type of that field.
+
+// Representation of `PolarPoint2d`.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolarRepr<Radius, Azimuth> {
+    pub radius: Radius,
+    pub azimuth: Azimuth,
+}
+
+// Representation of `CylindricalPoint3d`.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CylindricalRepr<Length, Azimuth> {
+    pub radius: Length,
+    pub azimuth: Azimuth,
+    pub z: Length,
+}
+
+// Representation of `SphericalPoint3d`.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SphericalRepr<Radius, Azimuth, Elevation> {
+    pub radius: Radius,
+    pub azimuth: Azimuth,
+    pub elevation: Elevation,
+}
+
+// Representation of `Ellipsoid`.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EllipsoidRepr<Length, Number> {
+    pub semi_major_axis: Length,
+    pub flattening: Number,
+}
+
+// Representation of `GeodeticPoint`.
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GeodeticRepr<Angle, Length> {
+    pub latitude: Angle,
+    pub longitude: Angle,
+    pub height: Length,
+}
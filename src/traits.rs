@@ -139,6 +139,20 @@ impl Decibel for f64 {
     }
 }
 
+pub trait Logarithm {
+    fn ln(self) -> Self;
+}
+impl Logarithm for f32 {
+    fn ln(self) -> Self {
+        self.ln()
+    }
+}
+impl Logarithm for f64 {
+    fn ln(self) -> Self {
+        self.ln()
+    }
+}
+
 pub trait HasZero {
     const ZERO: Self;
 }
@@ -171,16 +185,23 @@ impl HasHalf for f64 {
 
 pub trait HasSign {
     fn signum(self) -> Self;
+    fn abs(self) -> Self;
 }
 impl HasSign for f32 {
     fn signum(self) -> Self {
         self.signum()
     }
+    fn abs(self) -> Self {
+        self.abs()
+    }
 }
 impl HasSign for f64 {
     fn signum(self) -> Self {
         self.signum()
     }
+    fn abs(self) -> Self {
+        self.abs()
+    }
 }
 
 pub trait ArithmeticOps:
@@ -200,6 +221,7 @@ pub trait ArithmeticOps:
     + Trigonometry<Output = Self>
     + InverseTrigonometry<Output = Self>
     + Decibel
+    + Logarithm
     + HasZero
     + HasOne
     + HasHalf
@@ -233,6 +255,7 @@ impl<T> ArithmeticOps for T where
         + Trigonometry<Output = Self>
         + InverseTrigonometry<Output = Self>
         + Decibel
+        + Logarithm
         + HasZero
         + HasOne
         + HasHalf
@@ -0,0 +1,134 @@
+use crate::matrix_utils::symmetric_eigen_decomposition;
+use crate::traits::ArithmeticOps;
+
+// It returns the square root of the sum of the squares of two numbers.
+// It is the way in which two independent uncertainties are combined.
+pub fn in_quadrature<Number: ArithmeticOps>(a: Number, b: Number) -> Number {
+    (a * a + b * b).sqrt()
+}
+
+// It receives the lower and upper uncertainties of a quantity,
+// and a factor, and returns the lower and upper uncertainties
+// of the product of that quantity by that factor.
+// If the factor is negative, the two uncertainties are swapped.
+pub fn scaled_two_sided<Number: ArithmeticOps>(
+    lower: Number,
+    upper: Number,
+    factor: Number,
+) -> (Number, Number) {
+    if factor >= Number::ZERO {
+        (lower * factor, upper * factor)
+    } else {
+        (upper * -factor, lower * -factor)
+    }
+}
+
+// It applies `in_quadrature` to every pair of corresponding components.
+pub fn in_quadrature_array<Number: ArithmeticOps, const N: usize>(
+    a: [Number; N],
+    b: [Number; N],
+) -> [Number; N] {
+    core::array::from_fn(|i| in_quadrature(a[i], b[i]))
+}
+
+// It applies `scaled_two_sided` to every component.
+pub fn scaled_two_sided_array<Number: ArithmeticOps, const N: usize>(
+    lower: [Number; N],
+    upper: [Number; N],
+    factor: Number,
+) -> ([Number; N], [Number; N]) {
+    if factor >= Number::ZERO {
+        (lower.map(|l| l * factor), upper.map(|u| u * factor))
+    } else {
+        (upper.map(|u| u * -factor), lower.map(|l| l * -factor))
+    }
+}
+
+// It returns the sum of two covariance matrices,
+// i.e. the covariance matrix of the sum of two independent vectors.
+pub fn summed_covariances<Number: ArithmeticOps, const N: usize>(
+    a: &[[Number; N]; N],
+    b: &[[Number; N]; N],
+) -> [[Number; N]; N] {
+    core::array::from_fn(|i| core::array::from_fn(|j| a[i][j] + b[i][j]))
+}
+
+// It returns the covariance matrix of a vector multiplied by a factor.
+pub fn scaled_covariance<Number: ArithmeticOps, const N: usize>(
+    covariance: &[[Number; N]; N],
+    factor: Number,
+) -> [[Number; N]; N] {
+    let squared_factor = factor * factor;
+    covariance.map(|row| row.map(|c| c * squared_factor))
+}
+
+// It returns the covariance matrix of a vector whose components are independent
+// and have the specified standard uncertainties.
+pub fn diagonal_covariance<Number: ArithmeticOps, const N: usize>(
+    uncertainties: [Number; N],
+) -> [[Number; N]; N] {
+    core::array::from_fn(|i| {
+        core::array::from_fn(|j| {
+            if i == j {
+                uncertainties[i] * uncertainties[i]
+            } else {
+                Number::ZERO
+            }
+        })
+    })
+}
+
+// It returns the matrix `c * covariance * transposed(c)`,
+// i.e. the covariance matrix of a vector transformed by the linear map `c`.
+pub fn transformed_covariance<Number: ArithmeticOps, const N: usize>(
+    c: &[[Number; N]; N],
+    covariance: &[[Number; N]; N],
+) -> [[Number; N]; N] {
+    let c_cov: [[Number; N]; N] = core::array::from_fn(|i| {
+        core::array::from_fn(|j| (0..N).map(|k| c[i][k] * covariance[k][j]).sum())
+    });
+    core::array::from_fn(|i| core::array::from_fn(|j| (0..N).map(|k| c_cov[i][k] * c[j][k]).sum()))
+}
+
+// It returns `transposed(g) * covariance * g`,
+// i.e. the variance of a scalar function of a vector having the specified covariance matrix,
+// where `g` is the gradient of the function.
+pub fn propagated_variance<Number: ArithmeticOps, const N: usize>(
+    covariance: &[[Number; N]; N],
+    g: [Number; N],
+) -> Number {
+    (0..N)
+        .map(|i| {
+            (0..N)
+                .map(|j| g[i] * covariance[i][j] * g[j])
+                .sum::<Number>()
+        })
+        .sum()
+}
+
+// It returns the covariance matrix of the vector `v * s`,
+// where `v` is exact and `s` has the specified standard uncertainty.
+pub fn collinear_covariance<Number: ArithmeticOps, const N: usize>(
+    v: [Number; N],
+    uncertainty: Number,
+) -> [[Number; N]; N] {
+    let scaled = v.map(|c| c * uncertainty);
+    core::array::from_fn(|i| core::array::from_fn(|j| scaled[i] * scaled[j]))
+}
+
+// It returns the principal semi-axes of the standard error ellipse (or ellipsoid)
+// described by a covariance matrix, one per row, the longest first.
+pub fn principal_semi_axes<Number: ArithmeticOps, const N: usize>(
+    covariance: &[[Number; N]; N],
+) -> [[Number; N]; N] {
+    let (eigenvalues, eigenvectors) = symmetric_eigen_decomposition(covariance);
+    core::array::from_fn(|i| {
+        // Rounding errors could make a null eigenvalue slightly negative.
+        let length = if eigenvalues[i] > Number::ZERO {
+            eigenvalues[i].sqrt()
+        } else {
+            Number::ZERO
+        };
+        eigenvectors[i].map(|c| c * length)
+    })
+}
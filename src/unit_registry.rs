@@ -0,0 +1,82 @@
+use crate::parsing::{ParseMeasureError, Parser};
+use crate::traits::{ArithmeticOps, MeasurementUnit};
+use std::marker::PhantomData;
+
+// The constants of a unit of measurement registered in a `UnitRegistry`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RegisteredUnit {
+    // The suffix of the unit, without surrounding spaces.
+    pub suffix: &'static str,
+    pub ratio: f64,
+    pub offset: f64,
+}
+
+// A collection of the units of measurement of a property,
+// used to parse measures expressed in any of them.
+pub struct UnitRegistry<Property> {
+    units: Vec<RegisteredUnit>,
+    phantom: PhantomData<Property>,
+}
+
+impl<Property> UnitRegistry<Property> {
+    pub fn new() -> Self {
+        Self {
+            units: Vec::new(),
+            phantom: PhantomData,
+        }
+    }
+
+    // It adds the specified unit to the registry, and returns the registry.
+    // Registering the same unit twice has no effect.
+    pub fn with_unit<Unit: MeasurementUnit<Property = Property>>(mut self) -> Self {
+        self.register::<Unit>();
+        self
+    }
+
+    // It adds the specified unit to the registry.
+    // Registering the same unit twice has no effect.
+    pub fn register<Unit: MeasurementUnit<Property = Property>>(&mut self) {
+        let unit = RegisteredUnit {
+            suffix: Unit::SUFFIX.trim(),
+            ratio: Unit::RATIO,
+            offset: Unit::OFFSET,
+        };
+        if !self.units.contains(&unit) {
+            self.units.push(unit);
+        }
+    }
+
+    // It returns the registered units, in order of registration.
+    pub fn units(&self) -> &[RegisteredUnit] {
+        &self.units
+    }
+
+    // It returns the only registered unit having the specified suffix.
+    // Spaces around the suffix are ignored.
+    pub fn find(&self, suffix: &str) -> Result<RegisteredUnit, ParseMeasureError> {
+        let suffix = suffix.trim();
+        let mut found = self.units.iter().filter(|unit| unit.suffix == suffix);
+        match (found.next(), found.next()) {
+            (Some(&unit), None) => Ok(unit),
+            (Some(_), Some(_)) => Err(ParseMeasureError::AmbiguousSuffix(suffix.to_string())),
+            (None, _) => Err(ParseMeasureError::UnknownSuffix(suffix.to_string())),
+        }
+    }
+
+    // It parses a number followed by the suffix of a registered unit,
+    // and it returns the number and that unit.
+    pub fn parse<Number: ArithmeticOps>(
+        &self,
+        text: &str,
+    ) -> Result<(Number, RegisteredUnit), ParseMeasureError> {
+        let mut parser = Parser::new(text);
+        let value = parser.number()?;
+        Ok((value, self.find(parser.rest())?))
+    }
+}
+
+impl<Property> Default for UnitRegistry<Property> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -12,7 +12,11 @@ fn compile_fail(tester: &TestCases, case: &str) {
 fn tests() {
     let t = TestCases::new();
     pass(&t, "1_is_1_mul_1"); // id1:1 == id2:1 * id3:1  =>  expand_1_1(id2, id3, id1)
+    pass(&t, "1_is_1_mul_1_mul_literal"); // id1:1 == id2:1 * id3:1 * k  =>  expand_1_1_scaled(id2, id3, id1, 1 / k)
+    pass(&t, "1_is_1_mul_1_div_literal"); // id1:1 == id2:1 * id3:1 / k  =>  expand_1_1_scaled(id2, id3, id1, k)
     pass(&t, "1_is_1_mul_itself"); // id1:1 == id2:1 * =:1  =>  expand_1_1(id2, id1)
+    pass(&t, "1_is_1_pow_2"); // id1:1 == id2:1 ^ 2      =>  expand_1_1_same(id2, id1)
+    pass(&t, "1_is_1_pow_3"); // id1:1 == id2:1 ^ 3      =>  expand_1_1_cube(id2, id1)
     pass(&t, "2_is_1_mul_2"); // id1:2 == id2:1 * id3:2  =>  expand_1_2(id2, id3, id1)
     pass(&t, "2_is_2_mul_1"); // id1:2 == id2:2 * id3:1  =>  expand_1_2(id3, id2, id1)
     pass(&t, "3_is_1_mul_3"); // id1:3 == id2:1 * id3:3  =>  expand_1_3(id2, id3, id1)
@@ -22,12 +26,21 @@ fn tests() {
     pass(&t, "1_is_3_mul_3"); // id1:1 == id2:3 * id3:3  =>  expand_3_3(id2, id3, id1)
     pass(&t, "1_is_3_mul_itself"); // id1:1 == id2:3 * =:3  =>  expand_3_3(id2, id1)
     pass(&t, "1_is_0_div_1"); // id1:1 == 1 / id3:1      =>  expand_inverse(id1, id3)
+    pass(&t, "1_is_1_div_1"); // id1:1 == id2:1 / id3:1  =>  expand_1_1(id1, id3, id2)
+    pass(&t, "2_is_2_div_1"); // id1:2 == id2:2 / id3:1  =>  expand_1_2(id3, id1, id2)
+    pass(&t, "3_is_3_div_1"); // id1:3 == id2:3 / id3:1  =>  expand_1_3(id3, id1, id2)
     pass(&t, "1_is_2_cross_2"); // id1:1 == id2:2 X id3:2  =>  expand_cross_2(id2, id3, id1)
     pass(&t, "1_is_2_cross_itself"); // id1:1 == id2:2 X =:2  =>  expand_cross_2(id2, id1)
     pass(&t, "3_is_3_cross_3"); // id1:3 == id2:3 X id3:3  =>  expand_cross_3(id2, id3, id1)
     pass(&t, "3_is_3_cross_itself"); // id1:3 == id2:3 X =:3  =>  expand_cross_3(id2, id1)
+    compile_fail(&t, "fail_1_is_1_mul_1_bad_ratio"); // id1:1 == id2:1 * id3:1, with inconsistent ratios
+    compile_fail(&t, "fail_1_is_1_mul_1_literal_bad_ratio"); // id1:1 == id2:1 * id3:1 * k, with inconsistent ratios
+    compile_fail(&t, "fail_1_is_1_mul_1_twice"); // id1:1 == id2:1 * id3:1, defined twice
     compile_fail(&t, "fail_1_is_1_mul_2"); // id1:1 == id2:1 * id3:2
+    compile_fail(&t, "fail_1_is_1_div_2"); // id1:1 == id2:1 / id3:2
+    compile_fail(&t, "fail_2_is_1_div_2"); // id1:2 == id2:1 / id3:2
     compile_fail(&t, "fail_1_is_bad_literal_div_1"); // id1:1 == 2 / id2:1
+    compile_fail(&t, "fail_1_is_1_pow_4"); // id1:1 == id2:1 ^ 4
     compile_fail(&t, "fail_1_mul_1_mul_1"); // id1:1 * id2:1 * id3:1
     compile_fail(&t, "fail_4_is_1_mul_1"); // id1:4 == id2:1 * id3:1
 }
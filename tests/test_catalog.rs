@@ -0,0 +1,76 @@
+#![cfg(feature = "catalog")]
+use rs_measures::catalog::{
+    dynamics::*, electricity::*, geometry::*, information::*, kinematics::*, magnetism::*,
+    optics::*, thermodynamics::*,
+};
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: true,
+        with_3d: true,
+        with_transformations: false,
+        with_uncertainty: None,
+    }
+}
+
+rs_measures::define_units_catalog_relationships! {
+    with_2d: true,
+    with_3d: true,
+    domains: [
+        information,
+        geometry,
+        kinematics,
+        dynamics,
+        thermodynamics,
+        chemistry,
+        radioactivity,
+        optics,
+        electricity,
+        magnetism,
+        dimensionless,
+    ],
+}
+
+mod test_utils;
+
+#[test]
+fn geometry_and_kinematics() {
+    let area: Measure<SquareMetre> = Measure::<Metre>::new(3.) * Measure::<Metre>::new(4.);
+    assert_eq!(area.value, 12.);
+    let speed: Measure<KiloMetrePerHour> =
+        Measure::<KiloMetre>::new(150.) / Measure::<Hour>::new(2.);
+    assert_eq!(speed.value, 75.);
+    let displacement: Measure3d<Metre> =
+        Measure3d::<MetrePerSecond>::new(1., 2., 3.) * Measure::<Second>::new(2.);
+    assert_eq!(displacement, Measure3d::<Metre>::new(2., 4., 6.));
+    assert_eq_64!(
+        Measure::<Degree>::new(180.).convert::<Radian>().value,
+        core::f64::consts::PI
+    );
+}
+
+#[test]
+fn dynamics_and_electricity() {
+    let force: Measure<Newton> =
+        Measure::<KiloGram>::new(2.) * Measure::<MetrePerSquareSecond>::new(3.);
+    assert_eq!(force.value, 6.);
+    let power: Measure<Watt> = Measure::<Volt>::new(230.) * Measure::<Ampere>::new(2.);
+    assert_eq!(power.value, 460.);
+    assert_eq!(Measure::<KiloWatt>::new(1.5).to_string(), "1.5 kW");
+}
+
+#[test]
+fn information_and_temperature() {
+    let rate: Measure<MegaBytePerSecond> =
+        Measure::<MegaByte>::new(100.) / Measure::<Second>::new(4.);
+    assert_eq!(rate.value, 25.);
+    assert_eq_64!(
+        MeasurePoint::<Fahrenheit>::new(212.)
+            .convert::<Celsius>()
+            .value,
+        100.
+    );
+    let _: Measure<Weber> = Measure::<Henry>::new(1.) * Measure::<Ampere>::new(1.);
+    let _: Measure<Lux> = Measure::<Lumen>::new(1.) / Measure::<SquareMetre>::new(1.);
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "catalog")]
+use rs_measures::catalog::{geometry::*, kinematics::*};
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: false,
+        with_directions: false,
+        with_2d: false,
+        with_3d: false,
+        with_transformations: false,
+        with_uncertainty: None,
+    }
+}
+
+rs_measures::define_units_catalog_relationships! {
+    with_2d: false,
+    with_3d: false,
+    domains: [kinematics],
+}
+
+#[test]
+fn kinematics_without_vectors() {
+    let speed: Measure<MetrePerSecond> = Measure::<Metre>::new(10.) / Measure::<Second>::new(4.);
+    assert_eq!(speed.value, 2.5);
+    let acceleration: Measure<MetrePerSquareSecond> = speed / Measure::<Second>::new(5.);
+    assert_eq!(acceleration.value, 0.5);
+}
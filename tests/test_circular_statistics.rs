@@ -0,0 +1,149 @@
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: false,
+        with_3d: false,
+        with_transformations: false,
+        with_uncertainty: None,
+    }
+}
+
+mod test_utils;
+
+pub struct Degree;
+impl MeasurementUnit for Degree {
+    type Property = Angle;
+    const RATIO: f64 = core::f64::consts::TAU / 360.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " deg";
+}
+impl AngleMeasurementUnit for Degree {
+    const CYCLE_FRACTION: f64 = 360.;
+}
+
+pub struct Gradian;
+impl MeasurementUnit for Gradian {
+    type Property = Angle;
+    const RATIO: f64 = core::f64::consts::TAU / 400.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " grad";
+}
+impl AngleMeasurementUnit for Gradian {
+    const CYCLE_FRACTION: f64 = 400.;
+}
+
+fn unsigned(values: &[f64]) -> Vec<UnsignedDirection<Degree>> {
+    values.iter().map(|v| UnsignedDirection::new(*v)).collect()
+}
+
+#[test]
+fn circular_mean() {
+    // Across the wrap-around, the arithmetic mean would be 180 degrees.
+    let mean = UnsignedDirection::circular_mean(unsigned(&[350., 10.])).unwrap();
+    assert_eq_tolerance!((mean - UnsignedDirection::new(0.)).value, 0., 1e-12);
+    let mean = UnsignedDirection::circular_mean(unsigned(&[340., 10., 30.])).unwrap();
+    assert!(mean.value > 0. && mean.value < 10.);
+    let mean = SignedDirection::<Degree>::circular_mean(
+        [170., -170., 180.].iter().map(|v| SignedDirection::new(*v)),
+    )
+    .unwrap();
+    assert_eq_64!(mean.value.abs(), 180.);
+    let mean = UnsignedDirection::circular_mean(
+        [390., 10.]
+            .iter()
+            .map(|v| UnsignedDirection::<Gradian>::new(*v)),
+    )
+    .unwrap();
+    assert_eq_tolerance!((mean - UnsignedDirection::new(0.)).value, 0., 1e-12);
+    let mean = UnsignedDirection::circular_mean(
+        [0.1, 0.3]
+            .iter()
+            .map(|v| UnsignedDirection::<Radian>::new(*v)),
+    )
+    .unwrap();
+    assert_eq_64!(mean.value, 0.2);
+
+    assert!(UnsignedDirection::<Degree>::circular_mean(vec![]).is_none());
+    // The directions are spread evenly.
+    assert!(UnsignedDirection::circular_mean(unsigned(&[0., 120., 240.])).is_none());
+}
+
+#[test]
+fn mean_resultant_length_and_variance() {
+    assert_eq_64!(
+        UnsignedDirection::mean_resultant_length(unsigned(&[20., 20., 20.])).unwrap(),
+        1.
+    );
+    assert_eq_64!(
+        UnsignedDirection::mean_resultant_length(unsigned(&[315., 45.])).unwrap(),
+        0.5_f64.sqrt()
+    );
+    assert_eq_64!(
+        UnsignedDirection::circular_variance(unsigned(&[0., 180.])).unwrap(),
+        1.
+    );
+    assert_eq_64!(
+        UnsignedDirection::circular_variance(unsigned(&[315., 45.])).unwrap(),
+        1. - 0.5_f64.sqrt()
+    );
+    assert!(UnsignedDirection::<Degree>::circular_variance(vec![]).is_none());
+}
+
+#[test]
+fn circular_standard_deviation() {
+    let sd = UnsignedDirection::circular_standard_deviation(unsigned(&[30., 30.])).unwrap();
+    assert_eq_tolerance!(sd.value, 0., 1e-6);
+    // For a small spread, it is near to the standard deviation of the angles, which is 1 degree.
+    let sd = UnsignedDirection::circular_standard_deviation(unsigned(&[359., 1.])).unwrap();
+    assert_eq_tolerance!(sd.value, 1., 1e-4);
+    let sd = UnsignedDirection::circular_standard_deviation(
+        [399., 1.]
+            .iter()
+            .map(|v| UnsignedDirection::<Gradian>::new(*v)),
+    )
+    .unwrap();
+    assert_eq_tolerance!(sd.value, 1., 1e-4);
+    let r = UnsignedDirection::mean_resultant_length(unsigned(&[0., 90.])).unwrap();
+    let sd = UnsignedDirection::circular_standard_deviation(unsigned(&[0., 90.])).unwrap();
+    assert_eq_64!(sd.value, (-2. * r.ln()).sqrt().to_degrees());
+}
+
+#[test]
+fn circular_standard_deviation_of_identical_directions() {
+    // Rounding errors could make the mean resultant length of identical directions
+    // slightly greater than 1.
+    for tenths in 0..3600 {
+        let value = f64::from(tenths) * 0.1;
+        let r = UnsignedDirection::mean_resultant_length(unsigned(&[value; 3])).unwrap();
+        assert!(r <= 1., "Length {} for {}.", r, value);
+        let sd = UnsignedDirection::circular_standard_deviation(unsigned(&[value; 3])).unwrap();
+        assert_eq_tolerance!(sd.value, 0., 1e-5);
+        assert!(sd.value.is_sign_positive());
+        let sd = SignedDirection::circular_standard_deviation(
+            [value; 3]
+                .iter()
+                .map(|v| SignedDirection::<Degree>::new(*v)),
+        )
+        .unwrap();
+        assert_eq_tolerance!(sd.value, 0., 1e-5);
+        assert!(sd.value.is_sign_positive());
+    }
+    let sd = UnsignedDirection::circular_standard_deviation(unsigned(&[8., 8., 8.])).unwrap();
+    assert_eq!(sd.to_string(), "0 deg");
+}
+
+#[test]
+fn circular_median() {
+    let median =
+        UnsignedDirection::circular_median(unsigned(&[350., 355., 5., 10., 100.])).unwrap();
+    assert_eq!(median.value, 5.);
+    let median = SignedDirection::<Degree>::circular_median(
+        [175., -175., -170., 160.]
+            .iter()
+            .map(|v| SignedDirection::new(*v)),
+    )
+    .unwrap();
+    assert_eq!(median.value, 175.);
+    assert!(UnsignedDirection::<Degree>::circular_median(vec![]).is_none());
+}
@@ -0,0 +1,460 @@
+use rs_measures::traits::CrossProduct;
+use std::f64::consts::TAU;
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: true,
+        with_3d: true,
+        with_transformations: true,
+        with_uncertainty: Symmetric,
+    }
+}
+
+mod test_utils;
+
+pub struct Dimensionless;
+impl VectorProperty for Dimensionless {}
+
+pub struct Unspecified;
+impl MeasurementUnit for Unspecified {
+    type Property = Dimensionless;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = "";
+}
+
+struct Length;
+impl VectorProperty for Length {}
+
+struct Metre;
+impl MeasurementUnit for Metre {
+    type Property = Length;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m";
+}
+
+struct MilliMetre;
+impl MeasurementUnit for MilliMetre {
+    type Property = Length;
+    const RATIO: f64 = 0.001;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mm";
+}
+
+struct Degree;
+impl MeasurementUnit for Degree {
+    type Property = Angle;
+    const RATIO: f64 = TAU / 360.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " deg";
+}
+impl AngleMeasurementUnit for Degree {
+    const CYCLE_FRACTION: f64 = 360.;
+}
+
+struct Area;
+impl VectorProperty for Area {}
+
+struct SquareMetre;
+impl MeasurementUnit for SquareMetre {
+    type Property = Area;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m\u{b2}";
+}
+
+struct Time;
+
+struct Second;
+impl MeasurementUnit for Second {
+    type Property = Time;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " s";
+}
+
+struct Velocity;
+impl VectorProperty for Velocity {}
+
+struct MetrePerSecond;
+impl MeasurementUnit for MetrePerSecond {
+    type Property = Velocity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m/s";
+}
+
+rs_measures::define_units_relationship! { Metre:2 == MetrePerSecond:2 * Second }
+rs_measures::define_units_relationship! { SquareMetre == Metre:2 * =:2 }
+rs_measures::define_units_relationship! { SquareMetre == Metre:2 X =:2 }
+rs_measures::define_units_relationship! { SquareMetre:3 == Metre:3 X =:3 }
+
+#[test]
+fn measure_2d_new_has_no_uncertainty() {
+    let m = Measure2d::<Metre>::new(3., 4.);
+    assert_eq!(m.covariance, [[0., 0.], [0., 0.]]);
+}
+
+#[test]
+fn measure_2d_with_uncertainty() {
+    let m = Measure2d::<Metre>::with_uncertainty(3., 4., [0.1, 0.2]);
+    assert_eq_64!(m.covariance[0][0], 0.01);
+    assert_eq!(m.covariance[0][1], 0.);
+    assert_eq!(m.covariance[1][0], 0.);
+    assert_eq_64!(m.covariance[1][1], 0.04);
+    assert_eq_64!(m.y().uncertainty, 0.2);
+}
+
+#[test]
+fn measure_2d_convert() {
+    let m = Measure2d::<Metre>::with_covariance(3., 4., [[4., 1.], [1., 9.]]);
+    let m2 = m.convert::<MilliMetre>();
+    assert_eq!(m2.x, 3000.);
+    assert_eq!(m2.y, 4000.);
+    assert_eq!(m2.covariance, [[4e6, 1e6], [1e6, 9e6]]);
+}
+
+#[test]
+fn measure_2d_add_sub() {
+    let m1 = Measure2d::<Metre>::with_covariance(3., 4., [[4., 1.], [1., 9.]]);
+    let m2 = Measure2d::<Metre>::with_covariance(1., 2., [[1., 0.], [0., 1.]]);
+    let sum = m1 + m2;
+    assert_eq!(sum.x, 4.);
+    assert_eq!(sum.y, 6.);
+    assert_eq!(sum.covariance, [[5., 1.], [1., 10.]]);
+    let difference = m1 - m2;
+    assert_eq!(difference.x, 2.);
+    assert_eq!(difference.y, 2.);
+    assert_eq!(difference.covariance, [[5., 1.], [1., 10.]]);
+}
+
+#[test]
+fn measure_2d_mul_number() {
+    let m = Measure2d::<Metre>::with_covariance(3., 4., [[4., 1.], [1., 9.]]) * -2.;
+    assert_eq!(m.x, -6.);
+    assert_eq!(m.y, -8.);
+    assert_eq!(m.covariance, [[16., 4.], [4., 36.]]);
+}
+
+#[test]
+fn measure_2d_error_ellipse() {
+    let m = Measure2d::<Metre>::with_covariance(0., 0., [[1., 0.], [0., 4.]]);
+    let [major, minor] = m.error_ellipse();
+    assert_eq_64!(major.x, 0.);
+    assert_eq_64!(major.y.abs(), 2.);
+    assert_eq_64!(minor.x.abs(), 1.);
+    assert_eq_64!(minor.y, 0.);
+
+    let m = Measure2d::<Metre>::with_covariance(0., 0., [[2.5, 1.5], [1.5, 2.5]]);
+    let [major, minor] = m.error_ellipse();
+    assert_eq_64!(major.squared_norm(), 4.);
+    assert_eq_64!(major.x.abs(), 2f64.sqrt());
+    assert_eq_64!(major.y.abs(), 2f64.sqrt());
+    assert_eq_64!(minor.squared_norm(), 1.);
+}
+
+#[test]
+fn measure_2d_linear_map_rotation() {
+    let m = Measure2d::<Metre>::with_covariance(1., 0., [[4., 0.], [0., 1.]]);
+    let rotated = LinearMap2d::rotation(Measure::<Degree>::new(90.)).apply_to(m);
+    assert_eq_64!(rotated.x, 0.);
+    assert_eq_64!(rotated.y, 1.);
+    assert_eq_64!(rotated.covariance[0][0], 1.);
+    assert_eq_64!(rotated.covariance[0][1], 0.);
+    assert_eq_64!(rotated.covariance[1][0], 0.);
+    assert_eq_64!(rotated.covariance[1][1], 4.);
+
+    let rotated = LinearMap2d::rotation(Measure::<Degree>::new(45.)).apply_to(m);
+    assert_eq_64!(rotated.covariance[0][0], 2.5);
+    assert_eq_64!(rotated.covariance[0][1], 1.5);
+    assert_eq_64!(rotated.covariance[1][0], 1.5);
+    assert_eq_64!(rotated.covariance[1][1], 2.5);
+}
+
+#[test]
+fn measure_2d_formatting() {
+    let m = Measure2d::<Metre>::with_uncertainty(3., 4., [0.5, 0.25]);
+    assert_eq!(format!("{}", m), "(3 \u{b1} 0.5, 4 \u{b1} 0.25) m");
+    assert_eq!(format!("{:?}", m), "(3 \u{b1} 0.5, 4 \u{b1} 0.25) m");
+}
+
+#[test]
+fn measure_point_add_sub_measure() {
+    let p = MeasurePoint::<Metre>::new(10.) + Measure::<Metre>::with_uncertainty(1., 0.5);
+    assert_eq!(p.value, 11.);
+    assert_eq!(p.uncertainty, 0.5);
+    assert_eq!(format!("{}", p), "at 11 \u{b1} 0.5 m");
+    let p = MeasurePoint::<Metre>::with_uncertainty(12., 0.3)
+        - Measure::<Metre>::with_uncertainty(7., 0.4);
+    assert_eq!(p.value, 5.);
+    assert_eq_64!(p.uncertainty, 0.5);
+    let mut p = MeasurePoint::<Metre>::with_uncertainty(12., 0.3);
+    p += Measure::<Metre>::with_uncertainty(7., 0.4);
+    assert_eq!(p.value, 19.);
+    assert_eq_64!(p.uncertainty, 0.5);
+    p -= Measure::<Metre>::new(7.);
+    assert_eq!(p.value, 12.);
+    assert_eq_64!(p.uncertainty, 0.5);
+}
+
+#[test]
+fn measure_point_round_trip() {
+    let p = MeasurePoint::<Metre>::with_uncertainty(12., 0.3);
+    let m = Measure::<Metre>::with_uncertainty(7., 0.4);
+    let displacement = (p + m) - p;
+    assert_eq!(displacement.value, 7.);
+    assert_eq_64!(
+        displacement.uncertainty,
+        (0.3f64 * 0.3 + 0.3 * 0.3 + 0.4 * 0.4).sqrt()
+    );
+    let q = (p - m) + m;
+    assert_eq!(q.value, 12.);
+    assert_eq_64!(q.uncertainty, (0.3f64 * 0.3 + 0.4 * 0.4 + 0.4 * 0.4).sqrt());
+}
+
+#[test]
+fn measure_point_convert_and_midpoint() {
+    let p = MeasurePoint::<Metre>::with_uncertainty(1.5, 0.002).convert::<MilliMetre>();
+    assert_eq_64!(p.value, 1500.);
+    assert_eq_64!(p.uncertainty, 2.);
+    let mid = midpoint(
+        MeasurePoint::<Metre>::with_uncertainty(10., 0.6),
+        MeasurePoint::<Metre>::with_uncertainty(20., 0.8),
+    );
+    assert_eq!(mid.value, 15.);
+    assert_eq_64!(mid.uncertainty, 0.5);
+    assert_eq!(
+        barycentric_combination(
+            &[
+                MeasurePoint::<Metre>::with_uncertainty(10., 0.6),
+                MeasurePoint::<Metre>::with_uncertainty(20., 0.8),
+            ],
+            &[0.5, 0.5],
+        ),
+        mid
+    );
+}
+
+#[test]
+fn measure_point_2d_components() {
+    let p = MeasurePoint2d::<Metre>::with_uncertainty(3., 4., [0.5, 0.25]);
+    assert_eq!(p.x(), MeasurePoint::<Metre>::with_uncertainty(3., 0.5));
+    assert_eq!(p.y(), MeasurePoint::<Metre>::with_uncertainty(4., 0.25));
+}
+
+#[test]
+fn measure_point_2d_operations() {
+    let p1 = MeasurePoint2d::<Metre>::with_covariance(3., 4., [[4., 1.], [1., 9.]]);
+    let p2 = MeasurePoint2d::<Metre>::with_covariance(1., 2., [[2., 0.], [0., 1.]]);
+    let v = p1 - p2;
+    assert_eq!(v.x, 2.);
+    assert_eq!(v.y, 2.);
+    assert_eq!(v.covariance, [[6., 1.], [1., 10.]]);
+    let p3 = p2 + v;
+    assert_eq!(p3.x, 3.);
+    assert_eq!(p3.y, 4.);
+    assert_eq!(p3.covariance, [[8., 1.], [1., 11.]]);
+    let mid = midpoint_2d(p1, p2);
+    assert_eq!(mid.x, 2.);
+    assert_eq!(mid.y, 3.);
+    assert_eq!(mid.covariance, [[1.5, 0.25], [0.25, 2.5]]);
+    let combination = barycentric_combination_2d(&[p1, p2], &[0.5, 0.5]);
+    assert_eq!(combination, mid);
+}
+
+#[test]
+fn measure_point_2d_affine_map_rotation() {
+    let p = MeasurePoint2d::<Metre>::with_covariance(2., 1., [[4., 0.], [0., 1.]]);
+    let fixed_point = MeasurePoint2d::<Metre>::new(1., 1.);
+    let rotated = AffineMap2d::rotation(fixed_point, Measure::<Degree>::new(90.)).apply_to(p);
+    assert_eq_64!(rotated.x, 1.);
+    assert_eq_64!(rotated.y, 2.);
+    assert_eq_64!(rotated.covariance[0][0], 1.);
+    assert_eq_64!(rotated.covariance[0][1], 0.);
+    assert_eq_64!(rotated.covariance[1][0], 0.);
+    assert_eq_64!(rotated.covariance[1][1], 4.);
+    let [major, minor] = rotated.error_ellipse();
+    assert_eq_64!(major.x, 0.);
+    assert_eq_64!(major.y.abs(), 2.);
+    assert_eq_64!(minor.x.abs(), 1.);
+    assert_eq_64!(minor.y, 0.);
+}
+
+#[test]
+fn measure_point_2d_formatting() {
+    let p = MeasurePoint2d::<Metre>::with_uncertainty(3., 4., [0.5, 0.25]);
+    assert_eq!(format!("{}", p), "at (3 \u{b1} 0.5, 4 \u{b1} 0.25) m");
+}
+
+#[test]
+fn measure_2d_products_keep_correlations() {
+    let a = Measure2d::<Metre>::with_covariance(3., 4., [[4., 2.], [2., 9.]]);
+    let dot: Measure<SquareMetre> = a * Measure2d::<Metre>::new(1., 1.);
+    assert_eq!(dot.value, 7.);
+    assert_eq_64!(dot.uncertainty, 17_f64.sqrt());
+    let cross: Measure<SquareMetre> = a.cross_product(Measure2d::<Metre>::new(1., 2.));
+    assert_eq!(cross.value, 2.);
+    assert_eq_64!(cross.uncertainty, 17_f64.sqrt());
+
+    let v = Measure2d::<Metre>::with_covariance(3., 4., [[0.04, 0.01], [0.01, 0.09]]);
+    let squared: Measure<SquareMetre> = v.squared();
+    assert_eq!(squared.value, 25.);
+    assert_eq_64!(squared.uncertainty, 2. * 2.04_f64.sqrt());
+}
+
+#[test]
+fn measure_2d_scaled_keeps_correlations() {
+    let v = Measure2d::<MetrePerSecond>::with_covariance(3., 4., [[0.04, 0.01], [0.01, 0.09]]);
+    let t = Measure::<Second>::with_uncertainty(2., 0.1);
+    let d: Measure2d<Metre> = v * t;
+    assert_eq!(d.x, 6.);
+    assert_eq!(d.y, 8.);
+    assert_eq_64!(d.covariance[0][0], 0.25);
+    assert_eq_64!(d.covariance[0][1], 0.16);
+    assert_eq_64!(d.covariance[1][0], 0.16);
+    assert_eq_64!(d.covariance[1][1], 0.52);
+    assert_eq!(t * v, d);
+    let v2: Measure2d<MetrePerSecond> = d / t;
+    assert_eq!(v2.x, 3.);
+    assert_eq!(v2.y, 4.);
+    assert_eq_64!(v2.covariance[0][0], 0.085);
+    assert_eq_64!(v2.covariance[0][1], 0.07);
+    assert_eq_64!(v2.covariance[1][1], 0.17);
+}
+
+#[test]
+fn measure_3d_with_uncertainty() {
+    let m = Measure3d::<Metre>::with_uncertainty(3., 4., 5., [0.1, 0.2, 0.3]);
+    assert_eq_64!(m.covariance[0][0], 0.01);
+    assert_eq_64!(m.covariance[1][1], 0.04);
+    assert_eq_64!(m.covariance[2][2], 0.09);
+    assert_eq!(m.covariance[0][2], 0.);
+    assert_eq_64!(m.z().uncertainty, 0.3);
+}
+
+#[test]
+fn measure_3d_error_ellipsoid() {
+    let m =
+        Measure3d::<Metre>::with_covariance(0., 0., 0., [[1., 0., 0.], [0., 9., 0.], [0., 0., 4.]]);
+    let [a, b, c] = m.error_ellipsoid();
+    assert_eq_64!(a.y.abs(), 3.);
+    assert_eq_64!(a.squared_norm(), 9.);
+    assert_eq_64!(b.z.abs(), 2.);
+    assert_eq_64!(b.squared_norm(), 4.);
+    assert_eq_64!(c.x.abs(), 1.);
+    assert_eq_64!(c.squared_norm(), 1.);
+}
+
+#[test]
+fn measure_3d_linear_map_rotation() {
+    let m =
+        Measure3d::<Metre>::with_covariance(1., 0., 0., [[4., 0., 0.], [0., 1., 0.], [0., 0., 9.]]);
+    let axis = Measure3d::<Unspecified>::new(0., 0., 1.);
+    let rotated = LinearMap3d::rotation(Measure::<Degree>::new(90.), axis).apply_to(m);
+    assert_eq_64!(rotated.x, 0.);
+    assert_eq_64!(rotated.y, 1.);
+    assert_eq_64!(rotated.z, 0.);
+    assert_eq_64!(rotated.covariance[0][0], 1.);
+    assert_eq_64!(rotated.covariance[1][1], 4.);
+    assert_eq_64!(rotated.covariance[2][2], 9.);
+    assert_eq_64!(rotated.covariance[0][1], 0.);
+    assert_eq_64!(rotated.covariance[0][2], 0.);
+    assert_eq_64!(rotated.covariance[1][2], 0.);
+}
+
+#[test]
+fn measure_point_3d_affine_map_rotation() {
+    let p = MeasurePoint3d::<Metre>::with_covariance(
+        2.,
+        1.,
+        1.,
+        [[4., 0., 0.], [0., 1., 0.], [0., 0., 9.]],
+    );
+    let fixed_point = MeasurePoint3d::<Metre>::new(1., 1., 1.);
+    let axis = Measure3d::<Unspecified>::new(0., 0., 1.);
+    let rotated = AffineMap3d::rotation(fixed_point, axis, Measure::<Degree>::new(90.)).apply_to(p);
+    assert_eq_64!(rotated.x, 1.);
+    assert_eq_64!(rotated.y, 2.);
+    assert_eq_64!(rotated.z, 1.);
+    assert_eq_64!(rotated.covariance[0][0], 1.);
+    assert_eq_64!(rotated.covariance[1][1], 4.);
+    assert_eq_64!(rotated.covariance[2][2], 9.);
+    assert_eq_64!(rotated.covariance[0][1], 0.);
+}
+
+#[test]
+fn measure_point_3d_formatting() {
+    let p = MeasurePoint3d::<Metre>::with_uncertainty(3., 4., 5., [0.5, 0.25, 2.]);
+    assert_eq!(
+        format!("{}", p),
+        "at (3 \u{b1} 0.5, 4 \u{b1} 0.25, 5 \u{b1} 2) m"
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn measure_2d_serde() {
+    let m = Measure2d::<Metre>::with_covariance(3., 4., [[4., 1.], [1., 9.]]);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(
+        json,
+        r#"{"x":3.0,"y":4.0,"covariance":[[4.0,1.0],[1.0,9.0]],"unit":"m"}"#
+    );
+    assert_eq!(serde_json::from_str::<Measure2d<Metre>>(&json).unwrap(), m);
+    let m: Measure2d<Metre> = serde_json::from_str(r#"{"x":3.0,"y":4.0}"#).unwrap();
+    assert_eq!(m.covariance, [[0., 0.], [0., 0.]]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn measure_point_serde() {
+    let p = MeasurePoint::<Metre>::with_uncertainty(11., 0.5);
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"value":11.0,"uncertainty":0.5,"unit":"m"}"#);
+    assert_eq!(
+        serde_json::from_str::<MeasurePoint<Metre>>(&json).unwrap(),
+        p
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn measure_point_3d_serde() {
+    let p = MeasurePoint3d::<Metre>::with_uncertainty(1., 2., 3., [1., 2., 3.]);
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        serde_json::from_str::<MeasurePoint3d<Metre>>(&json).unwrap(),
+        p
+    );
+    assert!(serde_json::from_str::<MeasurePoint3d<MilliMetre>>(&json).is_err());
+}
+
+#[test]
+fn measure_2d_parse() {
+    let m = Measure2d::<Metre>::with_uncertainty(3., 4., [2., 0.5]);
+    assert_eq!(m.to_string(), "(3 \u{b1} 2, 4 \u{b1} 0.5) m");
+    assert_eq!(m.to_string().parse::<Measure2d<Metre>>(), Ok(m));
+    let p = MeasurePoint3d::<Metre>::with_uncertainty(1., 2., 3., [1., 2., 3.]);
+    assert_eq!(p.to_string().parse::<MeasurePoint3d<Metre>>(), Ok(p));
+    let p = MeasurePoint::<Metre>::with_uncertainty(11., 0.5);
+    assert_eq!(p.to_string(), "at 11 \u{b1} 0.5 m");
+    assert_eq!(p.to_string().parse::<MeasurePoint<Metre>>(), Ok(p));
+}
+
+#[test]
+fn measure_3d_cross_product_keeps_correlations() {
+    let a = Measure3d::<Metre>::with_covariance(
+        1.,
+        2.,
+        3.,
+        [[0.01, 0.005, 0.], [0.005, 0.01, 0.], [0., 0., 0.01]],
+    );
+    let c: Measure3d<SquareMetre> = a.cross_product(Measure3d::<Metre>::new(0., 0., 1.));
+    assert_eq!((c.x, c.y, c.z), (2., -1., 0.));
+    assert_eq_64!(c.covariance[0][0], 0.01);
+    assert_eq_64!(c.covariance[0][1], -0.005);
+    assert_eq_64!(c.covariance[1][1], 0.01);
+    assert_eq!(c.covariance[2], [0., 0., 0.]);
+}
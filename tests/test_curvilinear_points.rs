@@ -0,0 +1,112 @@
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: true,
+        with_3d: true,
+        with_transformations: false,
+        with_uncertainty: None,
+    }
+}
+
+mod test_utils;
+
+pub struct Length;
+impl VectorProperty for Length {}
+
+pub struct Metre;
+impl MeasurementUnit for Metre {
+    type Property = Length;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m";
+}
+
+pub struct KiloMetre;
+impl MeasurementUnit for KiloMetre {
+    type Property = Length;
+    const RATIO: f64 = 1000.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " km";
+}
+
+pub struct Degree;
+impl MeasurementUnit for Degree {
+    type Property = Angle;
+    const RATIO: f64 = core::f64::consts::TAU / 360.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " deg";
+}
+impl AngleMeasurementUnit for Degree {
+    const CYCLE_FRACTION: f64 = 360.;
+}
+
+#[test]
+fn polar_point_2d() {
+    let p = PolarPoint2d::<Metre, Degree>::new(Measure::new(2.), UnsignedDirection::new(-60.));
+    assert_eq_64!(p.azimuth.value, 300.);
+    let cartesian: MeasurePoint2d<Metre> = p.into();
+    assert_eq_64!(cartesian.x, 1.);
+    assert_eq_64!(cartesian.y, -(3_f64.sqrt()));
+    let back = PolarPoint2d::<Metre, Degree>::from(cartesian);
+    assert_eq_64!(back.radius.value, 2.);
+    assert_eq_64!(back.azimuth.value, 300.);
+
+    let p = PolarPoint2d::<Metre, Degree>::from_point(MeasurePoint2d::new(-3., 0.));
+    assert_eq_64!(p.radius.value, 3.);
+    assert_eq_64!(p.azimuth.value, 180.);
+    let p2 = p.convert::<KiloMetre, Radian>();
+    assert_eq_64!(p2.radius.value, 0.003);
+    assert_eq_64!(p2.azimuth.value, core::f64::consts::PI);
+    assert_eq!(format!("{}", p), "radius 3 m, azimuth 180 deg");
+    assert_eq!(
+        PolarPoint2d::<Metre, Degree>::default().to_point(),
+        MeasurePoint2d::new(0., 0.)
+    );
+}
+
+#[test]
+fn cylindrical_point_3d() {
+    let p = CylindricalPoint3d::<Metre, Degree>::new(
+        Measure::new(2.),
+        UnsignedDirection::new(90.),
+        Measure::new(-5.),
+    );
+    let cartesian: MeasurePoint3d<Metre> = p.into();
+    assert_eq_64!(cartesian.x, 0.);
+    assert_eq_64!(cartesian.y, 2.);
+    assert_eq_64!(cartesian.z, -5.);
+    let back = CylindricalPoint3d::<Metre, Degree>::from(MeasurePoint3d::new(-1., -1., 4.));
+    assert_eq_64!(back.radius.value, 2_f64.sqrt());
+    assert_eq_64!(back.azimuth.value, 225.);
+    assert_eq_64!(back.z.value, 4.);
+    assert_eq!(format!("{}", p), "radius 2 m, azimuth 90 deg, z -5 m");
+}
+
+#[test]
+fn spherical_point_3d() {
+    let p = SphericalPoint3d::<Metre, Degree>::new(
+        Measure::new(4.),
+        UnsignedDirection::new(180.),
+        SignedDirection::new(30.),
+    );
+    let cartesian: MeasurePoint3d<Metre> = p.into();
+    assert_eq_64!(cartesian.x, -2. * 3_f64.sqrt());
+    assert_eq_64!(cartesian.y, 0.);
+    assert_eq_64!(cartesian.z, 2.);
+    let back = SphericalPoint3d::<Metre, Degree>::from(cartesian);
+    assert_eq_64!(back.radius.value, 4.);
+    assert_eq_64!(back.azimuth.value, 180.);
+    assert_eq_64!(back.elevation.value, 30.);
+    let direction = p.direction();
+    assert_eq_64!(direction.azimuth().value, 180.);
+    assert_eq_64!(direction.elevation().value, 30.);
+
+    let below = SphericalPoint3d::<KiloMetre, Degree>::from_point(MeasurePoint3d::new(0., 0., -7.));
+    assert_eq_64!(below.radius.value, 7.);
+    assert_eq_64!(below.elevation.value, -90.);
+    assert_eq!(
+        format!("{}", below.convert::<Metre, Degree>()),
+        "radius 7000 m, azimuth 0 deg, elevation -90 deg"
+    );
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "derive")]
+use rs_measures::{MeasurementUnit, VectorProperty};
+use std::f64::consts::TAU;
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: true,
+        with_3d: false,
+        with_transformations: false,
+        with_uncertainty: None,
+    }
+}
+
+mod test_utils;
+
+#[derive(VectorProperty)]
+struct Length;
+
+#[derive(MeasurementUnit)]
+#[unit(property = Length, suffix = " m", prefixes(Kilo, Milli, Micro))]
+struct Metre;
+
+#[derive(MeasurementUnit)]
+#[unit(property = Length, ratio = 0.3048, suffix = " ft")]
+struct Foot;
+
+struct Information;
+
+#[derive(MeasurementUnit)]
+#[unit(property = Information, ratio = 8., suffix = " B", si_prefixes, binary_prefixes)]
+struct Byte;
+
+struct Temperature;
+
+#[derive(MeasurementUnit)]
+#[unit(property = Temperature, ratio = 5. / 9., offset = 273.15 - 32. * 5. / 9.)]
+#[unit(suffix = " \u{b0}F")]
+struct DegreeFahrenheit;
+
+#[derive(MeasurementUnit)]
+#[unit(property = Temperature, offset = 273.15, suffix = " \u{b0}C")]
+struct DegreeCelsius;
+
+#[derive(MeasurementUnit)]
+#[unit(property = Angle, ratio = TAU / 360., suffix = "\u{b0}", cycle_fraction = 360.)]
+struct Degree;
+
+#[derive(MeasurementUnit)]
+#[unit(property = Angle, ratio = TAU, suffix = " cycles", cycle_fraction = 1., prefixes(Milli))]
+struct Cycle;
+
+#[test]
+fn derived_measurement_unit() {
+    assert_eq!(Metre::RATIO, 1.);
+    assert_eq!(Metre::OFFSET, 0.);
+    assert_eq!(Metre::SUFFIX, " m");
+    assert_eq!(Foot::RATIO, 0.3048);
+    assert_eq_64!(Measure::<Foot>::new(10.).convert::<Metre>().value, 3.048);
+    assert_eq!(Measure2d::<Foot>::new(1., 2.).to_string(), "(1, 2) ft");
+}
+
+#[test]
+fn derived_measurement_unit_with_offset() {
+    assert_eq_64!(
+        MeasurePoint::<DegreeFahrenheit>::new(212.)
+            .convert::<DegreeCelsius>()
+            .value,
+        100.
+    );
+    assert_eq!(
+        MeasurePoint::<DegreeCelsius>::new(20.).to_string(),
+        "at 20 \u{b0}C"
+    );
+}
+
+#[test]
+fn derived_angle_measurement_unit() {
+    assert_eq!(Degree::CYCLE_FRACTION, 360.);
+    assert_eq!(UnsignedDirection::<Degree>::new(-90.).value, 270.);
+    assert_eq_64!(
+        Measure::<Degree>::new(180.).convert::<Radian>().value,
+        TAU / 2.
+    );
+}
+
+#[test]
+fn derived_prefixed_units() {
+    assert_eq!(KiloMetre::RATIO, 1e3);
+    assert_eq!(KiloMetre::SUFFIX, " km");
+    assert_eq!(MilliMetre::RATIO, 1e-3);
+    assert_eq!(MilliMetre::SUFFIX, " mm");
+    assert_eq!(MicroMetre::SUFFIX, " \u{b5}m");
+    assert_eq_64!(
+        Measure::<KiloMetre>::new(1.5).convert::<MilliMetre>().value,
+        1.5e6
+    );
+    assert_eq!(Measure::<MicroMetre>::new(3.).to_string(), "3 \u{b5}m");
+}
+
+#[test]
+fn derived_si_and_binary_prefixed_units() {
+    assert_eq!(KiloByte::RATIO, 8e3);
+    assert_eq!(KiloByte::SUFFIX, " kB");
+    assert_eq!(DecaByte::SUFFIX, " daB");
+    assert_eq!(QuettaByte::SUFFIX, " QB");
+    assert_eq!(QuectoByte::SUFFIX, " qB");
+    assert_eq!(KibiByte::RATIO, 8. * 1024.);
+    assert_eq!(KibiByte::SUFFIX, " KiB");
+    assert_eq!(MebiByte::SUFFIX, " MiB");
+    assert_eq!(YobiByte::RATIO, 8. * 1024_f64.powi(8));
+    assert_eq_64!(
+        Measure::<GibiByte>::new(1.).convert::<MegaByte>().value,
+        1073.741824
+    );
+}
+
+#[test]
+fn derived_prefixed_angle_units() {
+    assert_eq!(MilliCycle::SUFFIX, " mcycles");
+    assert_eq!(MilliCycle::CYCLE_FRACTION, 1000.);
+    assert_eq_64!(UnsignedDirection::<MilliCycle>::new(-250.).value, 750.);
+    assert_eq_64!(
+        Measure::<MilliCycle>::new(500.).convert::<Radian>().value,
+        TAU / 2.
+    );
+}
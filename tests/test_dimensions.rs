@@ -0,0 +1,111 @@
+#![cfg(feature = "dimensions")]
+use rs_measures::dimensions::{
+    Acceleration, Area, Dimensionless, Energy, Force, Length, Mass, SiUnit, Time, Velocity,
+};
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: false,
+        with_directions: false,
+        with_2d: true,
+        with_3d: true,
+        with_transformations: false,
+        with_uncertainty: None,
+    }
+}
+
+rs_measures::define_dimension_relationships! {
+    with_2d: true,
+    with_3d: true,
+}
+
+mod test_utils;
+
+pub struct KiloMetre;
+impl MeasurementUnit for KiloMetre {
+    type Property = Length;
+    const RATIO: f64 = 1000.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " km";
+}
+
+// A property which is not an SI dimension, with an explicit relationship.
+pub struct Information;
+pub struct Bit;
+impl MeasurementUnit for Bit {
+    type Property = Information;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " b";
+}
+pub struct InformationRate;
+pub struct BitPerSecond;
+impl MeasurementUnit for BitPerSecond {
+    type Property = InformationRate;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " b/s";
+}
+type Second = SiUnit<Time>;
+rs_measures::define_units_relationship! { Bit == BitPerSecond * Second }
+
+#[test]
+fn suffixes() {
+    assert_eq!(SiUnit::<Dimensionless>::SUFFIX, "");
+    assert_eq!(SiUnit::<Length>::SUFFIX, " m");
+    assert_eq!(SiUnit::<Area>::SUFFIX, " m\u{b2}");
+    assert_eq!(SiUnit::<Velocity>::SUFFIX, " m\u{b7}s\u{207b}\u{b9}");
+    assert_eq!(SiUnit::<Force>::SUFFIX, " m\u{b7}kg\u{b7}s\u{207b}\u{b2}");
+    assert_eq!(
+        format!("{}", Measure::<SiUnit<Energy>>::new(2.5)),
+        "2.5 m\u{b2}\u{b7}kg\u{b7}s\u{207b}\u{b2}"
+    );
+}
+
+#[test]
+fn products_and_quotients() {
+    let mass = Measure::<SiUnit<Mass>>::new(3.);
+    let acceleration = Measure::<SiUnit<Acceleration>>::new(2.);
+    let force: Measure<SiUnit<Force>> = mass * acceleration;
+    assert_eq!(force.value, 6.);
+    let distance = Measure::<SiUnit<Length>>::new(4.);
+    let energy: Measure<SiUnit<Energy>> = force * distance;
+    assert_eq!(energy.value, 24.);
+    let speed: Measure<SiUnit<Velocity>> = distance.divided_by(Measure::<Second>::new(8.));
+    assert_eq!(speed.value, 0.5);
+    let ratio: Measure<SiUnit<Dimensionless>> = energy.divided_by(energy);
+    assert_eq!(ratio.value, 1.);
+    assert_eq!(energy / energy, 1.);
+    let side: Measure<SiUnit<Length>> = (distance * distance).sqrt();
+    assert_eq_64!(side.value, 4.);
+    let from_km: Measure<SiUnit<Area>> =
+        Measure::<KiloMetre>::new(2.).convert::<SiUnit<Length>>() * distance;
+    assert_eq!(from_km.value, 8000.);
+}
+
+#[test]
+fn vector_products_and_quotients() {
+    let mass = Measure::<SiUnit<Mass>>::new(2.);
+    let acceleration = Measure2d::<SiUnit<Acceleration>>::new(3., -1.);
+    let force: Measure2d<SiUnit<Force>> = mass * acceleration;
+    assert_eq!(force, Measure2d::<SiUnit<Force>>::new(6., -2.));
+    assert_eq!(acceleration * mass, force);
+    let velocity = Measure3d::<SiUnit<Velocity>>::new(1., 2., 3.);
+    let displacement: Measure3d<SiUnit<Length>> = velocity * Measure::<Second>::new(2.);
+    assert_eq!(displacement, Measure3d::<SiUnit<Length>>::new(2., 4., 6.));
+    assert_eq!(
+        displacement.divided_by(Measure::<Second>::new(2.)),
+        velocity
+    );
+    assert_eq!(
+        force.divided_by(mass),
+        Measure2d::<SiUnit<Acceleration>>::new(3., -1.)
+    );
+}
+
+#[test]
+fn explicit_relationships() {
+    let data: Measure<Bit> = Measure::<BitPerSecond>::new(100.) * Measure::<Second>::new(3.);
+    assert_eq!(data.value, 300.);
+    let rate: Measure<BitPerSecond> = data / Measure::<Second>::new(3.);
+    assert_eq!(rate.value, 100.);
+}
@@ -0,0 +1,94 @@
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: false,
+        with_3d: true,
+        with_transformations: false,
+        with_uncertainty: None,
+    }
+}
+
+mod test_utils;
+
+pub struct Dimensionless;
+impl VectorProperty for Dimensionless {}
+
+pub struct Unspecified;
+impl MeasurementUnit for Unspecified {
+    type Property = Dimensionless;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = "";
+}
+
+pub struct Degree;
+impl MeasurementUnit for Degree {
+    type Property = Angle;
+    const RATIO: f64 = core::f64::consts::TAU / 360.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " deg";
+}
+impl AngleMeasurementUnit for Degree {
+    const CYCLE_FRACTION: f64 = 360.;
+}
+
+#[test]
+fn direction_3d_new() {
+    let d = Direction3d::<Degree>::new(-30., 40.);
+    assert_eq_64!(d.azimuth().value, 330.);
+    assert_eq_64!(d.elevation().value, 40.);
+    let d2 = d.convert::<Radian>();
+    assert_eq_64!(d2.azimuth().value, 330_f64.to_radians());
+    assert_eq_64!(d2.elevation().value, 40_f64.to_radians());
+    let default = Direction3d::<Degree>::default();
+    assert_eq_64!(default.azimuth().value, 0.);
+    assert_eq_64!(default.elevation().value, 0.);
+}
+
+#[test]
+fn direction_3d_unit_vector() {
+    let d = Direction3d::<Degree>::new(90., 45.);
+    let v = d.to_unit_vector::<Unspecified>();
+    assert_eq_64!(v.x, 0.);
+    assert_eq_64!(v.y, 0.5_f64.sqrt());
+    assert_eq_64!(v.z, 0.5_f64.sqrt());
+    assert_eq!(Measure3d::<Unspecified>::from_direction(d), v);
+
+    let m = Measure3d::<Unspecified>::new(0., 0., -4.);
+    let d = m.direction::<Degree>();
+    assert_eq_64!(d.elevation().value, -90.);
+    let m = Measure3d::<Unspecified>::new(-2., 2., 0.);
+    let d = m.direction::<Degree>();
+    assert_eq_64!(d.azimuth().value, 135.);
+    assert_eq_64!(d.elevation().value, 0.);
+}
+
+#[test]
+fn direction_3d_angular_distance() {
+    let d1 = Direction3d::<Degree>::new(10., 0.);
+    let d2 = Direction3d::<Degree>::new(100., 0.);
+    assert_eq_64!(d1.angular_distance(&d2).value, 90.);
+    let north_pole = Direction3d::<Degree>::new(0., 90.);
+    assert_eq_64!(d2.angular_distance(&north_pole).value, 90.);
+    let opposite = Direction3d::<Degree>::new(190., 0.);
+    assert_eq_64!(d1.angular_distance(&opposite).value, 180.);
+    assert_eq_64!(d1.angular_distance(&d1).value, 0.);
+}
+
+#[test]
+fn direction_3d_slerp() {
+    let d1 = Direction3d::<Degree>::new(0., 0.);
+    let d2 = Direction3d::<Degree>::new(0., 90.);
+    let d = d1.slerp(&d2, 1. / 3.);
+    assert_eq_64!(d.azimuth().value, 0.);
+    assert_eq_64!(d.elevation().value, 30.);
+    assert_eq_64!(d1.slerp(&d2, 0.).elevation().value, 0.);
+    assert_eq_64!(d1.slerp(&d2, 1.).elevation().value, 90.);
+}
+
+#[test]
+fn direction_3d_display() {
+    let d = Direction3d::<Degree>::new(90., 0.);
+    assert_eq!(format!("{:.1}", d), "azimuth 90.0 deg, elevation 0.0 deg");
+}
@@ -0,0 +1,138 @@
+use rs_measures::direction_format::CompassPoints;
+use rs_measures::parsing::ParseMeasureError;
+
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: false,
+        with_3d: false,
+        with_transformations: false,
+        with_uncertainty: None,
+    }
+}
+
+mod test_utils;
+
+pub struct Degree;
+impl MeasurementUnit for Degree {
+    type Property = Angle;
+    const RATIO: f64 = core::f64::consts::TAU / 360.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = "\u{b0}";
+}
+impl AngleMeasurementUnit for Degree {
+    const CYCLE_FRACTION: f64 = 360.;
+}
+
+pub struct Gradian;
+impl MeasurementUnit for Gradian {
+    type Property = Angle;
+    const RATIO: f64 = core::f64::consts::TAU / 400.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " grad";
+}
+impl AngleMeasurementUnit for Gradian {
+    const CYCLE_FRACTION: f64 = 400.;
+}
+
+#[test]
+fn direction_range_in_unit() {
+    assert_eq!(
+        UnsignedDirection::<Degree>::new(30.).to_string(),
+        "at 30\u{b0} (in 0\u{b0}..360\u{b0})"
+    );
+    assert_eq!(
+        UnsignedDirection::<Radian>::new(1.5).to_string(),
+        "at 1.5 rad (in 0 rad..2\u{3c0} rad)"
+    );
+    let d = SignedDirection::<Radian>::new(-1.5);
+    assert_eq!(d.to_string(), "at -1.5 rad (in -\u{3c0} rad..\u{3c0} rad)");
+    assert_eq!(d.to_string().parse::<SignedDirection<Radian>>(), Ok(d));
+    let d = SignedDirection::<Gradian>::new(-150.);
+    assert_eq!(d.to_string(), "at -150 grad (in -200 grad..200 grad)");
+    assert_eq!(d.to_string().parse::<SignedDirection<Gradian>>(), Ok(d));
+}
+
+#[test]
+fn dms_format() {
+    let d = UnsignedDirection::<Degree>::new(45. + 30. / 60. + 15.2 / 3600.);
+    assert_eq!(format!("{:.1}", d.dms()), "45\u{b0}30'15.2\"");
+    assert_eq!(format!("{}", d.dms()), "45\u{b0}30'15\"");
+    // The rounding carries to the minutes and to the degrees.
+    let d = UnsignedDirection::<Degree>::new(9.999_99);
+    assert_eq!(format!("{}", d.dms()), "10\u{b0}00'00\"");
+    assert_eq!(format!("{:.2}", d.dms()), "9\u{b0}59'59.96\"");
+    let d = SignedDirection::<Degree>::new(-(12. + 5. / 60.));
+    assert_eq!(format!("{}", d.dms()), "-12\u{b0}05'00\"");
+    let d = UnsignedDirection::<Gradian>::new(100.);
+    assert_eq!(format!("{}", d.dms()), "90\u{b0}00'00\"");
+}
+
+#[test]
+fn dms_parse() {
+    let d = UnsignedDirection::<Degree>::parse_dms("45\u{b0}30'15.2\"").unwrap();
+    assert_eq_64!(d.value, 45. + 30. / 60. + 15.2 / 3600.);
+    let d = SignedDirection::<Degree>::parse_dms(" -12\u{b0} 5\u{2032} ").unwrap();
+    assert_eq_64!(d.value, -(12. + 5. / 60.));
+    let d = UnsignedDirection::<Radian>::parse_dms("90\u{b0}").unwrap();
+    assert_eq_64!(d.value, core::f64::consts::FRAC_PI_2);
+    assert_eq!(
+        UnsignedDirection::<Degree>::parse_dms("45\u{b0}75'"),
+        Err(ParseMeasureError::InvalidNumber("45\u{b0}75'".to_string()))
+    );
+    assert_eq!(
+        UnsignedDirection::<Degree>::parse_dms("45\u{b0}30"),
+        Err(ParseMeasureError::UnexpectedText {
+            expected: "'",
+            found: "".to_string(),
+        })
+    );
+}
+
+#[test]
+fn bearing() {
+    // The directions are counterclockwise from east,
+    // and the bearings are clockwise from north.
+    let d = UnsignedDirection::<Degree>::new(45.);
+    assert_eq!(format!("{}", d.bearing()), "045\u{b0}");
+    let d = SignedDirection::<Degree>::new(-90.);
+    assert_eq!(format!("{}", d.bearing()), "180\u{b0}");
+    let d = UnsignedDirection::<Degree>::new(90.2);
+    assert_eq!(format!("{}", d.bearing()), "000\u{b0}");
+    assert_eq!(format!("{:.1}", d.bearing()), "359.8\u{b0}");
+    let d = UnsignedDirection::<Degree>::new(100.);
+    assert_eq!(format!("{:.1}", d.bearing()), "350.0\u{b0}");
+
+    let d = UnsignedDirection::<Degree>::parse_bearing("270\u{b0}").unwrap();
+    assert_eq_64!(d.value, 180.);
+    let d = SignedDirection::<Radian>::parse_bearing("045").unwrap();
+    assert_eq_64!(d.value, core::f64::consts::FRAC_PI_4);
+    assert!(UnsignedDirection::<Degree>::parse_bearing("45 m").is_err());
+}
+
+#[test]
+fn compass_points() {
+    let d = UnsignedDirection::<Degree>::new(90. - 35.);
+    assert_eq!(d.compass_point(CompassPoints::Four), "N");
+    assert_eq!(d.compass_point(CompassPoints::Eight), "NE");
+    assert_eq!(d.compass_point(CompassPoints::Sixteen), "NE");
+    assert_eq!(d.compass_point(CompassPoints::ThirtyTwo), "NEbN");
+    let d = SignedDirection::<Degree>::new(90. - 337.);
+    assert_eq!(d.compass_point(CompassPoints::Sixteen), "NNW");
+    assert_eq!(d.compass_point(CompassPoints::Eight), "NW");
+    let d = UnsignedDirection::<Degree>::new(90. - 350.);
+    assert_eq!(d.compass_point(CompassPoints::Eight), "N");
+
+    let d = UnsignedDirection::<Degree>::parse_compass_point("nnw").unwrap();
+    assert_eq_64!(d.value, 112.5);
+    let d = UnsignedDirection::<Degree>::parse_compass_point("SEbS").unwrap();
+    assert_eq_64!(d.value, 90. - 146.25 + 360.);
+    assert_eq!(
+        UnsignedDirection::<Degree>::parse_compass_point("NX"),
+        Err(ParseMeasureError::UnexpectedText {
+            expected: "a compass point",
+            found: "NX".to_string(),
+        })
+    );
+}
@@ -0,0 +1,197 @@
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: false,
+        with_3d: true,
+        with_transformations: true,
+        with_uncertainty: None,
+    }
+}
+
+mod test_utils;
+
+pub struct Length;
+impl VectorProperty for Length {}
+
+pub struct Metre;
+impl MeasurementUnit for Metre {
+    type Property = Length;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m";
+}
+
+pub struct KiloMetre;
+impl MeasurementUnit for KiloMetre {
+    type Property = Length;
+    const RATIO: f64 = 1000.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " km";
+}
+
+pub struct Degree;
+impl MeasurementUnit for Degree {
+    type Property = Angle;
+    const RATIO: f64 = core::f64::consts::TAU / 360.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " deg";
+}
+impl AngleMeasurementUnit for Degree {
+    const CYCLE_FRACTION: f64 = 360.;
+}
+
+fn point(latitude: f64, longitude: f64, height: f64) -> GeodeticPoint<Metre, Degree> {
+    GeodeticPoint::new(
+        SignedDirection::new(latitude),
+        SignedDirection::new(longitude),
+        Measure::new(height),
+    )
+}
+
+fn dms(degrees: f64, minutes: f64, seconds: f64) -> f64 {
+    degrees.signum() * (degrees.abs() + minutes / 60. + seconds / 3600.)
+}
+
+#[test]
+fn ellipsoid() {
+    let wgs84 = Ellipsoid::<Metre>::wgs84();
+    assert_eq!(wgs84.semi_major_axis.value, 6_378_137.);
+    assert_eq_tolerance!(wgs84.semi_minor_axis().value, 6_356_752.314_245, 1e-12);
+    let in_km = Ellipsoid::<KiloMetre>::default();
+    assert_eq_64!(in_km.semi_major_axis.value, 6_378.137);
+    assert_eq!(wgs84.convert::<KiloMetre>(), in_km);
+    let sphere = Ellipsoid::<KiloMetre>::new(Measure::new(6371.), 0.);
+    assert_eq!(sphere.semi_minor_axis().value, 6371.);
+}
+
+#[test]
+fn ecef_conversions() {
+    let wgs84 = Ellipsoid::<Metre>::wgs84();
+    let p = wgs84.to_ecef(&point(0., 90., 100.));
+    assert_eq_tolerance!(p.x, 0., 1e-9);
+    assert_eq_64!(p.y, 6_378_237.);
+    assert_eq_64!(p.z, 0.);
+    let north_pole = wgs84.to_ecef(&point(90., 0., 0.));
+    assert_eq_tolerance!(north_pole.x, 0., 1e-9);
+    assert_eq_64!(north_pole.z, wgs84.semi_minor_axis().value);
+
+    for original in [
+        point(45.5, 9.2, 120.),
+        point(-33.9, -151.2, -30.),
+        point(89.999, 12., 8_000.),
+        point(-90., 0., 0.),
+    ] {
+        let back = wgs84.from_ecef::<Degree>(wgs84.to_ecef(&original));
+        assert_eq_tolerance!(back.latitude.value, original.latitude.value, 1e-12);
+        assert_eq_tolerance!(back.longitude.value, original.longitude.value, 1e-12);
+        assert_eq_tolerance!(back.height.value, original.height.value, 1e-6);
+    }
+}
+
+#[test]
+fn enu_frame() {
+    let wgs84 = Ellipsoid::<Metre>::wgs84();
+    let origin = point(45., 7., 200.);
+    let frame = wgs84.enu_frame(&origin);
+    let local = frame.apply_to(wgs84.to_ecef(&origin));
+    assert_eq_tolerance!(local.x, 0., 1e-9);
+    assert_eq_tolerance!(local.y, 0., 1e-9);
+    assert_eq_tolerance!(local.z, 0., 1e-9);
+    let above = frame.apply_to(wgs84.to_ecef(&point(45., 7., 300.)));
+    assert_eq_tolerance!(above.x, 0., 1e-8);
+    assert_eq_tolerance!(above.y, 0., 1e-8);
+    assert_eq_tolerance!(above.z, 100., 1e-10);
+    let east = frame.apply_to(wgs84.to_ecef(&point(45., 7.001, 200.)));
+    assert!(east.x > 78. && east.x < 79.);
+    assert!(east.y.abs() < 0.01);
+    let back = wgs84.from_ecef::<Degree>(frame.inverted().apply_to(east));
+    assert_eq_tolerance!(back.longitude.value, 7.001, 1e-12);
+}
+
+#[test]
+fn geodesic_distance_and_bearing() {
+    // The example of Vincenty's paper, from Flinders Peak to Buninyong,
+    // whose reference values are computed on the GRS80 ellipsoid.
+    let grs80 = Ellipsoid::<Metre>::new(Measure::new(6_378_137.), 1. / 298.257_222_101);
+    let flinders_peak = point(dms(-37., 57., 3.72030), dms(144., 25., 29.52440), 0.);
+    let buninyong = point(dms(-37., 39., 10.15610), dms(143., 55., 35.38390), 0.);
+    assert_eq_tolerance!(
+        grs80.geodesic_distance(&flinders_peak, &buninyong).value,
+        54_972.271,
+        1e-8
+    );
+    assert_eq_tolerance!(
+        grs80.initial_bearing(&flinders_peak, &buninyong).value,
+        dms(306., 52., 5.37),
+        1e-7
+    );
+
+    let wgs84 = Ellipsoid::<KiloMetre>::wgs84();
+    let equator = |longitude| {
+        GeodeticPoint::<KiloMetre, Degree>::new(
+            SignedDirection::new(0.),
+            SignedDirection::new(longitude),
+            Measure::new(0.),
+        )
+    };
+    assert_eq_tolerance!(
+        wgs84.geodesic_distance(&equator(10.), &equator(100.)).value,
+        wgs84.semi_major_axis.value * core::f64::consts::FRAC_PI_2,
+        1e-12
+    );
+    assert_eq_64!(
+        wgs84.initial_bearing(&equator(10.), &equator(100.)).value,
+        90.
+    );
+    assert_eq_64!(
+        wgs84.initial_bearing(&equator(10.), &equator(-10.)).value,
+        270.
+    );
+    assert_eq!(
+        wgs84.geodesic_distance(&equator(10.), &equator(10.)).value,
+        0.
+    );
+}
+
+#[test]
+fn initial_direction() {
+    let grs80 = Ellipsoid::<Metre>::new(Measure::new(6_378_137.), 1. / 298.257_222_101);
+    let flinders_peak = point(dms(-37., 57., 3.72030), dms(144., 25., 29.52440), 0.);
+    let buninyong = point(dms(-37., 39., 10.15610), dms(143., 55., 35.38390), 0.);
+    // The direction is counterclockwise from east, and the bearing is clockwise from north.
+    let direction = grs80.initial_direction(&flinders_peak, &buninyong);
+    assert_eq_tolerance!(direction.value, 450. - dms(306., 52., 5.37), 1e-7);
+    assert_eq!(format!("{:.2}", direction.bearing()), "306.87\u{b0}");
+
+    let wgs84 = Ellipsoid::<KiloMetre>::wgs84();
+    let equator = |longitude| {
+        GeodeticPoint::<KiloMetre, Degree>::new(
+            SignedDirection::new(0.),
+            SignedDirection::new(longitude),
+            Measure::new(0.),
+        )
+    };
+    assert_eq_tolerance!(
+        wgs84.initial_direction(&equator(10.), &equator(100.)).value,
+        0.,
+        1e-12
+    );
+    assert_eq_64!(
+        wgs84.initial_direction(&equator(10.), &equator(-10.)).value,
+        180.
+    );
+}
+
+#[test]
+fn geodetic_point_display() {
+    let p = point(45.5, -9.25, 120.);
+    assert_eq!(
+        format!("{}", p),
+        "latitude 45.5 deg, longitude -9.25 deg, height 120 m"
+    );
+    assert_eq!(
+        format!("{}", p.convert::<KiloMetre, Degree>()),
+        "latitude 45.5 deg, longitude -9.25 deg, height 0.12 km"
+    );
+}
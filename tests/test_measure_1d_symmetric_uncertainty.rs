@@ -0,0 +1,335 @@
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: false,
+        with_directions: false,
+        with_2d: false,
+        with_3d: false,
+        with_transformations: false,
+        with_uncertainty: Symmetric,
+    }
+}
+
+mod test_utils;
+
+struct Length;
+
+struct Metre;
+impl MeasurementUnit for Metre {
+    type Property = Length;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m";
+}
+
+struct MilliMetre;
+impl MeasurementUnit for MilliMetre {
+    type Property = Length;
+    const RATIO: f64 = 0.001;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mm";
+}
+
+struct Area;
+
+struct SquareMetre;
+impl MeasurementUnit for SquareMetre {
+    type Property = Area;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m\u{b2}";
+}
+
+rs_measures::define_units_relationship! { SquareMetre == Metre * = }
+
+struct Volume;
+
+struct CubicMetre;
+impl MeasurementUnit for CubicMetre {
+    type Property = Volume;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m\u{b3}";
+}
+
+rs_measures::define_units_relationship! { CubicMetre == Metre ^ 3 }
+
+struct Time;
+
+struct Second;
+impl MeasurementUnit for Second {
+    type Property = Time;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " s";
+}
+
+struct Velocity;
+
+struct MetrePerSecond;
+impl MeasurementUnit for MetrePerSecond {
+    type Property = Velocity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m/s";
+}
+
+rs_measures::define_units_relationship! { Metre == MetrePerSecond * Second }
+
+struct Frequency;
+
+struct Hertz;
+impl MeasurementUnit for Hertz {
+    type Property = Frequency;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Hz";
+}
+
+rs_measures::define_units_relationship! { Hertz == 1 / Second }
+
+#[test]
+fn measure_default() {
+    let m = Measure::<Metre>::default();
+    assert_eq!(m.value, 0.);
+    assert_eq!(m.uncertainty, 0.);
+}
+
+#[test]
+fn measure_new() {
+    let m = Measure::<Metre, f32>::new(12.);
+    assert_eq!(m.value, 12.);
+    assert_eq!(m.uncertainty, 0.);
+}
+
+#[test]
+fn measure_with_uncertainty() {
+    let m = Measure::<Metre, f32>::with_uncertainty(12., 0.5);
+    assert_eq!(m.value, 12.);
+    assert_eq!(m.uncertainty, 0.5);
+}
+
+#[test]
+fn measure_relative_uncertainty() {
+    let m = Measure::<Metre>::with_uncertainty(-12., 0.6);
+    assert_eq_64!(m.relative_uncertainty(), 0.05);
+}
+
+#[test]
+fn measure_convert() {
+    let m1 = Measure::<Metre, f32>::with_uncertainty(12., 0.5);
+    let m2: Measure<MilliMetre, f32> = m1.convert::<MilliMetre>();
+    assert_eq!(m2.value, 12000.);
+    assert_eq!(m2.uncertainty, 500.);
+}
+
+#[test]
+fn measure_lossless_into() {
+    let m1 = Measure::<Metre, f32>::with_uncertainty(12., 0.5);
+    let m2: Measure<Metre, f64> = m1.lossless_into::<f64>();
+    assert_eq!(m2.value, 12.);
+    assert_eq!(m2.uncertainty, 0.5);
+}
+
+#[test]
+fn measure_lossy_into() {
+    let m1 = Measure::<Metre, f64>::with_uncertainty(12., 0.5);
+    let m2: Measure<Metre, f32> = m1.lossy_into::<f32>();
+    assert_eq!(m2.value, 12.);
+    assert_eq!(m2.uncertainty, 0.5);
+}
+
+#[test]
+fn measure_neg() {
+    let m = -Measure::<Metre>::with_uncertainty(12., 0.5);
+    assert_eq!(m.value, -12.);
+    assert_eq!(m.uncertainty, 0.5);
+}
+
+#[test]
+fn measure_add_measure() {
+    let m =
+        Measure::<Metre>::with_uncertainty(12., 0.3) + Measure::<Metre>::with_uncertainty(7., 0.4);
+    assert_eq!(m.value, 19.);
+    assert_eq_64!(m.uncertainty, 0.5);
+}
+
+#[test]
+fn measure_add_assign_measure() {
+    let mut m = Measure::<Metre>::with_uncertainty(12., 0.3);
+    m += Measure::<Metre>::with_uncertainty(7., 0.4);
+    assert_eq!(m.value, 19.);
+    assert_eq_64!(m.uncertainty, 0.5);
+}
+
+#[test]
+fn measure_sub_measure() {
+    let m =
+        Measure::<Metre>::with_uncertainty(12., 0.3) - Measure::<Metre>::with_uncertainty(7., 0.4);
+    assert_eq!(m.value, 5.);
+    assert_eq_64!(m.uncertainty, 0.5);
+}
+
+#[test]
+fn measure_sub_assign_measure() {
+    let mut m = Measure::<Metre>::with_uncertainty(12., 0.3);
+    m -= Measure::<Metre>::with_uncertainty(7., 0.4);
+    assert_eq!(m.value, 5.);
+    assert_eq_64!(m.uncertainty, 0.5);
+}
+
+#[test]
+fn measure_mul_number() {
+    let m = Measure::<Metre>::with_uncertainty(12., 0.5) * -3.;
+    assert_eq!(m.value, -36.);
+    assert_eq!(m.uncertainty, 1.5);
+}
+
+#[test]
+fn measure_mul_assign_number() {
+    let mut m = Measure::<Metre>::with_uncertainty(12., 0.5);
+    m *= -3.;
+    assert_eq!(m.value, -36.);
+    assert_eq!(m.uncertainty, 1.5);
+}
+
+#[test]
+fn number_mul_measure() {
+    let m = -3. * Measure::<Metre>::with_uncertainty(12., 0.5);
+    assert_eq!(m.value, -36.);
+    assert_eq!(m.uncertainty, 1.5);
+}
+
+#[test]
+fn measure_div_number() {
+    let m = Measure::<Metre>::with_uncertainty(12., 0.5) / -2.;
+    assert_eq!(m.value, -6.);
+    assert_eq!(m.uncertainty, 0.25);
+}
+
+#[test]
+fn measure_div_assign_number() {
+    let mut m = Measure::<Metre>::with_uncertainty(12., 0.5);
+    m /= -2.;
+    assert_eq!(m.value, -6.);
+    assert_eq!(m.uncertainty, 0.25);
+}
+
+#[test]
+fn measure_squared() {
+    let m: Measure<SquareMetre> = Measure::<Metre>::with_uncertainty(3., 0.1).squared();
+    assert_eq!(m.value, 9.);
+    assert_eq_64!(m.uncertainty, 0.6);
+}
+
+#[test]
+fn measure_sqrt() {
+    let m: Measure<Metre> = Measure::<SquareMetre>::with_uncertainty(9., 0.6).sqrt();
+    assert_eq!(m.value, 3.);
+    assert_eq_64!(m.uncertainty, 0.1);
+}
+
+#[test]
+fn measure_cubed_and_cubic_root() {
+    let m: Measure<CubicMetre> = Measure::<Metre>::with_uncertainty(3., 0.1).cubed();
+    assert_eq!(m.value, 27.);
+    assert_eq_64!(m.uncertainty, 2.7);
+    let m: Measure<Metre> = Measure::<CubicMetre>::with_uncertainty(27., 2.7).cubic_root();
+    assert_eq_64!(m.value, 3.);
+    assert_eq_64!(m.uncertainty, 0.1);
+}
+
+#[test]
+fn measure_roots_of_zero() {
+    let m: Measure<Metre> = Measure::<SquareMetre>::new(0.).sqrt();
+    assert_eq!(m.value, 0.);
+    assert_eq!(m.uncertainty, 0.);
+    let m: Measure<Metre> = Measure::<SquareMetre>::with_uncertainty(0., 0.09).sqrt();
+    assert_eq!(m.value, 0.);
+    assert_eq_64!(m.uncertainty, 0.3);
+    let sum = m + Measure::<Metre>::with_uncertainty(2., 0.4);
+    assert_eq_64!(sum.uncertainty, 0.5);
+    let m: Measure<Metre> = Measure::<CubicMetre>::new(0.).cubic_root();
+    assert_eq!(m.value, 0.);
+    assert_eq!(m.uncertainty, 0.);
+    let m: Measure<Metre> = Measure::<CubicMetre>::with_uncertainty(0., 0.008).cubic_root();
+    assert_eq!(m.value, 0.);
+    assert_eq_64!(m.uncertainty, 0.2);
+}
+
+#[test]
+fn measure_mul_measure() {
+    let m: Measure<Metre> = Measure::<MetrePerSecond>::with_uncertainty(2., 0.1)
+        * Measure::<Second>::with_uncertainty(3., 0.2);
+    assert_eq!(m.value, 6.);
+    assert_eq_64!(m.uncertainty, 0.5);
+}
+
+#[test]
+fn measure_div_measure() {
+    let m: Measure<MetrePerSecond> =
+        Measure::<Metre>::with_uncertainty(6., 0.3) / Measure::<Second>::with_uncertainty(3., 0.2);
+    assert_eq!(m.value, 2.);
+    assert_eq_64!(m.uncertainty, 0.5 / 3.);
+}
+
+#[test]
+fn number_div_measure() {
+    let m: Measure<Hertz> = 1. / Measure::<Second>::with_uncertainty(4., 0.2);
+    assert_eq!(m.value, 0.25);
+    assert_eq_64!(m.uncertainty, 0.0125);
+}
+
+#[test]
+fn measure_equals() {
+    let m1 = Measure::<Metre>::with_uncertainty(12., 0.5);
+    assert!(m1 == Measure::<Metre>::with_uncertainty(12., 0.5));
+    assert!(m1 != Measure::<Metre>::with_uncertainty(12., 0.25));
+    assert!(m1 != Measure::<Metre>::with_uncertainty(13., 0.5));
+}
+
+#[test]
+fn measure_partial_cmp() {
+    let m1 = Measure::<Metre>::with_uncertainty(12., 0.5);
+    assert!(m1 < Measure::<Metre>::with_uncertainty(13., 0.1));
+    assert!(m1 > Measure::<Metre>::with_uncertainty(11., 0.1));
+    assert_eq!(
+        m1.partial_cmp(&Measure::<Metre>::with_uncertainty(12., 0.1)),
+        None
+    );
+}
+
+#[test]
+fn measure_max() {
+    let m1 = Measure::<Metre>::with_uncertainty(12., 0.5);
+    let m2 = Measure::<Metre>::with_uncertainty(13., 0.1);
+    assert_eq!(max(m1, m2), m2);
+    assert_eq!(max(m2, m1), m2);
+}
+
+#[test]
+fn measure_formatting() {
+    let m = Measure::<Metre, f32>::with_uncertainty(12.3, 0.2);
+    assert_eq!(format!("{}", m), "12.3 \u{b1} 0.2 m");
+    assert_eq!(format!("{:.2}", m), "12.30 \u{b1} 0.20 m");
+    assert_eq!(format!("{:?}", m), "12.3 \u{b1} 0.2 m");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn measure_serde() {
+    let m = Measure::<Metre>::with_uncertainty(12.5, 0.25);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(json, r#"{"value":12.5,"uncertainty":0.25,"unit":"m"}"#);
+    assert_eq!(serde_json::from_str::<Measure<Metre>>(&json).unwrap(), m);
+    assert!(serde_json::from_str::<Measure<MilliMetre>>(&json).is_err());
+}
+
+#[test]
+fn measure_parse() {
+    let m = Measure::<Metre>::with_uncertainty(12.5, 0.25);
+    assert_eq!(m.to_string().parse::<Measure<Metre>>(), Ok(m));
+    let m: Measure<Metre> = "12.5 m".parse().unwrap();
+    assert_eq!(m.value, 12.5);
+    assert_eq!(m.uncertainty, 0.);
+    assert!("12.5 \u{b1} 0.25 mm".parse::<Measure<Metre>>().is_err());
+}
@@ -0,0 +1,179 @@
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: false,
+        with_directions: false,
+        with_2d: true,
+        with_3d: true,
+        with_transformations: false,
+        with_uncertainty: None,
+    }
+}
+
+mod test_utils;
+
+pub struct AngularSpeed;
+impl VectorProperty for AngularSpeed {}
+
+pub struct RadianPerSecond;
+impl MeasurementUnit for RadianPerSecond {
+    type Property = AngularSpeed;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " rad/s";
+}
+
+pub struct MomentOfInertia;
+
+pub struct KiloGramSquareMetre;
+impl MeasurementUnit for KiloGramSquareMetre {
+    type Property = MomentOfInertia;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kg\u{b7}m\u{b2}";
+}
+
+pub struct AngularMomentum;
+impl VectorProperty for AngularMomentum {}
+
+pub struct KiloGramSquareMetrePerSecond;
+impl MeasurementUnit for KiloGramSquareMetrePerSecond {
+    type Property = AngularMomentum;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " kg\u{b7}m\u{b2}/s";
+}
+
+pub struct GramSquareCentiMetrePerSecond;
+impl MeasurementUnit for GramSquareCentiMetrePerSecond {
+    type Property = AngularMomentum;
+    const RATIO: f64 = 1e-7;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " g\u{b7}cm\u{b2}/s";
+}
+
+pub struct ElectricalConductivity;
+
+pub struct SiemensPerMetre;
+impl MeasurementUnit for SiemensPerMetre {
+    type Property = ElectricalConductivity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " S/m";
+}
+
+pub struct ElectricFieldStrength;
+impl VectorProperty for ElectricFieldStrength {}
+
+pub struct VoltPerMetre;
+impl MeasurementUnit for VoltPerMetre {
+    type Property = ElectricFieldStrength;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " V/m";
+}
+
+pub struct CurrentDensity;
+impl VectorProperty for CurrentDensity {}
+
+pub struct AmperePerSquareMetre;
+impl MeasurementUnit for AmperePerSquareMetre {
+    type Property = CurrentDensity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " A/m\u{b2}";
+}
+
+rs_measures::define_units_relationship! {KiloGramSquareMetrePerSecond:3 == KiloGramSquareMetre * RadianPerSecond:3}
+rs_measures::define_units_relationship! {AmperePerSquareMetre:2 == SiemensPerMetre * VoltPerMetre:2}
+
+fn inertia_tensor() -> MeasureLinearMap3d<RadianPerSecond, KiloGramSquareMetrePerSecond> {
+    MeasureLinearMap3d::from([
+        [
+            Measure::<KiloGramSquareMetre>::new(2.),
+            Measure::new(0.),
+            Measure::new(-1.),
+        ],
+        [Measure::new(0.), Measure::new(3.), Measure::new(0.)],
+        [Measure::new(-1.), Measure::new(0.), Measure::new(4.)],
+    ])
+}
+
+#[test]
+fn measure_linear_map_3d_apply_to() {
+    let tensor = inertia_tensor();
+    assert_eq!(
+        tensor.coefficients(),
+        [[2., 0., -1.], [0., 3., 0.], [-1., 0., 4.]]
+    );
+    let angular_velocity = Measure3d::<RadianPerSecond>::new(1., 2., 3.);
+    let angular_momentum: Measure3d<KiloGramSquareMetrePerSecond> =
+        tensor.apply_to(angular_velocity);
+    assert_eq!(
+        angular_momentum,
+        Measure3d::<KiloGramSquareMetrePerSecond>::new(-1., 6., 11.)
+    );
+    assert_eq!(tensor * angular_velocity, angular_momentum);
+}
+
+#[test]
+fn measure_linear_map_3d_inverted() {
+    let tensor = inertia_tensor();
+    let inverse: MeasureLinearMap3d<KiloGramSquareMetrePerSecond, RadianPerSecond> =
+        tensor.inverted();
+    let angular_velocity = inverse.apply_to(Measure3d::new(-1., 6., 11.));
+    assert_eq_64!(angular_velocity.x, 1.);
+    assert_eq_64!(angular_velocity.y, 2.);
+    assert_eq_64!(angular_velocity.z, 3.);
+    let identity = inverse.combined_with(&tensor).coefficients();
+    for (i, row) in identity.iter().enumerate() {
+        for (j, c) in row.iter().enumerate() {
+            assert_eq_64!(*c, if i == j { 1. } else { 0. });
+        }
+    }
+}
+
+#[test]
+fn measure_linear_map_3d_combined_with() {
+    let scaling = MeasureLinearMap3d::<RadianPerSecond, RadianPerSecond>::new([
+        [0., 1., 0.],
+        [1., 0., 0.],
+        [0., 0., 2.],
+    ]);
+    let combined: MeasureLinearMap3d<RadianPerSecond, KiloGramSquareMetrePerSecond> =
+        inertia_tensor().combined_with(&scaling);
+    let angular_velocity = Measure3d::<RadianPerSecond>::new(1., 2., 3.);
+    assert_eq!(
+        combined.apply_to(angular_velocity),
+        inertia_tensor().apply_to(scaling.apply_to(angular_velocity))
+    );
+}
+
+#[test]
+fn measure_linear_map_3d_convert() {
+    let tensor = inertia_tensor().convert::<RadianPerSecond, GramSquareCentiMetrePerSecond>();
+    let angular_momentum = tensor.apply_to(Measure3d::new(1., 2., 3.));
+    assert_eq_64!(angular_momentum.x, -1e7);
+    assert_eq_64!(angular_momentum.y, 6e7);
+    assert_eq_64!(angular_momentum.z, 11e7);
+    assert_eq!(
+        format!("{}", inertia_tensor()),
+        "[ 2 0 -1] kg\u{b7}m\u{b2}/s/rad/s\n[ 0 3  0]\n[-1 0  4]"
+    );
+}
+
+#[test]
+fn measure_linear_map_2d() {
+    let conductivity = MeasureLinearMap2d::<VoltPerMetre, AmperePerSquareMetre>::from([
+        [Measure::<SiemensPerMetre>::new(4.), Measure::new(1.)],
+        [Measure::new(2.), Measure::new(3.)],
+    ]);
+    let field = Measure2d::<VoltPerMetre>::new(1., -2.);
+    let current_density = conductivity * field;
+    assert_eq!(
+        current_density,
+        Measure2d::<AmperePerSquareMetre>::new(2., -4.)
+    );
+    let back = conductivity.inverted().apply_to(current_density);
+    assert_eq_64!(back.x, 1.);
+    assert_eq_64!(back.y, -2.);
+}
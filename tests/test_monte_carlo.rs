@@ -0,0 +1,201 @@
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: true,
+        with_3d: true,
+        with_transformations: false,
+        with_uncertainty: Symmetric,
+    }
+}
+
+use rs_measures::monte_carlo::Sampler;
+use std::f64::consts::PI;
+
+mod test_utils;
+
+struct Length;
+impl VectorProperty for Length {}
+
+struct Metre;
+impl MeasurementUnit for Metre {
+    type Property = Length;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m";
+}
+
+struct Area;
+
+struct SquareMetre;
+impl MeasurementUnit for SquareMetre {
+    type Property = Area;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m\u{b2}";
+}
+
+rs_measures::define_units_relationship! { SquareMetre == Metre * = }
+
+const TRIALS: usize = 200_000;
+
+#[test]
+fn sampler_is_deterministic() {
+    let mut s1 = Sampler::new(42);
+    let mut s2 = Sampler::new(42);
+    let mut s3 = Sampler::new(43);
+    let values1: Vec<u64> = (0..10).map(|_| s1.next_u64()).collect();
+    let values2: Vec<u64> = (0..10).map(|_| s2.next_u64()).collect();
+    let values3: Vec<u64> = (0..10).map(|_| s3.next_u64()).collect();
+    assert_eq!(values1, values2);
+    assert_ne!(values1, values3);
+}
+
+#[test]
+fn sampler_distributions() {
+    let mut sampler = Sampler::new(1);
+    let uniform: Vec<f64> = (0..TRIALS).map(|_| sampler.uniform()).collect();
+    assert!(uniform.iter().all(|&u| (0. ..1.).contains(&u)));
+    let (mean, standard_deviation) =
+        rs_measures::monte_carlo::mean_and_standard_deviation(&uniform);
+    assert_eq_tolerance!(mean, 0.5, 0.005);
+    assert_eq_tolerance!(standard_deviation, (1. / 12_f64).sqrt(), 0.005);
+
+    let normal: Vec<f64> = (0..TRIALS).map(|_| sampler.normal(10., 2.)).collect();
+    let (mean, standard_deviation) = rs_measures::monte_carlo::mean_and_standard_deviation(&normal);
+    assert_eq_tolerance!(mean, 10., 0.002);
+    assert_eq_tolerance!(standard_deviation, 2., 0.01);
+}
+
+#[test]
+fn quantile_and_shortest_interval() {
+    let sorted = [1., 2., 3., 4., 10.];
+    assert_eq_64!(rs_measures::monte_carlo::quantile(&sorted, 0.), 1.);
+    assert_eq_64!(rs_measures::monte_carlo::quantile(&sorted, 0.5), 3.);
+    assert_eq_64!(rs_measures::monte_carlo::quantile(&sorted, 0.625), 3.5);
+    assert_eq_64!(rs_measures::monte_carlo::quantile(&sorted, 1.), 10.);
+    assert_eq!(
+        rs_measures::monte_carlo::shortest_interval(&sorted, 0.8),
+        (1., 4.)
+    );
+}
+
+#[test]
+fn measure_sample() {
+    let m = Measure::<Metre>::with_uncertainty(5., 0.5);
+    let mut sampler = Sampler::new(2);
+    let sample = m.sample(&mut sampler);
+    assert_eq!(sample.uncertainty, 0.);
+    let result = monte_carlo(TRIALS, &mut sampler, |s| m.sample(s));
+    assert_eq!(result.sorted_values.len(), TRIALS);
+    assert_eq_tolerance!(result.mean().value, 5., 0.002);
+    assert_eq_tolerance!(result.standard_deviation().value, 0.5, 0.01);
+}
+
+#[test]
+fn monte_carlo_linear_function() {
+    let m1 = Measure::<Metre>::with_uncertainty(2., 0.3);
+    let m2 = Measure::<Metre>::with_uncertainty(3., 0.4);
+    let mut sampler = Sampler::new(3);
+    let result = monte_carlo(TRIALS, &mut sampler, |s| m1.sample(s) + m2.sample(s));
+    let analytic = m1 + m2;
+    let measure = result.to_measure();
+    assert_eq_tolerance!(measure.value, analytic.value, 0.002);
+    assert_eq_tolerance!(measure.uncertainty, analytic.uncertainty, 0.01);
+    let (low, high) = result.coverage_interval(0.95);
+    assert_eq_tolerance!(low.value, 5. - 1.959964 * 0.5, 0.005);
+    assert_eq_tolerance!(high.value, 5. + 1.959964 * 0.5, 0.004);
+}
+
+#[test]
+fn monte_carlo_nonlinear_function() {
+    // The first-order propagation of the uncertainty of the square of zero
+    // yields a null uncertainty.
+    let m = Measure::<Metre>::with_uncertainty(0., 1.);
+    assert_eq!(m.squared().uncertainty, 0.);
+
+    // Instead, the square of a standard normal variable has a chi-squared distribution
+    // with one degree of freedom, having mean 1 and standard deviation sqrt(2).
+    let mut sampler = Sampler::new(4);
+    let result: MonteCarloResult<SquareMetre> =
+        monte_carlo(TRIALS, &mut sampler, |s| m.sample(s).squared());
+    assert_eq_tolerance!(result.mean().value, 1., 0.02);
+    assert_eq_tolerance!(result.standard_deviation().value, 2_f64.sqrt(), 0.02);
+    let (low, high) = result.coverage_interval(0.95);
+    assert_eq_tolerance!(low.value, 0.000982, 0.0002);
+    assert_eq_tolerance!(high.value, 5.0239, 0.02);
+    let (low, high) = result.shortest_coverage_interval(0.95);
+    assert_eq_tolerance!(low.value, 0., 0.0001);
+    assert_eq_tolerance!(high.value, 3.8415, 0.026);
+}
+
+#[test]
+fn measure_2d_sample() {
+    let v = Measure2d::<Metre>::with_covariance(1., 2., [[4., 1.2], [1.2, 1.]]);
+    let mut sampler = Sampler::new(5);
+    let samples: Vec<Measure2d<Metre>> = (0..TRIALS).map(|_| v.sample(&mut sampler)).collect();
+    assert_eq!(samples[0].covariance, [[0., 0.], [0., 0.]]);
+    let xs: Vec<f64> = samples.iter().map(|m| m.x).collect();
+    let ys: Vec<f64> = samples.iter().map(|m| m.y).collect();
+    let (mean_x, sd_x) = rs_measures::monte_carlo::mean_and_standard_deviation(&xs);
+    let (mean_y, sd_y) = rs_measures::monte_carlo::mean_and_standard_deviation(&ys);
+    assert_eq_tolerance!(mean_x, 1., 0.02);
+    assert_eq_tolerance!(mean_y, 2., 0.005);
+    assert_eq_tolerance!(sd_x, 2., 0.01);
+    assert_eq_tolerance!(sd_y, 1., 0.01);
+    let covariance_xy = xs
+        .iter()
+        .zip(ys.iter())
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum::<f64>()
+        / (TRIALS - 1) as f64;
+    assert_eq_tolerance!(covariance_xy, 1.2, 0.02);
+}
+
+#[test]
+fn monte_carlo_signed_direction() {
+    let v = Measure2d::<Metre>::with_uncertainty(1., 0., [0.1, 0.1]);
+    let mut sampler = Sampler::new(6);
+    let result = monte_carlo(TRIALS, &mut sampler, |s| {
+        Measure::<Radian>::new(v.sample(s).signed_direction::<Radian>().value)
+    });
+    assert_eq_tolerance!(result.mean().value, 0., 0.001);
+    // The first-order estimate is 0.1 rad.
+    assert_eq_tolerance!(result.standard_deviation().value, 0.1, 0.003);
+}
+
+#[test]
+fn measure_point_3d_sample() {
+    let p = MeasurePoint3d::<Metre>::with_uncertainty(1., 2., 3., [0.1, 0.2, 0.]);
+    let mut sampler = Sampler::new(7);
+    let samples: Vec<MeasurePoint3d<Metre>> = (0..TRIALS).map(|_| p.sample(&mut sampler)).collect();
+    assert!(samples.iter().all(|s| s.z == 3.));
+    let ys: Vec<f64> = samples.iter().map(|m| m.y).collect();
+    let (mean_y, sd_y) = rs_measures::monte_carlo::mean_and_standard_deviation(&ys);
+    assert_eq_tolerance!(mean_y, 2., 0.001);
+    assert_eq_tolerance!(sd_y, 0.2, 0.002);
+}
+
+#[test]
+fn monte_carlo_signed_direction_near_wrap_around() {
+    let v = Measure2d::<Metre>::with_uncertainty(-1., 0., [0.1, 0.1]);
+    let mut sampler = Sampler::new(8);
+
+    // Averaged linearly, the directions near half cycle, which are split
+    // between about -180° and about +180°, yield a mean near zero.
+    let linear = monte_carlo(TRIALS, &mut sampler, |s| {
+        Measure::<Radian>::new(v.sample(s).signed_direction::<Radian>().value)
+    });
+    assert_eq_tolerance!(linear.mean().value, 0., 0.05);
+    assert!(linear.standard_deviation().value > 3.);
+
+    // Averaged circularly, they yield a mean near half cycle.
+    let result = monte_carlo_direction(TRIALS, &mut sampler, |s| {
+        v.sample(s).signed_direction::<Radian>()
+    });
+    let mean = SignedDirection::<Radian>::new(result.mean().value);
+    assert_eq_tolerance!(mean.value.abs(), PI, 0.001);
+    assert_eq_tolerance!(result.standard_deviation().value, 0.1, 0.003);
+    let (low, high) = result.coverage_interval(0.95);
+    assert_eq_tolerance!(high.value - low.value, 2. * 1.959964 * 0.1, 0.02);
+}
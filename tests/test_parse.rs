@@ -0,0 +1,187 @@
+use rs_measures::parsing::ParseMeasureError;
+use std::f64::consts::TAU;
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: true,
+        with_3d: true,
+        with_transformations: false,
+        with_uncertainty: None,
+    }
+}
+
+pub struct Dimensionless;
+impl VectorProperty for Dimensionless {}
+
+pub struct Unspecified;
+impl MeasurementUnit for Unspecified {
+    type Property = Dimensionless;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = "";
+}
+
+struct Length;
+impl VectorProperty for Length {}
+
+struct Metre;
+impl MeasurementUnit for Metre {
+    type Property = Length;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m";
+}
+
+struct KiloMetre;
+impl MeasurementUnit for KiloMetre {
+    type Property = Length;
+    const RATIO: f64 = 1000.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " km";
+}
+
+struct Degree;
+impl MeasurementUnit for Degree {
+    type Property = Angle;
+    const RATIO: f64 = TAU / 360.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = "\u{b0}";
+}
+impl AngleMeasurementUnit for Degree {
+    const CYCLE_FRACTION: f64 = 360.;
+}
+
+#[test]
+fn measure_parse() {
+    let m: Measure<KiloMetre> = "12.5 km".parse().unwrap();
+    assert_eq!(m.value, 12.5);
+    let m: Measure<KiloMetre, f32> = " -3e2km ".parse().unwrap();
+    assert_eq!(m.value, -300.);
+    let m = Measure::<KiloMetre>::new(-0.25);
+    assert_eq!(m.to_string().parse::<Measure<KiloMetre>>(), Ok(m));
+    let m: Measure<Unspecified> = "7".parse().unwrap();
+    assert_eq!(m.value, 7.);
+}
+
+#[test]
+fn measure_parse_errors() {
+    assert_eq!(
+        "12.5 m".parse::<Measure<KiloMetre>>(),
+        Err(ParseMeasureError::WrongSuffix {
+            expected: "km",
+            found: "m".to_string(),
+        })
+    );
+    assert_eq!(
+        "12.5 km".parse::<Measure<Unspecified>>(),
+        Err(ParseMeasureError::WrongSuffix {
+            expected: "",
+            found: "km".to_string(),
+        })
+    );
+    assert_eq!(
+        "km".parse::<Measure<KiloMetre>>(),
+        Err(ParseMeasureError::InvalidNumber("km".to_string()))
+    );
+    assert_eq!(
+        "12.5 m"
+            .parse::<Measure<KiloMetre>>()
+            .unwrap_err()
+            .to_string(),
+        r#"mismatched unit of measurement: expected "km", found "m""#
+    );
+}
+
+#[test]
+fn measure_point_parse() {
+    let p = MeasurePoint::<Metre>::new(-4.5);
+    assert_eq!(p.to_string(), "at -4.5 m");
+    assert_eq!(p.to_string().parse::<MeasurePoint<Metre>>(), Ok(p));
+    assert_eq!(
+        "-4.5 m".parse::<MeasurePoint<Metre>>(),
+        Err(ParseMeasureError::UnexpectedText {
+            expected: "at",
+            found: "-4.5 m".to_string(),
+        })
+    );
+}
+
+#[test]
+fn directions_parse() {
+    let d = UnsignedDirection::<Degree>::new(30.);
+    assert_eq!(d.to_string(), "at 30° (in 0°..360°)");
+    assert_eq!(d.to_string().parse::<UnsignedDirection<Degree>>(), Ok(d));
+    let d: UnsignedDirection<Degree> = "at -30°".parse().unwrap();
+    assert_eq!(d.value, 330.);
+    let d = SignedDirection::<Degree>::new(-30.);
+    assert_eq!(d.to_string(), "at -30° (in -180°..180°)");
+    assert_eq!(d.to_string().parse::<SignedDirection<Degree>>(), Ok(d));
+    assert_eq!(
+        "at 30 m (in 0°..360°)".parse::<UnsignedDirection<Degree>>(),
+        Err(ParseMeasureError::WrongSuffix {
+            expected: "°",
+            found: "m".to_string(),
+        })
+    );
+}
+
+#[test]
+fn directions_parse_in_radians() {
+    let d = UnsignedDirection::<Radian>::new(1.);
+    assert_eq!(d.to_string(), "at 1 rad (in 0 rad..2\u{3c0} rad)");
+    assert_eq!(d.to_string().parse::<UnsignedDirection<Radian>>(), Ok(d));
+    let d = SignedDirection::<Radian>::new(-1.);
+    assert_eq!(d.to_string(), "at -1 rad (in -\u{3c0} rad..\u{3c0} rad)");
+    assert_eq!(d.to_string().parse::<SignedDirection<Radian>>(), Ok(d));
+
+    // The trailer formatted before the ranges were expressed in the unit of the direction
+    // is still accepted.
+    assert_eq!(
+        "at 1 rad (in 0°..360°)".parse::<UnsignedDirection<Radian>>(),
+        Ok(UnsignedDirection::<Radian>::new(1.))
+    );
+    assert_eq!(
+        "at -1 rad (in -180°..180°)".parse::<SignedDirection<Radian>>(),
+        Ok(SignedDirection::<Radian>::new(-1.))
+    );
+    assert_eq!(
+        "at 1 rad (in -180°..180°)".parse::<UnsignedDirection<Radian>>(),
+        Err(ParseMeasureError::WrongSuffix {
+            expected: "rad",
+            found: "rad (in -180°..180°)".to_string(),
+        })
+    );
+}
+
+#[test]
+fn measure_2d_parse() {
+    let m = Measure2d::<Metre>::new(1.5, -2.);
+    assert_eq!(m.to_string(), "(1.5, -2) m");
+    assert_eq!(m.to_string().parse::<Measure2d<Metre>>(), Ok(m));
+    let p = MeasurePoint2d::<Metre>::new(1.5, -2.);
+    assert_eq!(p.to_string().parse::<MeasurePoint2d<Metre>>(), Ok(p));
+    assert_eq!(
+        "(1.5 -2) m".parse::<Measure2d<Metre>>(),
+        Err(ParseMeasureError::UnexpectedText {
+            expected: ",",
+            found: "-2) m".to_string(),
+        })
+    );
+}
+
+#[test]
+fn measure_3d_parse() {
+    let m = Measure3d::<Metre>::new(1.5, -2., 3.25);
+    assert_eq!(m.to_string(), "(1.5, -2, 3.25) m");
+    assert_eq!(m.to_string().parse::<Measure3d<Metre>>(), Ok(m));
+    let p = MeasurePoint3d::<Metre>::new(1.5, -2., 3.25);
+    assert_eq!(p.to_string().parse::<MeasurePoint3d<Metre>>(), Ok(p));
+    assert_eq!(
+        "at (1, 2, 3) km".parse::<MeasurePoint3d<Metre>>(),
+        Err(ParseMeasureError::WrongSuffix {
+            expected: "m",
+            found: "km".to_string(),
+        })
+    );
+}
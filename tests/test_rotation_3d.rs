@@ -0,0 +1,226 @@
+use std::f64::consts::TAU;
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: false,
+        with_2d: false,
+        with_3d: true,
+        with_transformations: true,
+        with_uncertainty: None,
+    }
+}
+
+mod test_utils;
+
+pub struct Dimensionless;
+impl VectorProperty for Dimensionless {}
+
+pub struct Unspecified;
+impl MeasurementUnit for Unspecified {
+    type Property = Dimensionless;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = "";
+}
+
+struct Length;
+impl VectorProperty for Length {}
+
+struct Metre;
+impl MeasurementUnit for Metre {
+    type Property = Length;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m";
+}
+
+struct Degree;
+impl MeasurementUnit for Degree {
+    type Property = Angle;
+    const RATIO: f64 = TAU / 360.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " deg";
+}
+impl AngleMeasurementUnit for Degree {
+    const CYCLE_FRACTION: f64 = 360.;
+}
+
+#[test]
+fn rotation_3d_from_axis_angle() {
+    let m = Measure3d::<Metre>::new(8., 5., -2.);
+    // The axis is not a unit vector.
+    let r = Rotation3d::from_axis_angle(
+        Measure3d::<Unspecified>::new(3., 0., 0.),
+        Measure::<Degree>::new(90.),
+    );
+    let m2 = r.apply_to(m);
+    assert_eq_64!(m2.x, 8.);
+    assert_eq_64!(m2.y, 2.);
+    assert_eq_64!(m2.z, 5.);
+    assert_eq_64!(r.angle::<Degree>().value, 90.);
+    let axis = r.axis::<Unspecified>();
+    assert_eq_64!(axis.x, 1.);
+    assert_eq_64!(axis.y, 0.);
+    assert_eq_64!(axis.z, 0.);
+    assert_eq!(
+        Rotation3d::from_axis_angle(
+            Measure3d::<Unspecified>::new(0., 0., 0.),
+            Measure::<Degree>::new(90.),
+        ),
+        Rotation3d::identity()
+    );
+    assert_eq!(Rotation3d::<f64>::default().apply_to(m), m);
+}
+
+#[test]
+fn rotation_3d_linear_map() {
+    let axis = Measure3d::<Unspecified>::new(1., -2., 2.) / 3.;
+    let angle = Measure::<Degree>::new(130.);
+    let r = Rotation3d::from_axis_angle(axis, angle);
+    let lm = LinearMap3d::rotation(angle, axis);
+    let m = Measure3d::<Metre>::new(8., 5., -2.);
+    let expected = lm.apply_to(m);
+    let m2 = r.apply_to(m);
+    assert_eq_64!(m2.x, expected.x);
+    assert_eq_64!(m2.y, expected.y);
+    assert_eq_64!(m2.z, expected.z);
+    let m2 = LinearMap3d::from(r).apply_to(m);
+    assert_eq_64!(m2.x, expected.x);
+    assert_eq_64!(m2.y, expected.y);
+    assert_eq_64!(m2.z, expected.z);
+    let q = Rotation3d::from_linear_map(&lm).quaternion();
+    let expected = r.quaternion();
+    for i in 0..4 {
+        assert_eq_64!(q[i], expected[i]);
+    }
+}
+
+#[test]
+fn rotation_3d_euler_angles() {
+    let r = Rotation3d::from_euler_angles(
+        Measure::<Degree>::new(10.),
+        Measure::<Degree>::new(-20.),
+        Measure::<Degree>::new(30.),
+    );
+    let yaw = Rotation3d::from_axis_angle(
+        Measure3d::<Unspecified>::new(0., 0., 1.),
+        Measure::<Degree>::new(30.),
+    );
+    let pitch = Rotation3d::from_axis_angle(
+        Measure3d::<Unspecified>::new(0., 1., 0.),
+        Measure::<Degree>::new(-20.),
+    );
+    let roll = Rotation3d::from_axis_angle(
+        Measure3d::<Unspecified>::new(1., 0., 0.),
+        Measure::<Degree>::new(10.),
+    );
+    let m = Measure3d::<Metre>::new(1., 2., 3.);
+    let m2 = r.apply_to(m);
+    let expected = yaw.combined_with(&pitch).combined_with(&roll).apply_to(m);
+    assert_eq_64!(m2.x, expected.x);
+    assert_eq_64!(m2.y, expected.y);
+    assert_eq_64!(m2.z, expected.z);
+    let (roll, pitch, yaw) = r.to_euler_angles::<Degree>();
+    assert_eq_64!(roll.value, 10.);
+    assert_eq_64!(pitch.value, -20.);
+    assert_eq_64!(yaw.value, 30.);
+}
+
+#[test]
+fn rotation_3d_combined_with_and_inverted() {
+    let r1 = Rotation3d::from_axis_angle(
+        Measure3d::<Unspecified>::new(0., 0., 1.),
+        Measure::<Degree>::new(90.),
+    );
+    let r2 = Rotation3d::from_axis_angle(
+        Measure3d::<Unspecified>::new(1., 0., 0.),
+        Measure::<Degree>::new(90.),
+    );
+    let m = Measure3d::<Metre>::new(1., 0., 0.);
+    // First r2, leaving the X axis unchanged, then r1, bringing it to the Y axis.
+    let m2 = r1.combined_with(&r2).apply_to(m);
+    assert_eq_64!(m2.x, 0.);
+    assert_eq_64!(m2.y, 1.);
+    assert_eq_64!(m2.z, 0.);
+    // First r1, bringing the X axis to the Y axis, then r2, bringing it to the Z axis.
+    let m2 = r2.combined_with(&r1).apply_to(m);
+    assert_eq_64!(m2.x, 0.);
+    assert_eq_64!(m2.y, 0.);
+    assert_eq_64!(m2.z, 1.);
+    let m2 = r1.inverted().apply_to(r1.apply_to(m));
+    assert_eq_64!(m2.x, 1.);
+    assert_eq_64!(m2.y, 0.);
+    assert_eq_64!(m2.z, 0.);
+
+    // Many small steps do not deform the rotation.
+    let step = Rotation3d::from_axis_angle(
+        Measure3d::<Unspecified>::new(1., 2., 3.),
+        Measure::<Degree>::new(0.001),
+    );
+    let mut r = Rotation3d::identity();
+    for _ in 0..360_000 {
+        r = step.combined_with(&r);
+    }
+    let norm = r.quaternion().iter().map(|c| c * c).sum::<f64>();
+    assert_eq_64!(norm, 1.);
+    let v = r.apply_to(Measure3d::<Metre>::new(3., 4., 12.));
+    assert_eq_64!(v.squared_norm(), 169.);
+}
+
+#[test]
+fn rotation_3d_slerp() {
+    let axis = Measure3d::<Unspecified>::new(0., 0., 1.);
+    let r1 = Rotation3d::from_axis_angle(axis, Measure::<Degree>::new(20.));
+    let r2 = Rotation3d::from_axis_angle(axis, Measure::<Degree>::new(100.));
+    assert_eq_64!(r1.slerp(&r2, 0.25).angle::<Degree>().value, 40.);
+    assert_eq_64!(r1.slerp(&r2, 1.).angle::<Degree>().value, 100.);
+    assert_eq_64!(r1.slerp(&r1, 0.5).angle::<Degree>().value, 20.);
+}
+
+#[test]
+fn rotation_3d_apply_to_point() {
+    let r = Rotation3d::from_axis_angle(
+        Measure3d::<Unspecified>::new(0., 0., 1.),
+        Measure::<Degree>::new(90.),
+    );
+    let p = r.apply_to_point(MeasurePoint3d::<Metre>::new(2., 0., 5.));
+    assert_eq_64!(p.x, 0.);
+    assert_eq_64!(p.y, 2.);
+    assert_eq_64!(p.z, 5.);
+    assert_eq!(
+        format!("{}", Rotation3d::<f64>::identity()),
+        "quaternion(1, 0, 0, 0)"
+    );
+}
+
+#[test]
+fn rotation_3d_tutorial_example() {
+    let attitude = Rotation3d::from_euler_angles(
+        Measure::<Degree>::new(0.),
+        Measure::<Degree>::new(10.),
+        Measure::<Degree>::new(45.),
+    );
+    let turn = Rotation3d::from_axis_angle(
+        Measure3d::<Unspecified>::new(0., 0., 1.),
+        Measure::<Degree>::new(1.),
+    );
+    let mut new_attitude = attitude;
+    for _ in 0..90 {
+        new_attitude = turn.combined_with(&new_attitude);
+    }
+    let (_, pitch, yaw) = new_attitude.to_euler_angles::<Degree>();
+    assert_eq!(format!("{:.1}, {:.1}", pitch, yaw), "10.0 deg, 135.0 deg");
+}
+
+#[test]
+fn rotation_3d_eq_opposite_quaternions() {
+    // The quaternions q and -q represent the same rotation.
+    let r1 = Rotation3d::from_quaternion([1., 2., 3., 4.]);
+    let r2 = Rotation3d::from_quaternion([-1., -2., -3., -4.]);
+    assert_eq!(r1, r2);
+    assert_ne!(r1, Rotation3d::from_quaternion([1., 2., 3., -4.]));
+    assert_eq!(
+        Rotation3d::from_quaternion([-1., 0., 0., 0.]),
+        Rotation3d::identity()
+    );
+}
@@ -0,0 +1,335 @@
+#![cfg(feature = "serde")]
+use std::f64::consts::TAU;
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: true,
+        with_3d: true,
+        with_transformations: true,
+        with_uncertainty: None,
+    }
+}
+
+pub struct Dimensionless;
+impl VectorProperty for Dimensionless {}
+
+pub struct Unspecified;
+impl MeasurementUnit for Unspecified {
+    type Property = Dimensionless;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = "";
+}
+
+struct Length;
+impl VectorProperty for Length {}
+
+struct Metre;
+impl MeasurementUnit for Metre {
+    type Property = Length;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m";
+}
+
+struct MilliMetre;
+impl MeasurementUnit for MilliMetre {
+    type Property = Length;
+    const RATIO: f64 = 0.001;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mm";
+}
+
+struct Degree;
+impl MeasurementUnit for Degree {
+    type Property = Angle;
+    const RATIO: f64 = TAU / 360.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = "\u{b0}";
+}
+impl AngleMeasurementUnit for Degree {
+    const CYCLE_FRACTION: f64 = 360.;
+}
+
+#[test]
+fn measure_serde() {
+    let m = Measure::<Metre>::new(1.5);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(json, r#"{"value":1.5,"unit":"m"}"#);
+    let m2: Measure<Metre> = serde_json::from_str(&json).unwrap();
+    assert_eq!(m2, m);
+    let m3: Measure<Metre, f32> = serde_json::from_str(r#"{"value":2.5}"#).unwrap();
+    assert_eq!(m3.value, 2.5);
+}
+
+#[test]
+fn measure_serde_rejects_mismatched_unit() {
+    let json = serde_json::to_string(&Measure::<MilliMetre>::new(1.5)).unwrap();
+    assert_eq!(json, r#"{"value":1.5,"unit":"mm"}"#);
+    let error = serde_json::from_str::<Measure<Metre>>(&json).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with(r#"mismatched unit of measurement: expected "m", found "mm""#));
+}
+
+#[test]
+fn measure_serde_rejects_unknown_fields() {
+    assert!(serde_json::from_str::<Measure<Metre>>(r#"{"value":1.5,"uncertainty":0.1}"#).is_err());
+}
+
+#[test]
+fn measure_serde_dimensionless() {
+    let m = Measure::<Unspecified>::new(3.);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(json, r#"{"value":3.0}"#);
+    assert_eq!(
+        serde_json::from_str::<Measure<Unspecified>>(&json).unwrap(),
+        m
+    );
+    assert!(serde_json::from_str::<Measure<Unspecified>>(r#"{"value":3.0,"unit":"m"}"#).is_err());
+}
+
+#[test]
+fn measure_point_serde() {
+    let p = MeasurePoint::<Metre>::new(-2.);
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"value":-2.0,"unit":"m"}"#);
+    assert_eq!(
+        serde_json::from_str::<MeasurePoint<Metre>>(&json).unwrap(),
+        p
+    );
+}
+
+#[test]
+fn directions_serde() {
+    let d = SignedDirection::<Degree>::new(-30.);
+    let json = serde_json::to_string(&d).unwrap();
+    assert_eq!(json, r#"{"value":-30.0,"unit":"°"}"#);
+    assert_eq!(
+        serde_json::from_str::<SignedDirection<Degree>>(&json).unwrap(),
+        d
+    );
+    let d =
+        serde_json::from_str::<UnsignedDirection<Degree>>(r#"{"value":-30.0,"unit":"°"}"#).unwrap();
+    assert_eq!(d.value, 330.);
+    assert!(
+        serde_json::from_str::<UnsignedDirection<Degree>>(r#"{"value":1.0,"unit":"rad"}"#).is_err()
+    );
+}
+
+#[test]
+fn measure_2d_serde() {
+    let m = Measure2d::<Metre>::new(1., 2.);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(json, r#"{"x":1.0,"y":2.0,"unit":"m"}"#);
+    assert_eq!(serde_json::from_str::<Measure2d<Metre>>(&json).unwrap(), m);
+    assert!(serde_json::from_str::<Measure2d<MilliMetre>>(&json).is_err());
+}
+
+#[test]
+fn measure_point_2d_serde() {
+    let p = MeasurePoint2d::<Metre, f32>::new(1., 2.);
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"x":1.0,"y":2.0,"unit":"m"}"#);
+    assert_eq!(
+        serde_json::from_str::<MeasurePoint2d<Metre, f32>>(&json).unwrap(),
+        p
+    );
+}
+
+#[test]
+fn measure_3d_serde() {
+    let m = Measure3d::<Metre>::new(1., 2., 3.);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(json, r#"{"x":1.0,"y":2.0,"z":3.0,"unit":"m"}"#);
+    assert_eq!(serde_json::from_str::<Measure3d<Metre>>(&json).unwrap(), m);
+}
+
+#[test]
+fn measure_point_3d_serde() {
+    let p = MeasurePoint3d::<Metre>::new(1., 2., 3.);
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(json, r#"{"x":1.0,"y":2.0,"z":3.0,"unit":"m"}"#);
+    assert_eq!(
+        serde_json::from_str::<MeasurePoint3d<Metre>>(&json).unwrap(),
+        p
+    );
+    assert!(serde_json::from_str::<MeasurePoint3d<Metre>>(r#"{"x":1.0,"y":2.0}"#).is_err());
+}
+
+#[test]
+fn linear_maps_serde() {
+    let lm = LinearMap2d::new([[1., 2.], [3., 4.]]);
+    let json = serde_json::to_string(&lm).unwrap();
+    assert_eq!(json, r#"{"coefficients":[[1.0,2.0],[3.0,4.0]]}"#);
+    let lm2: LinearMap2d<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&lm2).unwrap(), json);
+
+    let lm = LinearMap3d::new([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+    let json = serde_json::to_string(&lm).unwrap();
+    let lm2: LinearMap3d<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&lm2).unwrap(), json);
+    assert!(
+        serde_json::from_str::<LinearMap3d<f64>>(r#"{"coefficients":[[1.0,2.0],[3.0,4.0]]}"#)
+            .is_err()
+    );
+}
+
+#[test]
+fn affine_maps_serde() {
+    let am = AffineMap2d::<Metre>::new([[1., 2., 3.], [4., 5., 6.]]);
+    let json = serde_json::to_string(&am).unwrap();
+    assert_eq!(
+        json,
+        r#"{"coefficients":[[1.0,2.0,3.0],[4.0,5.0,6.0]],"unit":"m"}"#
+    );
+    let am2: AffineMap2d<Metre> = serde_json::from_str(&json).unwrap();
+    assert_eq!(serde_json::to_string(&am2).unwrap(), json);
+    assert!(serde_json::from_str::<AffineMap2d<MilliMetre>>(&json).is_err());
+
+    let am = AffineMap3d::<Metre>::new([[1., 2., 3., 4.], [5., 6., 7., 8.], [9., 10., 11., 12.]]);
+    let json = serde_json::to_string(&am).unwrap();
+    let am2: AffineMap3d<Metre> = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        am2.apply_to(MeasurePoint3d::new(1., 0., 0.)),
+        MeasurePoint3d::new(5., 13., 21.)
+    );
+}
+
+#[test]
+fn measure_linear_maps_serde() {
+    let mlm = MeasureLinearMap2d::<Metre, MilliMetre>::new([[1., 2.], [3., 4.]]);
+    let json = serde_json::to_string(&mlm).unwrap();
+    assert_eq!(
+        json,
+        r#"{"coefficients":[[1.0,2.0],[3.0,4.0]],"in_unit":"m","out_unit":"mm"}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<MeasureLinearMap2d<Metre, MilliMetre>>(&json).unwrap(),
+        mlm
+    );
+    assert!(serde_json::from_str::<MeasureLinearMap2d<MilliMetre, Metre>>(&json).is_err());
+
+    let mlm =
+        MeasureLinearMap3d::<Metre, Unspecified>::new([[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
+    let json = serde_json::to_string(&mlm).unwrap();
+    assert!(!json.contains("out_unit"));
+    assert_eq!(
+        serde_json::from_str::<MeasureLinearMap3d<Metre, Unspecified>>(&json).unwrap(),
+        mlm
+    );
+}
+
+#[test]
+fn rotation_3d_serde() {
+    let r = Rotation3d::from_quaternion([1., 1., 1., 1.]);
+    let json = serde_json::to_string(&r).unwrap();
+    assert_eq!(json, r#"{"quaternion":[0.5,0.5,0.5,0.5]}"#);
+    assert_eq!(serde_json::from_str::<Rotation3d>(&json).unwrap(), r);
+    assert_eq!(
+        serde_json::from_str::<Rotation3d>(r#"{"quaternion":[-2.0,-2.0,-2.0,-2.0]}"#).unwrap(),
+        r
+    );
+}
+
+#[test]
+fn tensors_serde() {
+    let t = Tensor2d::<Metre>::new(1., 2., 3.);
+    let json = serde_json::to_string(&t).unwrap();
+    assert_eq!(json, r#"{"xx":1.0,"yy":2.0,"xy":3.0,"unit":"m"}"#);
+    assert_eq!(serde_json::from_str::<Tensor2d<Metre>>(&json).unwrap(), t);
+    assert!(serde_json::from_str::<Tensor2d<MilliMetre>>(&json).is_err());
+
+    let t = Tensor3d::<Metre>::new(1., 2., 3., 4., 5., 6.);
+    let json = serde_json::to_string(&t).unwrap();
+    assert_eq!(
+        json,
+        r#"{"xx":1.0,"yy":2.0,"zz":3.0,"xy":4.0,"xz":5.0,"yz":6.0,"unit":"m"}"#
+    );
+    assert_eq!(serde_json::from_str::<Tensor3d<Metre>>(&json).unwrap(), t);
+}
+
+#[test]
+fn direction_3d_serde() {
+    let d =
+        serde_json::from_str::<Direction3d<Degree>>(r#"{"unit_vector":[0.0,3.0,4.0]}"#).unwrap();
+    assert_eq!(
+        serde_json::to_string(&d).unwrap(),
+        r#"{"unit_vector":[0.0,0.6,0.8]}"#
+    );
+    assert_eq!(d.azimuth().value, 90.);
+    let json = serde_json::to_string(&d).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Direction3d<Radian>>(&json).unwrap(),
+        d.convert::<Radian>()
+    );
+    assert!(
+        serde_json::from_str::<Direction3d<Degree>>(r#"{"unit_vector":[0.0,0.0,0.0]}"#).is_err()
+    );
+}
+
+#[test]
+fn curvilinear_points_serde() {
+    let p = PolarPoint2d::new(
+        Measure::<Metre>::new(2.),
+        UnsignedDirection::<Degree>::new(30.),
+    );
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"radius":{"value":2.0,"unit":"m"},"azimuth":{"value":30.0,"unit":"°"}}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<PolarPoint2d<Metre, Degree>>(&json).unwrap(),
+        p
+    );
+    assert!(serde_json::from_str::<PolarPoint2d<MilliMetre, Degree>>(&json).is_err());
+
+    let p = CylindricalPoint3d::new(
+        Measure::<Metre>::new(2.),
+        UnsignedDirection::<Degree>::new(30.),
+        Measure::<Metre>::new(-1.),
+    );
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        serde_json::from_str::<CylindricalPoint3d<Metre, Degree>>(&json).unwrap(),
+        p
+    );
+
+    let p = SphericalPoint3d::new(
+        Measure::<Metre>::new(2.),
+        UnsignedDirection::<Degree>::new(30.),
+        SignedDirection::<Degree>::new(-45.),
+    );
+    let json = serde_json::to_string(&p).unwrap();
+    assert!(json.ends_with(r#""elevation":{"value":-45.0,"unit":"°"}}"#));
+    assert_eq!(
+        serde_json::from_str::<SphericalPoint3d<Metre, Degree>>(&json).unwrap(),
+        p
+    );
+}
+
+#[test]
+fn geodesy_serde() {
+    let e = Ellipsoid::<Metre>::wgs84();
+    let json = serde_json::to_string(&e).unwrap();
+    assert!(json
+        .starts_with(r#"{"semi_major_axis":{"value":6378137.0,"unit":"m"},"flattening":0.00335"#));
+    assert_eq!(serde_json::from_str::<Ellipsoid<Metre>>(&json).unwrap(), e);
+
+    let p = GeodeticPoint::new(
+        SignedDirection::<Degree>::new(45.),
+        SignedDirection::<Degree>::new(-120.),
+        Measure::<Metre>::new(100.),
+    );
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"latitude":{"value":45.0,"unit":"°"},"longitude":{"value":-120.0,"unit":"°"},"height":{"value":100.0,"unit":"m"}}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<GeodeticPoint<Metre, Degree>>(&json).unwrap(),
+        p
+    );
+}
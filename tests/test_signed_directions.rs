@@ -411,23 +411,23 @@ fn signed_direction_is_equal_to_its_copy() {
 #[test]
 fn signed_direction_formatting_in_degrees() {
     let sd = SignedDirection::<Degree, f32>::new(12.25);
-    assert_eq!(format!("{}", sd), "at 12.25 deg (in -180°..180°)");
+    assert_eq!(format!("{}", sd), "at 12.25 deg (in -180 deg..180 deg)");
 }
 
 #[test]
 fn signed_direction_formatting_in_degrees_one_fractional_digit() {
     let sd = SignedDirection::<Degree, f32>::new(12.25);
-    assert_eq!(format!("{:.1}", sd), "at 12.2 deg (in -180°..180°)");
+    assert_eq!(format!("{:.1}", sd), "at 12.2 deg (in -180 deg..180 deg)");
 }
 
 #[test]
 fn signed_direction_formatting_for_debug_in_degrees() {
     let sd = SignedDirection::<Degree, f32>::new(12.25);
-    assert_eq!(format!("{:?}", sd), "at 12.25 deg (in -180°..180°)");
+    assert_eq!(format!("{:?}", sd), "at 12.25 deg (in -180 deg..180 deg)");
 }
 
 #[test]
 fn signed_direction_formatting_for_debug_in_degrees_one_fractional_digit() {
     let sd = SignedDirection::<Degree, f32>::new(12.25);
-    assert_eq!(format!("{:.1?}", sd), "at 12.2 deg (in -180°..180°)");
+    assert_eq!(format!("{:.1?}", sd), "at 12.2 deg (in -180 deg..180 deg)");
 }
@@ -0,0 +1,141 @@
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: true,
+        with_3d: true,
+        with_transformations: true,
+        with_uncertainty: None,
+    }
+}
+
+mod test_utils;
+
+pub struct Dimensionless;
+impl VectorProperty for Dimensionless {}
+
+pub struct Unspecified;
+impl MeasurementUnit for Unspecified {
+    type Property = Dimensionless;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = "";
+}
+
+pub struct Pressure;
+
+pub struct Pascal;
+impl MeasurementUnit for Pascal {
+    type Property = Pressure;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Pa";
+}
+
+pub struct MegaPascal;
+impl MeasurementUnit for MegaPascal {
+    type Property = Pressure;
+    const RATIO: f64 = 1e6;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " MPa";
+}
+
+#[test]
+fn tensor_3d_new() {
+    let t = Tensor3d::<MegaPascal>::new(1., 2., 3., 4., 5., 6.);
+    assert_eq!(t.matrix(), [[1., 4., 5.], [4., 2., 6.], [5., 6., 3.]]);
+    assert_eq!(Tensor3d::<MegaPascal>::from_matrix(t.matrix()), t);
+    assert_eq!(t.trace().value, 6.);
+    assert_eq!(
+        t.convert::<Pascal>(),
+        Tensor3d::<Pascal>::new(1e6, 2e6, 3e6, 4e6, 5e6, 6e6)
+    );
+    assert_eq!(t + t, t * 2.);
+    assert_eq!(t - t, Tensor3d::default());
+    assert_eq!(-t / 2., t * -0.5);
+    assert_eq!(format!("{}", t), "[1 4 5] MPa\n[4 2 6]\n[5 6 3]");
+}
+
+#[test]
+fn tensor_3d_principal_values_and_directions() {
+    let t = Tensor3d::<MegaPascal>::new(3., 3., 5., 1., 0., 0.);
+    let values = t.principal_values();
+    assert_eq_64!(values[0].value, 5.);
+    assert_eq_64!(values[1].value, 4.);
+    assert_eq_64!(values[2].value, 2.);
+    let directions = t.principal_directions::<Unspecified>();
+    for (value, direction) in values.iter().zip(directions) {
+        assert_eq_64!(direction.squared_norm(), 1.);
+        let m = t.matrix();
+        let d = [direction.x, direction.y, direction.z];
+        for (row, component) in m.iter().zip(d) {
+            let image: f64 = row.iter().zip(d).map(|(c, x)| c * x).sum();
+            assert_eq_64!(image, value.value * component);
+        }
+    }
+}
+
+#[test]
+fn tensor_3d_rotated() {
+    let t = Tensor3d::<MegaPascal>::new(10., -4., 2., 3., -1., 5.);
+    let rotation = LinearMap3d::rotation(
+        Measure::<Radian>::new(0.7),
+        Measure3d::<Unspecified>::new(0., 0.6, 0.8),
+    );
+    let rotated = t.rotated(&rotation);
+    assert_eq_64!(rotated.trace().value, t.trace().value);
+    assert_eq_64!(rotated.von_mises().value, t.von_mises().value);
+    let values = t.principal_values();
+    let rotated_values = rotated.principal_values();
+    for i in 0..3 {
+        assert_eq_64!(rotated_values[i].value, values[i].value);
+    }
+}
+
+#[test]
+fn tensor_3d_von_mises() {
+    // Uniaxial stress.
+    assert_eq_64!(
+        Tensor3d::<MegaPascal>::new(250., 0., 0., 0., 0., 0.)
+            .von_mises()
+            .value,
+        250.
+    );
+    // Pure shear.
+    assert_eq_64!(
+        Tensor3d::<MegaPascal>::new(0., 0., 0., 100., 0., 0.)
+            .von_mises()
+            .value,
+        100. * 3f64.sqrt()
+    );
+    // Hydrostatic pressure.
+    assert_eq!(
+        Tensor3d::<MegaPascal>::new(-7., -7., -7., 0., 0., 0.)
+            .von_mises()
+            .value,
+        0.
+    );
+}
+
+#[test]
+fn tensor_2d() {
+    let t = Tensor2d::<MegaPascal>::new(50., -30., 40.);
+    assert_eq!(t.matrix(), [[50., 40.], [40., -30.]]);
+    assert_eq!(t.trace().value, 20.);
+    let values = t.principal_values();
+    assert_eq_64!(values[0].value, 10. + 40f64.hypot(40.));
+    assert_eq_64!(values[0].value + values[1].value, 20.);
+    let direction = t.principal_directions::<Unspecified>()[0];
+    assert_eq_64!(
+        50. * direction.x + 40. * direction.y,
+        values[0].value * direction.x
+    );
+    assert_eq_64!(
+        t.von_mises().value,
+        (2500f64 + 1500. + 900. + 3. * 1600.).sqrt()
+    );
+    let rotated = t.rotated(&LinearMap2d::rotation(Measure::<Radian>::new(0.3)));
+    assert_eq_64!(rotated.trace().value, 20.);
+    assert_eq_64!(rotated.principal_values()[0].value, values[0].value);
+    assert_eq!(format!("{}", t), "[50  40] MPa\n[40 -30]");
+}
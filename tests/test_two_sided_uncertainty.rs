@@ -0,0 +1,650 @@
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: true,
+        with_2d: true,
+        with_3d: true,
+        with_transformations: true,
+        with_uncertainty: TwoSided,
+    }
+}
+
+mod test_utils;
+
+struct Length;
+impl VectorProperty for Length {}
+
+struct Metre;
+impl MeasurementUnit for Metre {
+    type Property = Length;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m";
+}
+
+struct MilliMetre;
+impl MeasurementUnit for MilliMetre {
+    type Property = Length;
+    const RATIO: f64 = 0.001;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " mm";
+}
+
+struct Area;
+
+struct SquareMetre;
+impl MeasurementUnit for SquareMetre {
+    type Property = Area;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m\u{b2}";
+}
+
+rs_measures::define_units_relationship! { SquareMetre == Metre * = }
+rs_measures::define_units_relationship! { SquareMetre == Metre:2 * =:2 }
+
+struct Volume;
+
+struct CubicMetre;
+impl MeasurementUnit for CubicMetre {
+    type Property = Volume;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m\u{b3}";
+}
+
+rs_measures::define_units_relationship! { CubicMetre == Metre ^ 3 }
+
+struct Time;
+
+struct Second;
+impl MeasurementUnit for Second {
+    type Property = Time;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " s";
+}
+
+struct Velocity;
+impl VectorProperty for Velocity {}
+
+struct MetrePerSecond;
+impl MeasurementUnit for MetrePerSecond {
+    type Property = Velocity;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m/s";
+}
+
+rs_measures::define_units_relationship! { Metre == MetrePerSecond * Second }
+rs_measures::define_units_relationship! { Metre:2 == MetrePerSecond:2 * Second }
+
+struct Frequency;
+
+struct Hertz;
+impl MeasurementUnit for Hertz {
+    type Property = Frequency;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " Hz";
+}
+
+rs_measures::define_units_relationship! { Hertz == 1 / Second }
+
+struct Temperature;
+
+struct Celsius;
+impl MeasurementUnit for Celsius {
+    type Property = Temperature;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 273.15;
+    const SUFFIX: &'static str = " \u{b0}C";
+}
+
+struct Fahrenheit;
+impl MeasurementUnit for Fahrenheit {
+    type Property = Temperature;
+    const RATIO: f64 = 5. / 9.;
+    const OFFSET: f64 = 273.15 - 32. * 5. / 9.;
+    const SUFFIX: &'static str = " \u{b0}F";
+}
+
+#[test]
+fn measure_new() {
+    let m = Measure::<Metre, f32>::new(12.);
+    assert_eq!(m.value, 12.);
+    assert_eq!(m.lower_uncertainty, 0.);
+    assert_eq!(m.upper_uncertainty, 0.);
+}
+
+#[test]
+fn measure_with_uncertainty() {
+    let m = Measure::<Metre, f32>::with_uncertainty(12., 0.1, 0.3);
+    assert_eq!(m.value, 12.);
+    assert_eq!(m.lower_uncertainty, 0.1);
+    assert_eq!(m.upper_uncertainty, 0.3);
+}
+
+#[test]
+fn measure_convert() {
+    let m = Measure::<Metre, f32>::with_uncertainty(12., 0.5, 0.25).convert::<MilliMetre>();
+    assert_eq!(m.value, 12000.);
+    assert_eq!(m.lower_uncertainty, 500.);
+    assert_eq!(m.upper_uncertainty, 250.);
+}
+
+#[test]
+fn measure_lossy_into() {
+    let m: Measure<Metre, f32> = Measure::<Metre>::with_uncertainty(12., 0.5, 0.25).lossy_into();
+    assert_eq!(m.value, 12.);
+    assert_eq!(m.lower_uncertainty, 0.5);
+    assert_eq!(m.upper_uncertainty, 0.25);
+}
+
+#[test]
+fn measure_neg() {
+    let m = -Measure::<Metre>::with_uncertainty(12., 0.1, 0.3);
+    assert_eq!(m.value, -12.);
+    assert_eq!(m.lower_uncertainty, 0.3);
+    assert_eq!(m.upper_uncertainty, 0.1);
+}
+
+#[test]
+fn measure_add_measure() {
+    let mut m = Measure::<Metre>::with_uncertainty(12., 0.3, 0.6);
+    m += Measure::<Metre>::with_uncertainty(7., 0.4, 0.8);
+    assert_eq!(m.value, 19.);
+    assert_eq_64!(m.lower_uncertainty, 0.5);
+    assert_eq_64!(m.upper_uncertainty, 1.);
+}
+
+#[test]
+fn measure_sub_measure() {
+    let mut m = Measure::<Metre>::with_uncertainty(12., 0.3, 0.6);
+    m -= Measure::<Metre>::with_uncertainty(7., 0.8, 0.4);
+    assert_eq!(m.value, 5.);
+    assert_eq_64!(m.lower_uncertainty, 0.5);
+    assert_eq_64!(m.upper_uncertainty, 1.);
+}
+
+#[test]
+fn measure_mul_number() {
+    let m = Measure::<Metre>::with_uncertainty(12., 0.1, 0.5) * 3.;
+    assert_eq!(m.value, 36.);
+    assert_eq_64!(m.lower_uncertainty, 0.3);
+    assert_eq_64!(m.upper_uncertainty, 1.5);
+    let m = -3. * Measure::<Metre>::with_uncertainty(12., 0.1, 0.5);
+    assert_eq!(m.value, -36.);
+    assert_eq_64!(m.lower_uncertainty, 1.5);
+    assert_eq_64!(m.upper_uncertainty, 0.3);
+}
+
+#[test]
+fn measure_div_number() {
+    let mut m = Measure::<Metre>::with_uncertainty(12., 0.1, 0.5);
+    m /= -2.;
+    assert_eq!(m.value, -6.);
+    assert_eq_64!(m.lower_uncertainty, 0.25);
+    assert_eq_64!(m.upper_uncertainty, 0.05);
+}
+
+#[test]
+fn measure_squared_and_sqrt() {
+    let m: Measure<SquareMetre> = Measure::<Metre>::with_uncertainty(-3., 0.1, 0.2).squared();
+    assert_eq!(m.value, 9.);
+    assert_eq_64!(m.lower_uncertainty, 1.2);
+    assert_eq_64!(m.upper_uncertainty, 0.6);
+    let m: Measure<Metre> = Measure::<SquareMetre>::with_uncertainty(9., 1.2, 0.6).sqrt();
+    assert_eq!(m.value, 3.);
+    assert_eq_64!(m.lower_uncertainty, 0.2);
+    assert_eq_64!(m.upper_uncertainty, 0.1);
+}
+
+#[test]
+fn measure_roots_of_zero() {
+    let m: Measure<Metre> = Measure::<SquareMetre>::new(0.).sqrt();
+    assert_eq!(m.value, 0.);
+    assert_eq!(m.lower_uncertainty, 0.);
+    assert_eq!(m.upper_uncertainty, 0.);
+    let m: Measure<Metre> = Measure::<SquareMetre>::with_uncertainty(0., 0.04, 0.09).sqrt();
+    assert_eq!(m.value, 0.);
+    assert_eq_64!(m.lower_uncertainty, 0.2);
+    assert_eq_64!(m.upper_uncertainty, 0.3);
+    let m: Measure<Metre> = Measure::<CubicMetre>::new(0.).cubic_root();
+    assert_eq!(m.value, 0.);
+    assert_eq!(m.lower_uncertainty, 0.);
+    assert_eq!(m.upper_uncertainty, 0.);
+    let m: Measure<Metre> = Measure::<CubicMetre>::with_uncertainty(0., 0.008, 0.027).cubic_root();
+    assert_eq!(m.value, 0.);
+    assert_eq_64!(m.lower_uncertainty, 0.2);
+    assert_eq_64!(m.upper_uncertainty, 0.3);
+}
+
+#[test]
+fn measure_cubed_and_cubic_root() {
+    let m: Measure<CubicMetre> = Measure::<Metre>::with_uncertainty(-3., 0.1, 0.2).cubed();
+    assert_eq!(m.value, -27.);
+    assert_eq_64!(m.lower_uncertainty, 2.7);
+    assert_eq_64!(m.upper_uncertainty, 5.4);
+    let m: Measure<Metre> = Measure::<CubicMetre>::with_uncertainty(27., 5.4, 2.7).cubic_root();
+    assert_eq_64!(m.value, 3.);
+    assert_eq_64!(m.lower_uncertainty, 0.2);
+    assert_eq_64!(m.upper_uncertainty, 0.1);
+}
+
+#[test]
+fn measure_mul_measure() {
+    let m: Measure<Metre> = Measure::<MetrePerSecond>::with_uncertainty(2., 0.1, 0.2)
+        * Measure::<Second>::with_uncertainty(3., 0.2, 0.4);
+    assert_eq!(m.value, 6.);
+    assert_eq_64!(m.lower_uncertainty, 0.5);
+    assert_eq_64!(m.upper_uncertainty, 1.);
+}
+
+#[test]
+fn measure_div_measure() {
+    let m: Measure<MetrePerSecond> = Measure::<Metre>::with_uncertainty(6., 0.3, 0.6)
+        / Measure::<Second>::with_uncertainty(3., 0.2, 0.4);
+    assert_eq!(m.value, 2.);
+    // The upper uncertainty of the divisor decreases the quotient.
+    assert_eq_64!(
+        m.lower_uncertainty,
+        (0.1_f64.powi(2) + (0.8_f64 / 3.).powi(2)).sqrt()
+    );
+    assert_eq_64!(
+        m.upper_uncertainty,
+        (0.2_f64.powi(2) + (0.4_f64 / 3.).powi(2)).sqrt()
+    );
+}
+
+#[test]
+fn number_div_measure() {
+    let m: Measure<Hertz> = 1. / Measure::<Second>::with_uncertainty(4., 0.2, 0.4);
+    assert_eq!(m.value, 0.25);
+    assert_eq_64!(m.lower_uncertainty, 0.025);
+    assert_eq_64!(m.upper_uncertainty, 0.0125);
+}
+
+#[test]
+fn measure_compare() {
+    let m = Measure::<Metre>::with_uncertainty(12., 0.1, 0.3);
+    assert!(m == Measure::<Metre>::with_uncertainty(12., 0.1, 0.3));
+    assert!(m != Measure::<Metre>::with_uncertainty(12., 0.3, 0.1));
+    assert!(m < Measure::<Metre>::with_uncertainty(13., 0.1, 0.3));
+    assert_eq!(
+        m.partial_cmp(&Measure::<Metre>::with_uncertainty(12., 0.3, 0.1)),
+        None
+    );
+    let m2 = Measure::<Metre>::with_uncertainty(13., 0.2, 0.2);
+    assert_eq!(max(m, m2), m2);
+}
+
+#[test]
+fn measure_formatting() {
+    let m = Measure::<Metre, f32>::with_uncertainty(5., 0.1, 0.3);
+    assert_eq!(format!("{}", m), "5 +0.3/-0.1 m");
+    assert_eq!(format!("{:.1}", m), "5.0 +0.3/-0.1 m");
+    assert_eq!(format!("{:?}", m), "5 +0.3/-0.1 m");
+}
+
+#[test]
+fn measure_point_convert() {
+    let p = MeasurePoint::<Celsius>::with_uncertainty(100., 0.5, 1.).convert::<Fahrenheit>();
+    assert_eq_64!(p.value, 212.);
+    assert_eq_64!(p.lower_uncertainty, 0.9);
+    assert_eq_64!(p.upper_uncertainty, 1.8);
+}
+
+#[test]
+fn measure_point_add_sub_measure() {
+    let p = MeasurePoint::<Metre>::with_uncertainty(12., 0.3, 0.6)
+        + Measure::<Metre>::with_uncertainty(7., 0.4, 0.8);
+    assert_eq!(p.value, 19.);
+    assert_eq_64!(p.lower_uncertainty, 0.5);
+    assert_eq_64!(p.upper_uncertainty, 1.);
+    let p = MeasurePoint::<Metre>::with_uncertainty(12., 0.3, 0.6)
+        - Measure::<Metre>::with_uncertainty(7., 0.8, 0.4);
+    assert_eq!(p.value, 5.);
+    assert_eq_64!(p.lower_uncertainty, 0.5);
+    assert_eq_64!(p.upper_uncertainty, 1.);
+}
+
+#[test]
+fn measure_point_sub_measure_point() {
+    let m: Measure<Metre> = MeasurePoint::<Metre>::with_uncertainty(12., 0.3, 0.6)
+        - MeasurePoint::<Metre>::with_uncertainty(7., 0.8, 0.4);
+    assert_eq!(m.value, 5.);
+    assert_eq_64!(m.lower_uncertainty, 0.5);
+    assert_eq_64!(m.upper_uncertainty, 1.);
+}
+
+#[test]
+fn measure_point_midpoint() {
+    let p = midpoint(
+        MeasurePoint::<Metre>::with_uncertainty(10., 0.6, 0.8),
+        MeasurePoint::<Metre>::with_uncertainty(20., 0.8, 0.6),
+    );
+    assert_eq!(p.value, 15.);
+    assert_eq_64!(p.lower_uncertainty, 0.5);
+    assert_eq_64!(p.upper_uncertainty, 0.5);
+}
+
+#[test]
+fn measure_point_formatting() {
+    let p = MeasurePoint::<Metre, f32>::with_uncertainty(5., 0.1, 0.3);
+    assert_eq!(format!("{}", p), "at 5 +0.3/-0.1 m");
+}
+
+#[test]
+fn measure_point_round_trip() {
+    let p = MeasurePoint::<Metre>::with_uncertainty(12., 0.3, 0.6);
+    let m = Measure::<Metre>::with_uncertainty(7., 0.4, 0.8);
+    let displacement = (p + m) - p;
+    assert_eq!(displacement.value, 7.);
+    assert_eq_64!(displacement.lower_uncertainty, 0.61f64.sqrt());
+    assert_eq_64!(displacement.upper_uncertainty, 1.09f64.sqrt());
+    let q = (p - m) + m;
+    assert_eq!(q.value, 12.);
+    assert_eq_64!(q.lower_uncertainty, 0.89f64.sqrt());
+    assert_eq_64!(q.upper_uncertainty, 1.16f64.sqrt());
+    let mut q = p;
+    q += m;
+    assert_eq!(q, p + m);
+    q -= m;
+    assert_eq!(q, (p + m) - m);
+}
+
+#[test]
+fn measure_point_2d_add_sub_measure() {
+    let p = MeasurePoint2d::<Metre>::with_uncertainty(12., 3., [0.3, 0.6], [0.6, 0.3]);
+    let q = p + Measure2d::<Metre>::with_uncertainty(7., 1., [0.4, 0.8], [0.8, 0.4]);
+    assert_eq!(q.x, 19.);
+    assert_eq!(q.y, 4.);
+    assert_eq_64!(q.lower_uncertainty[0], 0.5);
+    assert_eq_64!(q.lower_uncertainty[1], 1.);
+    assert_eq_64!(q.upper_uncertainty[0], 1.);
+    assert_eq_64!(q.upper_uncertainty[1], 0.5);
+    let x = q.x();
+    assert_eq!(x.value, 19.);
+    assert_eq_64!(x.lower_uncertainty, 0.5);
+    assert_eq_64!(x.upper_uncertainty, 1.);
+    let q = p - Measure2d::<Metre>::with_uncertainty(7., 1., [0.8, 0.4], [0.4, 0.8]);
+    assert_eq!(q.x, 5.);
+    assert_eq!(q.y, 2.);
+    assert_eq_64!(q.lower_uncertainty[0], 0.5);
+    assert_eq_64!(q.lower_uncertainty[1], 1.);
+    assert_eq_64!(q.upper_uncertainty[0], 1.);
+    assert_eq_64!(q.upper_uncertainty[1], 0.5);
+}
+
+#[test]
+fn measure_point_2d_round_trip() {
+    let p = MeasurePoint2d::<Metre>::with_uncertainty(12., 3., [0.3, 0.6], [0.6, 0.3]);
+    let m = Measure2d::<Metre>::with_uncertainty(7., 1., [0.4, 0.8], [0.8, 0.4]);
+    let displacement: Measure2d<Metre> = (p + m) - p;
+    assert_eq!(displacement.x, 7.);
+    assert_eq!(displacement.y, 1.);
+    assert_eq_64!(displacement.lower_uncertainty[0], 0.61f64.sqrt());
+    assert_eq_64!(displacement.lower_uncertainty[1], 1.09f64.sqrt());
+    assert_eq_64!(displacement.upper_uncertainty[0], 1.09f64.sqrt());
+    assert_eq_64!(displacement.upper_uncertainty[1], 0.61f64.sqrt());
+    let mut q = p;
+    q += m;
+    q -= m;
+    assert_eq!(q, (p + m) - m);
+    assert_eq!(q.x, 12.);
+    assert_eq!(q.y, 3.);
+}
+
+#[test]
+fn measure_point_2d_affine_map() {
+    let p = MeasurePoint2d::<Metre>::with_uncertainty(2., 1., [0.1, 0.2], [0.3, 0.4]);
+    let rotated = AffineMap2d::rotation_at_left(MeasurePoint2d::<Metre>::new(1., 1.)).apply_to(p);
+    assert_eq_64!(rotated.x, 1.);
+    assert_eq_64!(rotated.y, 2.);
+    assert_eq_64!(rotated.lower_uncertainty[0], 0.4);
+    assert_eq_64!(rotated.upper_uncertainty[0], 0.2);
+    assert_eq_64!(rotated.lower_uncertainty[1], 0.1);
+    assert_eq_64!(rotated.upper_uncertainty[1], 0.3);
+    let translated = AffineMap2d::translation(Measure2d::<Metre>::new(1., -1.)).apply_to(p);
+    assert_eq!(
+        translated,
+        MeasurePoint2d::<Metre>::with_uncertainty(3., 0., [0.1, 0.2], [0.3, 0.4])
+    );
+}
+
+#[test]
+fn measure_point_3d_operations() {
+    let p1 = MeasurePoint3d::<Metre>::with_uncertainty(10., 0., 2., [0.6; 3], [0.8; 3]);
+    let p2 = MeasurePoint3d::<Metre>::with_uncertainty(20., 4., 2., [0.8; 3], [0.6; 3]);
+    let mid = midpoint_3d(p1, p2);
+    assert_eq!(mid.x, 15.);
+    assert_eq!(mid.y, 2.);
+    assert_eq!(mid.z, 2.);
+    assert_eq_64!(mid.lower_uncertainty[0], 0.5);
+    assert_eq_64!(mid.upper_uncertainty[2], 0.5);
+    assert_eq!(barycentric_combination_3d(&[p1, p2], &[0.5, 0.5]), mid);
+    let m = Measure3d::<Metre>::with_uncertainty(1., 2., 3., [0.1, 0.2, 0.3], [0.4, 0.5, 0.6]);
+    let displacement = (p1 + m) - p1;
+    assert_eq!(displacement.z, 3.);
+    assert_eq_64!(
+        displacement.lower_uncertainty[2],
+        (0.6f64 * 0.6 + 0.3 * 0.3 + 0.8 * 0.8).sqrt()
+    );
+    assert_eq_64!(
+        displacement.upper_uncertainty[2],
+        (0.8f64 * 0.8 + 0.6 * 0.6 + 0.6 * 0.6).sqrt()
+    );
+    let z = (p1 - m).z();
+    assert_eq!(z.value, -1.);
+    assert_eq_64!(z.lower_uncertainty, (0.6f64 * 0.6 + 0.6 * 0.6).sqrt());
+    assert_eq_64!(z.upper_uncertainty, (0.8f64 * 0.8 + 0.3 * 0.3).sqrt());
+}
+
+#[test]
+fn measure_point_2d_formatting() {
+    let p = MeasurePoint2d::<Metre, f32>::with_uncertainty(3., 4., [0.1, 0.2], [0.3, 0.4]);
+    assert_eq!(format!("{}", p), "at (3 +0.3/-0.1, 4 +0.4/-0.2) m");
+    let p = MeasurePoint3d::<Metre, f32>::with_uncertainty(
+        1.,
+        2.,
+        3.,
+        [0.1, 0.2, 0.3],
+        [0.4, 0.5, 0.6],
+    );
+    assert_eq!(
+        format!("{:?}", p),
+        "at (1 +0.4/-0.1, 2 +0.5/-0.2, 3 +0.6/-0.3) m"
+    );
+}
+
+#[test]
+fn measure_2d_components() {
+    let m = Measure2d::<Metre>::with_uncertainty(3., 4., [0.1, 0.2], [0.3, 0.4]);
+    let y = m.y();
+    assert_eq!(y.value, 4.);
+    assert_eq!(y.lower_uncertainty, 0.2);
+    assert_eq!(y.upper_uncertainty, 0.4);
+}
+
+#[test]
+fn measure_2d_arithmetic() {
+    let m = Measure2d::<Metre>::with_uncertainty(3., 4., [0.3, 0.6], [0.6, 0.3])
+        - Measure2d::<Metre>::with_uncertainty(1., 1., [0.8, 0.4], [0.4, 0.8]);
+    assert_eq!(m.x, 2.);
+    assert_eq!(m.y, 3.);
+    assert_eq_64!(m.lower_uncertainty[0], 0.5);
+    assert_eq_64!(m.lower_uncertainty[1], 1.);
+    assert_eq_64!(m.upper_uncertainty[0], 1.);
+    assert_eq_64!(m.upper_uncertainty[1], 0.5);
+    let m = -m * 2.;
+    assert_eq!(m.x, -4.);
+    assert_eq_64!(m.lower_uncertainty[0], 2.);
+    assert_eq_64!(m.upper_uncertainty[0], 1.);
+}
+
+#[test]
+fn measure_2d_mul_measure() {
+    let m: Measure2d<Metre> =
+        Measure2d::<MetrePerSecond>::with_uncertainty(1., -2., [0.1, 0.2], [0.2, 0.1])
+            * Measure::<Second>::new(3.);
+    assert_eq!(m.x, 3.);
+    assert_eq!(m.y, -6.);
+    assert_eq_64!(m.lower_uncertainty[0], 0.3);
+    assert_eq_64!(m.upper_uncertainty[0], 0.6);
+    assert_eq_64!(m.lower_uncertainty[1], 0.6);
+    assert_eq_64!(m.upper_uncertainty[1], 0.3);
+}
+
+#[test]
+fn measure_2d_dot_product() {
+    let m: Measure<SquareMetre> =
+        Measure2d::<Metre>::with_uncertainty(3., 4., [0.1, 0.1], [0.2, 0.2])
+            * Measure2d::<Metre>::new(1., 0.);
+    assert_eq!(m.value, 3.);
+    assert_eq_64!(m.lower_uncertainty, 0.1);
+    assert_eq_64!(m.upper_uncertainty, 0.2);
+}
+
+#[test]
+fn measure_2d_squared() {
+    let m: Measure<SquareMetre> =
+        Measure2d::<Metre>::with_uncertainty(3., 4., [0.1, 0.1], [0.2, 0.2]).squared();
+    assert_eq!(m.value, 25.);
+    assert_eq_64!(m.lower_uncertainty, 1.);
+    assert_eq_64!(m.upper_uncertainty, 2.);
+}
+
+#[test]
+fn measure_2d_formatting() {
+    let m = Measure2d::<Metre, f32>::with_uncertainty(3., 4., [0.1, 0.2], [0.3, 0.4]);
+    assert_eq!(format!("{}", m), "(3 +0.3/-0.1, 4 +0.4/-0.2) m");
+}
+
+#[test]
+fn measure_3d_convert() {
+    let m = Measure3d::<Metre, f32>::with_uncertainty(1., 2., 3., [0.1, 0.2, 0.3], [0.4, 0.5, 0.6])
+        .convert::<MilliMetre>();
+    assert_eq!(m.z, 3000.);
+    assert_eq!(m.lower_uncertainty, [100., 200., 300.]);
+    assert_eq!(m.upper_uncertainty, [400., 500., 600.]);
+}
+
+#[test]
+fn measure_3d_arithmetic() {
+    let m = Measure3d::<Metre>::with_uncertainty(1., 2., 3., [0.3, 0.3, 0.3], [0.6, 0.6, 0.6])
+        + Measure3d::<Metre>::with_uncertainty(1., 1., 1., [0.4, 0.4, 0.4], [0.8, 0.8, 0.8]);
+    assert_eq!(m.z, 4.);
+    assert_eq_64!(m.lower_uncertainty[2], 0.5);
+    assert_eq_64!(m.upper_uncertainty[2], 1.);
+    let m = m / -2.;
+    assert_eq!(m.z, -2.);
+    assert_eq_64!(m.lower_uncertainty[2], 0.5);
+    assert_eq_64!(m.upper_uncertainty[2], 0.25);
+}
+
+#[test]
+fn measure_3d_formatting() {
+    let m = Measure3d::<Metre, f32>::with_uncertainty(1., 2., 3., [0.1, 0.2, 0.3], [0.4, 0.5, 0.6]);
+    assert_eq!(
+        format!("{:?}", m),
+        "(1 +0.4/-0.1, 2 +0.5/-0.2, 3 +0.6/-0.3) m"
+    );
+}
+
+#[test]
+fn measure_monte_carlo() {
+    let m = Measure::<Metre>::with_uncertainty(5., 0.1, 0.3);
+    let mut sampler = rs_measures::monte_carlo::Sampler::new(1);
+    let sample = m.sample(&mut sampler);
+    assert_eq!(sample.lower_uncertainty, 0.);
+    assert_eq!(sample.upper_uncertainty, 0.);
+    let result = monte_carlo(200_000, &mut sampler, |s| m.sample(s));
+    // The mean of a split normal distribution is shifted towards the larger half.
+    let expected_mean = 5. + (2. / std::f64::consts::PI).sqrt() * (0.3 - 0.1);
+    assert!((result.mean().value - expected_mean).abs() < 0.003);
+    let (low, high) = result.coverage_interval(0.682_689_492_137_086);
+    assert!((low.value - 4.9525).abs() < 0.003);
+    assert!((high.value - 5.3749).abs() < 0.005);
+    let measure = result.to_measure();
+    assert_eq!(measure.value, result.mean().value);
+    assert_eq_64!(measure.lower_uncertainty, measure.value - low.value);
+    assert_eq_64!(measure.upper_uncertainty, high.value - measure.value);
+}
+
+#[test]
+fn measure_2d_sample() {
+    let v = Measure2d::<Metre>::with_uncertainty(1., 2., [0.1, 0.], [0.3, 0.]);
+    let mut sampler = rs_measures::monte_carlo::Sampler::new(2);
+    for _ in 0..1000 {
+        let sample = v.sample(&mut sampler);
+        assert!(sample.x >= 1. - 0.1 * 6. && sample.x <= 1. + 0.3 * 6.);
+        assert_eq!(sample.y, 2.);
+        assert_eq!(sample.lower_uncertainty, [0., 0.]);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn measure_serde() {
+    let m = Measure::<Metre>::with_uncertainty(5., 0.1, 0.3);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(
+        json,
+        r#"{"value":5.0,"lower_uncertainty":0.1,"upper_uncertainty":0.3,"unit":"m"}"#
+    );
+    assert_eq!(serde_json::from_str::<Measure<Metre>>(&json).unwrap(), m);
+    let m: Measure<Metre> = serde_json::from_str(r#"{"value":5.0}"#).unwrap();
+    assert_eq!(m.lower_uncertainty, 0.);
+    assert_eq!(m.upper_uncertainty, 0.);
+    assert!(serde_json::from_str::<Measure<Metre>>(r#"{"value":5.0,"uncertainty":0.1}"#).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn measure_2d_serde() {
+    let m = Measure2d::<Metre>::with_uncertainty(1., 2., [0.1, 0.2], [0.3, 0.4]);
+    let json = serde_json::to_string(&m).unwrap();
+    assert_eq!(
+        json,
+        r#"{"x":1.0,"y":2.0,"lower_uncertainty":[0.1,0.2],"upper_uncertainty":[0.3,0.4],"unit":"m"}"#
+    );
+    assert_eq!(serde_json::from_str::<Measure2d<Metre>>(&json).unwrap(), m);
+    assert!(serde_json::from_str::<Measure2d<MilliMetre>>(&json).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn measure_point_3d_serde() {
+    let p = MeasurePoint3d::<Metre>::with_uncertainty(1., 2., 3., [0.1, 0.2, 0.3], [0.4, 0.5, 0.6]);
+    let json = serde_json::to_string(&p).unwrap();
+    assert_eq!(
+        json,
+        r#"{"x":1.0,"y":2.0,"z":3.0,"lower_uncertainty":[0.1,0.2,0.3],"upper_uncertainty":[0.4,0.5,0.6],"unit":"m"}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<MeasurePoint3d<Metre>>(&json).unwrap(),
+        p
+    );
+    assert!(serde_json::from_str::<MeasurePoint3d<MilliMetre>>(&json).is_err());
+}
+
+#[test]
+fn measure_parse() {
+    let m = Measure::<Metre>::with_uncertainty(5., 0.1, 0.3);
+    assert_eq!(m.to_string(), "5 +0.3/-0.1 m");
+    assert_eq!(m.to_string().parse::<Measure<Metre>>(), Ok(m));
+    let p = MeasurePoint::<Metre>::with_uncertainty(5., 0.1, 0.3);
+    assert_eq!(p.to_string().parse::<MeasurePoint<Metre>>(), Ok(p));
+    assert!("5 +0.3/-0.1 mm".parse::<Measure<Metre>>().is_err());
+}
+
+#[test]
+fn measure_3d_parse() {
+    let m = Measure3d::<Metre>::with_uncertainty(1., 2., 3., [0.1, 0.2, 0.3], [0.4, 0.5, 0.6]);
+    assert_eq!(m.to_string().parse::<Measure3d<Metre>>(), Ok(m));
+    let p = MeasurePoint2d::<Metre>::with_uncertainty(3., 4., [0.1, 0.2], [0.3, 0.4]);
+    assert_eq!(p.to_string().parse::<MeasurePoint2d<Metre>>(), Ok(p));
+    let p = MeasurePoint3d::<Metre>::with_uncertainty(1., 2., 3., [0.1, 0.2, 0.3], [0.4, 0.5, 0.6]);
+    assert_eq!(p.to_string().parse::<MeasurePoint3d<Metre>>(), Ok(p));
+}
@@ -0,0 +1,141 @@
+use rs_measures::{parsing::ParseMeasureError, unit_registry::UnitRegistry};
+rs_measures::define_measure_types! {
+    MeasureFeatures {
+        with_points: true,
+        with_directions: false,
+        with_2d: false,
+        with_3d: false,
+        with_transformations: false,
+        with_uncertainty: None,
+    }
+}
+
+mod test_utils;
+
+struct Length;
+
+struct Metre;
+impl MeasurementUnit for Metre {
+    type Property = Length;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " m";
+}
+
+struct Foot;
+impl MeasurementUnit for Foot {
+    type Property = Length;
+    const RATIO: f64 = 0.3048;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " ft";
+}
+
+struct Yard;
+impl MeasurementUnit for Yard {
+    type Property = Length;
+    const RATIO: f64 = 0.9144;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " yd";
+}
+
+struct SurveyYard;
+impl MeasurementUnit for SurveyYard {
+    type Property = Length;
+    const RATIO: f64 = 3600. / 3937.;
+    const OFFSET: f64 = 0.;
+    const SUFFIX: &'static str = " yd";
+}
+
+struct Temperature;
+
+struct DegreeCelsius;
+impl MeasurementUnit for DegreeCelsius {
+    type Property = Temperature;
+    const RATIO: f64 = 1.;
+    const OFFSET: f64 = 273.15;
+    const SUFFIX: &'static str = " \u{b0}C";
+}
+
+struct DegreeFahrenheit;
+impl MeasurementUnit for DegreeFahrenheit {
+    type Property = Temperature;
+    const RATIO: f64 = 5. / 9.;
+    const OFFSET: f64 = 273.15 - 32. * 5. / 9.;
+    const SUFFIX: &'static str = " \u{b0}F";
+}
+
+fn lengths() -> UnitRegistry<Length> {
+    UnitRegistry::new()
+        .with_unit::<Metre>()
+        .with_unit::<Foot>()
+        .with_unit::<Yard>()
+}
+
+#[test]
+fn measure_parse_in_any_unit() {
+    let registry = lengths();
+    assert_eq!(registry.units().len(), 3);
+    assert_eq_64!(
+        Measure::<Metre>::parse_in_any_unit("3 ft", &registry)
+            .unwrap()
+            .value,
+        0.9144
+    );
+    assert_eq_64!(
+        Measure::<Metre>::parse_in_any_unit("0.9144 m", &registry)
+            .unwrap()
+            .value,
+        0.9144
+    );
+    assert_eq_64!(
+        Measure::<Metre>::parse_in_any_unit("1yd", &registry)
+            .unwrap()
+            .value,
+        0.9144
+    );
+    assert_eq_64!(
+        Measure::<Foot, f32>::parse_in_any_unit("1 yd", &registry)
+            .unwrap()
+            .value,
+        3.
+    );
+}
+
+#[test]
+fn measure_point_parse_in_any_unit() {
+    let mut registry = UnitRegistry::<Temperature>::default();
+    registry.register::<DegreeCelsius>();
+    registry.register::<DegreeFahrenheit>();
+    registry.register::<DegreeCelsius>();
+    assert_eq!(registry.units().len(), 2);
+    assert_eq_64!(
+        MeasurePoint::<DegreeCelsius>::parse_in_any_unit("212 \u{b0}F", &registry)
+            .unwrap()
+            .value,
+        100.
+    );
+    assert_eq_64!(
+        MeasurePoint::<DegreeFahrenheit>::parse_in_any_unit("-40 \u{b0}C", &registry)
+            .unwrap()
+            .value,
+        -40.
+    );
+}
+
+#[test]
+fn unknown_and_ambiguous_suffixes() {
+    assert_eq!(
+        Measure::<Metre>::parse_in_any_unit("3 mi", &lengths()),
+        Err(ParseMeasureError::UnknownSuffix("mi".to_string()))
+    );
+    let registry = lengths().with_unit::<SurveyYard>();
+    assert_eq!(
+        Measure::<Metre>::parse_in_any_unit("3 yd", &registry),
+        Err(ParseMeasureError::AmbiguousSuffix("yd".to_string()))
+    );
+    assert_eq!(registry.find("ft").map(|unit| unit.ratio), Ok(0.3048));
+    assert_eq!(
+        Measure::<Metre>::parse_in_any_unit("ft", &registry),
+        Err(ParseMeasureError::InvalidNumber("ft".to_string()))
+    );
+}
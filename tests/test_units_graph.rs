@@ -0,0 +1,64 @@
+use std::process::Command;
+
+fn units_graph(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_units-graph"))
+        .args(args)
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn dot() {
+    let (success, dot) = units_graph(&["dot", "src/catalog"]);
+    assert!(success);
+    assert!(dot.starts_with("digraph units {\n"));
+    assert!(dot.contains("    subgraph \"cluster_Energy\" {\n"));
+    assert!(dot.contains("        \"Joule\" [label=\"Joule\\nJ\"];\n"));
+    assert!(dot.contains("tooltip=\"Joule == Newton * Metre\"];\n"));
+    assert!(dot.ends_with("}\n"));
+}
+
+#[test]
+fn json() {
+    let (success, json) = units_graph(&["json", "src/catalog"]);
+    assert!(success);
+    let graph: serde_json::Value = serde_json::from_str(&json).unwrap();
+    let joule = graph["units"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|unit| unit["name"] == "Joule")
+        .unwrap();
+    assert_eq!(joule["property"], "Energy");
+    assert_eq!(joule["suffix"], " J");
+    let relationship = graph["relationships"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|relationship| relationship["text"] == "SquareMetre == Metre * =")
+        .unwrap();
+    assert_eq!(relationship["operation"], "product");
+    assert_eq!(relationship["operands"][1]["unit"], "Metre");
+}
+
+#[test]
+fn derive() {
+    let (success, steps) = units_graph(&["derive", "src/catalog", "KiloWattHour", "Watt", "Hour"]);
+    assert!(success);
+    assert_eq!(
+        steps,
+        "KiloWattHour can be obtained from Watt, Hour:
+    Watt: given
+    KiloWatt = Watt.convert::<KiloWatt>()
+    Hour: given
+    KiloWattHour = KiloWatt * Hour    [KiloWattHour == KiloWatt * Hour]
+"
+    );
+    let (success, steps) = units_graph(&["derive", "src/catalog", "Joule", "Newton", "Second"]);
+    assert!(!success);
+    assert_eq!(steps, "Joule cannot be obtained from Newton, Second.\n");
+}